@@ -0,0 +1,81 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonType } from "./JsonType";
+
+/**
+ * Outcome of one assertion after a request ran.
+ */
+export type AssertionResult = { 
+/**
+ * Whether the response satisfied it.
+ */
+passed: boolean, 
+/**
+ * The value found in the response, when there was one: the status, the
+ * header value, the `JSONPath` match, the regex match, the response time
+ * in milliseconds or the first schema violation.
+ */
+actual?: string, 
+/**
+ * Why the assertion failed.
+ */
+message?: string, } & ({ "check": "status_equals", 
+/**
+ * Expected status code.
+ */
+status: number, } | { "check": "status_in_range", 
+/**
+ * Lowest accepted status code.
+ */
+min: number, 
+/**
+ * Highest accepted status code.
+ */
+max: number, } | { "check": "header_exists", 
+/**
+ * Header name.
+ */
+name: string, } | { "check": "header_matches", 
+/**
+ * Header name.
+ */
+name: string, 
+/**
+ * The regular expression, searched anywhere in the value.
+ */
+pattern: string, } | { "check": "json_path_equals", 
+/**
+ * The `JSONPath` query.
+ */
+path: string, 
+/**
+ * Expected value.
+ */
+value: unknown, } | { "check": "json_path_contains", 
+/**
+ * The `JSONPath` query.
+ */
+path: string, 
+/**
+ * Value looked for.
+ */
+value: unknown, } | { "check": "json_path_type", 
+/**
+ * The `JSONPath` query.
+ */
+path: string, 
+/**
+ * Expected type.
+ */
+json_type: JsonType, } | { "check": "body_matches", 
+/**
+ * The regular expression, searched anywhere in the body.
+ */
+pattern: string, } | { "check": "response_time_below", 
+/**
+ * Exclusive upper bound on the total request time.
+ */
+max_ms: number, } | { "check": "matches_schema", 
+/**
+ * The JSON Schema; the draft is detected from `$schema`.
+ */
+schema: unknown, });
@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectToOverride } from "./ConnectToOverride";
+import type { ProxyConfig } from "./ProxyConfig";
+import type { ResolveOverride } from "./ResolveOverride";
+import type { TlsConfig } from "./TlsConfig";
 
 /**
  * A named environment with variable overrides for a collection.
@@ -14,4 +18,23 @@ name: string,
 /**
  * Variable key-value pairs for this environment.
  */
-variables?: { [key in string]: string }, };
+variables?: { [key in string]: string }, 
+/**
+ * TLS settings for requests executed from this collection while this environment
+ * is active. Per-call settings override them field by field.
+ */
+tls?: TlsConfig, 
+/**
+ * Proxy settings while this environment is active; override the global proxy.
+ */
+proxy?: ProxyConfig, 
+/**
+ * Host names pinned to fixed addresses while this environment is active
+ * (curl `--resolve`).
+ */
+resolve?: Array<ResolveOverride>, 
+/**
+ * Hosts and ports connected to in place of requested ones while this
+ * environment is active (curl `--connect-to`).
+ */
+connect_to?: Array<ConnectToOverride>, };
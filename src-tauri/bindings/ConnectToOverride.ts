@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Connect to another host or port in place of the requested one, like curl
+ * `--connect-to`.
+ *
+ * Unlike [`ResolveOverride`] the target may be a host name. TLS
+ * verification and the `Host` header keep the requested name.
+ */
+export type ConnectToOverride = { 
+/**
+ * Requested host the override applies to; every host when omitted.
+ */
+host?: string, 
+/**
+ * Requested port the override applies to; every port when omitted.
+ */
+port?: number, 
+/**
+ * Host to connect to instead; the requested host when omitted.
+ */
+to_host?: string, 
+/**
+ * Port to connect to instead; the requested port when omitted.
+ */
+to_port?: number, };
@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A stored HTTP cookie.
+ */
+export type Cookie = { 
+/**
+ * Cookie name.
+ */
+name: string, 
+/**
+ * Cookie value.
+ */
+value: string, 
+/**
+ * Domain the cookie belongs to, without a leading dot.
+ */
+domain: string, 
+/**
+ * Path prefix the cookie applies to.
+ */
+path: string, 
+/**
+ * Whether subdomains of `domain` also receive the cookie (`Domain=` attribute set).
+ */
+include_subdomains?: boolean, 
+/**
+ * Only sent over HTTPS.
+ */
+secure?: boolean, 
+/**
+ * Not exposed to scripts (`HttpOnly`).
+ */
+http_only?: boolean, 
+/**
+ * Expiry as Unix seconds; `None` for a session cookie.
+ */
+expires?: number, };
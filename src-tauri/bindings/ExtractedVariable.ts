@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VariableScope } from "./VariableScope";
+
+/**
+ * Outcome of one extraction rule after a request ran.
+ */
+export type ExtractedVariable = { 
+/**
+ * Name of the variable.
+ */
+variable: string, 
+/**
+ * Where the value was stored.
+ */
+scope: VariableScope, 
+/**
+ * Environment the value was stored in, for the environment scope.
+ */
+environment?: string, 
+/**
+ * The value stored; `None` when extraction failed and the variable was
+ * left unchanged.
+ */
+value?: string, 
+/**
+ * Why extraction failed.
+ */
+error?: string, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VariableScope } from "./VariableScope";
+
+/**
+ * Captures one value of a response into a variable.
+ */
+export type ExtractionRule = { 
+/**
+ * Name of the variable to set.
+ */
+variable: string, 
+/**
+ * Where the value is stored (default: runtime).
+ */
+scope?: VariableScope, } & ({ "from": "json_path", 
+/**
+ * The `JSONPath` query.
+ */
+path: string, } | { "from": "header", 
+/**
+ * Header name.
+ */
+name: string, } | { "from": "regex", 
+/**
+ * The regular expression.
+ */
+pattern: string, 
+/**
+ * Capture group to take; defaults to the first group, or the whole
+ * match when the pattern has none.
+ */
+group?: number, } | { "from": "status" });
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An argument or input object field.
+ */
+export type GraphqlInputValue = { 
+/**
+ * Name of the argument or field.
+ */
+name: string, 
+/**
+ * Type as written in SDL, e.g. `[ID!]!`.
+ */
+type_ref: string, 
+/**
+ * Default value, as GraphQL source.
+ */
+default_value?: string, 
+/**
+ * Description from the schema.
+ */
+description?: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A problem found in a GraphQL request.
+ */
+export type GraphqlIssue = { 
+/**
+ * What is wrong.
+ */
+message: string, 
+/**
+ * Line of the offending token, starting at 1.
+ */
+line?: number, 
+/**
+ * Column of the offending token, starting at 1.
+ */
+column?: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of a named GraphQL type.
+ */
+export type GraphqlTypeKind = "scalar" | "object" | "interface" | "union" | "enum" | "input_object";
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single HTTP header field.
+ */
+export type HttpHeader = { 
+/**
+ * Header name as sent or received (case preserved).
+ */
+name: string, 
+/**
+ * Header value.
+ */
+value: string, };
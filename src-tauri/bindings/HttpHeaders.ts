@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpHeader } from "./HttpHeader";
+
+/**
+ * HTTP header fields in wire order, keeping repeated names such as
+ * `Set-Cookie`, `Link`, `Vary` or `WWW-Authenticate`.
+ *
+ * Serialized as a list of `{ name, value }` fields. An object mapping names
+ * to values (the format used before repeated headers were kept) is still
+ * accepted, so older history files load unchanged.
+ */
+export type HttpHeaders = Array<HttpHeader>;
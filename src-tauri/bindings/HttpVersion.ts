@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * HTTP version preference for a request.
+ *
+ * Set per request on [`RequestParams::http_version`], or on a collection and
+ * its requests, where the request's preference wins.
+ */
+export type HttpVersion = "auto" | "http1.0" | "http1.1" | "http2" | "h2c" | "http3";
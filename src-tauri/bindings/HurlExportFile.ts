@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A `.hurl` file written by an export.
+ */
+export type HurlExportFile = { 
+/**
+ * Path of the file.
+ */
+path: string, 
+/**
+ * Number of requests in the file.
+ */
+request_count: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HurlExportGrouping } from "./HurlExportGrouping";
+
+/**
+ * Options of a Hurl export.
+ */
+export type HurlExportOptions = { 
+/**
+ * How requests are split into files (default: one per collection).
+ */
+group_by?: HurlExportGrouping, 
+/**
+ * Environment whose variables are exported (default: the active one).
+ */
+environment?: string, };
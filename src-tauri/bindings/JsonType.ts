@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Type of a JSON value, for [`Assertion::JsonPathType`].
+ */
+export type JsonType = "string" | "number" | "boolean" | "array" | "object" | "null";
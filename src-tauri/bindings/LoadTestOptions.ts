@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a load test is run.
+ *
+ * At least one of `iterations` and `duration_ms` must be set; with both, the
+ * test stops at whichever limit is reached first.
+ */
+export type LoadTestOptions = { 
+/**
+ * Total number of requests to send.
+ */
+iterations?: number, 
+/**
+ * How long to keep sending requests, in milliseconds.
+ */
+duration_ms?: number, 
+/**
+ * Number of workers sending requests at the same time (default: 1).
+ */
+concurrency?: number, 
+/**
+ * Time over which workers are started one after another, in
+ * milliseconds (default: 0, all at once).
+ */
+ramp_up_ms?: number, 
+/**
+ * Timeout for each request, in milliseconds (default: 30000).
+ */
+timeout_ms?: number, 
+/**
+ * Interval between live progress updates, in milliseconds (default: 1000).
+ */
+progress_interval_ms?: number, };
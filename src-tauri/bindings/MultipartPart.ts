@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One part of a `multipart/form-data` body: a text field or a file.
+ */
+export type MultipartPart = { 
+/**
+ * Form field name.
+ */
+name: string, 
+/**
+ * Inline text value. Exactly one of `value` and `file` must be set.
+ */
+value?: string, 
+/**
+ * Path of a file whose contents are streamed as the part.
+ */
+file?: string, 
+/**
+ * Filename sent in `Content-Disposition`. Defaults to the file's name
+ * for file parts; setting it on a text part sends the value as a file.
+ */
+filename?: string, 
+/**
+ * Content type of the part. Curl infers it from the extension for file
+ * parts when omitted.
+ */
+content_type?: string, };
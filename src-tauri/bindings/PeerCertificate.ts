@@ -0,0 +1,47 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One certificate of the chain, from curl's certinfo.
+ */
+export type PeerCertificate = { 
+/**
+ * Subject distinguished name, e.g. `CN = api.example.com, O = Example`.
+ */
+subject: string, 
+/**
+ * Issuer distinguished name.
+ */
+issuer: string, 
+/**
+ * Subject alternative names as curl prints them, e.g. `DNS:api.example.com`
+ * or `IP Address:10.0.0.1`.
+ */
+subject_alt_names?: Array<string>, 
+/**
+ * Serial number in hex.
+ */
+serial_number?: string, 
+/**
+ * Signature algorithm, e.g. `sha256WithRSAEncryption`.
+ */
+signature_algorithm?: string, 
+/**
+ * Public key algorithm, e.g. `rsaEncryption`.
+ */
+public_key_algorithm?: string, 
+/**
+ * Start of the validity window (RFC 3339 UTC).
+ */
+not_before?: string, 
+/**
+ * End of the validity window (RFC 3339 UTC).
+ */
+not_after?: string, 
+/**
+ * Whole days until `not_after`; negative once expired.
+ */
+days_until_expiry?: number, 
+/**
+ * SHA-256 fingerprint of the DER encoding, as colon-separated hex.
+ */
+sha256_fingerprint?: string, };
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpHeaders } from "./HttpHeaders";
+
+/**
+ * A redirect response that was followed on the way to the final response.
+ */
+export type RedirectHop = { 
+/**
+ * URL requested in this hop.
+ */
+url: string, 
+/**
+ * HTTP status code of the redirect (e.g. 301, 302, 307).
+ */
+status: number, 
+/**
+ * HTTP status text.
+ */
+status_text: string, 
+/**
+ * Response headers of the redirect, in wire order.
+ */
+headers: HttpHeaders, 
+/**
+ * Raw `Location` header value.
+ */
+location?: string, 
+/**
+ * Time from the start of this hop until its response headers arrived, in milliseconds.
+ */
+duration_ms: number, };
@@ -1,4 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectToOverride } from "./ConnectToOverride";
+import type { CookieJarScope } from "./CookieJarScope";
+import type { HttpHeaders } from "./HttpHeaders";
+import type { HttpVersion } from "./HttpVersion";
+import type { MultipartPart } from "./MultipartPart";
+import type { ProxyConfig } from "./ProxyConfig";
+import type { ResolveOverride } from "./ResolveOverride";
+import type { RetryPolicy } from "./RetryPolicy";
+import type { TlsConfig } from "./TlsConfig";
 
 /**
  * Parameters for an HTTP request.
@@ -13,14 +22,74 @@ url: string,
  */
 method: string, 
 /**
- * Request headers as key-value pairs.
+ * Request headers in the order they are sent; names may repeat.
  */
-headers: Record<string, string>, 
+headers: HttpHeaders, 
 /**
  * Optional request body.
  */
 body: string | null, 
+/**
+ * File whose bytes are streamed from disk as the body, instead of `body`.
+ */
+body_file?: string, 
+/**
+ * `multipart/form-data` parts. When present they are sent instead of
+ * `body` and `body_file`.
+ */
+multipart?: Array<MultipartPart>, 
 /**
  * Request timeout in milliseconds (default: 30000).
  */
-timeout_ms: number, };
+timeout_ms: number, 
+/**
+ * TLS settings (client certificate, CA bundle, verification).
+ */
+tls?: TlsConfig, 
+/**
+ * Proxy settings. When omitted, the global proxy settings apply.
+ */
+proxy?: ProxyConfig, 
+/**
+ * Cookie jar to send cookies from and store `Set-Cookie` responses into.
+ * Without one, the request runs with no cookie engine.
+ */
+cookie_jar?: CookieJarScope, 
+/**
+ * Follow `Location` redirects (default: true).
+ */
+follow_redirects?: boolean, 
+/**
+ * Maximum number of redirects to follow before failing (default: 10).
+ */
+max_redirects?: number, 
+/**
+ * HTTP version to speak. Anything other than `auto` is enforced: the
+ * request fails rather than falling back to another version.
+ */
+http_version?: HttpVersion, 
+/**
+ * Retry failed attempts under this policy. Without one, the request is
+ * tried once. Not applied to streaming requests.
+ */
+retry?: RetryPolicy, 
+/**
+ * Path of a Unix domain socket to connect through instead of TCP. The
+ * URL still supplies the scheme, `Host` header and path, e.g.
+ * `http://localhost/v1.45/containers/json` for the Docker API.
+ */
+unix_socket?: string, 
+/**
+ * Host names pinned to fixed addresses (curl `--resolve`).
+ */
+resolve?: Array<ResolveOverride>, 
+/**
+ * Hosts and ports connected to in place of requested ones (curl
+ * `--connect-to`).
+ */
+connect_to?: Array<ConnectToOverride>, 
+/**
+ * Capture a wire-level trace of the exchange in
+ * [`HttpResponse::trace`].
+ */
+verbose?: boolean, };
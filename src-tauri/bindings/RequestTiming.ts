@@ -31,4 +31,17 @@ tls_ms: number | null,
 /**
  * Time to first byte in milliseconds.
  */
-first_byte_ms: number | null, };
+first_byte_ms: number | null, 
+/**
+ * Time spent on redirects before the final request started, in milliseconds.
+ */
+redirect_ms?: number, 
+/**
+ * Time from the connection being ready to the last byte received
+ * (sending the request, server wait and download), in milliseconds.
+ */
+transfer_ms?: number, 
+/**
+ * Time from the first to the last response byte, in milliseconds.
+ */
+download_ms?: number, };
@@ -0,0 +1,51 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RunRequestResult } from "./RunRequestResult";
+
+/**
+ * Summary of a collection run.
+ */
+export type RunReport = { 
+/**
+ * Run ID; also the correlation ID its requests were sent with.
+ */
+run_id: string, 
+/**
+ * ID of the collection that was run.
+ */
+collection_id: string, 
+/**
+ * Name of the collection that was run.
+ */
+collection_name: string, 
+/**
+ * Environment the run used, if one was active.
+ */
+environment?: string, 
+/**
+ * RFC 3339 UTC timestamp of the start of the run.
+ */
+started_at: string, 
+/**
+ * Wall-clock duration of the run, in milliseconds, including delays.
+ */
+duration_ms: number, 
+/**
+ * Number of passed requests.
+ */
+passed: number, 
+/**
+ * Number of failed requests.
+ */
+failed: number, 
+/**
+ * Number of errored requests.
+ */
+errored: number, 
+/**
+ * Number of skipped requests.
+ */
+skipped: number, 
+/**
+ * Per-request results, in run order.
+ */
+results: Array<RunRequestResult>, };
@@ -0,0 +1,55 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RunRequestResult } from "./RunRequestResult";
+
+/**
+ * Live update of a running collection.
+ */
+export type RunUpdate = { "kind": "started", 
+/**
+ * ID of the collection being run.
+ */
+collection_id: string, 
+/**
+ * Number of requests that will run.
+ */
+total: number, } | { "kind": "request_started", 
+/**
+ * Zero-based position of the request in the run.
+ */
+index: number, 
+/**
+ * Request ID within the collection.
+ */
+request_id: string, 
+/**
+ * Request name.
+ */
+name: string, } | { "kind": "request_finished", 
+/**
+ * Zero-based position of the request in the run.
+ */
+index: number, 
+/**
+ * The request's result.
+ */
+result: RunRequestResult, } | { "kind": "finished", 
+/**
+ * Number of passed requests.
+ */
+passed: number, 
+/**
+ * Number of failed requests.
+ */
+failed: number, 
+/**
+ * Number of errored requests.
+ */
+errored: number, 
+/**
+ * Number of skipped requests.
+ */
+skipped: number, 
+/**
+ * Wall-clock duration of the run, in milliseconds.
+ */
+duration_ms: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Wire format of a streamed response body.
+ */
+export type StreamFormat = "sse" | "ndjson" | "raw";
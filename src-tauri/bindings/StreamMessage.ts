@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single message decoded from a streamed response.
+ */
+export type StreamMessage = { 
+/**
+ * Zero-based position of the message in the stream.
+ */
+seq: number, 
+/**
+ * Milliseconds since the request started when the message arrived.
+ */
+elapsed_ms: number, 
+/**
+ * SSE `event:` field, if any.
+ */
+event?: string, 
+/**
+ * SSE `id:` field (last event ID), if any.
+ */
+id?: string, 
+/**
+ * Message payload: SSE data, one NDJSON line, or a raw chunk.
+ */
+data: string, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VariableScope } from "./VariableScope";
+
+/**
+ * Payload of the `variable:changed` event, sent for every variable an
+ * extraction rule set.
+ */
+export type VariableChanged = { 
+/**
+ * ID of the collection the variable belongs to.
+ */
+collection_id: string, 
+/**
+ * ID of the request whose response the value came from.
+ */
+request_id: string, 
+/**
+ * Name of the variable.
+ */
+variable: string, 
+/**
+ * Where the value was stored.
+ */
+scope: VariableScope, 
+/**
+ * Environment the value was stored in, for the environment scope.
+ */
+environment?: string, 
+/**
+ * The new value.
+ */
+value: string, };
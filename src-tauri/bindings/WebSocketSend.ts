@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebSocketMessageKind } from "./WebSocketMessageKind";
+
+/**
+ * A message to send on an open session.
+ */
+export type WebSocketSend = { 
+/**
+ * Message kind (default: text). Close frames are sent with
+ * `close_websocket` instead.
+ */
+kind?: WebSocketMessageKind, 
+/**
+ * Payload: text, or base64 for binary messages.
+ */
+data: string, };
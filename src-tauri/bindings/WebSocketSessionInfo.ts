@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpHeaders } from "./HttpHeaders";
+
+/**
+ * An open WebSocket session.
+ */
+export type WebSocketSessionInfo = { 
+/**
+ * Session ID, used to send messages and close the session.
+ */
+session_id: string, 
+/**
+ * URL the session is connected to.
+ */
+url: string, 
+/**
+ * Subprotocol the server selected, if any.
+ */
+protocol?: string, 
+/**
+ * Handshake response headers, in wire order.
+ */
+headers: HttpHeaders, 
+/**
+ * RFC 3339 UTC timestamp of the handshake.
+ */
+opened_at: string, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WireTraceEntry } from "./WireTraceEntry";
+
+/**
+ * Wire-level trace of a request, captured when [`RequestParams::verbose`]
+ * is set. Bodies are not recorded.
+ */
+export type WireTrace = { 
+/**
+ * Messages in the order curl reported them.
+ */
+entries: Array<WireTraceEntry>, 
+/**
+ * Whether messages were dropped because the trace grew too large.
+ */
+truncated?: boolean, };
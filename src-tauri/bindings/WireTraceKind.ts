@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of a [`WireTraceEntry`], after curl's debug callback.
+ */
+export type WireTraceKind = "info" | "header_out" | "header_in";
@@ -1,5 +1,6 @@
 //! Tauri build script - configures the Tauri application build process.
 
 fn main() {
+    #[cfg(feature = "desktop")]
     tauri_build::build();
 }
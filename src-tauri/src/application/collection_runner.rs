@@ -0,0 +1,500 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Collection runner — sends a collection's requests in `seq` order.
+//!
+//! Requests run one at a time against the active environment, with the
+//! same parameters `execute_request` would use; values captured by a
+//! request's extraction rules are available to the requests after it, and
+//! a request with assertions passes or fails on them rather than its status.
+//! Progress is published as [`CollectionRunEvent`]s while the run goes on;
+//! the [`RunReport`] is returned once it is over.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::application::request_resolver::collection_request_params;
+use crate::application::variable_extraction::{
+    apply_extractions, changes_environment, save_environment_values_in_dir,
+};
+use crate::domain::assertion::evaluate_assertions;
+use crate::domain::collection::run::{
+    CollectionRunEvent, RunOptions, RunOutcome, RunReport, RunRequestResult, RunUpdate,
+};
+use crate::domain::collection::{Collection, CollectionRequest};
+use crate::domain::errors::AppError;
+use crate::domain::extraction::ExtractedVariable;
+use crate::domain::http::HttpResponse;
+use crate::infrastructure::http::{Cancellation, execute_http_request};
+
+/// Default per-request timeout, matching `execute_request`.
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+
+/// Run the requests of `collection` selected by `options`.
+///
+/// Every request is sent with `run_id` as its correlation ID, so
+/// `cancel_request` with the run ID aborts the request in flight, or the
+/// delay between two requests, and ends the run; the requests left are
+/// reported as skipped. Relative file paths resolve against `dir`, where the
+/// collection's environment is updated when an extraction rule sets an
+/// environment variable. A dropped `events` receiver does not stop the run.
+pub async fn run_collection(
+    collection: &Collection,
+    dir: &Path,
+    options: &RunOptions,
+    run_id: &str,
+    events: &UnboundedSender<CollectionRunEvent>,
+) -> RunReport {
+    let publish = |update: RunUpdate| {
+        let _ = events.send(CollectionRunEvent {
+            run_id: run_id.to_string(),
+            update,
+        });
+    };
+    let requests: Vec<_> = collection
+        .sorted_requests()
+        .into_iter()
+        .filter(|request| options.selects(request))
+        .collect();
+    let timeout_ms = options.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS);
+    // Environment values captured during the run apply to later requests
+    let mut working = collection.clone();
+
+    let started = Instant::now();
+    let mut report = RunReport::new(
+        run_id,
+        &collection.id,
+        &collection.metadata.name,
+        collection.active_environment.clone(),
+        chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    );
+    publish(RunUpdate::Started {
+        collection_id: collection.id.clone(),
+        total: u32::try_from(requests.len()).unwrap_or(u32::MAX),
+    });
+
+    let cancellation = Cancellation::register(run_id);
+    let mut stopped = false;
+    for (index, request) in requests.into_iter().enumerate() {
+        let index = u32::try_from(index).unwrap_or(u32::MAX);
+        if !stopped && index > 0 && options.delay_ms > 0 {
+            cancellation
+                .sleep(Duration::from_millis(options.delay_ms))
+                .await;
+        }
+        stopped |= cancellation.is_cancelled();
+        if stopped {
+            let result = RunRequestResult::skipped(request);
+            publish(RunUpdate::RequestFinished {
+                index,
+                result: result.clone(),
+            });
+            report.record(result);
+            continue;
+        }
+
+        publish(RunUpdate::RequestStarted {
+            index,
+            request_id: request.id.clone(),
+            name: request.name.clone(),
+        });
+        // A request whose variables do not resolve errors without being sent
+        let prepared = collection_request_params(&working, request, dir, timeout_ms);
+        let url = prepared
+            .as_ref()
+            .map_or_else(|_| request.url.clone(), |params| params.url.clone());
+        let request_started = Instant::now();
+        let outcome = match prepared {
+            Ok(params) => execute_http_request(params, Some(run_id.to_string())).await,
+            Err(e) => Err(e),
+        };
+        let mut result = RunRequestResult {
+            url,
+            duration_ms: u64::try_from(request_started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            ..RunRequestResult::skipped(request)
+        };
+        match outcome {
+            Ok(response) => {
+                result.assertions = evaluate_assertions(&request.assertions, &response);
+                result.outcome = RunOutcome::of_response(response.status, &result.assertions);
+                result.extracted = extract_variables(&mut working, request, &response, dir);
+                result.status = Some(response.status);
+                result.status_text = Some(response.status_text);
+            }
+            Err(e) => {
+                let error = serde_json::from_str::<AppError>(&e).ok();
+                stopped = error
+                    .as_ref()
+                    .is_some_and(|error| error.code == "REQUEST_CANCELLED");
+                result.outcome = RunOutcome::Errored;
+                result.error =
+                    Some(error.map_or(e, |error| format!("[{}] {}", error.code, error.message)));
+            }
+        }
+        stopped |= options.stop_on_failure && result.outcome != RunOutcome::Passed;
+        publish(RunUpdate::RequestFinished {
+            index,
+            result: result.clone(),
+        });
+        report.record(result);
+    }
+
+    report.duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    publish(RunUpdate::Finished {
+        passed: report.passed,
+        failed: report.failed,
+        errored: report.errored,
+        skipped: report.skipped,
+        duration_ms: report.duration_ms,
+    });
+    report
+}
+
+/// Apply the extraction rules of `request` to `response`, storing
+/// environment values in the collection saved in `dir` as well.
+///
+/// Only the environment values are written back, so edits made to the
+/// collection while the run goes on are kept.
+fn extract_variables(
+    collection: &mut Collection,
+    request: &CollectionRequest,
+    response: &HttpResponse,
+    dir: &Path,
+) -> Vec<ExtractedVariable> {
+    let extracted = apply_extractions(collection, request, response);
+    if changes_environment(&extracted)
+        && let Err(e) = save_environment_values_in_dir(dir, &collection.id, &extracted)
+    {
+        tracing::warn!("Failed to save extracted environment variables: {e}");
+    }
+    extracted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::assertion::Assertion;
+    use crate::domain::extraction::{ExtractionRule, ExtractionSource, VariableScope};
+    use crate::infrastructure::storage::collection_store::with_collections_dir_override_async;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serve `count` requests, answering `/status/<code>` with that status
+    /// and anything else with 200.
+    fn start_server(count: usize) -> Option<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+        let addr = listener.local_addr().ok()?;
+        std::thread::spawn(move || {
+            for _ in 0..count {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                let _ = reader.read_line(&mut request_line);
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                    line.clear();
+                }
+                let status = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|path| path.strip_prefix("/status/"))
+                    .unwrap_or("200")
+                    .to_string();
+                let _ = write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Test\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+            }
+        });
+        Some(format!("http://{addr}"))
+    }
+
+    fn collection(base_url: &str, paths: &[(&str, &str, &[&str])]) -> Collection {
+        let mut collection = Collection::new("Runner");
+        collection
+            .variables
+            .insert("base".to_string(), base_url.to_string());
+        // Declared in reverse so the run has to sort by seq
+        for (seq, (name, path, tags)) in paths.iter().enumerate().rev() {
+            collection.requests.push(CollectionRequest {
+                id: format!("req_{name}"),
+                name: (*name).to_string(),
+                seq: u32::try_from(seq + 1).unwrap(),
+                method: "GET".to_string(),
+                url: format!("{{{{base}}}}{path}"),
+                tags: tags.iter().map(ToString::to_string).collect(),
+                ..Default::default()
+            });
+        }
+        collection
+    }
+
+    async fn run(collection: &Collection, options: &RunOptions) -> (RunReport, Vec<RunUpdate>) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let report = with_collections_dir_override_async(dir.path().to_path_buf(), || async {
+            run_collection(collection, dir.path(), options, "run_test", &tx).await
+        })
+        .await;
+        drop(tx);
+        let mut updates = Vec::new();
+        while let Some(event) = rx.recv().await {
+            assert_eq!(event.run_id, "run_test");
+            updates.push(event.update);
+        }
+        (report, updates)
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_runs_requests_in_seq_order_and_reports_progress() {
+        let Some(base) = start_server(3) else {
+            return;
+        };
+        let collection = collection(
+            &base,
+            &[
+                ("first", "/a", &[]),
+                ("second", "/status/500", &[]),
+                ("third", "/c", &[]),
+            ],
+        );
+
+        let (report, updates) = run(&collection, &RunOptions::default()).await;
+
+        let names: Vec<_> = report.results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["first", "second", "third"]);
+        assert_eq!(report.results[0].url, format!("{base}/a"));
+        assert_eq!(report.results[1].status, Some(500));
+        assert_eq!((report.passed, report.failed, report.skipped), (2, 1, 0));
+        assert_eq!(updates.len(), 8);
+        assert_eq!(
+            updates[0],
+            RunUpdate::Started {
+                collection_id: collection.id.clone(),
+                total: 3
+            }
+        );
+        assert!(matches!(
+            updates[7],
+            RunUpdate::Finished {
+                passed: 2,
+                failed: 1,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_stop_on_failure_skips_the_rest() {
+        let Some(base) = start_server(2) else {
+            return;
+        };
+        let collection = collection(
+            &base,
+            &[
+                ("ok", "/a", &[]),
+                ("broken", "/status/503", &[]),
+                ("never", "/c", &[]),
+            ],
+        );
+        let options = RunOptions {
+            stop_on_failure: true,
+            ..Default::default()
+        };
+
+        let (report, _) = run(&collection, &options).await;
+
+        let outcomes: Vec<_> = report.results.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![RunOutcome::Passed, RunOutcome::Failed, RunOutcome::Skipped]
+        );
+        assert!(!report.success());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_tag_filter_and_connection_errors() {
+        let collection = collection(
+            "http://127.0.0.1:1",
+            &[("smoke", "/a", &["smoke"]), ("slow", "/b", &["slow"])],
+        );
+        let options = RunOptions {
+            tags: vec!["smoke".to_string()],
+            timeout_ms: Some(5_000),
+            ..Default::default()
+        };
+
+        let (report, _) = run(&collection, &options).await;
+
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].outcome, RunOutcome::Errored);
+        let error = report.results[0].error.as_deref().unwrap();
+        assert!(error.starts_with("[HTTP_REQUEST_FAILED]"), "{error}");
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_extracted_values_reach_later_requests() {
+        let Some(base) = start_server(2) else {
+            return;
+        };
+        let mut collection = collection(
+            &base,
+            &[
+                ("create", "/status/201", &[]),
+                ("fetch", "/items/{{created}}", &[]),
+            ],
+        );
+        let create = collection
+            .requests
+            .iter_mut()
+            .find(|request| request.name == "create")
+            .unwrap();
+        create.extract.push(ExtractionRule {
+            variable: "created".to_string(),
+            source: ExtractionSource::Status,
+            scope: VariableScope::Runtime,
+        });
+
+        let (report, _) = run(&collection, &RunOptions::default()).await;
+
+        assert_eq!(report.results[0].extracted[0].value.as_deref(), Some("201"));
+        assert_eq!(report.results[1].url, format!("{base}/items/201"));
+        assert_eq!(report.results[1].outcome, RunOutcome::Passed);
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_assertions_decide_pass_or_fail() {
+        let Some(base) = start_server(2) else {
+            return;
+        };
+        let mut collection = collection(
+            &base,
+            &[
+                ("missing", "/status/404", &[]),
+                ("created", "/status/201", &[]),
+            ],
+        );
+        for request in &mut collection.requests {
+            request
+                .assertions
+                .push(Assertion::StatusEquals { status: 404 });
+        }
+
+        let (report, _) = run(&collection, &RunOptions::default()).await;
+
+        assert_eq!(report.results[0].outcome, RunOutcome::Passed);
+        assert!(report.results[0].assertions[0].passed);
+        assert_eq!(report.results[1].outcome, RunOutcome::Failed);
+        assert_eq!(
+            report.results[1].assertions[0].actual.as_deref(),
+            Some("201")
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_unresolved_variables_error_without_sending() {
+        let collection = collection("http://127.0.0.1:1", &[("orphan", "/{{userId}}", &[])]);
+
+        let (report, _) = run(&collection, &RunOptions::default()).await;
+
+        assert_eq!(report.results[0].outcome, RunOutcome::Errored);
+        assert_eq!(report.results[0].url, "{{base}}/{{userId}}");
+        assert_eq!(
+            report.results[0].error.as_deref(),
+            Some("Unresolved variables: userId")
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_environment_values_are_merged_into_the_saved_collection() {
+        use crate::domain::collection::CollectionEnvironment;
+        use crate::infrastructure::storage::collection_store::{
+            load_collection_in_dir, save_collection_in_dir,
+        };
+
+        let Some(base) = start_server(1) else {
+            return;
+        };
+        let mut collection = collection(&base, &[("create", "/status/201", &[])]);
+        collection.environments.push(CollectionEnvironment {
+            name: "staging".to_string(),
+            ..Default::default()
+        });
+        collection.active_environment = Some("staging".to_string());
+        collection.requests[0].extract.push(ExtractionRule {
+            variable: "created".to_string(),
+            source: ExtractionSource::Status,
+            scope: VariableScope::Environment,
+        });
+        let dir = tempfile::TempDir::new().unwrap();
+        // The collection gains a request on disk after the run took its copy
+        let mut edited = collection.clone();
+        edited.requests.push(CollectionRequest {
+            id: "req_added".to_string(),
+            name: "Added".to_string(),
+            ..Default::default()
+        });
+        save_collection_in_dir(&edited, dir.path()).unwrap();
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        run_collection(
+            &collection,
+            dir.path(),
+            &RunOptions::default(),
+            "run_env",
+            &tx,
+        )
+        .await;
+
+        let saved = load_collection_in_dir(&collection.id, dir.path()).unwrap();
+        assert!(saved.requests.iter().any(|r| r.id == "req_added"));
+        assert_eq!(
+            saved.environments[0].variables.get("created"),
+            Some(&"201".to_string())
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_cancel_interrupts_the_delay_between_requests() {
+        let Some(base) = start_server(1) else {
+            return;
+        };
+        let collection = collection(&base, &[("first", "/a", &[]), ("second", "/b", &[])]);
+        let options = RunOptions {
+            delay_ms: 60_000,
+            ..Default::default()
+        };
+        let dir = tempfile::TempDir::new().unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let run = run_collection(&collection, dir.path(), &options, "run_delay", &tx);
+        let cancel = async {
+            while let Some(event) = rx.recv().await {
+                if matches!(event.update, RunUpdate::RequestFinished { .. }) {
+                    break;
+                }
+            }
+            crate::infrastructure::http::cancel_in_flight_request("run_delay")
+        };
+        let (report, cancelled) =
+            tokio::time::timeout(Duration::from_secs(10), async { tokio::join!(run, cancel) })
+                .await
+                .expect("cancel should cut the delay short");
+
+        assert!(cancelled);
+        let outcomes: Vec<_> = report.results.iter().map(|r| r.outcome).collect();
+        assert_eq!(outcomes, vec![RunOutcome::Passed, RunOutcome::Skipped]);
+    }
+}
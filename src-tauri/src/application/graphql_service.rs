@@ -0,0 +1,258 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! GraphQL service — schema introspection, the per-collection schema cache,
+//! and validation of GraphQL requests before they are sent.
+
+use std::path::Path;
+
+use crate::application::request_resolver::collection_request_params;
+use crate::domain::collection::{BodyType, Collection, CollectionRequest, RequestBody};
+use crate::domain::errors::AppError;
+use crate::domain::graphql::{
+    GraphqlIssue, GraphqlOperationInfo, GraphqlQuery, GraphqlSchema, GraphqlSchemaCache,
+    INTROSPECTION_QUERY, validate_query,
+};
+use crate::infrastructure::http::execute_http_request;
+use crate::infrastructure::storage::graphql_schema_store::{
+    load_graphql_schema_in_dir, save_graphql_schema_in_dir,
+};
+
+/// Timeout for introspection requests, matching `execute_request`.
+const INTROSPECTION_TIMEOUT_MS: u64 = 30_000;
+
+/// Introspect the endpoint of `request` and cache the schema for `collection`.
+///
+/// The introspection query is sent as a `POST` to the request's URL with the
+/// request's headers and the collection's environment settings (proxy, TLS,
+/// cookies), without retries. The cache in `dir` is replaced on success only.
+///
+/// # Errors
+///
+/// Returns an error if the request's variables cannot be resolved, the
+/// request fails, the endpoint does not answer with a
+/// 2xx JSON response, the response carries no schema, or the cache cannot be
+/// written.
+pub async fn introspect_schema(
+    collection: &Collection,
+    request: &CollectionRequest,
+    dir: &Path,
+) -> Result<GraphqlSchemaCache, String> {
+    let introspection = CollectionRequest {
+        method: "POST".to_string(),
+        body: Some(RequestBody {
+            body_type: BodyType::Graphql,
+            content: None,
+            file: None,
+            parts: Vec::new(),
+            graphql: Some(GraphqlQuery {
+                query: INTROSPECTION_QUERY.to_string(),
+                operation_name: Some("IntrospectionQuery".to_string()),
+                ..GraphqlQuery::default()
+            }),
+        }),
+        ..request.clone()
+    };
+    let mut params =
+        collection_request_params(collection, &introspection, dir, INTROSPECTION_TIMEOUT_MS)?;
+    params.retry = None;
+    let endpoint = params.url.clone();
+
+    let response = execute_http_request(params, None).await.map_err(|e| {
+        serde_json::from_str::<AppError>(&e).map_or(e, |app_error| app_error.message)
+    })?;
+    if !(200..300).contains(&response.status) {
+        return Err(format!(
+            "Introspection failed: HTTP {} {}",
+            response.status, response.status_text
+        ));
+    }
+    let json: serde_json::Value = serde_json::from_slice(&response.body_bytes())
+        .map_err(|e| format!("Introspection response is not JSON: {e}"))?;
+    let cache = GraphqlSchemaCache {
+        endpoint,
+        fetched_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        schema: GraphqlSchema::from_introspection(&json)?,
+    };
+    save_graphql_schema_in_dir(dir, &collection.id, &cache)?;
+    Ok(cache)
+}
+
+/// Validate a GraphQL request of `collection_id` against the collection's
+/// cached schema.
+///
+/// Requests without a GraphQL body, and collections without a cached schema,
+/// yield no issues: validation is only as good as the schema it runs against.
+///
+/// # Errors
+///
+/// Returns an error if the cache exists but cannot be read.
+pub fn validate_graphql_request_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    request: &CollectionRequest,
+) -> Result<Vec<GraphqlIssue>, String> {
+    let Some(query) = request.body.as_ref().and_then(RequestBody::graphql_query) else {
+        return Ok(Vec::new());
+    };
+    Ok(load_graphql_schema_in_dir(dir, collection_id)?
+        .map(|cache| validate_query(&cache.schema, &query))
+        .unwrap_or_default())
+}
+
+/// Operations of the schema cached for `collection_id`, optionally limited to
+/// one kind (`query`, `mutation` or `subscription`).
+///
+/// # Errors
+///
+/// Returns an error if no schema is cached or the cache cannot be read.
+pub fn list_graphql_operations_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    kind: Option<&str>,
+) -> Result<Vec<GraphqlOperationInfo>, String> {
+    let cache = load_graphql_schema_in_dir(dir, collection_id)?.ok_or_else(|| {
+        format!("No GraphQL schema cached for collection {collection_id}; introspect it first")
+    })?;
+    Ok(cache
+        .schema
+        .operations()
+        .into_iter()
+        .filter(|operation| kind.is_none_or(|kind| operation.kind == kind))
+        .collect())
+}
+
+/// Format validation issues as one error message.
+#[must_use]
+pub fn format_graphql_issues(issues: &[GraphqlIssue]) -> String {
+    let lines: Vec<String> = issues.iter().map(ToString::to_string).collect();
+    format!("GraphQL validation failed:\n{}", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use tempfile::TempDir;
+
+    const INTROSPECTION_RESPONSE: &str = r#"{"data":{"__schema":{
+        "queryType":{"name":"Query"},"mutationType":{"name":"Mutation"},"subscriptionType":null,
+        "types":[
+          {"kind":"OBJECT","name":"Query","fields":[
+            {"name":"me","args":[],"type":{"kind":"SCALAR","name":"String","ofType":null},"isDeprecated":false}]},
+          {"kind":"OBJECT","name":"Mutation","fields":[
+            {"name":"rename","args":[{"name":"to","type":{"kind":"NON_NULL","name":null,"ofType":{"kind":"SCALAR","name":"String","ofType":null}},"defaultValue":null}],
+             "type":{"kind":"SCALAR","name":"String","ofType":null},"isDeprecated":false}]},
+          {"kind":"SCALAR","name":"String"},
+          {"kind":"OBJECT","name":"__Schema","fields":[]}
+        ]}}}"#;
+
+    /// Serve one HTTP request with `body`, returning the raw request received.
+    fn serve_once(body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                received.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&received);
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end]
+                        .lines()
+                        .find_map(|line| {
+                            line.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if received.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(received).unwrap()
+        });
+        (url, handle)
+    }
+
+    fn graphql_request(url: &str, query: &str) -> CollectionRequest {
+        CollectionRequest {
+            id: "req_graphql".to_string(),
+            name: "GraphQL".to_string(),
+            method: "POST".to_string(),
+            url: url.to_string(),
+            body: Some(RequestBody {
+                body_type: BodyType::Graphql,
+                content: None,
+                file: None,
+                parts: Vec::new(),
+                graphql: Some(GraphqlQuery {
+                    query: query.to_string(),
+                    ..GraphqlQuery::default()
+                }),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_introspect_caches_schema_for_validation_and_listing() {
+        let dir = TempDir::new().unwrap();
+        let (url, server) = serve_once(INTROSPECTION_RESPONSE);
+        let mut collection = Collection::new("GraphQL API");
+        collection.id = "col_graphql_1234".to_string();
+        let request = graphql_request(&url, "{ me }");
+
+        let cache = introspect_schema(&collection, &request, dir.path())
+            .await
+            .unwrap();
+        assert_eq!(cache.endpoint, url);
+        assert!(!cache.schema.types.contains_key("__Schema"));
+        let received = server.join().unwrap();
+        assert!(received.starts_with("POST /graphql "));
+        assert!(received.contains("\"operationName\":\"IntrospectionQuery\""));
+
+        let valid = validate_graphql_request_in_dir(dir.path(), &collection.id, &request).unwrap();
+        assert!(valid.is_empty());
+        let invalid = graphql_request(&url, "{ you }");
+        let issues = validate_graphql_request_in_dir(dir.path(), &collection.id, &invalid).unwrap();
+        assert_eq!(
+            format_graphql_issues(&issues),
+            "GraphQL validation failed:\n1:3: Cannot query field \"you\" on type \"Query\""
+        );
+
+        let all = list_graphql_operations_in_dir(dir.path(), &collection.id, None).unwrap();
+        let names: Vec<_> = all
+            .iter()
+            .map(|op| (op.kind.as_str(), op.name.as_str()))
+            .collect();
+        assert_eq!(names, [("query", "me"), ("mutation", "rename")]);
+        let mutations =
+            list_graphql_operations_in_dir(dir.path(), &collection.id, Some("mutation")).unwrap();
+        assert_eq!(mutations.len(), 1);
+        assert_eq!(mutations[0].args[0].type_ref, "String!");
+    }
+
+    #[test]
+    fn test_requests_without_schema_or_graphql_body_are_not_validated() {
+        let dir = TempDir::new().unwrap();
+        let request = graphql_request("https://api.test/graphql", "{ anything }");
+        assert!(
+            validate_graphql_request_in_dir(dir.path(), "col_none", &request)
+                .unwrap()
+                .is_empty()
+        );
+        let err = list_graphql_operations_in_dir(dir.path(), "col_none", None).unwrap_err();
+        assert!(err.contains("No GraphQL schema cached"));
+    }
+}
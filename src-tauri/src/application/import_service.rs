@@ -13,12 +13,13 @@ use std::collections::BTreeMap;
 
 use crate::domain::collection::git_port::GitMetadataPort;
 use crate::domain::collection::spec_port::{
-    ContentFetcher, FetchResult, ParsedSpec, SpecParseError, SpecParser, SpecSource,
+    ContentFetcher, FetchResult, ParsedRequestBody, ParsedSpec, SpecParseError, SpecParser,
+    SpecSource,
 };
 use crate::domain::collection::{
     BodyType, Collection, CollectionEnvironment, CollectionMetadata, CollectionRequest,
-    CollectionSource, IntelligenceMetadata, RequestBody, RequestParam, SCHEMA_URL, SCHEMA_VERSION,
-    SourceType, SpecBinding,
+    CollectionSource, IntelligenceMetadata, RequestBody, RequestKind, RequestParam, SCHEMA_URL,
+    SCHEMA_VERSION, SourceType, SpecBinding,
 };
 use crate::infrastructure::spec::hasher::compute_spec_hash;
 
@@ -48,6 +49,7 @@ pub struct ImportOverrides {
 
 impl ImportService {
     /// Create a new import service with the given parsers and fetcher.
+    #[must_use]
     pub fn new(parsers: Vec<Box<dyn SpecParser>>, fetcher: Box<dyn ContentFetcher>) -> Self {
         Self {
             parsers,
@@ -57,6 +59,7 @@ impl ImportService {
     }
 
     /// Create an import service with git metadata resolution.
+    #[must_use]
     pub fn with_git_metadata(
         parsers: Vec<Box<dyn SpecParser>>,
         fetcher: Box<dyn ContentFetcher>,
@@ -145,6 +148,7 @@ impl ImportService {
     }
 
     /// Access the content fetcher for re-fetching during refresh.
+    #[must_use]
     pub fn fetcher(&self) -> &dyn ContentFetcher {
         self.fetcher.as_ref()
     }
@@ -152,6 +156,7 @@ impl ImportService {
     /// Resolve a git commit SHA if the git metadata port is available.
     ///
     /// Returns `None` if no git port is configured or resolution fails.
+    #[must_use]
     pub fn resolve_git_commit(&self, repo_root: &str, ref_name: Option<&str>) -> Option<String> {
         self.git_metadata
             .as_ref()
@@ -188,6 +193,7 @@ impl ImportService {
         let environments = vec![CollectionEnvironment {
             name: "local".to_string(),
             variables: local_vars,
+            ..Default::default()
         }];
 
         // Convert endpoints to requests with seq ordering, using {{baseUrl}} template
@@ -214,24 +220,21 @@ impl ImportService {
                     url,
                     headers: BTreeMap::new(),
                     params,
-                    body: ep.request_body.as_ref().and_then(|rb| {
-                        rb.example.as_ref().map(|_| {
-                            let body_type = rb
-                                .content_type
-                                .as_deref()
-                                .map_or(BodyType::Json, body_type_from_content_type);
-                            RequestBody {
-                                body_type,
-                                content: rb.example.clone(),
-                                file: None,
-                            }
-                        })
-                    }),
+                    body: ep.request_body.as_ref().and_then(example_body),
                     auth: None,
+                    http_version: None,
+                    retry: None,
+                    follow_redirects: None,
+                    unix_socket: None,
+                    extract: Vec::new(),
+                    assertions: Vec::new(),
                     docs: ep.description.clone(),
                     is_streaming: ep.is_streaming,
+                    kind: RequestKind::Http,
+                    subprotocols: Vec::new(),
                     binding: SpecBinding::from_operation(&operation_id, &ep.path, &ep.method),
                     intelligence: IntelligenceMetadata::default(),
+                    folder: None,
                     tags: ep.tags.clone(),
                     extensions: BTreeMap::new(),
                 }
@@ -264,6 +267,8 @@ impl ImportService {
             variables: BTreeMap::new(),
             environments,
             active_environment: Some("local".to_string()),
+            http_version: None,
+            retry: None,
             extensions: BTreeMap::new(),
             requests,
             pinned_versions: vec![],
@@ -327,6 +332,22 @@ fn extract_query_params(
         .collect()
 }
 
+/// Request body for an endpoint's example payload, if the spec has one.
+fn example_body(request_body: &ParsedRequestBody) -> Option<RequestBody> {
+    let content = request_body.example.clone()?;
+    let body_type = request_body
+        .content_type
+        .as_deref()
+        .map_or(BodyType::Json, body_type_from_content_type);
+    Some(RequestBody {
+        body_type,
+        content: Some(content),
+        file: None,
+        parts: Vec::new(),
+        graphql: None,
+    })
+}
+
 /// Map a MIME content-type string to the appropriate [`BodyType`].
 ///
 /// Strips optional parameters (e.g. `; charset=utf-8`), normalises to
@@ -348,7 +369,8 @@ fn body_type_from_content_type(content_type: &str) -> BodyType {
     // Exact matches on well-known MIME types.
     match mime.as_str() {
         "application/json" | "text/json" => return BodyType::Json,
-        "application/x-www-form-urlencoded" | "multipart/form-data" => return BodyType::Form,
+        "application/x-www-form-urlencoded" => return BodyType::Form,
+        "multipart/form-data" => return BodyType::Multipart,
         "application/xml" | "text/xml" => return BodyType::Xml,
         "application/graphql" => return BodyType::Graphql,
         _ => {}
@@ -650,7 +672,7 @@ mod tests {
         );
         assert_eq!(
             body_type_from_content_type("multipart/form-data"),
-            BodyType::Form
+            BodyType::Multipart
         );
         assert_eq!(
             body_type_from_content_type("application/xml"),
@@ -701,7 +723,7 @@ mod tests {
         );
         assert_eq!(
             body_type_from_content_type("multipart/form-data; boundary=---abc"),
-            BodyType::Form
+            BodyType::Multipart
         );
     }
 
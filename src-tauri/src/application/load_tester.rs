@@ -0,0 +1,350 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Load tester — sends one collection request repeatedly from concurrent workers.
+//!
+//! Each worker sends the request in a loop until the iteration budget is used
+//! up, the duration has elapsed or the test is cancelled. Statistics over the
+//! requests completed so far are published as [`LoadTestEvent`]s at a fixed
+//! interval; the [`LoadTestReport`] is returned once every worker is done.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinSet;
+
+use crate::application::request_resolver::collection_request_params;
+use crate::domain::collection::{Collection, CollectionRequest};
+use crate::domain::errors::AppError;
+use crate::domain::http::RequestParams;
+use crate::domain::load_test::{
+    LoadTestEvent, LoadTestOptions, LoadTestReport, LoadTestSamples, LoadTestStats, LoadTestUpdate,
+};
+use crate::infrastructure::http::execute_http_request;
+
+/// Default per-request timeout, matching `execute_request`.
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+
+/// Lower bound for the progress interval, so updates cannot flood the UI.
+const MIN_PROGRESS_INTERVAL_MS: u64 = 100;
+
+/// State shared by the workers of one load test.
+#[derive(Default)]
+struct LoadState {
+    samples: Mutex<LoadTestSamples>,
+    /// Requests handed out so far, checked against the iteration budget.
+    issued: AtomicU32,
+    /// Workers that started and are not done yet.
+    active: AtomicU32,
+    cancelled: AtomicBool,
+}
+
+impl LoadState {
+    fn samples(&self) -> std::sync::MutexGuard<'_, LoadTestSamples> {
+        self.samples.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Statistics over a snapshot of the samples, sorted outside the lock so
+    /// workers are not held up while progress is computed.
+    fn stats(&self, elapsed: Duration) -> LoadTestStats {
+        let snapshot = self.samples().clone();
+        snapshot.into_stats(elapsed)
+    }
+}
+
+/// When a worker stops taking new requests.
+#[derive(Clone, Copy)]
+struct Limits {
+    iterations: Option<u32>,
+    deadline: Option<Instant>,
+}
+
+/// Load test `request` of `collection` with `options`.
+///
+/// The request is built like `execute_request` builds it, but without its
+/// retry policy, since retries would hide failures and skew latencies, and
+/// without its cookie jar, which the workers would contend for. Every
+/// request is sent with `load_test_id` as its correlation ID, so
+/// `cancel_request` with that ID aborts the requests in flight and ends the
+/// test. Relative file paths resolve against `dir`. A dropped `events`
+/// receiver does not stop the test.
+///
+/// # Errors
+///
+/// Returns an error if the options are invalid (see [`LoadTestOptions::validate`])
+/// or the request's variables cannot be resolved.
+pub async fn run_load_test(
+    collection: &Collection,
+    request: &CollectionRequest,
+    dir: &Path,
+    options: &LoadTestOptions,
+    load_test_id: &str,
+    events: &UnboundedSender<LoadTestEvent>,
+) -> Result<LoadTestReport, String> {
+    options.validate()?;
+    let publish = |update: LoadTestUpdate| {
+        let _ = events.send(LoadTestEvent {
+            load_test_id: load_test_id.to_string(),
+            update,
+        });
+    };
+    let mut params = collection_request_params(
+        collection,
+        request,
+        dir,
+        options.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+    )?;
+    params.retry = None;
+    params.cookie_jar = None;
+    let url = params.url.clone();
+
+    let started_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    publish(LoadTestUpdate::Started {
+        url: url.clone(),
+        options: options.clone(),
+    });
+    let started = Instant::now();
+    let limits = Limits {
+        iterations: options.iterations,
+        deadline: options
+            .duration_ms
+            .map(|ms| started + Duration::from_millis(ms)),
+    };
+    let shared = Arc::new(LoadState::default());
+    let mut workers = JoinSet::new();
+    for worker in 0..options.concurrency {
+        workers.spawn(run_worker(
+            Arc::clone(&shared),
+            params.clone(),
+            limits,
+            options.start_delay(worker),
+            load_test_id.to_string(),
+        ));
+    }
+
+    let mut ticker = tokio::time::interval(Duration::from_millis(
+        options.progress_interval_ms.max(MIN_PROGRESS_INTERVAL_MS),
+    ));
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            joined = workers.join_next() => {
+                if joined.is_none() {
+                    break;
+                }
+            }
+            _ = ticker.tick() => publish(LoadTestUpdate::Progress {
+                active_workers: shared.active.load(Ordering::SeqCst),
+                stats: shared.stats(started.elapsed()),
+            }),
+        }
+    }
+
+    let stats = shared.stats(started.elapsed());
+    let cancelled = shared.cancelled.load(Ordering::SeqCst);
+    publish(LoadTestUpdate::Finished {
+        cancelled,
+        stats: stats.clone(),
+    });
+    Ok(LoadTestReport {
+        load_test_id: load_test_id.to_string(),
+        collection_id: collection.id.clone(),
+        request_id: request.id.clone(),
+        request_name: request.name.clone(),
+        method: params.method,
+        url,
+        started_at,
+        options: options.clone(),
+        cancelled,
+        stats,
+    })
+}
+
+/// Send requests until `limits` are reached or the test is cancelled.
+async fn run_worker(
+    state: Arc<LoadState>,
+    params: RequestParams,
+    limits: Limits,
+    start_delay: Duration,
+    load_test_id: String,
+) {
+    tokio::time::sleep(start_delay).await;
+    state.active.fetch_add(1, Ordering::SeqCst);
+    while !state.cancelled.load(Ordering::SeqCst)
+        && limits
+            .deadline
+            .is_none_or(|deadline| Instant::now() < deadline)
+        && limits
+            .iterations
+            .is_none_or(|budget| state.issued.fetch_add(1, Ordering::SeqCst) < budget)
+    {
+        match execute_http_request(params.clone(), Some(load_test_id.clone())).await {
+            Ok(response) => state.samples().record_response(&response),
+            Err(e) => {
+                let code = serde_json::from_str::<AppError>(&e)
+                    .map_or_else(|_| "UNKNOWN_ERROR".to_string(), |error| error.code);
+                if code == "REQUEST_CANCELLED" {
+                    state.cancelled.store(true, Ordering::SeqCst);
+                } else {
+                    state.samples().record_error(&code);
+                }
+            }
+        }
+    }
+    state.active.fetch_sub(1, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::collection_store::with_collections_dir_override_async;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Answer every connection with `status`, one thread per connection.
+    fn start_server(status: u16) -> Option<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+        let addr = listener.local_addr().ok()?;
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    return;
+                };
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream);
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                        line.clear();
+                    }
+                    let _ = write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {status} Test\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                    );
+                });
+            }
+        });
+        Some(format!("http://{addr}"))
+    }
+
+    fn target(url: &str) -> (Collection, CollectionRequest) {
+        let request = CollectionRequest {
+            id: "req_load".to_string(),
+            name: "Load".to_string(),
+            method: "GET".to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        };
+        let mut collection = Collection::new("Load");
+        collection.requests.push(request.clone());
+        (collection, request)
+    }
+
+    async fn load_test(
+        url: &str,
+        options: &LoadTestOptions,
+    ) -> (Result<LoadTestReport, String>, Vec<LoadTestUpdate>) {
+        let (collection, request) = target(url);
+        let dir = tempfile::TempDir::new().unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let report = with_collections_dir_override_async(dir.path().to_path_buf(), || async {
+            run_load_test(&collection, &request, dir.path(), options, "lt_test", &tx).await
+        })
+        .await;
+        drop(tx);
+        let mut updates = Vec::new();
+        while let Some(event) = rx.recv().await {
+            assert_eq!(event.load_test_id, "lt_test");
+            updates.push(event.update);
+        }
+        (report, updates)
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_iterations_are_shared_by_concurrent_workers() {
+        let Some(base) = start_server(200) else {
+            return;
+        };
+        let options = LoadTestOptions {
+            iterations: Some(20),
+            concurrency: 4,
+            ramp_up_ms: 40,
+            ..Default::default()
+        };
+
+        let (report, updates) = load_test(&base, &options).await;
+
+        let report = report.unwrap();
+        assert!(!report.cancelled);
+        assert_eq!((report.stats.requests, report.stats.succeeded), (20, 20));
+        assert_eq!(report.stats.status_codes.get(&200), Some(&20));
+        assert!(report.stats.error_rate.abs() < f64::EPSILON);
+        assert!(report.stats.throughput_rps > 0.0);
+        let total = report.stats.latency.total.unwrap();
+        assert_eq!(total.count, 20);
+        assert!(total.min_ms <= total.p50_ms && total.p50_ms <= total.p99_ms);
+        assert!(matches!(
+            updates.first(),
+            Some(LoadTestUpdate::Started { .. })
+        ));
+        assert!(matches!(
+            updates.last(),
+            Some(LoadTestUpdate::Finished { cancelled: false, stats }) if stats.requests == 20
+        ));
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_duration_limit_counts_failed_statuses() {
+        let Some(base) = start_server(503) else {
+            return;
+        };
+        let options = LoadTestOptions {
+            duration_ms: Some(300),
+            concurrency: 2,
+            progress_interval_ms: 100,
+            ..Default::default()
+        };
+
+        let (report, updates) = load_test(&base, &options).await;
+
+        let stats = report.unwrap().stats;
+        assert!(stats.requests > 0);
+        assert_eq!(stats.failed, stats.requests);
+        assert!((stats.error_rate - 1.0).abs() < f64::EPSILON);
+        assert!(stats.elapsed_ms >= 300);
+        assert!(
+            updates
+                .iter()
+                .any(|update| matches!(update, LoadTestUpdate::Progress { .. }))
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_connection_errors_are_counted_by_code() {
+        let options = LoadTestOptions {
+            iterations: Some(3),
+            timeout_ms: Some(5_000),
+            ..Default::default()
+        };
+
+        let (report, _) = load_test("http://127.0.0.1:1/", &options).await;
+
+        let stats = report.unwrap().stats;
+        assert_eq!((stats.requests, stats.errored), (3, 3));
+        assert_eq!(stats.errors.get("HTTP_REQUEST_FAILED"), Some(&3));
+        assert!(stats.latency.total.is_none());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_invalid_options_are_rejected() {
+        let (report, updates) = load_test("http://127.0.0.1:1/", &LoadTestOptions::default()).await;
+        assert!(report.is_err());
+        assert!(updates.is_empty());
+    }
+}
@@ -11,18 +11,34 @@ use std::sync::Arc;
 
 use serde_json::json;
 
+use crate::application::graphql_service::{
+    format_graphql_issues, list_graphql_operations_in_dir, validate_graphql_request_in_dir,
+};
+use crate::application::request_resolver::collection_request_params;
+use crate::application::variable_extraction::extract_variables_in_dir;
+use crate::domain::assertion::Assertion;
+use crate::domain::collection::run::RunOptions;
 use crate::domain::collection::{
     BodyType, Collection, CollectionEnvironment, CollectionRequest, IntelligenceMetadata,
-    RequestBody, SpecBinding,
+    RequestBody, RequestKind, SpecBinding,
+};
+use crate::domain::cookies::{Cookie, CookieJarScope, remove_cookies, upsert_cookie};
+use crate::domain::extraction::{ExtractedVariable, ExtractionRule, variable_changes};
+use crate::domain::graphql::GraphqlQuery;
+use crate::domain::http::{
+    ConnectToOverride, HttpVersion, MultipartPart, ProxyConfig, RequestParams, ResolveOverride,
+    TlsConfig, validate_host_overrides,
 };
-use crate::domain::http::RequestParams;
+use crate::domain::load_test::LoadTestOptions;
 use crate::domain::mcp::events::{Actor, EventEmitter};
 use crate::domain::mcp::protocol::{McpToolDefinition, ToolCallResult, ToolResponseContent};
 use crate::domain::participant::{LamportTimestamp, SeqCounter};
+use crate::domain::retry::RetryPolicy;
 use crate::infrastructure::storage::collection_store::{
     delete_collection_in_dir, list_collections_in_dir, load_collection_in_dir,
     save_collection_in_dir,
 };
+use crate::infrastructure::storage::cookie_store::{load_cookies_in_dir, update_cookies_in_dir};
 
 /// A registered tool with its definition and handler.
 struct RegisteredTool {
@@ -40,6 +56,123 @@ fn tool_def(name: &str, description: &str, input_schema: serde_json::Value) -> R
     }
 }
 
+/// Schema of a `RetryPolicy` argument.
+fn retry_policy_schema(description: &str) -> serde_json::Value {
+    json!({
+        "type": "object",
+        "description": description,
+        "properties": {
+            "max_attempts": { "type": "integer", "description": "Total attempts, including the first (default: 3)" },
+            "initial_delay_ms": { "type": "integer", "description": "Wait before the first retry, doubled for each further retry (default: 500)" },
+            "max_delay_ms": { "type": "integer", "description": "Upper bound for any single wait, including Retry-After (default: 30000)" },
+            "jitter": { "type": "boolean", "description": "Randomize waits between half and all of the backoff (default: true)" },
+            "retry_on_status": { "type": "array", "items": { "type": "integer" }, "description": "Response statuses to retry (default: [429, 502, 503, 504])" },
+            "retry_on_connection_error": { "type": "boolean", "description": "Retry failed or dropped connections (default: true)" },
+            "retry_non_idempotent": { "type": "boolean", "description": "Also retry POST, PATCH and other non-idempotent methods (default: false)" }
+        }
+    })
+}
+
+/// Schema of an `extract` argument: a request's extraction rules.
+fn extraction_rules_schema(description: &str) -> serde_json::Value {
+    json!({
+        "type": "array",
+        "description": description,
+        "items": {
+            "type": "object",
+            "properties": {
+                "variable": { "type": "string", "description": "Variable to set; later requests reference it as {{variable}}" },
+                "from": { "type": "string", "description": "Where the value comes from: the first JSONPath match in the JSON body, a response header, the first regex match in the body, or the status code", "enum": ["json_path", "header", "regex", "status"] },
+                "path": { "type": "string", "description": "JSONPath query for json_path, e.g. $.access_token" },
+                "name": { "type": "string", "description": "Header name for header" },
+                "pattern": { "type": "string", "description": "Regular expression for regex" },
+                "group": { "type": "integer", "description": "Capture group for regex (default: the first group, or the whole match without groups)" },
+                "scope": { "type": "string", "description": "Where the value is stored: the collection's in-memory runtime variables, or its active environment, which is saved (default: runtime)", "enum": ["runtime", "environment"] }
+            },
+            "required": ["variable", "from"]
+        }
+    })
+}
+
+/// Schema of an `assertions` argument: a request's response assertions.
+fn assertions_schema(description: &str) -> serde_json::Value {
+    json!({
+        "type": "array",
+        "description": description,
+        "items": {
+            "type": "object",
+            "properties": {
+                "check": { "type": "string", "description": "What to check: the status equals status or lies between min and max; header name is present or matches pattern; the first JSONPath match equals value, contains value (substring, array element or object key) or has json_type; the body matches pattern; the response time is below max_ms; the JSON body is valid against schema", "enum": ["status_equals", "status_in_range", "header_exists", "header_matches", "json_path_equals", "json_path_contains", "json_path_type", "body_matches", "response_time_below", "matches_schema"] },
+                "status": { "type": "integer", "description": "Expected status for status_equals" },
+                "min": { "type": "integer", "description": "Lowest accepted status for status_in_range" },
+                "max": { "type": "integer", "description": "Highest accepted status for status_in_range" },
+                "name": { "type": "string", "description": "Header name for header_exists and header_matches" },
+                "pattern": { "type": "string", "description": "Regular expression for header_matches and body_matches" },
+                "path": { "type": "string", "description": "JSONPath query for the json_path checks, e.g. $.user.id" },
+                "value": { "description": "Expected JSON value for json_path_equals and json_path_contains" },
+                "json_type": { "type": "string", "description": "Expected type for json_path_type", "enum": ["string", "number", "boolean", "array", "object", "null"] },
+                "max_ms": { "type": "integer", "description": "Exclusive response time limit for response_time_below, in milliseconds" },
+                "schema": { "type": "object", "description": "JSON Schema for matches_schema" }
+            },
+            "required": ["check"]
+        }
+    })
+}
+
+/// A collection run resolved for execution by the dispatcher.
+#[derive(Debug, Clone)]
+pub struct PreparedRun {
+    /// The collection to run.
+    pub collection: Collection,
+    /// Run options parsed from the tool arguments.
+    pub options: RunOptions,
+    /// Directory relative file paths resolve against.
+    pub dir: PathBuf,
+}
+
+/// A load test resolved for execution by the dispatcher.
+#[derive(Debug, Clone)]
+pub struct PreparedLoadTest {
+    /// The collection holding the request.
+    pub collection: Collection,
+    /// ID of the request to load test.
+    pub request_id: String,
+    /// Load test options parsed and validated from the tool arguments.
+    pub options: LoadTestOptions,
+    /// Directory relative file paths resolve against.
+    pub dir: PathBuf,
+}
+
+/// A schema introspection resolved for execution by the dispatcher.
+#[derive(Debug, Clone)]
+pub struct PreparedIntrospection {
+    /// The collection the schema is cached for.
+    pub collection: Collection,
+    /// The request whose endpoint is introspected.
+    pub request: CollectionRequest,
+    /// Collections directory holding the schema cache.
+    pub dir: PathBuf,
+}
+
+/// A collection request resolved for execution by the dispatcher.
+#[derive(Debug, Clone)]
+pub struct PreparedExecution {
+    /// Request parameters with variables resolved.
+    pub params: RequestParams,
+    /// ID of the collection containing the request.
+    pub collection_id: String,
+    /// ID of the request within the collection.
+    pub request_id: String,
+    /// Whether the request targets a streaming endpoint (SSE, NDJSON).
+    pub is_streaming: bool,
+    /// Whether the request is an HTTP request or a WebSocket session.
+    pub kind: RequestKind,
+    /// WebSocket subprotocols offered during the handshake.
+    pub subprotocols: Vec<String>,
+    /// Checks evaluated against the response.
+    pub assertions: Vec<Assertion>,
+}
+
 /// MCP server service that manages tool registration and dispatch.
 ///
 /// Tools operate on collections stored in a configurable directory,
@@ -150,21 +283,48 @@ impl McpServerService {
         );
     }
 
+    /// Apply the extraction rules of an executed request to its response.
+    ///
+    /// Emits a `variable:changed` event for every variable set. Called by the
+    /// dispatcher after async HTTP execution (outside the lock); a collection
+    /// that can no longer be loaded or saved is logged and yields nothing.
+    pub fn apply_extractions(
+        &mut self,
+        collection_id: &str,
+        request_id: &str,
+        response: &crate::domain::http::HttpResponse,
+    ) -> Vec<ExtractedVariable> {
+        let extracted = extract_variables_in_dir(self.dir(), collection_id, request_id, response)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to apply extraction rules of {request_id}: {e}");
+                Vec::new()
+            });
+        for change in variable_changes(collection_id, request_id, &extracted) {
+            self.emit(
+                "variable:changed",
+                serde_json::to_value(&change).unwrap_or_else(|_| json!({})),
+            );
+        }
+        extracted
+    }
+
     /// Prepare an HTTP request for execution from a collection.
     ///
     /// Synchronously loads the collection and converts the request to `RequestParams`.
     /// The caller (dispatcher) handles the async HTTP execution outside the lock.
+    /// GraphQL requests are validated against the collection's cached schema
+    /// unless `skip_graphql_validation` is set.
     ///
-    /// Returns `(params, collection_id, request_id)` on success.
+    /// Returns the resolved request together with its identifiers.
     ///
     /// # Errors
     ///
-    /// Returns an error if the collection or request is not found,
-    /// or if the arguments are invalid.
+    /// Returns an error if the collection or request is not found, the
+    /// arguments are invalid, or the request's variables cannot be resolved.
     pub fn prepare_execute_request(
         &self,
         args: &serde_json::Map<String, serde_json::Value>,
-    ) -> Result<(RequestParams, String, String), String> {
+    ) -> Result<PreparedExecution, String> {
         let collection_id = args
             .get("collection_id")
             .and_then(serde_json::Value::as_str)
@@ -177,6 +337,9 @@ impl McpServerService {
             .get("timeout_ms")
             .and_then(serde_json::Value::as_u64)
             .unwrap_or(30_000);
+        let tls_override: Option<TlsConfig> = parse_optional_arg(args, "tls")?;
+        let http_version: Option<HttpVersion> = parse_optional_arg(args, "http_version")?;
+        let retry: Option<RetryPolicy> = parse_optional_arg(args, "retry")?;
 
         Self::validate_collection_id(collection_id)?;
         let collection = load_collection_in_dir(collection_id, self.dir())?;
@@ -185,10 +348,145 @@ impl McpServerService {
             .iter()
             .find(|r| r.id == request_id)
             .ok_or_else(|| format!("Request not found: {request_id}"))?;
+        let skip_graphql_validation = args
+            .get("skip_graphql_validation")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        if !skip_graphql_validation {
+            let issues = validate_graphql_request_in_dir(self.dir(), collection_id, request)?;
+            if !issues.is_empty() {
+                return Err(format_graphql_issues(&issues));
+            }
+        }
+
+        let mut params = collection_request_params(&collection, request, self.dir(), timeout_ms)?;
+        if let Some(overrides) = tls_override {
+            params.tls = Some(match &params.tls {
+                Some(env_tls) => env_tls.merged_with(&overrides),
+                None => overrides,
+            });
+        }
+        if let Some(follow) = args
+            .get("follow_redirects")
+            .and_then(serde_json::Value::as_bool)
+        {
+            params.follow_redirects = follow;
+        }
+        if let Some(max) = args
+            .get("max_redirects")
+            .and_then(serde_json::Value::as_u64)
+        {
+            params.max_redirects = u32::try_from(max).unwrap_or(u32::MAX);
+        }
+        if let Some(version) = http_version {
+            params.http_version = version;
+        }
+        if retry.is_some() {
+            params.retry = retry;
+        }
+        params.verbose = args
+            .get("verbose")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Ok(PreparedExecution {
+            params,
+            collection_id: collection_id.to_string(),
+            request_id: request_id.to_string(),
+            is_streaming: request.is_streaming,
+            kind: request.kind,
+            subprotocols: request.subprotocols.clone(),
+            assertions: request.assertions.clone(),
+        })
+    }
+
+    /// Load the collection and parse the options for a `run_collection` call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection is not found or the options are invalid.
+    pub fn prepare_run_collection(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<PreparedRun, String> {
+        let collection_id = args
+            .get("collection_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
+        Self::validate_collection_id(collection_id)?;
+        let options = serde_json::from_value(serde_json::Value::Object(args.clone()))
+            .map_err(|e| format!("Invalid run options: {e}"))?;
+        Ok(PreparedRun {
+            collection: load_collection_in_dir(collection_id, self.dir())?,
+            options,
+            dir: self.dir().to_path_buf(),
+        })
+    }
+
+    /// Load the collection and parse the options for a `run_load_test` call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection or request is not found, or the
+    /// options are invalid.
+    pub fn prepare_load_test(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<PreparedLoadTest, String> {
+        let collection_id = args
+            .get("collection_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
+        let request_id = args
+            .get("request_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: request_id".to_string())?;
+        Self::validate_collection_id(collection_id)?;
+        let options: LoadTestOptions =
+            serde_json::from_value(serde_json::Value::Object(args.clone()))
+                .map_err(|e| format!("Invalid load test options: {e}"))?;
+        options.validate()?;
+        let collection = load_collection_in_dir(collection_id, self.dir())?;
+        if !collection.requests.iter().any(|r| r.id == request_id) {
+            return Err(format!("Request not found: {request_id}"));
+        }
+        Ok(PreparedLoadTest {
+            collection,
+            request_id: request_id.to_string(),
+            options,
+            dir: self.dir().to_path_buf(),
+        })
+    }
 
-        let vars = effective_vars(&collection);
-        let params = collection_request_to_params_with_vars(request, &vars, timeout_ms);
-        Ok((params, collection_id.to_string(), request_id.to_string()))
+    /// Load the collection and request for a `graphql_introspect` call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection or request is not found.
+    pub fn prepare_graphql_introspect(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<PreparedIntrospection, String> {
+        let collection_id = args
+            .get("collection_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
+        let request_id = args
+            .get("request_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: request_id".to_string())?;
+        Self::validate_collection_id(collection_id)?;
+        let collection = load_collection_in_dir(collection_id, self.dir())?;
+        let request = collection
+            .requests
+            .iter()
+            .find(|r| r.id == request_id)
+            .cloned()
+            .ok_or_else(|| format!("Request not found: {request_id}"))?;
+        Ok(PreparedIntrospection {
+            collection,
+            request,
+            dir: self.dir().to_path_buf(),
+        })
     }
 
     /// Prepare data for opening a collection request in a canvas tab.
@@ -267,11 +565,23 @@ impl McpServerService {
             "upsert_environment" => self.handle_upsert_environment(&args),
             "delete_environment" => self.handle_delete_environment(&args),
             "set_active_environment" => self.handle_set_active_environment(&args),
+            "list_cookies" => self.handle_list_cookies(&args),
+            "set_cookie" => self.handle_set_cookie(&args),
+            "delete_cookies" => self.handle_delete_cookies(&args),
             "remove_pinned_version" => self.handle_remove_pinned_version(&args),
+            "graphql_list_operations" => self.handle_graphql_list_operations(&args),
             // Async tools are handled in dispatcher (they need async I/O)
             "import_collection"
+            | "cancel_request"
             | "refresh_collection_spec"
             | "run_hurl_suite"
+            | "run_collection"
+            | "run_load_test"
+            | "websocket_connect"
+            | "websocket_send"
+            | "websocket_messages"
+            | "websocket_close"
+            | "graphql_introspect"
             | "pin_spec_version"
             | "activate_pinned_version" => {
                 Err(format!("Async tool '{name}' must be handled by dispatcher"))
@@ -307,7 +617,9 @@ impl McpServerService {
                 json!({
                     "type": "object",
                     "properties": {
-                        "name": { "type": "string", "description": "Name for the new collection" }
+                        "name": { "type": "string", "description": "Name for the new collection" },
+                        "http_version": { "type": "string", "description": "HTTP version for the collection's requests (default: auto). http2 needs https; h2c is cleartext HTTP/2 with prior knowledge.", "enum": ["auto", "http1.0", "http1.1", "http2", "h2c", "http3"] },
+                        "retry": retry_policy_schema("Retry policy for the collection's requests. Only idempotent methods are retried unless retry_non_idempotent is set.")
                     },
                     "required": ["name"]
                 }),
@@ -322,7 +634,7 @@ impl McpServerService {
             ),
             tool_def(
                 "add_request",
-                "Add an HTTP request to an existing collection",
+                "Add an HTTP request or WebSocket session to an existing collection",
                 json!({
                     "type": "object",
                     "properties": {
@@ -331,8 +643,43 @@ impl McpServerService {
                         "method": { "type": "string", "description": "HTTP method (GET, POST, PUT, PATCH, DELETE)", "enum": ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"] },
                         "url": { "type": "string", "description": "Request URL" },
                         "headers": { "type": "object", "description": "Request headers as key-value pairs", "additionalProperties": { "type": "string" } },
+                        "folder": { "type": "string", "description": "Folder to group the request under, as a /-separated path (e.g. users/admin)" },
+                        "tags": { "type": "array", "items": { "type": "string" }, "description": "Tags for filtering, e.g. in run_collection" },
                         "body": { "type": "string", "description": "Request body content" },
-                        "body_type": { "type": "string", "description": "Body content type", "enum": ["json", "form", "raw", "graphql", "xml"] }
+                        "body_type": { "type": "string", "description": "Body content type", "enum": ["json", "form", "multipart", "binary", "raw", "graphql", "xml"] },
+                        "body_file": { "type": "string", "description": "Path of a file streamed from disk as the body (body_type defaults to binary). Relative paths resolve against the collections directory." },
+                        "parts": {
+                            "type": "array",
+                            "description": "multipart/form-data parts; makes this a multipart body. Each part has exactly one of value or file.",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "name": { "type": "string", "description": "Form field name" },
+                                    "value": { "type": "string", "description": "Text value" },
+                                    "file": { "type": "string", "description": "Path of a file to upload" },
+                                    "filename": { "type": "string", "description": "Filename sent for the part (defaults to the file's name)" },
+                                    "content_type": { "type": "string", "description": "Content type of the part" }
+                                },
+                                "required": ["name"]
+                            }
+                        },
+                        "graphql": {
+                            "type": "object",
+                            "description": "GraphQL request; makes this a graphql body, sent as JSON for POST and as query parameters for GET",
+                            "properties": {
+                                "query": { "type": "string", "description": "GraphQL document" },
+                                "variables": { "type": "object", "description": "Variable values" },
+                                "operation_name": { "type": "string", "description": "Operation to run when the document defines several" }
+                            },
+                            "required": ["query"]
+                        },
+                        "http_version": { "type": "string", "description": "HTTP version for this request, overriding the collection's. Explicit versions fail rather than fall back.", "enum": ["auto", "http1.0", "http1.1", "http2", "h2c", "http3"] },
+                        "retry": retry_policy_schema("Retry policy for this request, overriding the collection's"),
+                        "kind": { "type": "string", "description": "Request kind; websocket requests are opened with websocket_connect and use GET for the handshake (default: http)", "enum": ["http", "websocket"] },
+                        "subprotocols": { "type": "array", "items": { "type": "string" }, "description": "WebSocket subprotocols offered during the handshake" },
+                        "unix_socket": { "type": "string", "description": "Path of a Unix domain socket to connect through instead of TCP (e.g. /var/run/docker.sock); the URL still supplies the path and Host header. Supports {{variable}} placeholders." },
+                        "extract": extraction_rules_schema("Values to capture from the response into variables after execute_request or run_collection sends the request, for chaining requests"),
+                        "assertions": assertions_schema("Checks on the response, evaluated every time execute_request or run_collection sends the request; when present they decide whether the request passes in a run")
                     },
                     "required": ["collection_id", "name", "method", "url"]
                 }),
@@ -349,8 +696,43 @@ impl McpServerService {
                         "method": { "type": "string", "description": "New HTTP method" },
                         "url": { "type": "string", "description": "New URL" },
                         "headers": { "type": "object", "description": "Request headers (replaces existing headers)", "additionalProperties": { "type": "string" } },
+                        "folder": { "type": "string", "description": "New folder, as a /-separated path; an empty string moves the request out of any folder" },
+                        "tags": { "type": "array", "items": { "type": "string" }, "description": "Tags (replaces existing tags)" },
                         "body": { "type": "string", "description": "Request body content" },
-                        "body_type": { "type": "string", "description": "Body content type", "enum": ["json", "form", "raw", "graphql", "xml"] }
+                        "body_type": { "type": "string", "description": "Body content type", "enum": ["json", "form", "multipart", "binary", "raw", "graphql", "xml"] },
+                        "body_file": { "type": "string", "description": "Path of a file streamed from disk as the body (body_type defaults to binary). Relative paths resolve against the collections directory." },
+                        "parts": {
+                            "type": "array",
+                            "description": "multipart/form-data parts; makes this a multipart body. Each part has exactly one of value or file.",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "name": { "type": "string", "description": "Form field name" },
+                                    "value": { "type": "string", "description": "Text value" },
+                                    "file": { "type": "string", "description": "Path of a file to upload" },
+                                    "filename": { "type": "string", "description": "Filename sent for the part (defaults to the file's name)" },
+                                    "content_type": { "type": "string", "description": "Content type of the part" }
+                                },
+                                "required": ["name"]
+                            }
+                        },
+                        "graphql": {
+                            "type": "object",
+                            "description": "GraphQL request; makes this a graphql body, sent as JSON for POST and as query parameters for GET",
+                            "properties": {
+                                "query": { "type": "string", "description": "GraphQL document" },
+                                "variables": { "type": "object", "description": "Variable values" },
+                                "operation_name": { "type": "string", "description": "Operation to run when the document defines several" }
+                            },
+                            "required": ["query"]
+                        },
+                        "http_version": { "type": "string", "description": "HTTP version for this request, overriding the collection's. Explicit versions fail rather than fall back.", "enum": ["auto", "http1.0", "http1.1", "http2", "h2c", "http3"] },
+                        "retry": retry_policy_schema("Retry policy for this request, overriding the collection's"),
+                        "kind": { "type": "string", "description": "New request kind", "enum": ["http", "websocket"] },
+                        "subprotocols": { "type": "array", "items": { "type": "string" }, "description": "WebSocket subprotocols (replaces existing subprotocols)" },
+                        "unix_socket": { "type": "string", "description": "Unix domain socket path to connect through (empty string to connect over TCP again)" },
+                        "extract": extraction_rules_schema("Extraction rules (replaces existing rules; an empty array removes them)"),
+                        "assertions": assertions_schema("Response assertions (replaces existing assertions; an empty array removes them)")
                     },
                     "required": ["collection_id", "request_id"]
                 }),
@@ -392,17 +774,37 @@ impl McpServerService {
             ),
             tool_def(
                 "execute_request",
-                "Execute an HTTP request from a collection and return the response",
+                "Execute an HTTP request from a collection and return the response. Values captured by the request's extraction rules are stored for later requests and listed in extracted; the outcome of its assertions, with the actual values, is listed in assertions.",
                 json!({
                     "type": "object",
                     "properties": {
                         "collection_id": { "type": "string", "description": "ID of the collection containing the request" },
                         "request_id": { "type": "string", "description": "ID of the request to execute" },
-                        "timeout_ms": { "type": "integer", "description": "Request timeout in milliseconds (default: 30000)" }
+                        "timeout_ms": { "type": "integer", "description": "Request timeout in milliseconds (default: 30000)" },
+                        "correlation_id": { "type": "string", "description": "Correlation ID for tracing and cancellation (default: mcp-<collection_id>-<request_id>)" },
+                        "tls": { "type": "object", "description": "TLS settings for this call, layered over the active environment's (same fields as upsert_environment's tls)" },
+                        "follow_redirects": { "type": "boolean", "description": "Follow Location redirects (default: true). Followed hops are listed in the response's redirects." },
+                        "max_redirects": { "type": "integer", "description": "Maximum number of redirects to follow before failing (default: 10)" },
+                        "http_version": { "type": "string", "description": "HTTP version for this call, overriding the request's and collection's", "enum": ["auto", "http1.0", "http1.1", "http2", "h2c", "http3"] },
+                        "retry": retry_policy_schema("Retry policy for this call, overriding the request's and collection's. Every attempt is listed in the response's attempts."),
+                        "skip_graphql_validation": { "type": "boolean", "description": "Send a GraphQL request without validating it against the collection's cached schema (default: false)" },
+                        "verbose": { "type": "boolean", "description": "Capture a curl -v style wire trace: the request line and headers as sent, TLS handshake messages and raw response headers. Returned as trace, and in the error details when the transfer fails (default: false)" }
                     },
                     "required": ["collection_id", "request_id"]
                 }),
             ),
+            tool_def(
+                "cancel_request",
+                "Cancel an in-flight execute_request call. Identify it by correlation_id, or by collection_id and request_id when the default correlation ID was used.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "correlation_id": { "type": "string", "description": "Correlation ID of the running request" },
+                        "collection_id": { "type": "string", "description": "ID of the collection containing the request" },
+                        "request_id": { "type": "string", "description": "ID of the running request" }
+                    }
+                }),
+            ),
             // Canvas observation tools (Phase 1)
             tool_def(
                 "canvas_list_tabs",
@@ -499,6 +901,125 @@ impl McpServerService {
                     "required": ["hurl_file_path"]
                 }),
             ),
+            tool_def(
+                "run_collection",
+                "Run a collection's requests in seq order against the active environment and return a run report. Progress is broadcast on the run:* SSE topics; cancel_request with the run ID stops the run.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection to run" },
+                        "run_id": { "type": "string", "description": "Run ID, also used as the correlation ID of every request (default: run-<uuid>)" },
+                        "stop_on_failure": { "type": "boolean", "description": "Stop at the first request that fails or errors; the rest are reported as skipped (default: false)" },
+                        "delay_ms": { "type": "integer", "description": "Pause between requests in milliseconds (default: 0)" },
+                        "folders": { "type": "array", "items": { "type": "string" }, "description": "Only run requests in these folders or their subfolders" },
+                        "tags": { "type": "array", "items": { "type": "string" }, "description": "Only run requests with at least one of these tags" },
+                        "requests": { "type": "array", "items": { "type": "string" }, "description": "Only run these requests, given by ID or name" },
+                        "timeout_ms": { "type": "integer", "description": "Timeout for each request in milliseconds (default: 30000)" },
+                        "format": { "type": "string", "description": "Report format (default: json)", "enum": ["json", "junit"] }
+                    },
+                    "required": ["collection_id"]
+                }),
+            ),
+            tool_def(
+                "run_load_test",
+                "Load test a collection request: send it repeatedly from concurrent workers and report throughput, error rate, status codes and p50/p90/p99 latency per timing phase. Progress is broadcast on the load_test:* SSE topics; cancel_request with the load test ID stops the test. The report is saved as a run artifact.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection containing the request" },
+                        "request_id": { "type": "string", "description": "ID of the request to load test" },
+                        "load_test_id": { "type": "string", "description": "Load test ID, also used as the correlation ID of every request (default: load-<uuid>)" },
+                        "iterations": { "type": "integer", "description": "Total number of requests to send" },
+                        "duration_ms": { "type": "integer", "description": "How long to keep sending requests in milliseconds. Set this, iterations, or both (the test ends at the first limit reached)" },
+                        "concurrency": { "type": "integer", "description": "Number of workers sending requests at the same time (default: 1, max: 1000)" },
+                        "ramp_up_ms": { "type": "integer", "description": "Spread worker starts evenly over this many milliseconds (default: 0)" },
+                        "timeout_ms": { "type": "integer", "description": "Timeout for each request in milliseconds (default: 30000)" },
+                        "progress_interval_ms": { "type": "integer", "description": "Interval between progress events in milliseconds (default: 1000)" }
+                    },
+                    "required": ["collection_id", "request_id"]
+                }),
+            ),
+            // WebSocket session tools
+            tool_def(
+                "websocket_connect",
+                "Open a WebSocket session for a websocket request of a collection, sending its headers and offering its subprotocols. Frames are broadcast on the websocket:* SSE topics and kept in a transcript; the transcript is saved to history when the session ends.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection containing the request" },
+                        "request_id": { "type": "string", "description": "ID of the websocket request" },
+                        "session_id": { "type": "string", "description": "Session ID used by the other websocket_* tools (default: ws-<uuid>)" },
+                        "subprotocols": { "type": "array", "items": { "type": "string" }, "description": "Subprotocols to offer instead of the request's" },
+                        "timeout_ms": { "type": "integer", "description": "Handshake timeout in milliseconds (default: 30000)" },
+                        "tls": { "type": "object", "description": "TLS settings for this connection, layered over the active environment's (same fields as upsert_environment's tls)" }
+                    },
+                    "required": ["collection_id", "request_id"]
+                }),
+            ),
+            tool_def(
+                "websocket_send",
+                "Send a message on an open WebSocket session",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "session_id": { "type": "string", "description": "ID of the session" },
+                        "data": { "type": "string", "description": "Message text; base64 for binary messages" },
+                        "kind": { "type": "string", "description": "Message kind (default: text)", "enum": ["text", "binary", "ping", "pong"] }
+                    },
+                    "required": ["session_id", "data"]
+                }),
+            ),
+            tool_def(
+                "websocket_messages",
+                "Read the transcript of a WebSocket session: messages sent and received, whether it is still open, and its close code once closed",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "session_id": { "type": "string", "description": "ID of the session" },
+                        "after_seq": { "type": "integer", "description": "Only return messages with a higher sequence number" },
+                        "wait_ms": { "type": "integer", "description": "Wait up to this many milliseconds for a new message or the session to close (default: 0, max: 30000)" }
+                    },
+                    "required": ["session_id"]
+                }),
+            ),
+            tool_def(
+                "websocket_close",
+                "Close an open WebSocket session with a close code and reason",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "session_id": { "type": "string", "description": "ID of the session" },
+                        "code": { "type": "integer", "description": "Close code: 1000-1003, 1007-1011 or 3000-4999 (default: 1000)" },
+                        "reason": { "type": "string", "description": "Close reason, at most 123 bytes" }
+                    },
+                    "required": ["session_id"]
+                }),
+            ),
+            // GraphQL tools
+            tool_def(
+                "graphql_introspect",
+                "Fetch the schema of a GraphQL endpoint by introspection, using a request of the collection for its URL, headers and environment, and cache it for the collection. execute_request validates GraphQL requests against the cached schema before sending them.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection to cache the schema for" },
+                        "request_id": { "type": "string", "description": "ID of a request to the GraphQL endpoint" }
+                    },
+                    "required": ["collection_id", "request_id"]
+                }),
+            ),
+            tool_def(
+                "graphql_list_operations",
+                "List the queries, mutations and subscriptions of the GraphQL schema cached for a collection, with their arguments and return types",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection" },
+                        "kind": { "type": "string", "description": "Only list operations of this kind", "enum": ["query", "mutation", "subscription"] }
+                    },
+                    "required": ["collection_id"]
+                }),
+            ),
             // Collection save/move/copy tools
             tool_def(
                 "save_tab_to_collection",
@@ -761,6 +1282,24 @@ impl McpServerService {
                             "type": "object",
                             "description": "Variable key-value pairs for this environment",
                             "additionalProperties": { "type": "string" }
+                        },
+                        "tls": {
+                            "type": "object",
+                            "description": "TLS settings for requests sent in this environment: client_cert, client_cert_format ('pem' | 'pkcs12'), client_key, client_key_password, ca_bundle (file paths), insecure_skip_verify, min_tls_version ('1.0' | '1.1' | '1.2' | '1.3'). Pass null to clear; omit to keep the current settings."
+                        },
+                        "proxy": {
+                            "type": "object",
+                            "description": "Proxy for requests sent in this environment, overriding the global proxy: url (http://, https://, socks4://, socks5://, socks5h://), username, password, no_proxy (host list), use_system (use HTTP(S)_PROXY/NO_PROXY env vars when url is unset). An object without url or use_system forces a direct connection. Pass null to clear; omit to keep the current settings."
+                        },
+                        "resolve": {
+                            "type": "array",
+                            "description": "Host-to-address pins, like curl --resolve: each { host, port?, addresses: [IP, ...] }. The port defaults to the URL's. TLS verification and the Host header keep the host name. Replaces the current list; omit to keep it.",
+                            "items": { "type": "object" }
+                        },
+                        "connect_to": {
+                            "type": "array",
+                            "description": "Connection redirects, like curl --connect-to: each { host?, port?, to_host?, to_port? }; omitted host/port match any, omitted to_host/to_port keep the original. Replaces the current list; omit to keep it.",
+                            "items": { "type": "object" }
                         }
                     },
                     "required": ["collection_id", "name", "variables"]
@@ -793,6 +1332,60 @@ impl McpServerService {
                     "required": ["collection_id"]
                 }),
             ),
+            // Cookie jar tools
+            tool_def(
+                "list_cookies",
+                "List the cookies stored for a collection. Cookies set by responses are captured per collection and environment and sent on later requests that match their domain and path.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection" },
+                        "environment": { "type": "string", "description": "Environment whose jar to read (defaults to the active environment)" }
+                    },
+                    "required": ["collection_id"]
+                }),
+            ),
+            tool_def(
+                "set_cookie",
+                "Add or replace a cookie in a collection's jar. A cookie with the same name, domain and path is replaced.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection" },
+                        "environment": { "type": "string", "description": "Environment whose jar to edit (defaults to the active environment)" },
+                        "cookie": {
+                            "type": "object",
+                            "description": "The cookie to store",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "value": { "type": "string" },
+                                "domain": { "type": "string", "description": "Host the cookie belongs to, without a leading dot" },
+                                "path": { "type": "string", "description": "Path prefix (default '/')" },
+                                "include_subdomains": { "type": "boolean", "description": "Also send to subdomains of domain" },
+                                "secure": { "type": "boolean", "description": "Only send over HTTPS" },
+                                "http_only": { "type": "boolean" },
+                                "expires": { "type": "integer", "description": "Expiry as Unix seconds; omit for a session cookie" }
+                            },
+                            "required": ["name", "value", "domain"]
+                        }
+                    },
+                    "required": ["collection_id", "cookie"]
+                }),
+            ),
+            tool_def(
+                "delete_cookies",
+                "Delete cookies from a collection's jar by name and/or domain. Omitting both clears the jar.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "collection_id": { "type": "string", "description": "ID of the collection" },
+                        "environment": { "type": "string", "description": "Environment whose jar to edit (defaults to the active environment)" },
+                        "name": { "type": "string", "description": "Only delete cookies with this name" },
+                        "domain": { "type": "string", "description": "Only delete cookies for this domain" }
+                    },
+                    "required": ["collection_id"]
+                }),
+            ),
             // Pinned spec version tools
             tool_def(
                 "pin_spec_version",
@@ -865,7 +1458,9 @@ impl McpServerService {
             .and_then(serde_json::Value::as_str)
             .ok_or_else(|| "Missing required parameter: name".to_string())?;
 
-        let collection = Collection::new(name);
+        let mut collection = Collection::new(name);
+        collection.http_version = parse_optional_arg(args, "http_version")?;
+        collection.retry = parse_optional_arg(args, "retry")?;
         save_collection_in_dir(&collection, self.dir())?;
 
         self.emit(
@@ -943,24 +1538,24 @@ impl McpServerService {
             })
             .unwrap_or_default();
 
-        // Parse optional body and body_type
-        let body_content = args.get("body").and_then(serde_json::Value::as_str);
-        let body_type_str = args.get("body_type").and_then(serde_json::Value::as_str);
-        let body = body_content.map(|content| {
-            use crate::domain::collection::{BodyType, RequestBody};
-            let body_type = match body_type_str {
-                Some("json") => BodyType::Json,
-                Some("form") => BodyType::Form,
-                Some("graphql") => BodyType::Graphql,
-                Some("xml") => BodyType::Xml,
-                _ => BodyType::Raw,
-            };
-            RequestBody {
-                body_type,
-                content: Some(content.to_string()),
-                file: None,
-            }
-        });
+        let body = parse_request_body(args)?;
+        let http_version = parse_optional_arg(args, "http_version")?;
+        let retry = parse_optional_arg(args, "retry")?;
+        let folder = args
+            .get("folder")
+            .and_then(serde_json::Value::as_str)
+            .map(|folder| folder.trim_matches('/').to_string())
+            .filter(|folder| !folder.is_empty());
+        let tags = parse_optional_arg(args, "tags")?.unwrap_or_default();
+        let kind = parse_optional_arg(args, "kind")?.unwrap_or_default();
+        let subprotocols = parse_optional_arg(args, "subprotocols")?.unwrap_or_default();
+        let unix_socket = args
+            .get("unix_socket")
+            .and_then(serde_json::Value::as_str)
+            .filter(|path| !path.is_empty())
+            .map(ToString::to_string);
+        let extract = parse_extraction_rules(args)?.unwrap_or_default();
+        let assertions = parse_assertions(args)?.unwrap_or_default();
 
         let request = CollectionRequest {
             id: CollectionRequest::generate_id(name),
@@ -970,6 +1565,15 @@ impl McpServerService {
             url: url.to_string(),
             headers,
             body,
+            http_version,
+            retry,
+            folder,
+            tags,
+            kind,
+            subprotocols,
+            unix_socket,
+            extract,
+            assertions,
             intelligence: IntelligenceMetadata::ai_generated("mcp"),
             ..Default::default()
         };
@@ -1035,20 +1639,36 @@ impl McpServerService {
         }
 
         // Update body if provided
-        if let Some(body_content) = args.get("body").and_then(serde_json::Value::as_str) {
-            use crate::domain::collection::{BodyType, RequestBody};
-            let body_type = match args.get("body_type").and_then(serde_json::Value::as_str) {
-                Some("json") => BodyType::Json,
-                Some("form") => BodyType::Form,
-                Some("graphql") => BodyType::Graphql,
-                Some("xml") => BodyType::Xml,
-                _ => BodyType::Raw,
-            };
-            request.body = Some(RequestBody {
-                body_type,
-                content: Some(body_content.to_string()),
-                file: None,
-            });
+        if let Some(body) = parse_request_body(args)? {
+            request.body = Some(body);
+        }
+        if let Some(version) = parse_optional_arg(args, "http_version")? {
+            request.http_version = Some(version);
+        }
+        if let Some(retry) = parse_optional_arg(args, "retry")? {
+            request.retry = Some(retry);
+        }
+        if let Some(folder) = args.get("folder").and_then(serde_json::Value::as_str) {
+            let folder = folder.trim_matches('/');
+            request.folder = (!folder.is_empty()).then(|| folder.to_string());
+        }
+        if let Some(tags) = parse_optional_arg(args, "tags")? {
+            request.tags = tags;
+        }
+        if let Some(kind) = parse_optional_arg(args, "kind")? {
+            request.kind = kind;
+        }
+        if let Some(subprotocols) = parse_optional_arg(args, "subprotocols")? {
+            request.subprotocols = subprotocols;
+        }
+        if let Some(path) = args.get("unix_socket").and_then(serde_json::Value::as_str) {
+            request.unix_socket = (!path.is_empty()).then(|| path.to_string());
+        }
+        if let Some(rules) = parse_extraction_rules(args)? {
+            request.extract = rules;
+        }
+        if let Some(assertions) = parse_assertions(args)? {
+            request.assertions = assertions;
         }
 
         let updated_name = request.name.clone();
@@ -1202,6 +1822,8 @@ impl McpServerService {
             body_type: body_type.clone(),
             content: Some(content.to_string()),
             file: None,
+            parts: Vec::new(),
+            graphql: None,
         });
 
         let request = CollectionRequest {
@@ -1491,14 +2113,46 @@ impl McpServerService {
             .iter()
             .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
             .collect();
+        // `Some(None)` clears a setting, `None` leaves it untouched.
+        let tls: Option<Option<TlsConfig>> = parse_optional_arg(args, "tls")?;
+        let proxy: Option<Option<ProxyConfig>> = parse_optional_arg(args, "proxy")?;
+        if let Some(Err(e)) = proxy
+            .as_ref()
+            .and_then(Option::as_ref)
+            .map(|p| p.resolve("https://example.com", |_| None))
+        {
+            return Err(e);
+        }
+        let resolve: Option<Vec<ResolveOverride>> = parse_optional_arg(args, "resolve")?;
+        let connect_to: Option<Vec<ConnectToOverride>> = parse_optional_arg(args, "connect_to")?;
+        validate_host_overrides(
+            resolve.as_deref().unwrap_or_default(),
+            connect_to.as_deref().unwrap_or_default(),
+        )?;
 
         let mut collection = load_collection_in_dir(collection_id, self.dir())?;
         if let Some(env) = collection.environments.iter_mut().find(|e| e.name == name) {
             env.variables = variables;
+            if let Some(tls) = tls {
+                env.tls = tls;
+            }
+            if let Some(proxy) = proxy {
+                env.proxy = proxy;
+            }
+            if let Some(resolve) = resolve {
+                env.resolve = resolve;
+            }
+            if let Some(connect_to) = connect_to {
+                env.connect_to = connect_to;
+            }
         } else {
             collection.environments.push(CollectionEnvironment {
                 name: name.to_string(),
                 variables,
+                tls: tls.flatten(),
+                proxy: proxy.flatten(),
+                resolve: resolve.unwrap_or_default(),
+                connect_to: connect_to.unwrap_or_default(),
             });
         }
         save_collection_in_dir(&collection, self.dir())?;
@@ -1624,20 +2278,153 @@ impl McpServerService {
             is_error: false,
         })
     }
-    /// Handle `remove_pinned_version` MCP tool call.
-    ///
-    /// Removes a staged or archived pinned spec version from a collection.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the collection cannot be found or the pinned version ID does not exist.
-    fn handle_remove_pinned_version(
-        &mut self,
-        args: &serde_json::Map<String, serde_json::Value>,
-    ) -> Result<ToolCallResult, String> {
-        use crate::domain::collection::PinnedVersionRole;
 
-        let collection_id = args
+    /// Resolve the cookie jar named by `collection_id` and the optional
+    /// `environment` argument, defaulting to the active environment.
+    fn cookie_jar_scope(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<CookieJarScope, String> {
+        let collection_id = args
+            .get("collection_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
+        Self::validate_collection_id(collection_id)?;
+        let collection = load_collection_in_dir(collection_id, self.dir())?;
+        let environment = args
+            .get("environment")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+            .or(collection.active_environment);
+        Ok(CookieJarScope {
+            collection_id: collection_id.to_string(),
+            environment,
+        })
+    }
+
+    /// List the cookies in a collection's jar.
+    fn handle_list_cookies(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ToolCallResult, String> {
+        let scope = self.cookie_jar_scope(args)?;
+        let cookies = load_cookies_in_dir(self.dir(), &scope)?;
+        Ok(ToolCallResult {
+            content: vec![ToolResponseContent::Text {
+                text: json!({
+                    "collection_id": scope.collection_id,
+                    "environment": scope.environment,
+                    "cookies": cookies,
+                })
+                .to_string(),
+            }],
+            is_error: false,
+        })
+    }
+
+    /// List the operations of the GraphQL schema cached for a collection.
+    fn handle_graphql_list_operations(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ToolCallResult, String> {
+        let collection_id = args
+            .get("collection_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
+        Self::validate_collection_id(collection_id)?;
+        let kind = args.get("kind").and_then(serde_json::Value::as_str);
+        let operations = list_graphql_operations_in_dir(self.dir(), collection_id, kind)?;
+        Ok(ToolCallResult {
+            content: vec![ToolResponseContent::Text {
+                text: json!({
+                    "collection_id": collection_id,
+                    "operations": operations,
+                })
+                .to_string(),
+            }],
+            is_error: false,
+        })
+    }
+
+    /// Insert or replace a cookie in a collection's jar.
+    fn handle_set_cookie(
+        &mut self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ToolCallResult, String> {
+        let cookie: Cookie = parse_optional_arg(args, "cookie")?
+            .ok_or_else(|| "Missing required parameter: cookie".to_string())?;
+        if cookie.name.trim().is_empty() || cookie.domain.trim().is_empty() {
+            return Err("Cookie name and domain are required".to_string());
+        }
+        let scope = self.cookie_jar_scope(args)?;
+        let name = cookie.name.clone();
+        let cookies = update_cookies_in_dir(self.dir(), &scope, |jar| upsert_cookie(jar, cookie))?;
+
+        self.emit(
+            "collection:cookies-updated",
+            json!({"collection_id": scope.collection_id}),
+        );
+
+        Ok(ToolCallResult {
+            content: vec![ToolResponseContent::Text {
+                text: json!({
+                    "collection_id": scope.collection_id,
+                    "environment": scope.environment,
+                    "cookies": cookies,
+                    "message": format!("Cookie '{name}' saved")
+                })
+                .to_string(),
+            }],
+            is_error: false,
+        })
+    }
+
+    /// Delete cookies from a collection's jar by name and/or domain.
+    fn handle_delete_cookies(
+        &mut self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ToolCallResult, String> {
+        let name = args.get("name").and_then(serde_json::Value::as_str);
+        let domain = args.get("domain").and_then(serde_json::Value::as_str);
+        let scope = self.cookie_jar_scope(args)?;
+        let mut removed = 0;
+        let cookies = update_cookies_in_dir(self.dir(), &scope, |jar| {
+            removed = remove_cookies(jar, name, domain);
+        })?;
+
+        self.emit(
+            "collection:cookies-updated",
+            json!({"collection_id": scope.collection_id}),
+        );
+
+        Ok(ToolCallResult {
+            content: vec![ToolResponseContent::Text {
+                text: json!({
+                    "collection_id": scope.collection_id,
+                    "environment": scope.environment,
+                    "removed": removed,
+                    "cookies": cookies,
+                })
+                .to_string(),
+            }],
+            is_error: false,
+        })
+    }
+
+    /// Handle `remove_pinned_version` MCP tool call.
+    ///
+    /// Removes a staged or archived pinned spec version from a collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection cannot be found or the pinned version ID does not exist.
+    fn handle_remove_pinned_version(
+        &mut self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ToolCallResult, String> {
+        use crate::domain::collection::PinnedVersionRole;
+
+        let collection_id = args
             .get("collection_id")
             .and_then(serde_json::Value::as_str)
             .ok_or_else(|| "Missing required parameter: collection_id".to_string())?;
@@ -1690,65 +2477,100 @@ impl McpServerService {
     }
 }
 
-/// Resolve `{{key}}` template variables in a string using the provided map.
-///
-/// Single-pass scanner: each `{{...}}` placeholder is substituted at most once,
-/// preventing cascading substitutions where a resolved value contains further
-/// `{{...}}` markers.  Unknown placeholders are left verbatim.
-fn resolve_variables(template: &str, vars: &std::collections::BTreeMap<String, String>) -> String {
-    let mut result = String::with_capacity(template.len());
-    let mut rest = template;
-    while let Some(start) = rest.find("{{") {
-        result.push_str(&rest[..start]);
-        rest = &rest[start + 2..];
-        if let Some(end) = rest.find("}}") {
-            let key = &rest[..end];
-            if let Some(val) = vars.get(key) {
-                result.push_str(val);
-            } else {
-                result.push_str("{{");
-                result.push_str(key);
-                result.push_str("}}");
-            }
-            rest = &rest[end + 2..];
-        } else {
-            result.push_str("{{");
-        }
+/// Deserialize an optional structured tool argument.
+fn parse_optional_arg<T: serde::de::DeserializeOwned>(
+    args: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<T>, String> {
+    args.get(key)
+        .map(|v| serde_json::from_value(v.clone()))
+        .transpose()
+        .map_err(|e| format!("Invalid {key} settings: {e}"))
+}
+
+/// Parse and validate the `extract` tool argument.
+fn parse_extraction_rules(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<Vec<ExtractionRule>>, String> {
+    let rules: Option<Vec<ExtractionRule>> = parse_optional_arg(args, "extract")?;
+    for rule in rules.iter().flatten() {
+        rule.validate()?;
     }
-    result.push_str(rest);
-    result
+    Ok(rules)
 }
 
-/// Build the effective variable map for a collection: collection-level variables
-/// merged with the active environment's variables (env takes precedence).
-fn effective_vars(
-    collection: &crate::domain::collection::Collection,
-) -> std::collections::BTreeMap<String, String> {
-    let mut vars = collection.variables.clone();
-    if let Some(env_name) = &collection.active_environment {
-        if let Some(env) = collection.environments.iter().find(|e| &e.name == env_name) {
-            vars.extend(env.variables.clone());
-        }
+/// Parse and validate the `assertions` tool argument.
+fn parse_assertions(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<Vec<Assertion>>, String> {
+    let assertions: Option<Vec<Assertion>> = parse_optional_arg(args, "assertions")?;
+    for assertion in assertions.iter().flatten() {
+        assertion.validate()?;
     }
-    vars
+    Ok(assertions)
 }
 
-fn collection_request_to_params_with_vars(
-    req: &CollectionRequest,
-    vars: &std::collections::BTreeMap<String, String>,
-    timeout_ms: u64,
-) -> RequestParams {
-    RequestParams {
-        url: resolve_variables(&req.url, vars),
-        method: req.method.clone(),
-        headers: req
-            .headers
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect(),
-        body: req.body.as_ref().and_then(|b| b.content.clone()),
-        timeout_ms,
+/// Parse the `body`, `body_type`, `body_file`, `parts` and `graphql` tool
+/// arguments.
+///
+/// `graphql` makes a GraphQL body, `parts` a multipart body and `body_file` a
+/// body streamed from disk (binary unless `body_type` says otherwise);
+/// otherwise `body` is inline content. Returns `None` when none of them is
+/// given.
+fn parse_request_body(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<RequestBody>, String> {
+    let body_type = |default| match args.get("body_type").and_then(serde_json::Value::as_str) {
+        Some("json") => BodyType::Json,
+        Some("form") => BodyType::Form,
+        Some("multipart") => BodyType::Multipart,
+        Some("binary") => BodyType::Binary,
+        Some("graphql") => BodyType::Graphql,
+        Some("xml") => BodyType::Xml,
+        Some("raw") => BodyType::Raw,
+        _ => default,
+    };
+
+    if let Some(graphql) = parse_optional_arg::<GraphqlQuery>(args, "graphql")? {
+        return Ok(Some(RequestBody {
+            body_type: BodyType::Graphql,
+            content: None,
+            file: None,
+            parts: Vec::new(),
+            graphql: Some(graphql),
+        }));
+    }
+    if let Some(parts) = parse_optional_arg::<Vec<MultipartPart>>(args, "parts")? {
+        for part in &parts {
+            part.validate()?;
+        }
+        return Ok(Some(RequestBody {
+            body_type: BodyType::Multipart,
+            content: None,
+            file: None,
+            parts,
+            graphql: None,
+        }));
     }
+    if let Some(file) = args.get("body_file").and_then(serde_json::Value::as_str) {
+        return Ok(Some(RequestBody {
+            body_type: body_type(BodyType::Binary),
+            content: None,
+            file: Some(file.to_string()),
+            parts: Vec::new(),
+            graphql: None,
+        }));
+    }
+    Ok(args
+        .get("body")
+        .and_then(serde_json::Value::as_str)
+        .map(|content| RequestBody {
+            body_type: body_type(BodyType::Raw),
+            content: Some(content.to_string()),
+            file: None,
+            parts: Vec::new(),
+            graphql: None,
+        }))
 }
 
 #[cfg(test)]
@@ -1772,14 +2594,16 @@ mod tests {
     }
 
     #[test]
-    fn test_registers_thirty_six_tools() {
+    fn test_registers_forty_eight_tools() {
         let (service, _dir) = make_service();
         let tools = service.list_tools();
         // 8 collection tools + 3 save/move/copy tools + 3 import/refresh/hurl tools
         // + 6 canvas tools + 1 streaming tool + 2 project context tools
-        // + 1 execute_request + 3 suggestion tools + 3 environment tools
-        // + 3 drift review tools + 3 pinned version tools = 36 total
-        assert_eq!(tools.len(), 36);
+        // + 2 execute/cancel request tools + 3 suggestion tools + 3 environment tools
+        // + 3 cookie jar tools + 3 drift review tools + 3 pinned version tools
+        // + 1 collection run tool + 1 load test tool + 4 WebSocket tools
+        // + 2 GraphQL tools = 48 total
+        assert_eq!(tools.len(), 48);
         let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
         // Collection tools
         assert!(names.contains(&"create_collection"));
@@ -1790,6 +2614,7 @@ mod tests {
         assert!(names.contains(&"open_collection_request"));
         assert!(names.contains(&"delete_collection"));
         assert!(names.contains(&"execute_request"));
+        assert!(names.contains(&"cancel_request"));
         // Save/move/copy tools
         assert!(names.contains(&"save_tab_to_collection"));
         assert!(names.contains(&"move_request"));
@@ -1798,6 +2623,16 @@ mod tests {
         assert!(names.contains(&"import_collection"));
         assert!(names.contains(&"refresh_collection_spec"));
         assert!(names.contains(&"run_hurl_suite"));
+        assert!(names.contains(&"run_collection"));
+        assert!(names.contains(&"run_load_test"));
+        // WebSocket tools
+        assert!(names.contains(&"websocket_connect"));
+        assert!(names.contains(&"websocket_send"));
+        assert!(names.contains(&"websocket_messages"));
+        assert!(names.contains(&"websocket_close"));
+        // GraphQL tools
+        assert!(names.contains(&"graphql_introspect"));
+        assert!(names.contains(&"graphql_list_operations"));
         // Canvas tools
         assert!(names.contains(&"canvas_list_tabs"));
         assert!(names.contains(&"canvas_get_active_tab"));
@@ -1818,6 +2653,10 @@ mod tests {
         assert!(names.contains(&"upsert_environment"));
         assert!(names.contains(&"delete_environment"));
         assert!(names.contains(&"set_active_environment"));
+        // Cookie jar tools
+        assert!(names.contains(&"list_cookies"));
+        assert!(names.contains(&"set_cookie"));
+        assert!(names.contains(&"delete_cookies"));
         // Drift review tools
         assert!(names.contains(&"get_drift_review"));
         assert!(names.contains(&"accept_drift_change"));
@@ -2874,62 +3713,6 @@ mod tests {
         assert!(!result.is_error);
     }
 
-    #[test]
-    fn test_collection_request_to_params_basic() {
-        let req = CollectionRequest {
-            id: "req_test".to_string(),
-            name: "Test".to_string(),
-            method: "POST".to_string(),
-            url: "https://api.example.com/users".to_string(),
-            ..Default::default()
-        };
-
-        let params =
-            collection_request_to_params_with_vars(&req, &std::collections::BTreeMap::new(), 5000);
-        assert_eq!(params.url, "https://api.example.com/users");
-        assert_eq!(params.method, "POST");
-        assert!(params.headers.is_empty());
-        assert!(params.body.is_none());
-        assert_eq!(params.timeout_ms, 5000);
-    }
-
-    #[test]
-    fn test_collection_request_to_params_with_headers_and_body() {
-        use crate::domain::collection::{BodyType, RequestBody};
-        use std::collections::BTreeMap;
-
-        let mut headers = BTreeMap::new();
-        headers.insert("Content-Type".to_string(), "application/json".to_string());
-        headers.insert("Authorization".to_string(), "Bearer tok".to_string());
-
-        let req = CollectionRequest {
-            id: "req_test".to_string(),
-            name: "Test".to_string(),
-            method: "PUT".to_string(),
-            url: "https://api.example.com/update".to_string(),
-            headers,
-            body: Some(RequestBody {
-                body_type: BodyType::Json,
-                content: Some(r#"{"key":"val"}"#.to_string()),
-                file: None,
-            }),
-            ..Default::default()
-        };
-
-        let params = collection_request_to_params_with_vars(
-            &req,
-            &std::collections::BTreeMap::new(),
-            10_000,
-        );
-        assert_eq!(params.headers.len(), 2);
-        assert_eq!(
-            params.headers.get("Content-Type"),
-            Some(&"application/json".to_string())
-        );
-        assert_eq!(params.body, Some(r#"{"key":"val"}"#.to_string()));
-        assert_eq!(params.timeout_ms, 10_000);
-    }
-
     #[test]
     fn test_prepare_execute_request_success() {
         let (mut service, _dir) = make_service();
@@ -2965,12 +3748,234 @@ mod tests {
         exec_args.insert("collection_id".to_string(), json!(collection_id));
         exec_args.insert("request_id".to_string(), json!(request_id));
 
-        let (params, cid, rid) = service.prepare_execute_request(&exec_args).unwrap();
-        assert_eq!(params.url, "https://api.example.com/users");
-        assert_eq!(params.method, "GET");
-        assert_eq!(params.timeout_ms, 30_000);
-        assert_eq!(cid, collection_id);
-        assert_eq!(rid, request_id);
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert_eq!(prepared.params.url, "https://api.example.com/users");
+        assert_eq!(prepared.params.method, "GET");
+        assert_eq!(prepared.params.timeout_ms, 30_000);
+        assert!(prepared.params.follow_redirects);
+        assert_eq!(prepared.params.max_redirects, 10);
+        assert_eq!(prepared.collection_id, collection_id);
+        assert_eq!(prepared.request_id, request_id);
+        assert!(!prepared.is_streaming);
+    }
+
+    #[test]
+    fn test_multipart_and_file_bodies_reach_execution() {
+        let (mut service, dir) = make_service();
+        let created = service
+            .call_tool("create_collection", Some(args(&[("name", "Uploads")])))
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(match &created.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let collection_id = created["id"].as_str().unwrap();
+
+        let mut add_args = args(&[
+            ("collection_id", collection_id),
+            ("name", "Upload Avatar"),
+            ("method", "POST"),
+            ("url", "https://api.example.com/avatar"),
+        ]);
+        add_args.insert(
+            "parts".to_string(),
+            json!([
+                {"name": "title", "value": "Profile"},
+                {"name": "avatar", "file": "fixtures/avatar.png", "content_type": "image/png"}
+            ]),
+        );
+        let added = service.call_tool("add_request", Some(add_args)).unwrap();
+        let added: serde_json::Value = serde_json::from_str(match &added.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let request_id = added["request_id"].as_str().unwrap();
+
+        let collection = load_collection_in_dir(collection_id, dir.path()).unwrap();
+        let body = collection.requests[0].body.as_ref().unwrap();
+        assert_eq!(body.body_type, BodyType::Multipart);
+        assert_eq!(body.parts.len(), 2);
+
+        let exec_args = args(&[("collection_id", collection_id), ("request_id", request_id)]);
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert_eq!(prepared.params.body, None);
+        assert_eq!(
+            prepared.params.multipart[0].value.as_deref(),
+            Some("Profile")
+        );
+        assert_eq!(
+            prepared.params.multipart[1].file.as_deref().map(Path::new),
+            Some(dir.path().join("fixtures/avatar.png").as_path())
+        );
+
+        let update_args = args(&[
+            ("collection_id", collection_id),
+            ("request_id", request_id),
+            ("body_file", "/tmp/firmware.bin"),
+        ]);
+        service
+            .call_tool("update_request", Some(update_args))
+            .unwrap();
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert!(prepared.params.multipart.is_empty());
+        assert_eq!(
+            prepared.params.body_file.as_deref(),
+            Some("/tmp/firmware.bin")
+        );
+        let collection = load_collection_in_dir(collection_id, dir.path()).unwrap();
+        assert_eq!(
+            collection.requests[0].body.as_ref().unwrap().body_type,
+            BodyType::Binary
+        );
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)] // One request walked through POST, GET, validation and cleanup
+    fn test_graphql_requests_are_serialized_and_validated() {
+        use crate::domain::graphql::GraphqlSchemaCache;
+        use crate::infrastructure::storage::graphql_schema_store::save_graphql_schema_in_dir;
+
+        let (mut service, dir) = make_service();
+        let created = service
+            .call_tool("create_collection", Some(args(&[("name", "GraphQL")])))
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(match &created.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let collection_id = created["id"].as_str().unwrap();
+
+        let mut add_args = args(&[
+            ("collection_id", collection_id),
+            ("name", "User"),
+            ("method", "POST"),
+            ("url", "https://api.example.com/graphql"),
+        ]);
+        add_args.insert(
+            "graphql".to_string(),
+            json!({
+                "query": "query User($id: ID!) { user(id: $id) { name } }",
+                "variables": { "id": "1" },
+                "operation_name": "User"
+            }),
+        );
+        let added = service.call_tool("add_request", Some(add_args)).unwrap();
+        let added: serde_json::Value = serde_json::from_str(match &added.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let request_id = added["request_id"].as_str().unwrap();
+
+        // POST: JSON envelope with a JSON content type
+        let exec_args = args(&[("collection_id", collection_id), ("request_id", request_id)]);
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(prepared.params.body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["operationName"], "User");
+        assert_eq!(body["variables"], json!({ "id": "1" }));
+        assert_eq!(
+            prepared.params.headers.get("Content-Type"),
+            Some("application/json")
+        );
+
+        // GET: query string parameters, no body
+        service
+            .call_tool(
+                "update_request",
+                Some(args(&[
+                    ("collection_id", collection_id),
+                    ("request_id", request_id),
+                    ("method", "GET"),
+                ])),
+            )
+            .unwrap();
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert!(prepared.params.body.is_none());
+        assert!(
+            prepared
+                .params
+                .url
+                .starts_with("https://api.example.com/graphql?query=query+User")
+        );
+        assert!(prepared.params.url.ends_with("&operationName=User"));
+
+        // A cached schema without `user` rejects the request unless skipped
+        let schema = serde_json::from_value(json!({
+            "query_type": "Query",
+            "types": {
+                "Query": { "kind": "object", "fields": [{ "name": "me", "type_ref": "String" }] },
+                "ID": { "kind": "scalar" },
+                "String": { "kind": "scalar" }
+            }
+        }))
+        .unwrap();
+        let cache = GraphqlSchemaCache {
+            endpoint: "https://api.example.com/graphql".to_string(),
+            fetched_at: "2026-01-31T10:30:00Z".to_string(),
+            schema,
+        };
+        save_graphql_schema_in_dir(dir.path(), collection_id, &cache).unwrap();
+        let err = service.prepare_execute_request(&exec_args).unwrap_err();
+        assert!(
+            err.contains("Cannot query field \"user\" on type \"Query\""),
+            "{err}"
+        );
+        let mut skip_args = exec_args;
+        skip_args.insert("skip_graphql_validation".to_string(), json!(true));
+        assert!(service.prepare_execute_request(&skip_args).is_ok());
+
+        let listed = service
+            .call_tool(
+                "graphql_list_operations",
+                Some(args(&[("collection_id", collection_id)])),
+            )
+            .unwrap();
+        let listed: serde_json::Value = serde_json::from_str(match &listed.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        assert_eq!(listed["operations"][0]["name"], "me");
+        assert_eq!(listed["operations"][0]["kind"], "query");
+
+        // Deleting the collection removes the cached schema
+        service
+            .call_tool(
+                "delete_collection",
+                Some(args(&[("collection_id", collection_id)])),
+            )
+            .unwrap();
+        assert!(
+            !dir.path()
+                .join(format!("{collection_id}.graphql.json"))
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_add_request_rejects_ambiguous_multipart_part() {
+        let (mut service, _dir) = make_service();
+        let created = service
+            .call_tool("create_collection", Some(args(&[("name", "Uploads")])))
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(match &created.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+
+        let mut add_args = args(&[
+            ("collection_id", created["id"].as_str().unwrap()),
+            ("name", "Upload"),
+            ("method", "POST"),
+            ("url", "https://api.example.com/upload"),
+        ]);
+        add_args.insert(
+            "parts".to_string(),
+            json!([{"name": "doc", "value": "inline", "file": "doc.pdf"}]),
+        );
+        let err = service
+            .call_tool("add_request", Some(add_args))
+            .unwrap_err();
+        assert!(err.contains("exactly one of value or file"), "{err}");
     }
 
     #[test]
@@ -3041,24 +4046,160 @@ mod tests {
         exec_args.insert("collection_id".to_string(), json!(collection_id));
         exec_args.insert("request_id".to_string(), json!(request_id));
         exec_args.insert("timeout_ms".to_string(), json!(60_000));
-
-        let (params, _, _) = service.prepare_execute_request(&exec_args).unwrap();
-        assert_eq!(params.timeout_ms, 60_000);
+        exec_args.insert("follow_redirects".to_string(), json!(false));
+        exec_args.insert("max_redirects".to_string(), json!(3));
+
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert_eq!(prepared.params.timeout_ms, 60_000);
+        assert!(!prepared.params.follow_redirects);
+        assert_eq!(prepared.params.max_redirects, 3);
+        assert!(!prepared.params.verbose);
+
+        exec_args.insert("verbose".to_string(), json!(true));
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert!(prepared.params.verbose);
     }
 
     #[test]
-    fn test_update_request_emits_event_with_name() {
-        let dir = TempDir::new().unwrap();
-        let emitter = crate::domain::mcp::events::TestEventEmitter::new();
-        let events = emitter.events_handle();
-        let mut service =
-            McpServerService::with_emitter(dir.path().to_path_buf(), Arc::new(emitter));
-
-        // Create collection and add request
-        let (collection_id, request_id) = create_collection_with_request(
-            &mut service,
-            "Update Event Test",
-            "Original Name",
+    fn test_http_version_request_overrides_collection() {
+        let (mut service, _dir) = make_service();
+        let created = service
+            .call_tool(
+                "create_collection",
+                Some(args(&[("name", "Gateway"), ("http_version", "h2c")])),
+            )
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(match &created.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let collection_id = created["id"].as_str().unwrap();
+        let add = |service: &mut McpServerService, name: &str, version: Option<&str>| {
+            let mut pairs = vec![
+                ("collection_id", collection_id),
+                ("name", name),
+                ("method", "GET"),
+                ("url", "http://localhost:8080/v1/items"),
+            ];
+            pairs.extend(version.map(|v| ("http_version", v)));
+            let result = service
+                .call_tool("add_request", Some(args(&pairs)))
+                .unwrap();
+            let added: serde_json::Value = serde_json::from_str(match &result.content[0] {
+                ToolResponseContent::Text { text } => text,
+            })
+            .unwrap();
+            added["request_id"].as_str().unwrap().to_string()
+        };
+        let inherited = add(&mut service, "Inherited", None);
+        let pinned = add(&mut service, "Pinned", Some("http1.1"));
+
+        let prepare = |request_id: &str, version: Option<&str>| {
+            let mut pairs = vec![("collection_id", collection_id), ("request_id", request_id)];
+            pairs.extend(version.map(|v| ("http_version", v)));
+            service
+                .prepare_execute_request(&args(&pairs))
+                .unwrap()
+                .params
+                .http_version
+        };
+        assert_eq!(prepare(&inherited, None), HttpVersion::H2c);
+        assert_eq!(prepare(&pinned, None), HttpVersion::Http11);
+        assert_eq!(prepare(&pinned, Some("http1.0")), HttpVersion::Http10);
+
+        let error = service
+            .call_tool(
+                "update_request",
+                Some(args(&[
+                    ("collection_id", collection_id),
+                    ("request_id", &pinned),
+                    ("http_version", "spdy"),
+                ])),
+            )
+            .unwrap_err();
+        assert!(error.contains("http_version"), "{error}");
+    }
+
+    #[test]
+    fn test_retry_policy_request_overrides_collection() {
+        let (mut service, _dir) = make_service();
+        let object = |value: serde_json::Value| value.as_object().unwrap().clone();
+        let created = service
+            .call_tool(
+                "create_collection",
+                Some(object(
+                    json!({"name": "Flaky", "retry": {"max_attempts": 5}}),
+                )),
+            )
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(match &created.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let collection_id = created["id"].as_str().unwrap();
+        let mut add = |name: &str, retry: Option<serde_json::Value>| {
+            let mut request = object(json!({
+                "collection_id": collection_id,
+                "name": name,
+                "method": "GET",
+                "url": "http://localhost:8080/status",
+            }));
+            if let Some(retry) = retry {
+                request.insert("retry".to_string(), retry);
+            }
+            let result = service.call_tool("add_request", Some(request)).unwrap();
+            let added: serde_json::Value = serde_json::from_str(match &result.content[0] {
+                ToolResponseContent::Text { text } => text,
+            })
+            .unwrap();
+            added["request_id"].as_str().unwrap().to_string()
+        };
+        let inherited = add("Inherited", None);
+        let pinned = add("Pinned", Some(json!({"max_attempts": 2, "jitter": false})));
+
+        let prepare = |request_id: &str, retry: Option<serde_json::Value>| {
+            let mut exec =
+                object(json!({"collection_id": collection_id, "request_id": request_id}));
+            if let Some(retry) = retry {
+                exec.insert("retry".to_string(), retry);
+            }
+            service.prepare_execute_request(&exec).unwrap().params.retry
+        };
+        assert_eq!(prepare(&inherited, None).unwrap().max_attempts, 5);
+        let pinned_policy = prepare(&pinned, None).unwrap();
+        assert_eq!(pinned_policy.max_attempts, 2);
+        assert!(!pinned_policy.jitter);
+        assert_eq!(pinned_policy.retry_on_status, vec![429, 502, 503, 504]);
+        assert_eq!(
+            prepare(&pinned, Some(json!({"max_attempts": 7})))
+                .unwrap()
+                .max_attempts,
+            7
+        );
+
+        let error = service
+            .prepare_execute_request(&object(json!({
+                "collection_id": collection_id,
+                "request_id": pinned,
+                "retry": {"max_attempts": "many"},
+            })))
+            .unwrap_err();
+        assert!(error.contains("retry"), "{error}");
+    }
+
+    #[test]
+    fn test_update_request_emits_event_with_name() {
+        let dir = TempDir::new().unwrap();
+        let emitter = crate::domain::mcp::events::TestEventEmitter::new();
+        let events = emitter.events_handle();
+        let mut service =
+            McpServerService::with_emitter(dir.path().to_path_buf(), Arc::new(emitter));
+
+        // Create collection and add request
+        let (collection_id, request_id) = create_collection_with_request(
+            &mut service,
+            "Update Event Test",
+            "Original Name",
             "GET",
             "http://example.com",
         );
@@ -3173,6 +4314,380 @@ mod tests {
         assert!(text.contains("local"));
     }
 
+    #[test]
+    fn test_prepare_execute_request_applies_active_environment_tls() {
+        let (mut service, _dir) = make_service();
+
+        let result = service
+            .call_tool("create_collection", Some(args(&[("name", "Env TLS Test")])))
+            .unwrap();
+        let text = match &result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        };
+        let json: serde_json::Value = serde_json::from_str(text).unwrap();
+        let collection_id = json["id"].as_str().unwrap().to_string();
+
+        let mut env_args = serde_json::Map::new();
+        env_args.insert("collection_id".to_string(), json!(&collection_id));
+        env_args.insert("name".to_string(), json!("internal"));
+        env_args.insert("variables".to_string(), json!({}));
+        env_args.insert(
+            "tls".to_string(),
+            json!({
+                "client_cert": "/certs/client.p12",
+                "client_key_password": "secret",
+                "ca_bundle": "/certs/corp-ca.pem",
+                "min_tls_version": "1.2"
+            }),
+        );
+        let result = service
+            .call_tool("upsert_environment", Some(env_args))
+            .unwrap();
+        assert!(!result.is_error);
+
+        let add_result = service
+            .call_tool(
+                "add_request",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("name", "Internal"),
+                    ("method", "GET"),
+                    ("url", "https://internal.example.com/health"),
+                ])),
+            )
+            .unwrap();
+        let added: serde_json::Value = serde_json::from_str(match &add_result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+        let request_id = added["request_id"].as_str().unwrap();
+
+        let mut exec_args = serde_json::Map::new();
+        exec_args.insert("collection_id".to_string(), json!(&collection_id));
+        exec_args.insert("request_id".to_string(), json!(request_id));
+
+        // No active environment: no TLS settings applied.
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert!(prepared.params.tls.is_none());
+
+        service
+            .call_tool(
+                "set_active_environment",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("name", "internal"),
+                ])),
+            )
+            .unwrap();
+
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        let tls = prepared.params.tls.expect("environment TLS settings");
+        assert_eq!(tls.client_cert.as_deref(), Some("/certs/client.p12"));
+        assert_eq!(tls.ca_bundle.as_deref(), Some("/certs/corp-ca.pem"));
+        assert_eq!(
+            tls.min_tls_version,
+            Some(crate::domain::http::TlsVersion::Tls1_2)
+        );
+
+        assert!(prepared.params.proxy.is_none());
+
+        // Per-call settings override the environment field by field.
+        exec_args.insert(
+            "tls".to_string(),
+            json!({"client_cert": "/certs/other.pem", "client_key": "/certs/other.key"}),
+        );
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        let tls = prepared.params.tls.expect("merged TLS settings");
+        assert_eq!(tls.client_cert.as_deref(), Some("/certs/other.pem"));
+        assert_eq!(tls.client_key.as_deref(), Some("/certs/other.key"));
+        assert_eq!(tls.ca_bundle.as_deref(), Some("/certs/corp-ca.pem"));
+    }
+
+    #[test]
+    fn test_upsert_environment_proxy_applies_to_execution() {
+        let (mut service, _dir) = make_service();
+
+        let result = service
+            .call_tool(
+                "create_collection",
+                Some(args(&[("name", "Env Proxy Test")])),
+            )
+            .unwrap();
+        let text = match &result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        };
+        let json: serde_json::Value = serde_json::from_str(text).unwrap();
+        let collection_id = json["id"].as_str().unwrap().to_string();
+
+        let mut env_args = serde_json::Map::new();
+        env_args.insert("collection_id".to_string(), json!(&collection_id));
+        env_args.insert("name".to_string(), json!("corp"));
+        env_args.insert("variables".to_string(), json!({}));
+        env_args.insert("proxy".to_string(), json!({"url": "ftp://proxy:21"}));
+        let err = service
+            .call_tool("upsert_environment", Some(env_args.clone()))
+            .unwrap_err();
+        assert!(err.contains("Unsupported proxy scheme"));
+
+        env_args.insert(
+            "proxy".to_string(),
+            json!({"url": "socks5h://proxy.corp:1080", "no_proxy": [".corp"]}),
+        );
+        service
+            .call_tool("upsert_environment", Some(env_args))
+            .unwrap();
+        service
+            .call_tool(
+                "set_active_environment",
+                Some(args(&[("collection_id", &collection_id), ("name", "corp")])),
+            )
+            .unwrap();
+
+        let add_result = service
+            .call_tool(
+                "add_request",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("name", "External"),
+                    ("method", "GET"),
+                    ("url", "https://api.example.com/status"),
+                ])),
+            )
+            .unwrap();
+        let added: serde_json::Value = serde_json::from_str(match &add_result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+
+        let mut exec_args = serde_json::Map::new();
+        exec_args.insert("collection_id".to_string(), json!(&collection_id));
+        exec_args.insert("request_id".to_string(), added["request_id"].clone());
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        let proxy = prepared.params.proxy.expect("environment proxy");
+        assert_eq!(proxy.url.as_deref(), Some("socks5h://proxy.corp:1080"));
+        assert_eq!(proxy.no_proxy, vec![".corp".to_string()]);
+    }
+
+    #[test]
+    fn test_host_overrides_and_unix_socket_apply_to_execution() {
+        let (mut service, _dir) = make_service();
+
+        let result = service
+            .call_tool("create_collection", Some(args(&[("name", "Routing Test")])))
+            .unwrap();
+        let text = match &result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        };
+        let json: serde_json::Value = serde_json::from_str(text).unwrap();
+        let collection_id = json["id"].as_str().unwrap().to_string();
+
+        let mut env_args = serde_json::Map::new();
+        env_args.insert("collection_id".to_string(), json!(&collection_id));
+        env_args.insert("name".to_string(), json!("staging"));
+        env_args.insert(
+            "variables".to_string(),
+            json!({"dockerSock": "/var/run/docker.sock"}),
+        );
+        env_args.insert(
+            "resolve".to_string(),
+            json!([{"host": "api.example.com", "addresses": ["staging-lb"]}]),
+        );
+        let err = service
+            .call_tool("upsert_environment", Some(env_args.clone()))
+            .unwrap_err();
+        assert!(err.contains("expected an IP address"));
+
+        env_args.insert(
+            "resolve".to_string(),
+            json!([{"host": "api.example.com", "addresses": ["10.0.0.12"]}]),
+        );
+        env_args.insert(
+            "connect_to".to_string(),
+            json!([{"host": "auth.example.com", "to_host": "auth-canary.internal"}]),
+        );
+        service
+            .call_tool("upsert_environment", Some(env_args))
+            .unwrap();
+        service
+            .call_tool(
+                "set_active_environment",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("name", "staging"),
+                ])),
+            )
+            .unwrap();
+
+        let add_result = service
+            .call_tool(
+                "add_request",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("name", "Containers"),
+                    ("method", "GET"),
+                    ("url", "http://localhost/containers/json"),
+                    ("unix_socket", "{{dockerSock}}"),
+                ])),
+            )
+            .unwrap();
+        let added: serde_json::Value = serde_json::from_str(match &add_result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        })
+        .unwrap();
+
+        let mut exec_args = serde_json::Map::new();
+        exec_args.insert("collection_id".to_string(), json!(&collection_id));
+        exec_args.insert("request_id".to_string(), added["request_id"].clone());
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert_eq!(
+            prepared.params.unix_socket.as_deref(),
+            Some("/var/run/docker.sock")
+        );
+        assert_eq!(prepared.params.resolve[0].addresses, ["10.0.0.12"]);
+        assert_eq!(
+            prepared.params.connect_to[0].to_host.as_deref(),
+            Some("auth-canary.internal")
+        );
+
+        service
+            .call_tool(
+                "update_request",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("request_id", added["request_id"].as_str().unwrap()),
+                    ("unix_socket", ""),
+                ])),
+            )
+            .unwrap();
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert!(prepared.params.unix_socket.is_none());
+    }
+
+    #[test]
+    fn test_assertions_are_validated_and_prepared_for_execution() {
+        let (mut service, _dir) = make_service();
+        let (collection_id, request_id) = create_collection_with_request(
+            &mut service,
+            "Checks",
+            "List",
+            "GET",
+            "https://api.example.com/items",
+        );
+        let mut update = args(&[
+            ("collection_id", &collection_id),
+            ("request_id", &request_id),
+        ]);
+        update.insert(
+            "assertions".to_string(),
+            json!([{"check": "status_in_range", "min": 299, "max": 200}]),
+        );
+        let err = service
+            .call_tool("update_request", Some(update.clone()))
+            .unwrap_err();
+        assert_eq!(err, "Status range 299-200 is empty");
+        update.insert(
+            "assertions".to_string(),
+            json!([
+                {"check": "status_in_range", "min": 200, "max": 299},
+                {"check": "json_path_type", "path": "$.items", "json_type": "array"}
+            ]),
+        );
+        service.call_tool("update_request", Some(update)).unwrap();
+
+        let exec_args = args(&[
+            ("collection_id", &collection_id),
+            ("request_id", &request_id),
+        ]);
+        let prepared = service.prepare_execute_request(&exec_args).unwrap();
+        assert_eq!(
+            prepared.assertions,
+            vec![
+                Assertion::StatusInRange { min: 200, max: 299 },
+                Assertion::JsonPathType {
+                    path: "$.items".to_string(),
+                    json_type: crate::domain::assertion::JsonType::Array,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extraction_rules_set_variables_and_emit_events() {
+        let dir = TempDir::new().unwrap();
+        let emitter = crate::domain::mcp::events::TestEventEmitter::new();
+        let events = emitter.events_handle();
+        let mut service =
+            McpServerService::with_emitter(dir.path().to_path_buf(), Arc::new(emitter));
+        let (collection_id, request_id) = create_collection_with_request(
+            &mut service,
+            "Chaining",
+            "Login",
+            "POST",
+            "https://api.example.com/login",
+        );
+
+        let mut update = args(&[
+            ("collection_id", &collection_id),
+            ("request_id", &request_id),
+        ]);
+        update.insert(
+            "extract".to_string(),
+            json!([{"variable": "token", "from": "regex", "pattern": "("}]),
+        );
+        let err = service
+            .call_tool("update_request", Some(update.clone()))
+            .unwrap_err();
+        assert!(err.starts_with("Invalid pattern ("), "{err}");
+        update.insert(
+            "extract".to_string(),
+            json!([
+                {"variable": "token", "from": "json_path", "path": "$.token"},
+                {"variable": "traceId", "from": "header", "name": "X-Trace"}
+            ]),
+        );
+        service.call_tool("update_request", Some(update)).unwrap();
+
+        let response = crate::domain::http::HttpResponse {
+            status: 200,
+            body: r#"{"token":"t0k"}"#.to_string(),
+            ..Default::default()
+        };
+        let extracted = service.apply_extractions(&collection_id, &request_id, &response);
+        assert_eq!(extracted[0].value.as_deref(), Some("t0k"));
+        assert_eq!(
+            extracted[1].error.as_deref(),
+            Some("Response has no X-Trace header")
+        );
+
+        let captured = events.lock().expect("lock events");
+        let changed: Vec<_> = captured
+            .iter()
+            .filter(|(name, _, _)| name == "variable:changed")
+            .collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].2["variable"], "token");
+        assert_eq!(changed[0].2["value"], "t0k");
+        drop(captured);
+
+        let (other_collection, other_request) = create_collection_with_request(
+            &mut service,
+            "Chaining 2",
+            "Me",
+            "GET",
+            "https://api.example.com/me?token={{token}}",
+        );
+        assert!(
+            service
+                .prepare_execute_request(&args(&[
+                    ("collection_id", &other_collection),
+                    ("request_id", &other_request),
+                ]))
+                .unwrap_err()
+                .contains("Unresolved variables: token"),
+            "runtime variables are scoped to their collection"
+        );
+    }
+
     #[test]
     fn test_delete_environment_removes_it() {
         let (mut service, _dir) = make_service();
@@ -3326,6 +4841,105 @@ mod tests {
         assert!(text.contains("(none)"));
     }
 
+    #[test]
+    fn test_cookie_tools_edit_jar_and_scope_execution() {
+        let (mut service, dir) = make_service();
+
+        let col_result = service
+            .call_tool("create_collection", Some(args(&[("name", "Cookie Test")])))
+            .unwrap();
+        let text = match &col_result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        };
+        let json: serde_json::Value = serde_json::from_str(text).unwrap();
+        let collection_id = json["id"].as_str().unwrap().to_string();
+
+        let mut set_args = args(&[("collection_id", &collection_id), ("environment", "qa")]);
+        set_args.insert(
+            "cookie".to_string(),
+            json!({"name": "session", "value": "abc123", "domain": "api.example.com", "http_only": true}),
+        );
+        service.call_tool("set_cookie", Some(set_args)).unwrap();
+        assert!(
+            dir.path()
+                .join(format!("{collection_id}.cookies.json"))
+                .exists()
+        );
+
+        let list = |service: &mut McpServerService, environment: &str| {
+            let result = service
+                .call_tool(
+                    "list_cookies",
+                    Some(args(&[
+                        ("collection_id", &collection_id),
+                        ("environment", environment),
+                    ])),
+                )
+                .unwrap();
+            let text = match &result.content[0] {
+                ToolResponseContent::Text { text } => text.clone(),
+            };
+            serde_json::from_str::<serde_json::Value>(&text).unwrap()["cookies"]
+                .as_array()
+                .unwrap()
+                .len()
+        };
+        assert_eq!(list(&mut service, "qa"), 1);
+        assert_eq!(list(&mut service, "prod"), 0);
+
+        let mut bad_args = args(&[("collection_id", &collection_id)]);
+        bad_args.insert(
+            "cookie".to_string(),
+            json!({"name": "x", "value": "y", "domain": ""}),
+        );
+        let err = service.call_tool("set_cookie", Some(bad_args)).unwrap_err();
+        assert!(err.contains("name and domain are required"));
+
+        service
+            .call_tool(
+                "delete_cookies",
+                Some(args(&[
+                    ("collection_id", &collection_id),
+                    ("environment", "qa"),
+                    ("name", "session"),
+                ])),
+            )
+            .unwrap();
+        assert_eq!(list(&mut service, "qa"), 0);
+        assert!(
+            !dir.path()
+                .join(format!("{collection_id}.cookies.json"))
+                .exists()
+        );
+
+        // Executed requests read and write the jar of the active environment.
+        let add_args = args(&[
+            ("collection_id", &collection_id),
+            ("name", "Me"),
+            ("method", "GET"),
+            ("url", "https://api.example.com/me"),
+        ]);
+        let add_result = service.call_tool("add_request", Some(add_args)).unwrap();
+        let text = match &add_result.content[0] {
+            ToolResponseContent::Text { text } => text,
+        };
+        let json: serde_json::Value = serde_json::from_str(text).unwrap();
+        let request_id = json["request_id"].as_str().unwrap().to_string();
+        let prepared = service
+            .prepare_execute_request(&args(&[
+                ("collection_id", &collection_id),
+                ("request_id", &request_id),
+            ]))
+            .unwrap();
+        assert_eq!(
+            prepared.params.cookie_jar,
+            Some(CookieJarScope {
+                collection_id: collection_id.clone(),
+                environment: None,
+            })
+        );
+    }
+
     // -------------------------------------------------------------------------
     // handle_remove_pinned_version tests
     // -------------------------------------------------------------------------
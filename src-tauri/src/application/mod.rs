@@ -3,13 +3,25 @@
 
 // Application layer - Use cases and services
 
+/// Collection runner — ordered execution of a collection's requests with run reports.
+pub mod collection_runner;
+/// GraphQL service — schema introspection and query validation.
+pub mod graphql_service;
 /// Spec import orchestration — pluggable format detection and conversion.
 pub mod import_service;
+/// Load tester — concurrent repetition of one request with latency statistics.
+pub mod load_tester;
 /// MCP server application service — tool registry, dispatch, and collection CRUD.
 pub mod mcp_server_service;
 /// Project context service — persistent user working state.
 pub mod project_context_service;
 /// HTTP proxy application service.
 pub mod proxy_service;
+/// Request resolution — collection requests as they go on the wire.
+pub mod request_resolver;
+/// Spec refresh — drift against re-fetched specs.
+pub mod spec_refresh;
 /// Suggestion service — AI suggestion CRUD with TOML file persistence.
 pub mod suggestion_service;
+/// Variable extraction — captured values for request chaining.
+pub mod variable_extraction;
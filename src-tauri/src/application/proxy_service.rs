@@ -6,9 +6,12 @@
 
 use crate::domain::models::HelloWorldResponse;
 
+/// HTTP proxy service; only answers `hello_world` for now.
+#[derive(Default)]
 pub struct ProxyService;
 
 impl ProxyService {
+    /// Create the proxy service.
     #[must_use]
     pub const fn new() -> Self {
         Self
@@ -0,0 +1,627 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Request resolution — turns a collection request into the parameters that
+//! go on the wire.
+//!
+//! Every path that sends a saved request (the UI, MCP `execute_request`, the
+//! collection runner, load tests and GraphQL introspection) resolves it here,
+//! so they all send what the collection describes: variables substituted,
+//! enabled query parameters appended, auth inherited from the collection and
+//! rendered, and the active environment's connection settings applied.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+
+use crate::domain::collection::{
+    AuthConfig, AuthType, BodyType, Collection, CollectionRequest, RequestBody,
+};
+use crate::domain::cookies::CookieJarScope;
+use crate::domain::graphql::GraphqlQuery;
+use crate::domain::http::{MultipartPart, RequestParams};
+use crate::domain::variables::TemplateResolver;
+use crate::infrastructure::storage::runtime_variable_store::runtime_variables;
+
+/// Header an API key is sent in when [`AuthConfig::header`] is not set.
+const DEFAULT_API_KEY_HEADER: &str = "X-API-Key";
+
+/// Build the parameters for sending `request` from `collection`.
+///
+/// Resolves variables against the active environment and the collection's
+/// runtime variables (see [`crate::domain::variables`]), applies the request's
+/// auth (or the collection's when the request has none), the environment's
+/// proxy, TLS and host overrides and the collection's HTTP version and retry
+/// defaults, and scopes cookies to the collection and environment. Relative
+/// file paths resolve against `dir`.
+///
+/// # Errors
+///
+/// Returns an error listing the variables that could not be resolved, naming
+/// a reference cycle between variables, or when a GraphQL GET request's URL
+/// is not an absolute URL.
+pub fn collection_request_params(
+    collection: &Collection,
+    request: &CollectionRequest,
+    dir: &Path,
+    timeout_ms: u64,
+) -> Result<RequestParams, String> {
+    let vars = effective_vars(collection);
+    let resolver = TemplateResolver::new(&vars);
+    let mut params = match collection_request_to_params_with_vars(request, &resolver, timeout_ms) {
+        Ok(params) => params,
+        Err(e) => {
+            // Unresolved variables explain a malformed URL better than the URL error
+            resolver.finish()?;
+            return Err(e);
+        }
+    };
+    if let Some(auth) = request.auth.as_ref().or(collection.auth.as_ref()) {
+        apply_auth(&mut params, auth, &resolver);
+    }
+    resolver.finish()?;
+    params.resolve_file_paths(dir);
+    let env = collection.active_env();
+    params.proxy = env.and_then(|env| env.proxy.clone());
+    params.tls = env.and_then(|env| env.tls.clone());
+    params.resolve = env.map(|env| env.resolve.clone()).unwrap_or_default();
+    params.connect_to = env.map(|env| env.connect_to.clone()).unwrap_or_default();
+    params.http_version = request
+        .http_version
+        .or(collection.http_version)
+        .unwrap_or_default();
+    params.retry = request.retry.clone().or_else(|| collection.retry.clone());
+    params.cookie_jar = Some(CookieJarScope {
+        collection_id: collection.id.clone(),
+        environment: collection.active_environment.clone(),
+    });
+    Ok(params)
+}
+
+/// Build the effective variable map for a collection: collection-level variables
+/// merged with the active environment's variables and then the runtime
+/// variables captured by extraction rules (later sources take precedence).
+fn effective_vars(collection: &Collection) -> BTreeMap<String, String> {
+    let mut vars = collection.variables.clone();
+    if let Some(env) = collection.active_env() {
+        vars.extend(env.variables.clone());
+    }
+    vars.extend(runtime_variables(&collection.id));
+    vars
+}
+
+/// Convert `req` into request parameters, substituting variables in the URL,
+/// query parameters, headers, body and Unix socket path.
+///
+/// Fails when a GraphQL query cannot be added to the URL of a GET request.
+fn collection_request_to_params_with_vars(
+    req: &CollectionRequest,
+    resolver: &TemplateResolver<'_>,
+    timeout_ms: u64,
+) -> Result<RequestParams, String> {
+    let resolve = |template: &str| resolver.resolve(template);
+    let query: Vec<(String, String)> = req
+        .params
+        .iter()
+        .filter(|param| param.enabled)
+        .map(|param| (resolve(&param.key), resolve(&param.value)))
+        .collect();
+    let mut params = RequestParams {
+        url: append_query(&resolve(&req.url), &query),
+        method: req.method.clone(),
+        headers: req
+            .headers
+            .iter()
+            .map(|(k, v)| (resolve(k), resolve(v)))
+            .collect(),
+        body: req
+            .body
+            .as_ref()
+            .and_then(|b| b.content.as_deref())
+            .map(resolve),
+        body_file: req
+            .body
+            .as_ref()
+            .filter(|b| b.content.is_none())
+            .and_then(|b| b.file.as_deref())
+            .map(resolve),
+        multipart: req
+            .body
+            .as_ref()
+            .filter(|b| b.body_type == BodyType::Multipart)
+            .map(|b| {
+                b.parts
+                    .iter()
+                    .map(|part| resolve_multipart_part(part, resolver))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        timeout_ms,
+        unix_socket: req.unix_socket.as_deref().map(resolve),
+        ..Default::default()
+    };
+    if let Some(follow) = req.follow_redirects {
+        params.follow_redirects = follow;
+    }
+    if let Some(query) = req.body.as_ref().and_then(RequestBody::graphql_query) {
+        apply_graphql_query(&mut params, &resolve_graphql_query(&query, resolver))?;
+    }
+    Ok(params)
+}
+
+/// Append `pairs` to the query string of `url`, form-encoded, ahead of any
+/// fragment.
+fn append_query(url: &str, pairs: &[(String, String)]) -> String {
+    if pairs.is_empty() {
+        return url.to_string();
+    }
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish();
+    let (base, fragment) = url
+        .split_once('#')
+        .map_or((url, None), |(base, fragment)| (base, Some(fragment)));
+    let separator = if base.ends_with(['?', '&']) {
+        ""
+    } else if base.contains('?') {
+        "&"
+    } else {
+        "?"
+    };
+    let mut result = format!("{base}{separator}{query}");
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    result
+}
+
+/// Substitute variables in the text fields of a multipart part.
+fn resolve_multipart_part(part: &MultipartPart, resolver: &TemplateResolver<'_>) -> MultipartPart {
+    let resolve = |value: &Option<String>| value.as_deref().map(|value| resolver.resolve(value));
+    MultipartPart {
+        name: resolver.resolve(&part.name),
+        value: resolve(&part.value),
+        file: resolve(&part.file),
+        filename: resolve(&part.filename),
+        content_type: part.content_type.clone(),
+    }
+}
+
+/// Substitute variables in a GraphQL document and in the string values of
+/// its variables, so substituted values never break the JSON envelope.
+fn resolve_graphql_query(query: &GraphqlQuery, resolver: &TemplateResolver<'_>) -> GraphqlQuery {
+    fn resolve_json(
+        value: &serde_json::Value,
+        resolver: &TemplateResolver<'_>,
+    ) -> serde_json::Value {
+        match value {
+            serde_json::Value::String(text) => serde_json::Value::String(resolver.resolve(text)),
+            serde_json::Value::Array(items) => items
+                .iter()
+                .map(|item| resolve_json(item, resolver))
+                .collect(),
+            serde_json::Value::Object(fields) => fields
+                .iter()
+                .map(|(key, field)| (key.clone(), resolve_json(field, resolver)))
+                .collect(),
+            other => other.clone(),
+        }
+    }
+    GraphqlQuery {
+        query: resolver.resolve(&query.query),
+        variables: query
+            .variables
+            .as_ref()
+            .map(|variables| resolve_json(variables, resolver)),
+        operation_name: query.operation_name.clone(),
+    }
+}
+
+/// Serialize a GraphQL request into `params`: query-string parameters for GET,
+/// a JSON body for every other method.
+fn apply_graphql_query(params: &mut RequestParams, query: &GraphqlQuery) -> Result<(), String> {
+    if params.method.eq_ignore_ascii_case("GET") {
+        params.url = query.apply_to_url(&params.url)?;
+        params.body = None;
+        return Ok(());
+    }
+    params.body = Some(query.to_json_body());
+    if !params.headers.contains("Content-Type") {
+        params.headers.append("Content-Type", "application/json");
+    }
+    Ok(())
+}
+
+/// Where auth puts its credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AuthPlacement {
+    /// A request header (bearer tokens, header API keys).
+    Header(String, String),
+    /// A query parameter (query API keys).
+    Query(String, String),
+    /// HTTP Basic credentials.
+    Basic {
+        /// User name.
+        username: String,
+        /// Password, empty when not set.
+        password: String,
+    },
+}
+
+/// Where `auth` sends its credentials, or `None` when it sends nothing.
+///
+/// `field` turns each set field into its value (variables resolved, or
+/// templated for export) and returns `None` for an empty one. Auth without
+/// the fields it needs (e.g. a bearer token) sends nothing.
+pub(crate) fn auth_placement(
+    auth: &AuthConfig,
+    mut field: impl FnMut(&str) -> Option<String>,
+) -> Option<AuthPlacement> {
+    let mut field = |value: &Option<String>| value.as_deref().and_then(&mut field);
+    match auth.auth_type {
+        AuthType::None => None,
+        AuthType::Bearer => field(&auth.token).map(|token| {
+            AuthPlacement::Header("Authorization".to_string(), format!("Bearer {token}"))
+        }),
+        AuthType::Basic => field(&auth.username).map(|username| AuthPlacement::Basic {
+            username,
+            password: field(&auth.password).unwrap_or_default(),
+        }),
+        AuthType::ApiKey => {
+            let key = field(&auth.token)?;
+            if let Some(name) = field(&auth.query_param) {
+                return Some(AuthPlacement::Query(name, key));
+            }
+            let name = field(&auth.header).unwrap_or_else(|| DEFAULT_API_KEY_HEADER.to_string());
+            Some(AuthPlacement::Header(name, key))
+        }
+    }
+}
+
+/// Render `auth` into `params`, substituting variables in its fields.
+///
+/// A header the request sets explicitly wins over the one auth would add.
+fn apply_auth(params: &mut RequestParams, auth: &AuthConfig, resolver: &TemplateResolver<'_>) {
+    let placement = auth_placement(auth, |value| {
+        Some(resolver.resolve(value)).filter(|value| !value.is_empty())
+    });
+    let (name, value) = match placement {
+        None => return,
+        Some(AuthPlacement::Query(name, key)) => {
+            params.url = append_query(&params.url, &[(name, key)]);
+            return;
+        }
+        Some(AuthPlacement::Header(name, value)) => (name, value),
+        Some(AuthPlacement::Basic { username, password }) => {
+            let credentials = BASE64.encode(format!("{username}:{password}"));
+            ("Authorization".to_string(), format!("Basic {credentials}"))
+        }
+    };
+    if !params.headers.contains(&name) {
+        params.headers.append(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::collection::{CollectionEnvironment, RequestParam};
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    fn param(key: &str, value: &str, enabled: bool) -> RequestParam {
+        RequestParam {
+            key: key.to_string(),
+            value: value.to_string(),
+            enabled,
+        }
+    }
+
+    #[test]
+    fn test_collection_request_to_params_basic() {
+        let req = CollectionRequest {
+            id: "req_test".to_string(),
+            name: "Test".to_string(),
+            method: "POST".to_string(),
+            url: "https://api.example.com/users".to_string(),
+            ..Default::default()
+        };
+
+        let params = collection_request_to_params_with_vars(
+            &req,
+            &TemplateResolver::new(&BTreeMap::new()),
+            5000,
+        )
+        .unwrap();
+        assert_eq!(params.url, "https://api.example.com/users");
+        assert_eq!(params.method, "POST");
+        assert!(params.headers.is_empty());
+        assert!(params.body.is_none());
+        assert_eq!(params.timeout_ms, 5000);
+        assert!(params.follow_redirects);
+
+        let no_redirects = CollectionRequest {
+            follow_redirects: Some(false),
+            ..req
+        };
+        let params = collection_request_to_params_with_vars(
+            &no_redirects,
+            &TemplateResolver::new(&BTreeMap::new()),
+            5000,
+        )
+        .unwrap();
+        assert!(!params.follow_redirects);
+    }
+
+    #[test]
+    fn test_collection_request_to_params_with_headers_and_body() {
+        let mut headers = BTreeMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        headers.insert("Authorization".to_string(), "Bearer tok".to_string());
+
+        let req = CollectionRequest {
+            id: "req_test".to_string(),
+            name: "Test".to_string(),
+            method: "PUT".to_string(),
+            url: "https://api.example.com/update".to_string(),
+            headers,
+            body: Some(RequestBody {
+                body_type: BodyType::Json,
+                content: Some(r#"{"key":"val"}"#.to_string()),
+                file: None,
+                parts: Vec::new(),
+                graphql: None,
+            }),
+            ..Default::default()
+        };
+
+        let params = collection_request_to_params_with_vars(
+            &req,
+            &TemplateResolver::new(&BTreeMap::new()),
+            10_000,
+        )
+        .unwrap();
+        assert_eq!(params.headers.len(), 2);
+        assert_eq!(params.headers.get("Content-Type"), Some("application/json"));
+        assert_eq!(params.body, Some(r#"{"key":"val"}"#.to_string()));
+        assert_eq!(params.timeout_ms, 10_000);
+    }
+
+    #[test]
+    fn test_variables_resolve_in_headers_params_and_body() {
+        let mut headers = BTreeMap::new();
+        headers.insert("X-Tenant".to_string(), "{{tenant}}".to_string());
+        let req = CollectionRequest {
+            method: "POST".to_string(),
+            url: "{{baseUrl}}/orders".to_string(),
+            headers,
+            params: vec![
+                param("tenant", "{{tenant}}", true),
+                param("q", "a&b c", true),
+                param("debug", "1", false),
+            ],
+            body: Some(RequestBody {
+                body_type: BodyType::Json,
+                content: Some(r#"{"tenant":"{{tenant}}","missing":"{{nope}}"}"#.to_string()),
+                file: None,
+                parts: Vec::new(),
+                graphql: None,
+            }),
+            ..Default::default()
+        };
+
+        let vars = vars(&[("baseUrl", "https://api.example.com"), ("tenant", "acme")]);
+        let resolver = TemplateResolver::new(&vars);
+        let params = collection_request_to_params_with_vars(&req, &resolver, 5000).unwrap();
+        assert_eq!(
+            params.url,
+            "https://api.example.com/orders?tenant=acme&q=a%26b+c"
+        );
+        assert_eq!(params.headers.get("X-Tenant"), Some("acme"));
+        assert_eq!(
+            params.body.as_deref(),
+            Some(r#"{"tenant":"acme","missing":"{{nope}}"}"#)
+        );
+        assert_eq!(resolver.finish().unwrap_err(), "Unresolved variables: nope");
+    }
+
+    #[test]
+    fn test_query_params_join_existing_query_and_keep_fragment() {
+        let pairs = [("page".to_string(), "2".to_string())];
+        assert_eq!(
+            append_query("https://api.test/items?sort=asc#top", &pairs),
+            "https://api.test/items?sort=asc&page=2#top"
+        );
+        assert_eq!(
+            append_query("https://api.test/items?", &pairs),
+            "https://api.test/items?page=2"
+        );
+        assert_eq!(
+            append_query("https://api.test/items", &[]),
+            "https://api.test/items"
+        );
+    }
+
+    #[test]
+    fn test_graphql_variables_resolve_inside_json() {
+        let req = CollectionRequest {
+            method: "POST".to_string(),
+            url: "https://api.test/graphql".to_string(),
+            body: Some(RequestBody {
+                body_type: BodyType::Graphql,
+                content: None,
+                file: None,
+                parts: Vec::new(),
+                graphql: Some(GraphqlQuery {
+                    query: "query { user(id: \"{{userId}}\") { name } }".to_string(),
+                    variables: Some(serde_json::json!({ "note": "{{note}}" })),
+                    operation_name: None,
+                }),
+            }),
+            ..Default::default()
+        };
+        let vars = vars(&[("userId", "42"), ("note", "say \"hi\"")]);
+        let params =
+            collection_request_to_params_with_vars(&req, &TemplateResolver::new(&vars), 5000)
+                .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(params.body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["query"], "query { user(id: \"42\") { name } }");
+        assert_eq!(body["variables"]["note"], "say \"hi\"");
+    }
+
+    #[test]
+    fn test_graphql_get_with_a_relative_url_fails() {
+        let mut collection = Collection::new("GraphQL");
+        collection.requests.push(CollectionRequest {
+            method: "GET".to_string(),
+            url: "{{path}}".to_string(),
+            body: Some(RequestBody {
+                body_type: BodyType::Graphql,
+                content: None,
+                file: None,
+                parts: Vec::new(),
+                graphql: Some(GraphqlQuery {
+                    query: "{ me }".to_string(),
+                    ..GraphqlQuery::default()
+                }),
+            }),
+            ..Default::default()
+        });
+        let request = collection.requests[0].clone();
+        let dir = Path::new(".");
+
+        let err = collection_request_params(&collection, &request, dir, 5000).unwrap_err();
+        assert_eq!(err, "Unresolved variables: path");
+        collection
+            .variables
+            .insert("path".to_string(), "/graphql".to_string());
+        let err = collection_request_params(&collection, &request, dir, 5000).unwrap_err();
+        assert!(err.starts_with("Invalid URL '/graphql'"), "{err}");
+    }
+
+    fn auth(auth_type: AuthType) -> AuthConfig {
+        AuthConfig {
+            auth_type,
+            token: None,
+            username: None,
+            password: None,
+            header: None,
+            query_param: None,
+        }
+    }
+
+    #[test]
+    fn test_auth_renders_bearer_basic_and_api_key() {
+        let vars = vars(&[("token", "s3cr3t")]);
+        let vars = TemplateResolver::new(&vars);
+        let base = RequestParams {
+            url: "https://api.test/me".to_string(),
+            ..Default::default()
+        };
+
+        let mut params = base.clone();
+        let bearer = AuthConfig {
+            token: Some("{{token}}".to_string()),
+            ..auth(AuthType::Bearer)
+        };
+        apply_auth(&mut params, &bearer, &vars);
+        assert_eq!(params.headers.get("Authorization"), Some("Bearer s3cr3t"));
+
+        let mut params = base.clone();
+        let basic = AuthConfig {
+            username: Some("aladdin".to_string()),
+            password: Some("opensesame".to_string()),
+            ..auth(AuthType::Basic)
+        };
+        apply_auth(&mut params, &basic, &vars);
+        assert_eq!(
+            params.headers.get("Authorization"),
+            Some("Basic YWxhZGRpbjpvcGVuc2VzYW1l")
+        );
+
+        let mut params = base.clone();
+        let header_key = AuthConfig {
+            token: Some("{{token}}".to_string()),
+            ..auth(AuthType::ApiKey)
+        };
+        apply_auth(&mut params, &header_key, &vars);
+        assert_eq!(params.headers.get("X-API-Key"), Some("s3cr3t"));
+
+        let mut params = base.clone();
+        let query_key = AuthConfig {
+            token: Some("k y".to_string()),
+            query_param: Some("api_key".to_string()),
+            ..auth(AuthType::ApiKey)
+        };
+        apply_auth(&mut params, &query_key, &vars);
+        assert_eq!(params.url, "https://api.test/me?api_key=k+y");
+        assert!(params.headers.is_empty());
+
+        // An explicit header wins, and incomplete auth sends nothing.
+        let mut params = base.clone();
+        params.headers.append("Authorization", "Bearer manual");
+        apply_auth(&mut params, &bearer, &vars);
+        assert_eq!(params.headers.get_all("authorization").count(), 1);
+        assert_eq!(params.headers.get("Authorization"), Some("Bearer manual"));
+        let mut params = base;
+        apply_auth(&mut params, &auth(AuthType::Bearer), &vars);
+        assert!(params.headers.is_empty());
+    }
+
+    #[test]
+    fn test_requests_inherit_collection_auth_unless_they_override_it() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut collection = Collection::new("Auth API");
+        collection.auth = Some(AuthConfig {
+            token: Some("{{token}}".to_string()),
+            ..auth(AuthType::Bearer)
+        });
+        collection.environments.push(CollectionEnvironment {
+            name: "prod".to_string(),
+            variables: vars(&[("token", "prod-token")]),
+            ..Default::default()
+        });
+        collection.active_environment = Some("prod".to_string());
+
+        let inherits = CollectionRequest {
+            url: "https://api.test/me".to_string(),
+            ..Default::default()
+        };
+        let params = collection_request_params(&collection, &inherits, dir.path(), 5000).unwrap();
+        assert_eq!(
+            params.headers.get("Authorization"),
+            Some("Bearer prod-token")
+        );
+
+        let opts_out = CollectionRequest {
+            auth: Some(auth(AuthType::None)),
+            ..inherits
+        };
+        let params = collection_request_params(&collection, &opts_out, dir.path(), 5000).unwrap();
+        assert!(params.headers.is_empty());
+    }
+
+    #[test]
+    fn test_unresolved_variables_fail_the_request() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut collection = Collection::new("Missing Vars");
+        collection.auth = Some(AuthConfig {
+            token: Some("{{accessToken}}".to_string()),
+            ..auth(AuthType::Bearer)
+        });
+        let request = CollectionRequest {
+            url: "{{baseUrl:-https://api.test}}/users/{{userId}}".to_string(),
+            ..Default::default()
+        };
+        let err = collection_request_params(&collection, &request, dir.path(), 5000).unwrap_err();
+        assert_eq!(err, "Unresolved variables: accessToken, userId");
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Spec refresh — re-fetches the spec a collection was imported from and
+//! computes drift between it and the collection's requests.
+//!
+//! Shared by the Tauri commands, MCP and the `runi-cli` binary, so nothing
+//! here depends on an `AppHandle`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::application::import_service::ImportService;
+use crate::domain::collection::Collection;
+use crate::domain::collection::drift::{SpecRefreshResult, compute_drift};
+use crate::domain::collection::spec_port::{
+    ParameterLocation, ParsedEndpoint, ParsedParameter, ParsedSpec, SpecSource,
+};
+use crate::infrastructure::spec::hasher::compute_spec_hash;
+use crate::infrastructure::storage::collection_store::{
+    load_collection_in_dir, save_collection_in_dir,
+};
+
+/// Extract the path portion from a URL or template URL.
+///
+/// Handles various input formats:
+/// - Full URLs: `https://api.example.com/users` → `/users`
+/// - Template URLs: `{{baseUrl}}/users` → `/users`
+/// - Paths: `/users` → `/users` (passthrough)
+/// - Root URLs: `https://api.example.com` → `/`
+///
+/// This is used when building old endpoints from collection requests for drift detection,
+/// ensuring path-only comparisons match the new spec's path format.
+fn extract_path_from_url(url: &str) -> String {
+    let raw = {
+        // 1. If already starts with `/`, it's a path — return as-is
+        if url.starts_with('/') {
+            url.to_string()
+        }
+        // 2. If starts with `{{`, extract from first `/` after `}}`
+        else if url.starts_with("{{") {
+            url.find("}}").map_or_else(
+                || format!("/{url}"),
+                |end_idx| {
+                    let after_template = &url[end_idx + 2..];
+                    after_template.find('/').map_or_else(
+                        || format!("/{after_template}"),
+                        |slash_idx| after_template[slash_idx..].to_string(),
+                    )
+                },
+            )
+        }
+        // 3. If contains `://` (any scheme), extract path after authority
+        else if let Some(scheme_end) = url.find("://") {
+            let after_scheme = &url[scheme_end + 3..];
+            // Find first `/` after authority (host:port)
+            after_scheme.find('/').map_or_else(
+                || "/".to_string(),
+                |slash_idx| after_scheme[slash_idx..].to_string(),
+            )
+        }
+        // 4. Fallback: prepend `/` to make it a path
+        else {
+            format!("/{url}")
+        }
+    };
+
+    // Strip query string and fragment (OpenAPI paths never include these)
+    raw.split(['?', '#']).next().unwrap_or(&raw).to_string()
+}
+
+/// Build a [`ParsedSpec`] from a collection's current requests.
+///
+/// Maps each `CollectionRequest` to a `ParsedEndpoint`, using binding metadata
+/// (path, method, operation ID) when available and falling back to request fields.
+/// The resulting `ParsedSpec` represents the "old" spec state for drift comparisons.
+#[must_use]
+pub fn build_parsed_spec_from_collection(collection: &Collection) -> ParsedSpec {
+    let endpoints: Vec<ParsedEndpoint> = collection
+        .requests
+        .iter()
+        .map(|req| {
+            let path = req
+                .binding
+                .path
+                .clone()
+                .unwrap_or_else(|| extract_path_from_url(&req.url));
+            let method = req
+                .binding
+                .method
+                .clone()
+                .unwrap_or_else(|| req.method.clone());
+            ParsedEndpoint {
+                operation_id: req.binding.operation_id.clone(),
+                method,
+                path,
+                summary: Some(req.name.clone()),
+                description: req.docs.clone(),
+                tags: req.tags.clone(),
+                parameters: req
+                    .params
+                    .iter()
+                    .map(|p| ParsedParameter {
+                        name: p.key.clone(),
+                        location: ParameterLocation::Query,
+                        required: false,
+                        schema_type: None,
+                        default_value: Some(p.value.clone()),
+                        description: None,
+                    })
+                    .collect(),
+                request_body: None,
+                deprecated: false,
+                is_streaming: req.is_streaming,
+            }
+        })
+        .collect();
+
+    ParsedSpec {
+        title: collection.metadata.name.clone(),
+        version: collection.source.spec_version.clone(),
+        description: collection.metadata.description.clone(),
+        base_urls: vec![],
+        endpoints,
+        auth_schemes: vec![],
+        variables: BTreeMap::new(),
+    }
+}
+
+/// Where to fetch a spec from: anything containing a scheme separator is a
+/// URL, the rest a file path.
+#[must_use]
+pub fn spec_source(source: &str) -> SpecSource {
+    if source.contains("://") {
+        SpecSource::Url(source.to_string())
+    } else {
+        SpecSource::File(PathBuf::from(source))
+    }
+}
+
+/// Refresh the spec of a collection saved in `dir` from its tracked source.
+///
+/// If `override_source` is provided it is used instead of the stored source
+/// (useful for "compare v1 collection against v2.json").
+///
+/// Re-fetches the spec, computes hash for fast-path skip, then structural drift
+/// if content changed. Updates the collection's `source.hash` and `source.fetched_at`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The collection cannot be found or loaded
+/// - No source can be resolved (`override_source` is `None` and the collection has no stored URL)
+/// - Re-fetching the spec content fails
+/// - Re-parsing the new spec content fails
+/// - The collection cannot be saved after the update
+pub async fn refresh_collection_spec_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    service: &ImportService,
+    override_source: Option<&str>,
+) -> Result<SpecRefreshResult, String> {
+    // 1. Load collection
+    let mut collection = load_collection_in_dir(collection_id, dir)?;
+
+    // 2. Resolve source — override > stored url/path
+    let source_str = override_source
+        .or(collection.source.url.as_deref())
+        .ok_or_else(|| "Collection has no tracked spec source".to_string())?;
+
+    // 3. Re-fetch content
+    let fetch_result = service.fetcher().fetch(&spec_source(source_str)).await?;
+
+    // 4. Compute new hash — fast path if unchanged
+    let new_hash = format!("sha256:{}", compute_spec_hash(&fetch_result.content));
+    let drift = if collection.source.hash.as_deref() == Some(&new_hash) {
+        SpecRefreshResult {
+            changed: false,
+            operations_added: vec![],
+            operations_removed: vec![],
+            operations_changed: vec![],
+        }
+    } else {
+        // 5. Re-parse and compare against the spec rebuilt from the requests
+        let new_spec = service.parse_content(&fetch_result.content)?;
+        let drift = compute_drift(&build_parsed_spec_from_collection(&collection), &new_spec);
+        collection.source.hash = Some(new_hash);
+        drift
+    };
+
+    // 6. Update source metadata, including source_commit if repo_root is tracked
+    let now = chrono::Utc::now();
+    collection.source.fetched_at = now.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    if let Some(repo_root) = &collection.source.repo_root {
+        if let Some(sha) =
+            service.resolve_git_commit(repo_root, collection.source.ref_name.as_deref())
+        {
+            collection.source.source_commit = Some(sha);
+        }
+    }
+
+    // 7. Persist
+    save_collection_in_dir(&collection, dir)?;
+
+    Ok(drift)
+}
+
+/// Drift between a collection's requests and a spec, without changing the
+/// collection.
+///
+/// The spec comes from `source` (a URL or file path) or, when `None`, the
+/// collection's tracked source.
+///
+/// # Errors
+///
+/// Returns an error if no source can be resolved, or the spec cannot be
+/// fetched or parsed.
+pub async fn diff_collection_spec(
+    collection: &Collection,
+    service: &ImportService,
+    source: Option<&str>,
+) -> Result<SpecRefreshResult, String> {
+    let source_str = source
+        .or(collection.source.url.as_deref())
+        .ok_or_else(|| "Collection has no tracked spec source".to_string())?;
+    let fetch_result = service.fetcher().fetch(&spec_source(source_str)).await?;
+    let new_spec = service.parse_content(&fetch_result.content)?;
+    Ok(compute_drift(
+        &build_parsed_spec_from_collection(collection),
+        &new_spec,
+    ))
+}
+
+/// Drift between a collection's requests and the spec stored in one of its
+/// pinned versions.
+///
+/// # Errors
+///
+/// Returns an error if the pinned version cannot be found or has no stored
+/// spec, or if the stored spec cannot be parsed.
+pub fn diff_pinned_version(
+    collection: &Collection,
+    pinned_version_id: &str,
+    service: &ImportService,
+) -> Result<SpecRefreshResult, String> {
+    let staged = collection
+        .pinned_versions
+        .iter()
+        .find(|v| v.id == pinned_version_id)
+        .ok_or_else(|| format!("Pinned version not found: {pinned_version_id}"))?;
+
+    // Guard: archived versions have no stored spec content
+    if staged.spec_content.is_empty() {
+        return Err(format!(
+            "Pinned version {pinned_version_id} has no stored spec content (role: {:?})",
+            staged.role
+        ));
+    }
+
+    let new_parsed_spec = service.parse_content(&staged.spec_content)?;
+    Ok(compute_drift(
+        &build_parsed_spec_from_collection(collection),
+        &new_parsed_spec,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_path_from_full_url() {
+        assert_eq!(
+            extract_path_from_url("https://api.example.com/users"),
+            "/users"
+        );
+        assert_eq!(
+            extract_path_from_url("https://api.example.com/users/123"),
+            "/users/123"
+        );
+        assert_eq!(
+            extract_path_from_url("http://localhost:8080/api/v1/items"),
+            "/api/v1/items"
+        );
+    }
+
+    #[test]
+    fn test_extract_path_from_template_url() {
+        assert_eq!(extract_path_from_url("{{baseUrl}}/users"), "/users");
+        assert_eq!(
+            extract_path_from_url("{{baseUrl}}/users/{{id}}"),
+            "/users/{{id}}"
+        );
+    }
+
+    #[test]
+    fn test_extract_path_already_a_path() {
+        assert_eq!(extract_path_from_url("/users"), "/users");
+        assert_eq!(extract_path_from_url("/users/123"), "/users/123");
+    }
+
+    #[test]
+    fn test_extract_path_root() {
+        assert_eq!(extract_path_from_url("https://api.example.com"), "/");
+        assert_eq!(extract_path_from_url("https://api.example.com/"), "/");
+    }
+
+    #[test]
+    fn test_extract_path_with_query_string() {
+        assert_eq!(
+            extract_path_from_url("https://api.example.com/users?limit=10"),
+            "/users"
+        );
+        assert_eq!(
+            extract_path_from_url("{{baseUrl}}/users?limit=10"),
+            "/users"
+        );
+    }
+
+    #[test]
+    fn test_extract_path_with_fragment() {
+        assert_eq!(
+            extract_path_from_url("https://api.example.com/users#section"),
+            "/users"
+        );
+        assert_eq!(extract_path_from_url("{{baseUrl}}/users#section"), "/users");
+    }
+
+    #[test]
+    fn test_extract_path_edge_cases() {
+        // Custom scheme
+        assert_eq!(
+            extract_path_from_url("custom://api.example.com/users"),
+            "/users"
+        );
+        // No path after authority
+        assert_eq!(extract_path_from_url("https://api.example.com"), "/");
+        // Fallback for malformed input
+        assert_eq!(extract_path_from_url("malformed"), "/malformed");
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Variable extraction — applies a request's extraction rules to its
+//! response and stores the captured values for the requests that follow.
+//!
+//! A failing rule leaves its variable unchanged and is reported next to the
+//! ones that succeeded; it never fails the request itself.
+
+use std::path::Path;
+
+use crate::domain::collection::{Collection, CollectionRequest};
+use crate::domain::extraction::{ExtractedVariable, VariableScope};
+use crate::domain::http::HttpResponse;
+use crate::infrastructure::storage::collection_store::{
+    load_collection_in_dir, save_collection_in_dir,
+};
+use crate::infrastructure::storage::runtime_variable_store::set_runtime_variable;
+
+/// Apply the extraction rules of `request` to `response`.
+///
+/// Runtime values go to the collection's runtime variables; environment
+/// values into the active environment of `collection`, which the caller
+/// saves when [`changes_environment`] says so. Environment rules fail when no
+/// environment is active.
+pub fn apply_extractions(
+    collection: &mut Collection,
+    request: &CollectionRequest,
+    response: &HttpResponse,
+) -> Vec<ExtractedVariable> {
+    let active = collection.active_environment.clone();
+    request
+        .extract
+        .iter()
+        .map(|rule| {
+            let environment = (rule.scope == VariableScope::Environment)
+                .then(|| active.clone())
+                .flatten();
+            let stored = rule.extract(response).and_then(|value| {
+                match rule.scope {
+                    VariableScope::Runtime => {
+                        set_runtime_variable(&collection.id, &rule.variable, &value);
+                    }
+                    VariableScope::Environment => {
+                        let env = collection
+                            .environments
+                            .iter_mut()
+                            .find(|env| Some(&env.name) == active.as_ref())
+                            .ok_or_else(|| {
+                                format!("No active environment to store {} in", rule.variable)
+                            })?;
+                        env.variables.insert(rule.variable.clone(), value.clone());
+                    }
+                }
+                Ok(value)
+            });
+            ExtractedVariable {
+                variable: rule.variable.clone(),
+                scope: rule.scope,
+                environment,
+                value: stored.as_ref().ok().cloned(),
+                error: stored.err(),
+            }
+        })
+        .collect()
+}
+
+/// Whether any of `extracted` was stored in the environment, leaving the
+/// collection to be saved.
+#[must_use]
+pub fn changes_environment(extracted: &[ExtractedVariable]) -> bool {
+    extracted
+        .iter()
+        .any(|entry| entry.scope == VariableScope::Environment && entry.value.is_some())
+}
+
+/// Apply the extraction rules of a saved request to its response, saving the
+/// collection in `dir` when an environment variable changed.
+///
+/// # Errors
+///
+/// Returns an error if the collection cannot be loaded or saved, or has no
+/// such request.
+pub fn extract_variables_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    request_id: &str,
+    response: &HttpResponse,
+) -> Result<Vec<ExtractedVariable>, String> {
+    let mut collection = load_collection_in_dir(collection_id, dir)?;
+    let request = collection
+        .requests
+        .iter()
+        .find(|r| r.id == request_id)
+        .cloned()
+        .ok_or_else(|| format!("Request not found: {request_id}"))?;
+    let extracted = apply_extractions(&mut collection, &request, response);
+    if changes_environment(&extracted) {
+        save_collection_in_dir(&collection, dir)?;
+    }
+    Ok(extracted)
+}
+
+/// Store the environment values of `extracted` in the collection saved in
+/// `dir`, leaving the rest of the file as it is on disk.
+///
+/// Values whose environment no longer exists are dropped.
+///
+/// # Errors
+///
+/// Returns an error if the collection cannot be loaded or saved.
+pub fn save_environment_values_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    extracted: &[ExtractedVariable],
+) -> Result<(), String> {
+    let mut collection = load_collection_in_dir(collection_id, dir)?;
+    for entry in extracted {
+        if entry.scope != VariableScope::Environment {
+            continue;
+        }
+        if let (Some(name), Some(value)) = (&entry.environment, &entry.value)
+            && let Some(env) = collection
+                .environments
+                .iter_mut()
+                .find(|env| &env.name == name)
+        {
+            env.variables.insert(entry.variable.clone(), value.clone());
+        }
+    }
+    save_collection_in_dir(&collection, dir).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::collection::CollectionEnvironment;
+    use crate::domain::extraction::{ExtractionRule, ExtractionSource};
+    use crate::infrastructure::storage::runtime_variable_store::runtime_variables;
+
+    fn login_collection() -> Collection {
+        let mut collection = Collection::new("Chained");
+        collection.environments.push(CollectionEnvironment {
+            name: "staging".to_string(),
+            ..Default::default()
+        });
+        collection.requests.push(CollectionRequest {
+            id: "req_login".to_string(),
+            name: "Login".to_string(),
+            method: "POST".to_string(),
+            url: "https://api.test/login".to_string(),
+            extract: vec![
+                ExtractionRule {
+                    variable: "accessToken".to_string(),
+                    source: ExtractionSource::JsonPath {
+                        path: "$.access_token".to_string(),
+                    },
+                    scope: VariableScope::Runtime,
+                },
+                ExtractionRule {
+                    variable: "sessionId".to_string(),
+                    source: ExtractionSource::Header {
+                        name: "X-Session".to_string(),
+                    },
+                    scope: VariableScope::Environment,
+                },
+            ],
+            ..Default::default()
+        });
+        collection
+    }
+
+    fn login_response() -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            headers: [("X-Session", "s-1")].into(),
+            body: r#"{"access_token":"t0k"}"#.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_environment_rules_need_an_active_environment() {
+        let mut collection = login_collection();
+        let request = collection.requests[0].clone();
+
+        let extracted = apply_extractions(&mut collection, &request, &login_response());
+
+        assert_eq!(extracted[0].value.as_deref(), Some("t0k"));
+        assert_eq!(runtime_variables(&collection.id)["accessToken"], "t0k");
+        assert_eq!(extracted[1].value, None);
+        assert_eq!(
+            extracted[1].error.as_deref(),
+            Some("No active environment to store sessionId in")
+        );
+        assert!(!changes_environment(&extracted));
+    }
+
+    #[test]
+    fn test_extract_variables_in_dir_saves_environment_values() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut collection = login_collection();
+        collection.active_environment = Some("staging".to_string());
+        save_collection_in_dir(&collection, dir.path()).unwrap();
+
+        let extracted =
+            extract_variables_in_dir(dir.path(), &collection.id, "req_login", &login_response())
+                .unwrap();
+
+        assert_eq!(extracted[1].environment.as_deref(), Some("staging"));
+        assert!(changes_environment(&extracted));
+        let saved = load_collection_in_dir(&collection.id, dir.path()).unwrap();
+        assert_eq!(saved.environments[0].variables["sessionId"], "s-1");
+        assert!(!saved.environments[0].variables.contains_key("accessToken"));
+        let err =
+            extract_variables_in_dir(dir.path(), &collection.id, "req_nope", &login_response())
+                .unwrap_err();
+        assert_eq!(err, "Request not found: req_nope");
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! `runi-cli` - headless runi for CI.
+//!
+//! Lists, runs and imports collections and checks their specs for drift
+//! through the same layers as the desktop app, leaving out the modules that
+//! need the Tauri runtime. Run `runi-cli help` for usage.
+
+use std::process::ExitCode;
+
+use tracing_subscriber::EnvFilter;
+
+fn main() -> ExitCode {
+    // Failures are already part of the output, so logs are opt-in via RUST_LOG;
+    // they go to stderr so that stdout stays parseable with --json
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("off")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("error: Failed to start the async runtime: {e}");
+            return ExitCode::from(2);
+        }
+    };
+    runtime.block_on(runi_lib::infrastructure::cli::main(
+        std::env::args().skip(1).collect(),
+    ))
+}
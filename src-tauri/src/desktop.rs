@@ -0,0 +1,201 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! The Tauri desktop app: plugins, command handlers and managed state.
+
+use sysinfo::System;
+use tauri::Manager;
+
+// Commands are named by module path in `generate_handler!` so that it finds
+// the macros `#[tauri::command]` generates next to each of them.
+use crate::infrastructure::commands::{
+    self, create_drift_review_store, create_project_context_service, create_proxy_service,
+    create_suggestion_service, init_network_settings,
+};
+use crate::infrastructure::http;
+use crate::infrastructure::logging::init_logging;
+use crate::infrastructure::mcp::commands::{
+    self as mcp_commands, SseBroadcasterHandle, create_mcp_server_state,
+};
+#[cfg(debug_assertions)]
+use crate::infrastructure::mcp::commands::{DEFAULT_MCP_PORT, start_server};
+use crate::infrastructure::mcp::server::sse_broadcaster::SseBroadcaster;
+use crate::infrastructure::memory_monitor::{self, start_memory_monitor};
+
+/// Initialize and run the Tauri application.
+///
+/// Sets up the Tauri builder with plugins, command handlers, and managed state.
+/// In debug mode, automatically opens developer tools.
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+#[allow(clippy::large_stack_frames)] // Tauri's generate_context! macro creates large stack frames
+#[allow(clippy::too_many_lines)] // Tauri builder setup is inherently verbose
+pub fn run() {
+    // Record process startup time (at the very start of main, before any initialization)
+    // This measures from process launch until Tauri setup completes
+    let process_start_time = std::time::Instant::now();
+
+    // Initialize structured logging before anything else
+    init_logging();
+
+    tauri::Builder::default()
+        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_fs::init())
+        .manage(create_mcp_server_state())
+        .manage::<SseBroadcasterHandle>(std::sync::Arc::new(SseBroadcaster::new()))
+        .manage(std::sync::Arc::new(tokio::sync::RwLock::new(
+            crate::domain::canvas_state::CanvasStateSnapshot::new(),
+        )))
+        .setup(move |app| {
+            #[cfg(debug_assertions)]
+            {
+                use tauri::Manager;
+                if let Some(window) = app.get_webview_window("main") {
+                    window.open_devtools();
+                }
+            }
+
+            // Calculate time from process start to Tauri setup completion
+            // This includes: Rust init, Tauri init, plugin init, WebView creation
+            // Note: This doesn't include HTML/JS loading time, which is measured separately in frontend
+            let process_startup_ms = process_start_time.elapsed().as_millis() as f64;
+            app.manage(std::sync::Mutex::new(process_startup_ms));
+
+            // Get system RAM for memory monitoring
+            let mut system = System::new();
+            system.refresh_memory();
+            // sysinfo::System::total_memory() returns KiB; convert KiB -> GiB by dividing by 1024^2
+            let total_ram_gb = system.total_memory() as f64 / (1024.0 * 1024.0);
+
+            // Apply the persisted global proxy before any request runs
+            if let Err(e) = init_network_settings() {
+                tracing::warn!("Failed to load network settings: {e}");
+            }
+
+            // Start memory monitoring service (heartbeat sampling)
+            let app_handle = app.handle();
+            start_memory_monitor(app_handle, total_ram_gb);
+
+            // In debug builds, auto-start MCP server so Claude Code can connect immediately.
+            // In release builds, the server must be started explicitly via Tauri commands
+            // to avoid opening a listening port by default.
+            #[cfg(debug_assertions)]
+            {
+                let mcp_state =
+                    app.state::<crate::infrastructure::mcp::commands::McpServerServiceState>();
+                let mcp_state_clone = mcp_state.inner().clone();
+                let sse_bc = app.state::<SseBroadcasterHandle>().inner().clone();
+                let mcp_app_handle = app.handle().clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = start_server(
+                        DEFAULT_MCP_PORT,
+                        &mcp_state_clone,
+                        Some(mcp_app_handle),
+                        sse_bc,
+                    )
+                    .await
+                    {
+                        tracing::warn!("Failed to auto-start MCP server: {e}");
+                    }
+                });
+            }
+
+            Ok(())
+        })
+        .manage(create_proxy_service())
+        .manage(create_project_context_service().expect("Failed to create project context service"))
+        .manage(create_suggestion_service().expect("Failed to create suggestion service"))
+        .manage(create_drift_review_store())
+        .invoke_handler(tauri::generate_handler![
+            commands::hello_world,
+            http::execute_request,
+            http::cancel_request,
+            commands::execute_streaming_request,
+            commands::get_platform,
+            commands::get_process_startup_time,
+            commands::get_system_specs,
+            commands::load_feature_flags,
+            commands::get_config_dir,
+            memory_monitor::get_ram_stats,
+            memory_monitor::collect_ram_sample,
+            memory_monitor::set_memory_monitoring_enabled,
+            commands::save_request_history,
+            commands::load_request_history,
+            commands::delete_history_entry,
+            commands::clear_request_history,
+            commands::get_history_count,
+            commands::get_history_ids,
+            commands::get_history_batch,
+            commands::cmd_create_collection,
+            commands::cmd_open_collection_file,
+            commands::cmd_save_collection,
+            commands::cmd_load_collection,
+            commands::cmd_list_collections,
+            commands::cmd_delete_collection,
+            commands::cmd_delete_request,
+            commands::cmd_rename_collection,
+            commands::cmd_rename_request,
+            commands::cmd_update_request,
+            commands::cmd_duplicate_collection,
+            commands::cmd_duplicate_request,
+            commands::cmd_add_request,
+            commands::cmd_save_tab_to_collection,
+            commands::cmd_move_request,
+            commands::cmd_copy_request_to_collection,
+            commands::cmd_add_httpbin_collection,
+            commands::cmd_import_collection,
+            commands::cmd_refresh_collection_spec,
+            commands::cmd_log_frontend_error,
+            commands::cmd_write_frontend_error_report,
+            commands::set_log_level,
+            commands::write_startup_timing,
+            commands::sync_canvas_state,
+            commands::cmd_run_hurl_suite,
+            commands::cmd_export_collection_hurl,
+            commands::cmd_resolve_collection_request,
+            commands::cmd_execute_collection_request,
+            commands::cmd_get_runtime_variables,
+            commands::cmd_clear_runtime_variables,
+            commands::cmd_run_collection,
+            commands::cmd_run_report_junit,
+            commands::cmd_run_load_test,
+            commands::cmd_list_load_tests,
+            commands::cmd_get_load_test,
+            commands::cmd_graphql_introspect,
+            commands::cmd_graphql_schema,
+            commands::cmd_graphql_validate,
+            commands::cmd_graphql_operations,
+            commands::cmd_websocket_connect,
+            commands::cmd_websocket_send,
+            commands::cmd_websocket_close,
+            commands::cmd_websocket_transcript,
+            commands::cmd_list_websocket_sessions,
+            mcp_commands::mcp_server_start,
+            mcp_commands::mcp_server_stop,
+            mcp_commands::mcp_server_status,
+            commands::cmd_get_project_context,
+            commands::cmd_update_project_context,
+            commands::cmd_list_suggestions,
+            commands::cmd_create_suggestion,
+            commands::cmd_resolve_suggestion,
+            commands::cmd_clear_suggestions,
+            commands::cmd_upsert_environment,
+            commands::cmd_set_environment_tls,
+            commands::cmd_set_environment_host_overrides,
+            commands::cmd_set_environment_proxy,
+            commands::cmd_get_proxy_settings,
+            commands::cmd_set_proxy_settings,
+            commands::cmd_list_cookies,
+            commands::cmd_set_cookie,
+            commands::cmd_delete_cookies,
+            commands::cmd_delete_environment,
+            commands::cmd_set_active_environment,
+            commands::cmd_set_drift_review_decision,
+            commands::cmd_pin_spec_version,
+            commands::cmd_activate_pinned_version,
+            commands::cmd_remove_pinned_version,
+            commands::cmd_compare_spec_versions
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
@@ -0,0 +1,520 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Declarative response assertions.
+//!
+//! A request's assertions are checked against its response every time it is
+//! executed — on its own, in a collection run or through MCP — and each one
+//! reports whether it passed together with the value it actually found.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(test)]
+use ts_rs::TS;
+
+use crate::domain::extraction::{compile_json_path, compile_regex};
+use crate::domain::http::HttpResponse;
+
+/// Type of a JSON value, for [`Assertion::JsonPathType`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonType {
+    /// A string.
+    String,
+    /// A number, integer or not.
+    Number,
+    /// `true` or `false`.
+    Boolean,
+    /// An array.
+    Array,
+    /// An object.
+    Object,
+    /// `null`.
+    Null,
+}
+
+impl JsonType {
+    /// The type of `value`.
+    #[must_use]
+    pub const fn of(value: &Value) -> Self {
+        match value {
+            Value::String(_) => Self::String,
+            Value::Number(_) => Self::Number,
+            Value::Bool(_) => Self::Boolean,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+            Value::Null => Self::Null,
+        }
+    }
+
+    /// Lowercase name of the type.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Array => "array",
+            Self::Object => "object",
+            Self::Null => "null",
+        }
+    }
+}
+
+/// A check on a response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum Assertion {
+    /// The status is exactly `status`.
+    StatusEquals {
+        /// Expected status code.
+        status: u16,
+    },
+    /// The status lies between `min` and `max`, both included.
+    StatusInRange {
+        /// Lowest accepted status code.
+        min: u16,
+        /// Highest accepted status code.
+        max: u16,
+    },
+    /// The response has a header, matched case-insensitively.
+    HeaderExists {
+        /// Header name.
+        name: String,
+    },
+    /// A header's value matches a regular expression.
+    HeaderMatches {
+        /// Header name.
+        name: String,
+        /// The regular expression, searched anywhere in the value.
+        pattern: String,
+    },
+    /// The first match of a `JSONPath` query equals a JSON value.
+    JsonPathEquals {
+        /// The `JSONPath` query.
+        path: String,
+        /// Expected value.
+        #[cfg_attr(test, ts(type = "unknown"))]
+        value: Value,
+    },
+    /// The first match of a `JSONPath` query contains a value: a substring
+    /// of a string, an element of an array or a key of an object.
+    JsonPathContains {
+        /// The `JSONPath` query.
+        path: String,
+        /// Value looked for.
+        #[cfg_attr(test, ts(type = "unknown"))]
+        value: Value,
+    },
+    /// The first match of a `JSONPath` query has a JSON type.
+    JsonPathType {
+        /// The `JSONPath` query.
+        path: String,
+        /// Expected type.
+        json_type: JsonType,
+    },
+    /// The body text matches a regular expression.
+    BodyMatches {
+        /// The regular expression, searched anywhere in the body.
+        pattern: String,
+    },
+    /// The response arrived in less than `max_ms` milliseconds.
+    ResponseTimeBelow {
+        /// Exclusive upper bound on the total request time.
+        #[cfg_attr(test, ts(type = "number"))]
+        max_ms: u64,
+    },
+    /// The JSON body is valid against a JSON Schema.
+    MatchesSchema {
+        /// The JSON Schema; the draft is detected from `$schema`.
+        #[cfg_attr(test, ts(type = "unknown"))]
+        schema: Value,
+    },
+}
+
+/// Outcome of one assertion after a request ran.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct AssertionResult {
+    /// The assertion that was checked.
+    #[serde(flatten)]
+    #[cfg_attr(test, ts(flatten))]
+    pub assertion: Assertion,
+    /// Whether the response satisfied it.
+    pub passed: bool,
+    /// The value found in the response, when there was one: the status, the
+    /// header value, the `JSONPath` match, the regex match, the response time
+    /// in milliseconds or the first schema violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub actual: Option<String>,
+    /// Why the assertion failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub message: Option<String>,
+}
+
+impl Assertion {
+    /// Check that the assertion's query, pattern or schema compiles and that
+    /// its status range is not empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the problem.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::StatusInRange { min, max } if min > max => {
+                Err(format!("Status range {min}-{max} is empty"))
+            }
+            Self::HeaderMatches { pattern, .. } | Self::BodyMatches { pattern } => {
+                compile_regex(pattern).map(drop)
+            }
+            Self::JsonPathEquals { path, .. }
+            | Self::JsonPathContains { path, .. }
+            | Self::JsonPathType { path, .. } => compile_json_path(path).map(drop),
+            Self::MatchesSchema { schema } => jsonschema::validator_for(schema)
+                .map(drop)
+                .map_err(|e| format!("Invalid schema: {e}")),
+            _ => Ok(()),
+        }
+    }
+
+    /// What the assertion expects, as used in failure messages (e.g.
+    /// "status to be 200").
+    #[must_use]
+    pub fn expectation(&self) -> String {
+        match self {
+            Self::StatusEquals { status } => format!("status to be {status}"),
+            Self::StatusInRange { min, max } => format!("status to be between {min} and {max}"),
+            Self::HeaderExists { name } => format!("header {name} to be present"),
+            Self::HeaderMatches { name, pattern } => format!("header {name} to match {pattern}"),
+            Self::JsonPathEquals { path, value } => format!("{path} to equal {value}"),
+            Self::JsonPathContains { path, value } => format!("{path} to contain {value}"),
+            Self::JsonPathType { path, json_type } => {
+                format!("{path} to be of type {}", json_type.as_str())
+            }
+            Self::BodyMatches { pattern } => format!("body to match {pattern}"),
+            Self::ResponseTimeBelow { max_ms } => format!("response time to be below {max_ms} ms"),
+            Self::MatchesSchema { .. } => "body to match the schema".to_string(),
+        }
+    }
+
+    /// Check the assertion against `response`.
+    ///
+    /// An assertion that cannot be checked — an invalid pattern, a body that
+    /// is not JSON, a query that matches nothing — fails with the reason as
+    /// its message.
+    #[must_use]
+    pub fn evaluate(&self, response: &HttpResponse) -> AssertionResult {
+        let (passed, actual, message) = match self.check(response) {
+            Ok((true, actual)) => (true, actual, None),
+            Ok((false, actual)) => {
+                let message = actual.as_ref().map_or_else(
+                    || format!("Expected {}", self.expectation()),
+                    |actual| format!("Expected {}, got {actual}", self.expectation()),
+                );
+                (false, actual, Some(message))
+            }
+            Err(message) => (false, None, Some(message)),
+        };
+        AssertionResult {
+            assertion: self.clone(),
+            passed,
+            actual,
+            message,
+        }
+    }
+
+    /// Whether the response passes, and the value it was judged on.
+    fn check(&self, response: &HttpResponse) -> Result<(bool, Option<String>), String> {
+        match self {
+            Self::StatusEquals { status } => Ok((
+                response.status == *status,
+                Some(response.status.to_string()),
+            )),
+            Self::StatusInRange { min, max } => Ok((
+                (*min..=*max).contains(&response.status),
+                Some(response.status.to_string()),
+            )),
+            Self::HeaderExists { name } => {
+                let value = response.headers.get(name);
+                Ok((value.is_some(), value.map(ToString::to_string)))
+            }
+            Self::HeaderMatches { name, pattern } => {
+                let regex = compile_regex(pattern)?;
+                let value = response
+                    .headers
+                    .get(name)
+                    .ok_or_else(|| format!("Response has no {name} header"))?;
+                Ok((regex.is_match(value), Some(value.to_string())))
+            }
+            Self::JsonPathEquals { path, value } => {
+                let found = json_path_match(path, response)?;
+                Ok((&found == value, Some(display(&found))))
+            }
+            Self::JsonPathContains { path, value } => {
+                let found = json_path_match(path, response)?;
+                let contains = match &found {
+                    Value::String(text) => text.contains(&display(value)),
+                    Value::Array(items) => items.contains(value),
+                    Value::Object(map) => map.contains_key(&display(value)),
+                    _ => false,
+                };
+                Ok((contains, Some(display(&found))))
+            }
+            Self::JsonPathType { path, json_type } => {
+                let found = json_path_match(path, response)?;
+                let actual = JsonType::of(&found);
+                Ok((actual == *json_type, Some(actual.as_str().to_string())))
+            }
+            Self::BodyMatches { pattern } => {
+                let regex = compile_regex(pattern)?;
+                let found = regex.find(&response.body).map(|m| m.as_str().to_string());
+                Ok((found.is_some(), found))
+            }
+            Self::ResponseTimeBelow { max_ms } => Ok((
+                response.timing.total_ms < *max_ms,
+                Some(response.timing.total_ms.to_string()),
+            )),
+            Self::MatchesSchema { schema } => {
+                let validator = jsonschema::validator_for(schema)
+                    .map_err(|e| format!("Invalid schema: {e}"))?;
+                let body = json_body(response)?;
+                let violation = validator.iter_errors(&body).next().map(|error| {
+                    let location = error.instance_path().to_string();
+                    if location.is_empty() {
+                        error.to_string()
+                    } else {
+                        format!("{location}: {error}")
+                    }
+                });
+                Ok((violation.is_none(), violation))
+            }
+        }
+    }
+}
+
+/// Evaluate `assertions` against `response`, in order.
+#[must_use]
+pub fn evaluate_assertions(
+    assertions: &[Assertion],
+    response: &HttpResponse,
+) -> Vec<AssertionResult> {
+    assertions
+        .iter()
+        .map(|assertion| assertion.evaluate(response))
+        .collect()
+}
+
+fn json_body(response: &HttpResponse) -> Result<Value, String> {
+    serde_json::from_str(&response.body).map_err(|e| format!("Response body is not JSON: {e}"))
+}
+
+fn json_path_match(path: &str, response: &HttpResponse) -> Result<Value, String> {
+    let query = compile_json_path(path)?;
+    let body = json_body(response)?;
+    query
+        .query(&body)
+        .first()
+        .cloned()
+        .ok_or_else(|| format!("JSONPath {path} matched nothing"))
+}
+
+/// Strings as they are, other values as compact JSON.
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::http::RequestTiming;
+    use serde_json::json;
+
+    fn response() -> HttpResponse {
+        HttpResponse {
+            status: 201,
+            status_text: "Created".to_string(),
+            headers: [("Content-Type", "application/json; charset=utf-8")].into(),
+            body: r#"{"id":42,"name":"Ada","roles":["admin"],"meta":{"page":1}}"#.to_string(),
+            timing: RequestTiming {
+                total_ms: 120,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn check(assertion: &Assertion) -> (bool, Option<String>) {
+        let result = assertion.evaluate(&response());
+        (result.passed, result.actual)
+    }
+
+    fn json_path_equals(path: &str, value: Value) -> Assertion {
+        Assertion::JsonPathEquals {
+            path: path.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_each_assertion_reports_the_actual_value() {
+        let pass = |actual: &str| (true, Some(actual.to_string()));
+        let fail = |actual: &str| (false, Some(actual.to_string()));
+
+        assert_eq!(check(&Assertion::StatusEquals { status: 200 }), fail("201"));
+        assert_eq!(
+            check(&Assertion::StatusInRange { min: 200, max: 299 }),
+            pass("201")
+        );
+        assert_eq!(
+            check(&Assertion::HeaderExists {
+                name: "content-type".to_string()
+            }),
+            pass("application/json; charset=utf-8")
+        );
+        assert_eq!(
+            check(&Assertion::HeaderExists {
+                name: "ETag".to_string()
+            }),
+            (false, None)
+        );
+        assert_eq!(
+            check(&Assertion::HeaderMatches {
+                name: "Content-Type".to_string(),
+                pattern: "^application/json".to_string(),
+            }),
+            pass("application/json; charset=utf-8")
+        );
+        assert_eq!(check(&json_path_equals("$.id", json!(42))), pass("42"));
+        assert_eq!(
+            check(&json_path_equals("$.name", json!("Bob"))),
+            fail("Ada")
+        );
+        let contains = |path: &str, value: Value| {
+            check(&Assertion::JsonPathContains {
+                path: path.to_string(),
+                value,
+            })
+            .0
+        };
+        assert!(contains("$.name", json!("Ad")));
+        assert!(contains("$.roles", json!("admin")));
+        assert!(contains("$.meta", json!("page")));
+        assert!(!contains("$.roles", json!("owner")));
+        assert_eq!(
+            check(&Assertion::JsonPathType {
+                path: "$.roles".to_string(),
+                json_type: JsonType::Object,
+            }),
+            fail("array")
+        );
+        assert_eq!(
+            check(&Assertion::BodyMatches {
+                pattern: r#""id":\d+"#.to_string()
+            }),
+            pass(r#""id":42"#)
+        );
+        assert_eq!(
+            check(&Assertion::ResponseTimeBelow { max_ms: 100 }),
+            fail("120")
+        );
+    }
+
+    #[test]
+    fn test_schema_failures_name_the_first_violation() {
+        let schema = json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": { "id": { "type": "integer" }, "name": { "type": "string" } }
+        });
+        assert_eq!(
+            check(&Assertion::MatchesSchema {
+                schema: schema.clone()
+            }),
+            (true, None)
+        );
+
+        let mut strict = schema;
+        strict["properties"]["name"] = json!({ "type": "integer" });
+        let result = Assertion::MatchesSchema { schema: strict }.evaluate(&response());
+        assert!(!result.passed);
+        assert_eq!(
+            result.actual.as_deref(),
+            Some(r#"/name: "Ada" is not of type "integer""#)
+        );
+        assert_eq!(
+            result.message.as_deref(),
+            Some(r#"Expected body to match the schema, got /name: "Ada" is not of type "integer""#)
+        );
+    }
+
+    #[test]
+    fn test_unevaluable_assertions_fail_with_the_reason() {
+        let result = json_path_equals("$.missing", json!(1)).evaluate(&response());
+        assert!(!result.passed);
+        assert_eq!(result.actual, None);
+        assert_eq!(
+            result.message.as_deref(),
+            Some("JSONPath $.missing matched nothing")
+        );
+
+        let text = HttpResponse {
+            body: "plain".to_string(),
+            ..response()
+        };
+        let result = json_path_equals("$.id", json!(42)).evaluate(&text);
+        assert!(
+            result
+                .message
+                .unwrap()
+                .starts_with("Response body is not JSON")
+        );
+
+        assert!(
+            Assertion::StatusInRange { min: 300, max: 200 }
+                .validate()
+                .is_err()
+        );
+        assert!(json_path_equals("id", json!(1)).validate().is_err());
+        assert!(
+            Assertion::MatchesSchema {
+                schema: json!({ "type": 12 })
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_results_round_trip_flattened() {
+        let yaml = "check: json_path_type\npath: $.roles\njson_type: array\n";
+        let assertion: Assertion = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(serde_yaml_ng::to_string(&assertion).unwrap(), yaml);
+
+        let result = assertion.evaluate(&response());
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "check": "json_path_type",
+                "path": "$.roles",
+                "json_type": "array",
+                "passed": true,
+                "actual": "array"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<AssertionResult>(json).unwrap(),
+            result
+        );
+    }
+}
@@ -0,0 +1,433 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// Peer certificate chain of an HTTPS response and the problems found in it
+
+use std::net::IpAddr;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+use ts_rs::TS;
+
+/// Date format curl (OpenSSL) uses for certificate validity: `Jan 31 10:30:00 2026 GMT`.
+const CERTINFO_DATE_FORMAT: &str = "%b %e %H:%M:%S %Y GMT";
+
+/// Certificates the server presented, leaf first, with anything worth flagging.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct CertificateChain {
+    /// The chain as sent by the server; the first entry is the server's own certificate.
+    pub certificates: Vec<PeerCertificate>,
+    /// Problems with the chain. Empty when nothing was found.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<CertificateIssue>>", optional))]
+    pub issues: Vec<CertificateIssue>,
+}
+
+/// A problem with the presented certificate chain.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum CertificateIssue {
+    /// A certificate in the chain is past its expiry date.
+    Expired,
+    /// A certificate in the chain is not valid yet.
+    NotYetValid,
+    /// The server certificate is signed by itself.
+    SelfSigned,
+    /// The server certificate does not cover the requested host name.
+    HostnameMismatch,
+}
+
+/// One certificate of the chain, from curl's certinfo.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct PeerCertificate {
+    /// Subject distinguished name, e.g. `CN = api.example.com, O = Example`.
+    pub subject: String,
+    /// Issuer distinguished name.
+    pub issuer: String,
+    /// Subject alternative names as curl prints them, e.g. `DNS:api.example.com`
+    /// or `IP Address:10.0.0.1`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub subject_alt_names: Vec<String>,
+    /// Serial number in hex.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub serial_number: Option<String>,
+    /// Signature algorithm, e.g. `sha256WithRSAEncryption`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub signature_algorithm: Option<String>,
+    /// Public key algorithm, e.g. `rsaEncryption`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub public_key_algorithm: Option<String>,
+    /// Start of the validity window (RFC 3339 UTC).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub not_before: Option<String>,
+    /// End of the validity window (RFC 3339 UTC).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub not_after: Option<String>,
+    /// Whole days until `not_after`; negative once expired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub days_until_expiry: Option<i64>,
+    /// SHA-256 fingerprint of the DER encoding, as colon-separated hex.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub sha256_fingerprint: Option<String>,
+}
+
+impl CertificateChain {
+    /// Build the chain from curl's certinfo (one list of `Name:value` fields
+    /// per certificate, leaf first) and check it against `host` at `now`.
+    ///
+    /// Returns `None` when curl reported no certificates, e.g. for plain HTTP.
+    #[must_use]
+    pub fn from_certinfo(certinfo: &[Vec<String>], host: &str, now: DateTime<Utc>) -> Option<Self> {
+        let parsed: Vec<_> = certinfo
+            .iter()
+            .map(|fields| ParsedCertificate::from_fields(fields))
+            .collect();
+        let leaf = parsed.first()?;
+
+        let mut issues = Vec::new();
+        if parsed
+            .iter()
+            .any(|cert| cert.not_after.is_some_and(|end| end < now))
+        {
+            issues.push(CertificateIssue::Expired);
+        }
+        if parsed
+            .iter()
+            .any(|cert| cert.not_before.is_some_and(|start| start > now))
+        {
+            issues.push(CertificateIssue::NotYetValid);
+        }
+        if leaf.certificate.is_self_signed() {
+            issues.push(CertificateIssue::SelfSigned);
+        }
+        if !leaf.certificate.matches_host(host) {
+            issues.push(CertificateIssue::HostnameMismatch);
+        }
+
+        let certificates = parsed
+            .into_iter()
+            .map(|cert| cert.into_certificate(now))
+            .collect();
+        Some(Self {
+            certificates,
+            issues,
+        })
+    }
+
+    /// Whether `issue` was found.
+    #[must_use]
+    pub fn has_issue(&self, issue: CertificateIssue) -> bool {
+        self.issues.contains(&issue)
+    }
+}
+
+impl PeerCertificate {
+    /// The subject's common name (`CN`), if any.
+    #[must_use]
+    pub fn common_name(&self) -> Option<&str> {
+        self.subject.split(',').find_map(|part| {
+            let (key, value) = part.split_once('=')?;
+            (key.trim() == "CN").then(|| value.trim())
+        })
+    }
+
+    /// Whether the certificate names itself as its issuer.
+    #[must_use]
+    pub fn is_self_signed(&self) -> bool {
+        !self.subject.is_empty() && self.subject == self.issuer
+    }
+
+    /// Whether the certificate is valid for `host` (a DNS name or IP address).
+    ///
+    /// Subject alternative names are authoritative; the common name is only
+    /// consulted when there are none. `*.example.com` matches exactly one label.
+    #[must_use]
+    pub fn matches_host(&self, host: &str) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return self
+                .subject_alt_names
+                .iter()
+                .filter_map(|san| san.strip_prefix("IP Address:"))
+                .any(|san| san.trim().parse::<IpAddr>().is_ok_and(|san| san == ip));
+        }
+        let dns_names: Vec<&str> = self
+            .subject_alt_names
+            .iter()
+            .filter_map(|san| san.strip_prefix("DNS:"))
+            .map(str::trim)
+            .collect();
+        if dns_names.is_empty() && !self.subject_alt_names.is_empty() {
+            return false;
+        }
+        if dns_names.is_empty() {
+            return self
+                .common_name()
+                .is_some_and(|cn| dns_name_matches(cn, host));
+        }
+        dns_names.iter().any(|name| dns_name_matches(name, host))
+    }
+}
+
+/// A certificate with its validity window still as timestamps.
+struct ParsedCertificate {
+    certificate: PeerCertificate,
+    not_before: Option<DateTime<Utc>>,
+    not_after: Option<DateTime<Utc>>,
+}
+
+impl ParsedCertificate {
+    fn from_fields(fields: &[String]) -> Self {
+        let field = |name: &str| {
+            fields.iter().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        };
+        let owned = |name: &str| field(name).map(str::to_string);
+
+        Self {
+            certificate: PeerCertificate {
+                subject: owned("Subject").unwrap_or_default(),
+                issuer: owned("Issuer").unwrap_or_default(),
+                subject_alt_names: field("X509v3 Subject Alternative Name")
+                    .map(|names| {
+                        names
+                            .split(',')
+                            .map(|name| name.trim().to_string())
+                            .filter(|name| !name.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                serial_number: owned("Serial Number"),
+                signature_algorithm: owned("Signature Algorithm"),
+                public_key_algorithm: owned("Public Key Algorithm"),
+                sha256_fingerprint: field("Cert").and_then(pem_sha256_fingerprint),
+                ..Default::default()
+            },
+            not_before: field("Start date").and_then(parse_certinfo_date),
+            not_after: field("Expire date").and_then(parse_certinfo_date),
+        }
+    }
+
+    fn into_certificate(self, now: DateTime<Utc>) -> PeerCertificate {
+        let rfc3339 = |date: DateTime<Utc>| date.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        PeerCertificate {
+            not_before: self.not_before.map(rfc3339),
+            not_after: self.not_after.map(rfc3339),
+            days_until_expiry: self.not_after.map(|end| (end - now).num_days()),
+            ..self.certificate
+        }
+    }
+}
+
+fn parse_certinfo_date(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, CERTINFO_DATE_FORMAT)
+        .ok()
+        .map(|date| date.and_utc())
+}
+
+/// SHA-256 over the DER bytes inside a PEM block, as `AB:CD:...`.
+fn pem_sha256_fingerprint(pem: &str) -> Option<String> {
+    let base64: String = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .map(str::trim)
+        .collect();
+    let der = BASE64.decode(base64).ok()?;
+    let digest = Sha256::digest(der);
+    Some(
+        digest
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<_>>()
+            .join(":"),
+    )
+}
+
+/// Match a certificate DNS name (possibly a leading `*.` wildcard) against a host.
+fn dns_name_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim_end_matches('.');
+    let host = host.trim_end_matches('.');
+    pattern.strip_prefix("*.").map_or_else(
+        || pattern.eq_ignore_ascii_case(host),
+        |suffix| {
+            host.split_once('.')
+                .is_some_and(|(label, rest)| !label.is_empty() && rest.eq_ignore_ascii_case(suffix))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEAF_PEM: &str = "-----BEGIN CERTIFICATE-----\nMIIBvDCCAWKgAwIBAgIUbVi5QZgvxSz9ABG+FzO3bjFoEG4wCgYIKoZIzj0EAwIw\n-----END CERTIFICATE-----\n";
+
+    fn certinfo(
+        subject: &str,
+        issuer: &str,
+        sans: Option<&str>,
+        start: &str,
+        end: &str,
+    ) -> Vec<String> {
+        let mut fields = vec![
+            format!("Subject:{subject}"),
+            format!("Issuer:{issuer}"),
+            "Serial Number:6d58b941".to_string(),
+            "Signature Algorithm:ecdsa-with-SHA256".to_string(),
+            "Public Key Algorithm:id-ecPublicKey".to_string(),
+            format!("Start date:{start}"),
+            format!("Expire date:{end}"),
+            format!("Cert:{LEAF_PEM}"),
+        ];
+        if let Some(sans) = sans {
+            fields.push(format!("X509v3 Subject Alternative Name:{sans}"));
+        }
+        fields
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-10-17T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parses_leaf_and_intermediate() {
+        let chain = CertificateChain::from_certinfo(
+            &[
+                certinfo(
+                    "CN = api.example.com",
+                    "CN = Example CA",
+                    Some("DNS:api.example.com, IP Address:10.0.0.1"),
+                    "Oct  6 00:00:00 2026 GMT",
+                    "Nov 16 12:00:00 2026 GMT",
+                ),
+                certinfo(
+                    "CN = Example CA",
+                    "CN = Example Root",
+                    None,
+                    "Jan  1 00:00:00 2020 GMT",
+                    "Jan  1 00:00:00 2030 GMT",
+                ),
+            ],
+            "api.example.com",
+            now(),
+        )
+        .expect("chain");
+
+        assert!(chain.issues.is_empty(), "{:?}", chain.issues);
+        assert_eq!(chain.certificates.len(), 2);
+        let leaf = &chain.certificates[0];
+        assert_eq!(leaf.common_name(), Some("api.example.com"));
+        assert_eq!(leaf.issuer, "CN = Example CA");
+        assert_eq!(
+            leaf.subject_alt_names,
+            vec!["DNS:api.example.com", "IP Address:10.0.0.1"]
+        );
+        assert_eq!(leaf.not_before.as_deref(), Some("2026-10-06T00:00:00Z"));
+        assert_eq!(leaf.not_after.as_deref(), Some("2026-11-16T12:00:00Z"));
+        assert_eq!(leaf.days_until_expiry, Some(30));
+        assert_eq!(leaf.serial_number.as_deref(), Some("6d58b941"));
+        let fingerprint = leaf.sha256_fingerprint.as_deref().expect("fingerprint");
+        assert_eq!(fingerprint.len(), 32 * 3 - 1);
+        assert!(fingerprint.split(':').all(|byte| byte.len() == 2));
+    }
+
+    #[test]
+    fn test_flags_expired_self_signed_and_mismatched_certificate() {
+        let chain = CertificateChain::from_certinfo(
+            &[certinfo(
+                "CN = internal.example.com",
+                "CN = internal.example.com",
+                Some("DNS:internal.example.com"),
+                "Jan  1 00:00:00 2025 GMT",
+                "Jan  1 00:00:00 2026 GMT",
+            )],
+            "api.example.com",
+            now(),
+        )
+        .expect("chain");
+
+        assert_eq!(
+            chain.issues,
+            vec![
+                CertificateIssue::Expired,
+                CertificateIssue::SelfSigned,
+                CertificateIssue::HostnameMismatch
+            ]
+        );
+        assert!(chain.certificates[0].days_until_expiry.unwrap() < 0);
+    }
+
+    #[test]
+    fn test_flags_certificate_not_valid_yet() {
+        let chain = CertificateChain::from_certinfo(
+            &[certinfo(
+                "CN = api.example.com",
+                "CN = Example CA",
+                Some("DNS:api.example.com"),
+                "Jan  1 00:00:00 2027 GMT",
+                "Jan  1 00:00:00 2028 GMT",
+            )],
+            "api.example.com",
+            now(),
+        )
+        .expect("chain");
+
+        assert!(chain.has_issue(CertificateIssue::NotYetValid));
+        assert!(!chain.has_issue(CertificateIssue::Expired));
+    }
+
+    #[test]
+    fn test_host_matching() {
+        let cert = |subject: &str, sans: &[&str]| PeerCertificate {
+            subject: subject.to_string(),
+            subject_alt_names: sans.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+
+        let wildcard = cert(
+            "CN = example.com",
+            &["DNS:*.example.com", "IP Address:0:0:0:0:0:0:0:1"],
+        );
+        assert!(wildcard.matches_host("api.example.com"));
+        assert!(wildcard.matches_host("API.Example.com"));
+        assert!(!wildcard.matches_host("example.com"));
+        assert!(!wildcard.matches_host("a.b.example.com"));
+        assert!(wildcard.matches_host("[::1]"));
+        assert!(!wildcard.matches_host("127.0.0.1"));
+
+        // The common name only counts when there are no SANs at all
+        assert!(cert("CN = legacy.example.com", &[]).matches_host("legacy.example.com"));
+        assert!(
+            !cert("CN = legacy.example.com", &["IP Address:10.0.0.1"])
+                .matches_host("legacy.example.com")
+        );
+    }
+
+    #[test]
+    fn test_no_certificates_means_no_chain() {
+        assert!(CertificateChain::from_certinfo(&[], "example.com", now()).is_none());
+    }
+}
@@ -43,6 +43,7 @@ pub struct SpecBinding {
 
 impl SpecBinding {
     /// Create a binding from an `OpenAPI` operation.
+    #[must_use]
     pub fn from_operation(operation_id: &str, path: &str, method: &str) -> Self {
         let now = chrono::Utc::now();
         Self {
@@ -55,6 +56,7 @@ impl SpecBinding {
     }
 
     /// Check if this binding has any data.
+    #[must_use]
     pub const fn is_bound(&self) -> bool {
         self.operation_id.is_some() || self.path.is_some()
     }
@@ -141,6 +141,15 @@ pub struct SpecRefreshResult {
     pub operations_changed: Vec<OperationChange>,
 }
 
+impl SpecRefreshResult {
+    /// Whether the drift breaks existing requests: an operation they call
+    /// was removed from the spec.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        !self.operations_removed.is_empty()
+    }
+}
+
 /// An operation identified by HTTP method and path.
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, TS)]
 #[ts(export)]
@@ -171,6 +180,7 @@ pub struct OperationChange {
 /// - Changed operations (in both, but with differences in summary, parameters count, or deprecated status)
 ///
 /// This is a pure function — no I/O, no side effects, easily testable.
+#[must_use]
 pub fn compute_drift(old: &ParsedSpec, new: &ParsedSpec) -> SpecRefreshResult {
     // Build lookup maps keyed by (method, path)
     let old_map: BTreeMap<(String, String), _> = old
@@ -344,6 +354,8 @@ mod tests {
         assert_eq!(result.operations_removed[0].method, "PUT");
         assert_eq!(result.operations_removed[0].path, "/users/{id}");
         assert!(result.operations_changed.is_empty());
+        assert!(result.is_breaking());
+        assert!(!compute_drift(&new, &old).is_breaking());
     }
 
     /// Test 2B.5: Detect changed operations (summary + parameters).
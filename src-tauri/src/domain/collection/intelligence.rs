@@ -38,6 +38,7 @@ pub struct IntelligenceMetadata {
 
 impl IntelligenceMetadata {
     /// Mark as AI-generated with model attribution.
+    #[must_use]
     pub fn ai_generated(model: &str) -> Self {
         Self {
             ai_generated: true,
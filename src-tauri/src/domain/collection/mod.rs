@@ -16,6 +16,8 @@ pub mod drift;
 pub mod git_port;
 /// AI attribution and verification metadata.
 pub mod intelligence;
+/// Run options, progress updates and reports for collection runs.
+pub mod run;
 /// Source type and provenance tracking.
 pub mod source;
 /// Domain ports for pluggable spec import (hexagonal architecture).
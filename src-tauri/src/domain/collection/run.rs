@@ -0,0 +1,588 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Collection runs: which requests to run, live progress, and the run report.
+//!
+//! The runner itself lives in the application layer; this module only holds
+//! the format-agnostic pieces, including the `JUnit` XML rendering of a report.
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+#[cfg(test)]
+use ts_rs::TS;
+
+use super::types::CollectionRequest;
+use crate::domain::assertion::AssertionResult;
+use crate::domain::extraction::ExtractedVariable;
+
+/// How a collection is run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RunOptions {
+    /// Stop at the first failed request; the rest are reported as skipped.
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub stop_on_failure: bool,
+    /// Pause between consecutive requests, in milliseconds.
+    #[serde(default)]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub delay_ms: u64,
+    /// Only run requests in one of these folders or their subfolders.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub folders: Vec<String>,
+    /// Only run requests carrying at least one of these tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub tags: Vec<String>,
+    /// Only run these requests, given by ID or name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub requests: Vec<String>,
+    /// Timeout for each request, in milliseconds (default: 30000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub timeout_ms: Option<u64>,
+}
+
+impl RunOptions {
+    /// Whether `request` passes the folder, tag and request filters.
+    #[must_use]
+    pub fn selects(&self, request: &CollectionRequest) -> bool {
+        let in_folder = self.folders.is_empty()
+            || request
+                .folder
+                .as_deref()
+                .is_some_and(|folder| self.folders.iter().any(|f| in_folder(folder, f)));
+        let tagged = self.tags.is_empty() || request.tags.iter().any(|t| self.tags.contains(t));
+        let named = self.requests.is_empty()
+            || self
+                .requests
+                .iter()
+                .any(|r| *r == request.id || *r == request.name);
+        in_folder && tagged && named
+    }
+}
+
+/// Whether `folder` is `parent` or one of its subfolders.
+fn in_folder(folder: &str, parent: &str) -> bool {
+    let folder = folder.trim_matches('/');
+    let parent = parent.trim_matches('/');
+    folder == parent
+        || folder
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Result of one request in a run.
+///
+/// A response is judged by the request's assertions when it has any, and by
+/// its status otherwise.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum RunOutcome {
+    /// Got a response that passed every assertion, or without assertions,
+    /// a status below 400.
+    Passed,
+    /// Got a response that failed an assertion, or without assertions, a
+    /// 4xx or 5xx status.
+    Failed,
+    /// No response: the request could not be built or the transfer failed.
+    Errored,
+    /// Not run because an earlier request stopped the run.
+    Skipped,
+}
+
+impl RunOutcome {
+    /// Outcome of a request that got a response with `status`, given the
+    /// results of its assertions.
+    #[must_use]
+    pub fn of_response(status: u16, assertions: &[AssertionResult]) -> Self {
+        let passed = if assertions.is_empty() {
+            status < 400
+        } else {
+            assertions.iter().all(|result| result.passed)
+        };
+        if passed { Self::Passed } else { Self::Failed }
+    }
+}
+
+/// One request's entry in a [`RunReport`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RunRequestResult {
+    /// Request ID within the collection.
+    pub request_id: String,
+    /// Request name.
+    pub name: String,
+    /// HTTP method.
+    pub method: String,
+    /// URL as sent, with variables resolved.
+    pub url: String,
+    /// Outcome of the request.
+    pub outcome: RunOutcome,
+    /// Response status, when a response arrived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub status: Option<u16>,
+    /// Response status text, when a response arrived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub status_text: Option<String>,
+    /// Error code and message, for errored requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub error: Option<String>,
+    /// Time spent on the request, in milliseconds.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub duration_ms: u64,
+    /// Outcome of the request's extraction rules, when a response arrived.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<ExtractedVariable>>", optional))]
+    pub extracted: Vec<ExtractedVariable>,
+    /// Outcome of the request's assertions, when a response arrived.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<AssertionResult>>", optional))]
+    pub assertions: Vec<AssertionResult>,
+}
+
+impl RunRequestResult {
+    /// Entry for a request that was not run.
+    #[must_use]
+    pub fn skipped(request: &CollectionRequest) -> Self {
+        Self {
+            request_id: request.id.clone(),
+            name: request.name.clone(),
+            method: request.method.clone(),
+            url: request.url.clone(),
+            outcome: RunOutcome::Skipped,
+            status: None,
+            status_text: None,
+            error: None,
+            duration_ms: 0,
+            extracted: Vec::new(),
+            assertions: Vec::new(),
+        }
+    }
+}
+
+/// Summary of a collection run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RunReport {
+    /// Run ID; also the correlation ID its requests were sent with.
+    pub run_id: String,
+    /// ID of the collection that was run.
+    pub collection_id: String,
+    /// Name of the collection that was run.
+    pub collection_name: String,
+    /// Environment the run used, if one was active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub environment: Option<String>,
+    /// RFC 3339 UTC timestamp of the start of the run.
+    pub started_at: String,
+    /// Wall-clock duration of the run, in milliseconds, including delays.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub duration_ms: u64,
+    /// Number of passed requests.
+    pub passed: u32,
+    /// Number of failed requests.
+    pub failed: u32,
+    /// Number of errored requests.
+    pub errored: u32,
+    /// Number of skipped requests.
+    pub skipped: u32,
+    /// Per-request results, in run order.
+    pub results: Vec<RunRequestResult>,
+}
+
+impl RunReport {
+    /// Start an empty report.
+    #[must_use]
+    pub fn new(
+        run_id: impl Into<String>,
+        collection_id: impl Into<String>,
+        collection_name: impl Into<String>,
+        environment: Option<String>,
+        started_at: impl Into<String>,
+    ) -> Self {
+        Self {
+            run_id: run_id.into(),
+            collection_id: collection_id.into(),
+            collection_name: collection_name.into(),
+            environment,
+            started_at: started_at.into(),
+            duration_ms: 0,
+            passed: 0,
+            failed: 0,
+            errored: 0,
+            skipped: 0,
+            results: Vec::new(),
+        }
+    }
+
+    /// Add a request's result and update the counters.
+    pub fn record(&mut self, result: RunRequestResult) {
+        match result.outcome {
+            RunOutcome::Passed => self.passed += 1,
+            RunOutcome::Failed => self.failed += 1,
+            RunOutcome::Errored => self.errored += 1,
+            RunOutcome::Skipped => self.skipped += 1,
+        }
+        self.results.push(result);
+    }
+
+    /// Whether no request failed or errored.
+    #[must_use]
+    pub const fn success(&self) -> bool {
+        self.failed == 0 && self.errored == 0
+    }
+
+    /// Render the report as `JUnit` XML: one test suite for the collection and
+    /// one test case per request.
+    #[must_use]
+    pub fn to_junit_xml(&self) -> String {
+        let suite = escape_xml(&self.collection_name);
+        let tests = self.results.len();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"runi\" tests=\"{tests}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">",
+            self.failed,
+            self.errored,
+            self.skipped,
+            seconds(self.duration_ms)
+        );
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{suite}\" id=\"{}\" tests=\"{tests}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\" timestamp=\"{}\">",
+            escape_xml(&self.collection_id),
+            self.failed,
+            self.errored,
+            self.skipped,
+            seconds(self.duration_ms),
+            escape_xml(&self.started_at)
+        );
+        for result in &self.results {
+            let name = escape_xml(&format!("{} {}", result.method, result.name));
+            let open = format!(
+                "    <testcase classname=\"{suite}\" name=\"{name}\" time=\"{}\"",
+                seconds(result.duration_ms)
+            );
+            match result.outcome {
+                RunOutcome::Passed => {
+                    let _ = writeln!(xml, "{open}/>");
+                }
+                RunOutcome::Failed => {
+                    let (kind, message) = failure(result);
+                    let _ = writeln!(
+                        xml,
+                        "{open}>\n      <failure type=\"{kind}\" message=\"{}\"/>\n    </testcase>",
+                        escape_xml(message.trim_end())
+                    );
+                }
+                RunOutcome::Errored => {
+                    let _ = writeln!(
+                        xml,
+                        "{open}>\n      <error type=\"request\" message=\"{}\"/>\n    </testcase>",
+                        escape_xml(result.error.as_deref().unwrap_or("Request failed"))
+                    );
+                }
+                RunOutcome::Skipped => {
+                    let _ = writeln!(xml, "{open}>\n      <skipped/>\n    </testcase>");
+                }
+            }
+        }
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+/// Type and message of the `JUnit` failure of a failed request: its failed
+/// assertions when it has assertions, its status otherwise.
+fn failure(result: &RunRequestResult) -> (&'static str, String) {
+    if result.assertions.is_empty() {
+        let message = format!(
+            "{} {} returned {} {}",
+            result.method,
+            result.url,
+            result.status.unwrap_or_default(),
+            result.status_text.as_deref().unwrap_or_default()
+        );
+        return ("status", message);
+    }
+    let failed: Vec<_> = result
+        .assertions
+        .iter()
+        .filter(|assertion| !assertion.passed)
+        .filter_map(|assertion| assertion.message.as_deref())
+        .collect();
+    ("assertion", failed.join("\n"))
+}
+
+/// Milliseconds as the fractional seconds `JUnit` reports use.
+fn seconds(ms: u64) -> String {
+    format!("{}.{:03}", ms / 1000, ms % 1000)
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c if c.is_control() && c != '\t' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Live update of a running collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RunUpdate {
+    /// The run started; `total` requests passed the filters.
+    Started {
+        /// ID of the collection being run.
+        collection_id: String,
+        /// Number of requests that will run.
+        total: u32,
+    },
+    /// A request is about to be sent.
+    RequestStarted {
+        /// Zero-based position of the request in the run.
+        index: u32,
+        /// Request ID within the collection.
+        request_id: String,
+        /// Request name.
+        name: String,
+    },
+    /// A request completed, failed or was skipped.
+    RequestFinished {
+        /// Zero-based position of the request in the run.
+        index: u32,
+        /// The request's result.
+        result: RunRequestResult,
+    },
+    /// The run is over.
+    Finished {
+        /// Number of passed requests.
+        passed: u32,
+        /// Number of failed requests.
+        failed: u32,
+        /// Number of errored requests.
+        errored: u32,
+        /// Number of skipped requests.
+        skipped: u32,
+        /// Wall-clock duration of the run, in milliseconds.
+        #[cfg_attr(test, ts(type = "number"))]
+        duration_ms: u64,
+    },
+}
+
+impl RunUpdate {
+    /// Event name used for SSE topics (e.g. `run:request_finished`).
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        match self {
+            Self::Started { .. } => "run:started",
+            Self::RequestStarted { .. } => "run:request_started",
+            Self::RequestFinished { .. } => "run:request_finished",
+            Self::Finished { .. } => "run:finished",
+        }
+    }
+}
+
+/// A [`RunUpdate`] tagged with the ID of its run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CollectionRunEvent {
+    /// ID of the run.
+    pub run_id: String,
+    /// The update itself.
+    #[serde(flatten)]
+    pub update: RunUpdate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::assertion::Assertion;
+    use crate::domain::http::HttpResponse;
+
+    fn request(id: &str, folder: Option<&str>, tags: &[&str]) -> CollectionRequest {
+        CollectionRequest {
+            id: id.to_string(),
+            name: format!("Request {id}"),
+            method: "GET".to_string(),
+            url: format!("https://api.example.com/{id}"),
+            folder: folder.map(ToString::to_string),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn result(id: &str, outcome: RunOutcome, status: Option<u16>) -> RunRequestResult {
+        RunRequestResult {
+            outcome,
+            status,
+            status_text: status.map(|_| "Internal Server Error".to_string()),
+            duration_ms: 1_250,
+            ..RunRequestResult::skipped(&request(id, None, &[]))
+        }
+    }
+
+    #[test]
+    fn test_no_filters_select_everything() {
+        let options = RunOptions::default();
+        assert!(options.selects(&request("a", None, &[])));
+        assert!(options.selects(&request("b", Some("users"), &["smoke"])));
+    }
+
+    #[test]
+    fn test_folder_filter_includes_subfolders_only() {
+        let options = RunOptions {
+            folders: vec!["users/".to_string()],
+            ..Default::default()
+        };
+        assert!(options.selects(&request("a", Some("users"), &[])));
+        assert!(options.selects(&request("b", Some("users/admin"), &[])));
+        assert!(!options.selects(&request("c", Some("users-legacy"), &[])));
+        assert!(!options.selects(&request("d", None, &[])));
+    }
+
+    #[test]
+    fn test_folder_and_tag_filters_combine() {
+        let options = RunOptions {
+            folders: vec!["users".to_string()],
+            tags: vec!["smoke".to_string(), "critical".to_string()],
+            ..Default::default()
+        };
+        assert!(options.selects(&request("a", Some("users"), &["critical"])));
+        assert!(!options.selects(&request("b", Some("users"), &["slow"])));
+        assert!(!options.selects(&request("c", Some("orders"), &["smoke"])));
+    }
+
+    #[test]
+    fn test_request_filter_matches_id_or_name() {
+        let options = RunOptions {
+            requests: vec!["a".to_string(), "Request b".to_string()],
+            ..Default::default()
+        };
+        assert!(options.selects(&request("a", None, &[])));
+        assert!(options.selects(&request("b", None, &[])));
+        assert!(!options.selects(&request("c", None, &[])));
+    }
+
+    #[test]
+    fn test_report_counts_outcomes() {
+        let mut report = RunReport::new("run_1", "col_1", "Shop", None, "2026-10-17T12:00:00Z");
+        report.record(result("a", RunOutcome::Passed, Some(200)));
+        assert!(report.success());
+        report.record(result("b", RunOutcome::Failed, Some(500)));
+        report.record(result("c", RunOutcome::Skipped, None));
+        assert_eq!(
+            (report.passed, report.failed, report.errored, report.skipped),
+            (1, 1, 0, 1)
+        );
+        assert!(!report.success());
+    }
+
+    #[test]
+    fn test_junit_xml_reports_every_outcome() {
+        let mut report = RunReport::new(
+            "run_1",
+            "col_1",
+            "Shop <prod>",
+            Some("prod".to_string()),
+            "2026-10-17T12:00:00Z",
+        );
+        report.duration_ms = 4_005;
+        report.record(result("a", RunOutcome::Passed, Some(200)));
+        report.record(result("b", RunOutcome::Failed, Some(500)));
+        report.record(RunRequestResult {
+            error: Some("[HTTP_REQUEST_FAILED] \"refused\"".to_string()),
+            ..result("c", RunOutcome::Errored, None)
+        });
+        report.record(result("d", RunOutcome::Skipped, None));
+
+        let xml = report.to_junit_xml();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites"));
+        assert!(
+            xml.contains("tests=\"4\" failures=\"1\" errors=\"1\" skipped=\"1\" time=\"4.005\"")
+        );
+        assert!(xml.contains("<testsuite name=\"Shop &lt;prod&gt;\""));
+        assert!(xml.contains(
+            "<testcase classname=\"Shop &lt;prod&gt;\" name=\"GET Request a\" time=\"1.250\"/>"
+        ));
+        assert!(xml.contains(
+            "<failure type=\"status\" message=\"GET https://api.example.com/b returned 500 Internal Server Error\"/>"
+        ));
+        assert!(xml.contains(
+            "<error type=\"request\" message=\"[HTTP_REQUEST_FAILED] &quot;refused&quot;\"/>"
+        ));
+        assert!(xml.contains("<skipped/>"));
+        assert!(xml.trim_end().ends_with("</testsuites>"));
+    }
+
+    #[test]
+    fn test_assertions_decide_the_outcome_when_present() {
+        let expect_404 = Assertion::StatusEquals { status: 404 };
+        let not_found = HttpResponse {
+            status: 404,
+            ..Default::default()
+        };
+        let passed = vec![expect_404.evaluate(&not_found)];
+        assert_eq!(RunOutcome::of_response(404, &[]), RunOutcome::Failed);
+        assert_eq!(RunOutcome::of_response(404, &passed), RunOutcome::Passed);
+
+        let failed = vec![
+            expect_404.evaluate(&HttpResponse::default()),
+            Assertion::ResponseTimeBelow { max_ms: 500 }.evaluate(&not_found),
+        ];
+        assert_eq!(RunOutcome::of_response(200, &failed), RunOutcome::Failed);
+
+        let mut report = RunReport::new("run_1", "col_1", "Shop", None, "2026-10-17T12:00:00Z");
+        report.record(RunRequestResult {
+            assertions: failed,
+            ..result("a", RunOutcome::Failed, Some(0))
+        });
+        assert!(report.to_junit_xml().contains(
+            "<failure type=\"assertion\" message=\"Expected status to be 404, got 0\"/>"
+        ));
+    }
+
+    #[test]
+    fn test_event_flattens_update_next_to_run_id() {
+        let event = CollectionRunEvent {
+            run_id: "run_1".to_string(),
+            update: RunUpdate::Started {
+                collection_id: "col_1".to_string(),
+                total: 3,
+            },
+        };
+        assert_eq!(event.update.event_type(), "run:started");
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "run_id": "run_1",
+                "kind": "started",
+                "collection_id": "col_1",
+                "total": 3,
+            })
+        );
+    }
+}
@@ -9,6 +9,13 @@ use ts_rs::TS;
 use super::binding::SpecBinding;
 use super::intelligence::IntelligenceMetadata;
 use super::source::CollectionSource;
+use crate::domain::assertion::Assertion;
+use crate::domain::extraction::ExtractionRule;
+use crate::domain::graphql::GraphqlQuery;
+use crate::domain::http::{
+    ConnectToOverride, HttpVersion, MultipartPart, ProxyConfig, ResolveOverride, TlsConfig,
+};
+use crate::domain::retry::RetryPolicy;
 
 /// Schema URL for JSON Schema validation + IDE autocomplete.
 /// Users can add: `# yaml-language-server: $schema=https://runi.dev/schema/collection/v1.json`
@@ -18,7 +25,7 @@ pub const SCHEMA_URL: &str = "https://runi.dev/schema/collection/v1.json";
 ///
 /// Environments allow switching between different deployment targets (local, staging, production)
 /// by overriding variables like `baseUrl` without modifying requests.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, TS)]
 #[ts(export)]
 pub struct CollectionEnvironment {
     /// Human-readable environment name (e.g., "local", "staging", "production").
@@ -26,6 +33,25 @@ pub struct CollectionEnvironment {
     /// Variable key-value pairs for this environment.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub variables: BTreeMap<String, String>,
+    /// TLS settings for requests executed from this collection while this environment
+    /// is active. Per-call settings override them field by field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub tls: Option<TlsConfig>,
+    /// Proxy settings while this environment is active; override the global proxy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub proxy: Option<ProxyConfig>,
+    /// Host names pinned to fixed addresses while this environment is active
+    /// (curl `--resolve`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[ts(as = "Option<Vec<ResolveOverride>>", optional)]
+    pub resolve: Vec<ResolveOverride>,
+    /// Hosts and ports connected to in place of requested ones while this
+    /// environment is active (curl `--connect-to`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[ts(as = "Option<Vec<ConnectToOverride>>", optional)]
+    pub connect_to: Vec<ConnectToOverride>,
 }
 
 /// Current schema version. Simple integer, not semver.
@@ -76,6 +102,14 @@ pub struct Collection {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active_environment: Option<String>,
 
+    /// HTTP version for requests that don't set their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_version: Option<HttpVersion>,
+
+    /// Retry policy for requests that don't set their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+
     /// Extension fields (x-team, x-owner, etc).
     #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
     pub extensions: BTreeMap<String, Value>,
@@ -90,6 +124,7 @@ pub struct Collection {
 
 impl Collection {
     /// Generate a collection ID from name.
+    #[must_use]
     pub fn generate_id(name: &str) -> String {
         let slug = name
             .to_lowercase()
@@ -101,6 +136,7 @@ impl Collection {
     }
 
     /// Get requests sorted by seq with id tiebreaker for deterministic ordering.
+    #[must_use]
     pub fn sorted_requests(&self) -> Vec<&CollectionRequest> {
         let mut sorted: Vec<_> = self.requests.iter().collect();
         sorted.sort_by(|a, b| match a.seq.cmp(&b.seq) {
@@ -110,12 +146,21 @@ impl Collection {
         sorted
     }
 
+    /// Get the active environment, if one is selected and exists.
+    #[must_use]
+    pub fn active_env(&self) -> Option<&CollectionEnvironment> {
+        let name = self.active_environment.as_deref()?;
+        self.environments.iter().find(|env| env.name == name)
+    }
+
     /// Get the next seq value for a new request.
+    #[must_use]
     pub fn next_seq(&self) -> u32 {
         self.requests.iter().map(|r| r.seq).max().unwrap_or(0) + 1
     }
 
     /// Create a new collection with defaults.
+    #[must_use]
     pub fn new(name: &str) -> Self {
         let now = chrono::Utc::now();
         let timestamp = now.format("%Y-%m-%dT%H:%M:%SZ").to_string();
@@ -136,6 +181,8 @@ impl Collection {
             variables: BTreeMap::new(),
             environments: vec![],
             active_environment: None,
+            http_version: None,
+            retry: None,
             extensions: BTreeMap::new(),
             requests: vec![],
             pinned_versions: vec![],
@@ -199,6 +246,27 @@ pub struct CollectionMetadata {
     pub modified_at: String,
 }
 
+/// How a collection request is sent.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestKind {
+    /// A single HTTP request and response.
+    #[default]
+    Http,
+    /// A WebSocket session opened with an upgrade handshake.
+    #[serde(rename = "websocket")]
+    WebSocket,
+}
+
+impl RequestKind {
+    /// Whether this is the default, plain HTTP kind.
+    #[must_use]
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature required by `skip_serializing_if`
+    pub const fn is_http(&self) -> bool {
+        matches!(self, Self::Http)
+    }
+}
+
 /// A single request in the collection.
 ///
 /// # Ordering
@@ -239,6 +307,31 @@ pub struct CollectionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auth: Option<AuthConfig>,
 
+    /// HTTP version for this request (overrides the collection's).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_version: Option<HttpVersion>,
+
+    /// Retry policy for this request (overrides the collection's).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+
+    /// Whether to follow `Location` redirects (default: true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+
+    /// Unix domain socket to send the request through instead of TCP.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unix_socket: Option<String>,
+
+    /// Values captured from the response into variables after the request
+    /// runs, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extract: Vec<ExtractionRule>,
+
+    /// Checks on the response, evaluated every time the request runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<Assertion>,
+
     /// Markdown documentation for this request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub docs: Option<String>,
@@ -247,6 +340,14 @@ pub struct CollectionRequest {
     #[serde(default)]
     pub is_streaming: bool,
 
+    /// Kind of request: plain HTTP (default) or a WebSocket session.
+    #[serde(default, skip_serializing_if = "RequestKind::is_http")]
+    pub kind: RequestKind,
+
+    /// WebSocket subprotocols offered in the handshake, most preferred first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subprotocols: Vec<String>,
+
     /// Link to `OpenAPI` operation (for drift detection).
     #[serde(default)]
     pub binding: SpecBinding,
@@ -255,6 +356,11 @@ pub struct CollectionRequest {
     #[serde(default)]
     pub intelligence: IntelligenceMetadata,
 
+    /// Folder the request is grouped under, as a `/`-separated path
+    /// (e.g. `users/admin`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+
     /// Tags for filtering/organizing.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
@@ -266,6 +372,7 @@ pub struct CollectionRequest {
 
 impl CollectionRequest {
     /// Generate a request ID from name.
+    #[must_use]
     pub fn generate_id(name: &str) -> String {
         let slug = name
             .to_lowercase()
@@ -307,9 +414,35 @@ pub struct RequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
 
-    /// External file reference for large bodies.
+    /// External file reference for large bodies, streamed from disk when sent.
+    /// Relative paths resolve against the collections directory.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<String>,
+
+    /// Parts of a `multipart` body.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<MultipartPart>,
+
+    /// Query, variables and operation name of a `graphql` body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<GraphqlQuery>,
+}
+
+impl RequestBody {
+    /// The GraphQL request carried by a `graphql` body, falling back to
+    /// `content` for bodies saved before queries were stored separately.
+    #[must_use]
+    pub fn graphql_query(&self) -> Option<GraphqlQuery> {
+        if self.body_type != BodyType::Graphql {
+            return None;
+        }
+        self.graphql.clone().or_else(|| {
+            self.content
+                .as_deref()
+                .filter(|content| !content.trim().is_empty())
+                .map(GraphqlQuery::from_body_text)
+        })
+    }
 }
 
 /// Supported request body types.
@@ -323,6 +456,10 @@ pub enum BodyType {
     Json,
     /// Form-encoded body.
     Form,
+    /// `multipart/form-data` body built from [`RequestBody::parts`].
+    Multipart,
+    /// Raw bytes of [`RequestBody::file`].
+    Binary,
     /// Raw body.
     Raw,
     /// GraphQL body.
@@ -338,7 +475,8 @@ pub struct AuthConfig {
     #[serde(rename = "type")]
     pub auth_type: AuthType,
 
-    /// Token for Bearer auth. Use variable: "{{accessToken}}".
+    /// Token for Bearer auth, or the key for API key auth. Use variable:
+    /// "{{accessToken}}".
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token: Option<String>,
 
@@ -350,9 +488,14 @@ pub struct AuthConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
 
-    /// Header name for API key auth.
+    /// Header name for API key auth (default `X-API-Key`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub header: Option<String>,
+
+    /// Query parameter name for API key auth; sends the key in the query
+    /// string instead of a header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_param: Option<String>,
 }
 
 /// Supported auth types.
@@ -373,6 +516,7 @@ pub enum AuthType {
 /// Generate a random 6-character hex suffix for IDs.
 ///
 /// Exposed for use in infrastructure layer ID generation.
+#[must_use]
 pub fn random_hex_suffix_pub() -> String {
     random_hex_suffix()
 }
@@ -421,10 +565,19 @@ mod tests {
             params: vec![],
             body: None,
             auth: None,
+            http_version: None,
+            retry: None,
+            follow_redirects: None,
+            unix_socket: None,
+            extract: Vec::new(),
+            assertions: Vec::new(),
             docs: None,
             is_streaming: false,
+            kind: RequestKind::Http,
+            subprotocols: Vec::new(),
             binding: SpecBinding::default(),
             intelligence: IntelligenceMetadata::default(),
+            folder: None,
             tags: vec![],
             extensions: BTreeMap::new(),
         };
@@ -464,10 +617,19 @@ mod tests {
             params: vec![],
             body: None,
             auth: None,
+            http_version: None,
+            retry: None,
+            follow_redirects: None,
+            unix_socket: None,
+            extract: Vec::new(),
+            assertions: Vec::new(),
             docs: None,
             is_streaming: false,
+            kind: RequestKind::Http,
+            subprotocols: Vec::new(),
             binding: SpecBinding::default(),
             intelligence: IntelligenceMetadata::default(),
+            folder: None,
             tags: vec![],
             extensions: BTreeMap::new(),
         };
@@ -501,6 +663,8 @@ mod tests {
             body_type: BodyType::Graphql,
             content: Some("query { }".to_string()),
             file: None,
+            parts: Vec::new(),
+            graphql: None,
         };
         let yaml = serde_yaml_ng::to_string(&body).unwrap();
         assert!(yaml.contains("type: graphql"));
@@ -575,6 +739,7 @@ mod tests {
         let env = CollectionEnvironment {
             name: "local".to_string(),
             variables: vars,
+            ..Default::default()
         };
         let yaml = serde_yaml_ng::to_string(&env).unwrap();
         assert!(yaml.contains("name: local"));
@@ -590,6 +755,7 @@ mod tests {
         let env = CollectionEnvironment {
             name: "empty".to_string(),
             variables: BTreeMap::new(),
+            ..Default::default()
         };
         let yaml = serde_yaml_ng::to_string(&env).unwrap();
         assert!(yaml.contains("name: empty"));
@@ -619,6 +785,7 @@ mod tests {
         collection.environments = vec![CollectionEnvironment {
             name: "local".to_string(),
             variables: vars,
+            ..Default::default()
         }];
         collection.active_environment = Some("local".to_string());
         let yaml = serde_yaml_ng::to_string(&collection).unwrap();
@@ -696,6 +863,7 @@ mod tests {
         collection.environments = vec![CollectionEnvironment {
             name: "production".to_string(),
             variables: vars,
+            ..Default::default()
         }];
         collection.active_environment = Some("production".to_string());
         let yaml = serde_yaml_ng::to_string(&collection).unwrap();
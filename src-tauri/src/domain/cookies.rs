@@ -0,0 +1,322 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Cookie jar domain models.
+//!
+//! Cookies are scoped to a collection and its active environment. The HTTP
+//! engine exchanges them with curl's cookie engine using the Netscape cookie
+//! file format, so matching (domain, path, secure, expiry) follows curl.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+use ts_rs::TS;
+
+/// A stored HTTP cookie.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct Cookie {
+    /// Cookie name.
+    pub name: String,
+    /// Cookie value.
+    pub value: String,
+    /// Domain the cookie belongs to, without a leading dot.
+    pub domain: String,
+    /// Path prefix the cookie applies to.
+    #[serde(default = "default_cookie_path")]
+    pub path: String,
+    /// Whether subdomains of `domain` also receive the cookie (`Domain=` attribute set).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub include_subdomains: bool,
+    /// Only sent over HTTPS.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub secure: bool,
+    /// Not exposed to scripts (`HttpOnly`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub http_only: bool,
+    /// Expiry as Unix seconds; `None` for a session cookie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub expires: Option<i64>,
+}
+
+fn default_cookie_path() -> String {
+    "/".to_string()
+}
+
+/// Netscape cookie-file prefix curl uses to mark `HttpOnly` cookies.
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+impl Cookie {
+    /// Whether this cookie and `other` occupy the same jar slot (name, domain, path).
+    #[must_use]
+    pub fn same_slot(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.domain.eq_ignore_ascii_case(&other.domain)
+            && self.path == other.path
+    }
+
+    /// Whether the cookie has expired at `now` (Unix seconds). Session cookies never expire.
+    #[must_use]
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Serialize as a Netscape cookie-file line, the format curl's `COOKIELIST` accepts.
+    #[must_use]
+    pub fn to_netscape_line(&self) -> String {
+        let flag = |b: bool| if b { "TRUE" } else { "FALSE" };
+        let prefix = if self.http_only { HTTP_ONLY_PREFIX } else { "" };
+        let domain = if self.include_subdomains {
+            format!(".{}", self.domain)
+        } else {
+            self.domain.clone()
+        };
+        format!(
+            "{prefix}{domain}\t{}\t{}\t{}\t{}\t{}\t{}",
+            flag(self.include_subdomains),
+            self.path,
+            flag(self.secure),
+            self.expires.unwrap_or(0),
+            self.name,
+            self.value
+        )
+    }
+
+    /// Parse a Netscape cookie-file line as produced by curl's `CURLINFO_COOKIELIST`.
+    ///
+    /// Returns `None` for comments and malformed lines.
+    #[must_use]
+    pub fn from_netscape_line(line: &str) -> Option<Self> {
+        let (http_only, line) = line
+            .strip_prefix(HTTP_ONLY_PREFIX)
+            .map_or((false, line), |rest| (true, rest));
+        if line.starts_with('#') {
+            return None;
+        }
+        let mut fields = line.splitn(7, '\t');
+        let domain = fields.next()?;
+        let include_subdomains = fields.next()?.eq_ignore_ascii_case("TRUE");
+        let path = fields.next()?.to_string();
+        let secure = fields.next()?.eq_ignore_ascii_case("TRUE");
+        let expires = fields.next()?.parse::<i64>().ok()?;
+        let name = fields.next()?.to_string();
+        let value = fields.next().unwrap_or_default().to_string();
+        Some(Self {
+            name,
+            value,
+            domain: domain.trim_start_matches('.').to_string(),
+            path,
+            include_subdomains,
+            secure,
+            http_only,
+            expires: (expires > 0).then_some(expires),
+        })
+    }
+}
+
+/// Identifies the cookie jar a request reads from and writes to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct CookieJarScope {
+    /// Collection that owns the jar.
+    pub collection_id: String,
+    /// Environment the jar belongs to; `None` when no environment is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub environment: Option<String>,
+}
+
+/// Insert or replace `cookie` in `cookies`, keyed by name, domain and path.
+pub fn upsert_cookie(cookies: &mut Vec<Cookie>, cookie: Cookie) {
+    if let Some(existing) = cookies.iter_mut().find(|c| c.same_slot(&cookie)) {
+        *existing = cookie;
+    } else {
+        cookies.push(cookie);
+    }
+}
+
+/// Apply to `cookies` the changes between a jar's `before` and `after` states.
+///
+/// Cookies in `after` that are new or differ from `before` are upserted;
+/// cookies of `before` missing from `after` are removed unless `cookies`
+/// already holds a different value for their slot. Slots the change did not
+/// touch keep whatever `cookies` holds, so concurrent updates are preserved.
+pub fn merge_cookie_changes(cookies: &mut Vec<Cookie>, before: &[Cookie], after: &[Cookie]) {
+    for cookie in after {
+        if !before.contains(cookie) {
+            upsert_cookie(cookies, cookie.clone());
+        }
+    }
+    for cookie in before {
+        if !after.iter().any(|c| c.same_slot(cookie)) {
+            cookies.retain(|c| c != cookie);
+        }
+    }
+}
+
+/// Remove cookies matching `name` and/or `domain`; with neither, clear all.
+///
+/// Returns the number of cookies removed.
+pub fn remove_cookies(
+    cookies: &mut Vec<Cookie>,
+    name: Option<&str>,
+    domain: Option<&str>,
+) -> usize {
+    let before = cookies.len();
+    cookies.retain(|cookie| {
+        let name_matches = name.is_none_or(|name| cookie.name == name);
+        let domain_matches = domain.is_none_or(|domain| {
+            cookie
+                .domain
+                .eq_ignore_ascii_case(domain.trim_start_matches('.'))
+        });
+        !(name_matches && domain_matches)
+    });
+    before - cookies.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_cookie() -> Cookie {
+        Cookie {
+            name: "session".to_string(),
+            value: "abc123".to_string(),
+            domain: "api.example.com".to_string(),
+            path: "/".to_string(),
+            include_subdomains: false,
+            secure: true,
+            http_only: true,
+            expires: None,
+        }
+    }
+
+    #[test]
+    fn test_netscape_round_trip() {
+        let cookie = session_cookie();
+        let line = cookie.to_netscape_line();
+        assert_eq!(
+            line,
+            "#HttpOnly_api.example.com\tFALSE\t/\tTRUE\t0\tsession\tabc123"
+        );
+        assert_eq!(Cookie::from_netscape_line(&line), Some(cookie));
+    }
+
+    #[test]
+    fn test_netscape_domain_cookie_with_expiry() {
+        let cookie =
+            Cookie::from_netscape_line(".example.com\tTRUE\t/app\tFALSE\t1999999999\tpref\ta=b")
+                .expect("valid line");
+        assert_eq!(cookie.domain, "example.com");
+        assert!(cookie.include_subdomains);
+        assert_eq!(cookie.path, "/app");
+        assert_eq!(cookie.value, "a=b");
+        assert_eq!(cookie.expires, Some(1_999_999_999));
+        assert!(!cookie.is_expired(1_700_000_000));
+        assert!(cookie.is_expired(2_000_000_000));
+        assert_eq!(
+            cookie.to_netscape_line(),
+            ".example.com\tTRUE\t/app\tFALSE\t1999999999\tpref\ta=b"
+        );
+    }
+
+    #[test]
+    fn test_netscape_rejects_comments_and_malformed_lines() {
+        assert_eq!(
+            Cookie::from_netscape_line("# Netscape HTTP Cookie File"),
+            None
+        );
+        assert_eq!(Cookie::from_netscape_line("example.com\tFALSE\t/"), None);
+    }
+
+    #[test]
+    fn test_upsert_cookie_replaces_same_slot() {
+        let mut cookies = vec![session_cookie()];
+        upsert_cookie(
+            &mut cookies,
+            Cookie {
+                value: "rotated".to_string(),
+                ..session_cookie()
+            },
+        );
+        upsert_cookie(
+            &mut cookies,
+            Cookie {
+                path: "/admin".to_string(),
+                ..session_cookie()
+            },
+        );
+
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0].value, "rotated");
+        assert_eq!(cookies[1].path, "/admin");
+    }
+
+    #[test]
+    fn test_remove_cookies_by_name_and_domain() {
+        let mut cookies = vec![
+            session_cookie(),
+            Cookie {
+                name: "theme".to_string(),
+                ..session_cookie()
+            },
+            Cookie {
+                domain: "auth.example.com".to_string(),
+                ..session_cookie()
+            },
+        ];
+
+        assert_eq!(
+            remove_cookies(&mut cookies, Some("session"), Some(".api.example.com")),
+            1
+        );
+        assert_eq!(remove_cookies(&mut cookies, Some("missing"), None), 0);
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(remove_cookies(&mut cookies, None, None), 2);
+        assert!(cookies.is_empty());
+    }
+
+    #[test]
+    fn test_merge_cookie_changes_keeps_concurrent_updates() {
+        let theme = Cookie {
+            name: "theme".to_string(),
+            ..session_cookie()
+        };
+        let before = vec![session_cookie(), theme.clone()];
+        // The transfer rotated the session and expired the theme cookie
+        let rotated = Cookie {
+            value: "rotated".to_string(),
+            ..session_cookie()
+        };
+        let after = vec![rotated.clone()];
+        // Meanwhile another request stored a new cookie
+        let csrf = Cookie {
+            name: "csrf".to_string(),
+            ..session_cookie()
+        };
+        let mut jar = vec![session_cookie(), theme, csrf.clone()];
+
+        merge_cookie_changes(&mut jar, &before, &after);
+
+        assert_eq!(jar, vec![rotated, csrf]);
+    }
+
+    #[test]
+    fn test_merge_cookie_changes_does_not_restore_untouched_cookies() {
+        let before = vec![session_cookie()];
+        let after = before.clone();
+        // The cookie was deleted while the transfer ran
+        let mut jar = Vec::new();
+
+        merge_cookie_changes(&mut jar, &before, &after);
+
+        assert!(jar.is_empty());
+    }
+}
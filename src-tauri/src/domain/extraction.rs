@@ -0,0 +1,361 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Response value extraction for request chaining.
+//!
+//! A request's extraction rules capture values from its response — a
+//! `JSONPath` match in the body, a header, a regex match or the status — into
+//! variables that later requests reference as `{{name}}`. Values land either
+//! in the collection's runtime variables, which live for the session, or in
+//! its active environment, which is saved.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json_path::JsonPath;
+#[cfg(test)]
+use ts_rs::TS;
+
+use crate::domain::assertion::AssertionResult;
+use crate::domain::http::{HttpResponse, RequestParams};
+
+/// Where an extraction rule reads its value from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum ExtractionSource {
+    /// First match of a `JSONPath` query (e.g. `$.access_token`) in the JSON
+    /// body. Strings are taken as is, other values as compact JSON.
+    JsonPath {
+        /// The `JSONPath` query.
+        path: String,
+    },
+    /// Value of a response header, matched case-insensitively.
+    Header {
+        /// Header name.
+        name: String,
+    },
+    /// First match of a regular expression in the body text.
+    Regex {
+        /// The regular expression.
+        pattern: String,
+        /// Capture group to take; defaults to the first group, or the whole
+        /// match when the pattern has none.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(test, ts(optional))]
+        group: Option<usize>,
+    },
+    /// The response status code.
+    Status,
+}
+
+/// Where an extracted value is stored.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum VariableScope {
+    /// The collection's runtime variables: kept in memory for the session,
+    /// and taking precedence over environment and collection variables.
+    #[default]
+    Runtime,
+    /// The collection's active environment, saved with the collection.
+    Environment,
+}
+
+impl VariableScope {
+    /// Whether this is the default, runtime scope.
+    #[must_use]
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature required by `skip_serializing_if`
+    pub const fn is_runtime(&self) -> bool {
+        matches!(self, Self::Runtime)
+    }
+}
+
+/// Captures one value of a response into a variable.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct ExtractionRule {
+    /// Name of the variable to set.
+    pub variable: String,
+    /// Where the value comes from.
+    #[serde(flatten)]
+    #[cfg_attr(test, ts(flatten))]
+    pub source: ExtractionSource,
+    /// Where the value is stored (default: runtime).
+    #[serde(default, skip_serializing_if = "VariableScope::is_runtime")]
+    #[cfg_attr(test, ts(as = "Option<VariableScope>", optional))]
+    pub scope: VariableScope,
+}
+
+impl ExtractionRule {
+    /// Check that the rule names a variable and that its query or pattern
+    /// compiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.variable.trim().is_empty() {
+            return Err("Extraction rule has no variable name".to_string());
+        }
+        match &self.source {
+            ExtractionSource::JsonPath { path } => compile_json_path(path).map(drop),
+            ExtractionSource::Regex { pattern, .. } => compile_regex(pattern).map(drop),
+            ExtractionSource::Header { .. } | ExtractionSource::Status => Ok(()),
+        }
+    }
+
+    /// Extract the rule's value from `response`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query or pattern is invalid, or the response
+    /// holds nothing it matches.
+    pub fn extract(&self, response: &HttpResponse) -> Result<String, String> {
+        match &self.source {
+            ExtractionSource::JsonPath { path } => {
+                let query = compile_json_path(path)?;
+                let json: serde_json::Value = serde_json::from_str(&response.body)
+                    .map_err(|e| format!("Response body is not JSON: {e}"))?;
+                match query.query(&json).first() {
+                    Some(serde_json::Value::String(text)) => Ok(text.clone()),
+                    Some(value) => Ok(value.to_string()),
+                    None => Err(format!("JSONPath {path} matched nothing")),
+                }
+            }
+            ExtractionSource::Header { name } => response
+                .headers
+                .get(name)
+                .map(ToString::to_string)
+                .ok_or_else(|| format!("Response has no {name} header")),
+            ExtractionSource::Regex { pattern, group } => {
+                let regex = compile_regex(pattern)?;
+                let captures = regex
+                    .captures(&response.body)
+                    .ok_or_else(|| format!("Pattern {pattern} matched nothing"))?;
+                let group = group.unwrap_or_else(|| usize::from(captures.len() > 1));
+                captures
+                    .get(group)
+                    .map(|found| found.as_str().to_string())
+                    .ok_or_else(|| format!("Pattern {pattern} captured nothing in group {group}"))
+            }
+            ExtractionSource::Status => Ok(response.status.to_string()),
+        }
+    }
+}
+
+/// Parse a `JSONPath` query.
+///
+/// # Errors
+///
+/// Returns an error naming the query if it does not parse.
+pub fn compile_json_path(path: &str) -> Result<JsonPath, String> {
+    JsonPath::parse(path).map_err(|e| format!("Invalid JSONPath {path}: {e}"))
+}
+
+/// Compile a regular expression.
+///
+/// # Errors
+///
+/// Returns an error naming the pattern if it does not compile.
+pub fn compile_regex(pattern: &str) -> Result<Regex, String> {
+    Regex::new(pattern).map_err(|e| format!("Invalid pattern {pattern}: {e}"))
+}
+
+/// Outcome of one extraction rule after a request ran.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct ExtractedVariable {
+    /// Name of the variable.
+    pub variable: String,
+    /// Where the value was stored.
+    pub scope: VariableScope,
+    /// Environment the value was stored in, for the environment scope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub environment: Option<String>,
+    /// The value stored; `None` when extraction failed and the variable was
+    /// left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub value: Option<String>,
+    /// Why extraction failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub error: Option<String>,
+}
+
+/// Payload of the `variable:changed` event, sent for every variable an
+/// extraction rule set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct VariableChanged {
+    /// ID of the collection the variable belongs to.
+    pub collection_id: String,
+    /// ID of the request whose response the value came from.
+    pub request_id: String,
+    /// Name of the variable.
+    pub variable: String,
+    /// Where the value was stored.
+    pub scope: VariableScope,
+    /// Environment the value was stored in, for the environment scope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub environment: Option<String>,
+    /// The new value.
+    pub value: String,
+}
+
+/// Events for the variables in `extracted` that were set.
+#[must_use]
+pub fn variable_changes(
+    collection_id: &str,
+    request_id: &str,
+    extracted: &[ExtractedVariable],
+) -> Vec<VariableChanged> {
+    extracted
+        .iter()
+        .filter_map(|entry| {
+            Some(VariableChanged {
+                collection_id: collection_id.to_string(),
+                request_id: request_id.to_string(),
+                variable: entry.variable.clone(),
+                scope: entry.scope,
+                environment: entry.environment.clone(),
+                value: entry.value.clone()?,
+            })
+        })
+        .collect()
+}
+
+/// A collection request as sent and its response, together with the
+/// variables its extraction rules captured and the outcome of its assertions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct CollectionRequestExecution {
+    /// The request as sent, with variables, query params and auth applied.
+    pub request: RequestParams,
+    /// The response.
+    pub response: HttpResponse,
+    /// Outcome of each extraction rule, in rule order.
+    pub extracted: Vec<ExtractedVariable>,
+    /// Outcome of each assertion, in assertion order.
+    pub assertions: Vec<AssertionResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(body: &str) -> HttpResponse {
+        HttpResponse {
+            status: 201,
+            status_text: "Created".to_string(),
+            headers: [("Location", "/users/42"), ("X-Request-Id", "abc")].into(),
+            body: body.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn rule(source: ExtractionSource) -> ExtractionRule {
+        ExtractionRule {
+            variable: "value".to_string(),
+            source,
+            scope: VariableScope::Runtime,
+        }
+    }
+
+    #[test]
+    fn test_extracts_from_each_source() {
+        let response = response(r#"{"access_token":"t0k","user":{"id":42},"roles":["admin"]}"#);
+        let json_path = |path: &str| {
+            rule(ExtractionSource::JsonPath {
+                path: path.to_string(),
+            })
+            .extract(&response)
+        };
+        assert_eq!(json_path("$.access_token").unwrap(), "t0k");
+        assert_eq!(json_path("$.user.id").unwrap(), "42");
+        assert_eq!(json_path("$.roles").unwrap(), r#"["admin"]"#);
+        assert_eq!(
+            json_path("$.refresh_token").unwrap_err(),
+            "JSONPath $.refresh_token matched nothing"
+        );
+
+        let header = rule(ExtractionSource::Header {
+            name: "location".to_string(),
+        });
+        assert_eq!(header.extract(&response).unwrap(), "/users/42");
+        assert_eq!(
+            rule(ExtractionSource::Status).extract(&response).unwrap(),
+            "201"
+        );
+
+        let regex = |pattern: &str, group| {
+            rule(ExtractionSource::Regex {
+                pattern: pattern.to_string(),
+                group,
+            })
+            .extract(&response)
+        };
+        assert_eq!(regex(r#""id":(\d+)"#, None).unwrap(), "42");
+        assert_eq!(regex(r"t\dk", None).unwrap(), "t0k");
+        assert_eq!(regex(r#""id":(\d+)"#, Some(0)).unwrap(), r#""id":42"#);
+        assert!(regex(r"(\d+)", Some(2)).unwrap_err().contains("group 2"));
+    }
+
+    #[test]
+    fn test_rules_round_trip_and_validate() {
+        let yaml = "variable: token\nfrom: json_path\npath: $.access_token\nscope: environment\n";
+        let rule: ExtractionRule = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(
+            rule.source,
+            ExtractionSource::JsonPath {
+                path: "$.access_token".to_string()
+            }
+        );
+        assert_eq!(rule.scope, VariableScope::Environment);
+        assert_eq!(serde_yaml_ng::to_string(&rule).unwrap(), yaml);
+
+        let status: ExtractionRule =
+            serde_json::from_str(r#"{"variable":"code","from":"status"}"#).unwrap();
+        assert_eq!(status.scope, VariableScope::Runtime);
+        assert!(status.validate().is_ok());
+
+        let bad_path = ExtractionRule {
+            source: ExtractionSource::JsonPath {
+                path: "access_token".to_string(),
+            },
+            ..status.clone()
+        };
+        assert!(
+            bad_path
+                .validate()
+                .unwrap_err()
+                .starts_with("Invalid JSONPath")
+        );
+        let bad_pattern = ExtractionRule {
+            source: ExtractionSource::Regex {
+                pattern: "(".to_string(),
+                group: None,
+            },
+            ..status.clone()
+        };
+        assert!(
+            bad_pattern
+                .validate()
+                .unwrap_err()
+                .starts_with("Invalid pattern")
+        );
+        let unnamed = ExtractionRule {
+            variable: " ".to_string(),
+            ..status
+        };
+        assert!(unnamed.validate().is_err());
+    }
+}
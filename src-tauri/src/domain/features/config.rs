@@ -37,20 +37,28 @@ pub struct FeatureFlagsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct HttpFlagsConfig {
+    /// Enable collections navigation in the sidebar.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collections_enabled: Option<bool>,
+    /// Allow saving and persisting collection changes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collections_saving: Option<bool>,
+    /// Import Bruno collections into runi.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub import_bruno: Option<bool>,
+    /// Import Postman collections and environments.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub import_postman: Option<bool>,
+    /// Import `OpenAPI` specs into the HTTP client.
     #[serde(rename = "importOpenAPI", skip_serializing_if = "Option::is_none")]
     pub import_open_api: Option<bool>,
+    /// Export requests as curl commands.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub export_curl: Option<bool>,
+    /// Export requests as Python code snippets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub export_python: Option<bool>,
+    /// Export requests as JavaScript code snippets.
     #[serde(rename = "exportJavaScript", skip_serializing_if = "Option::is_none")]
     pub export_javascript: Option<bool>,
 }
@@ -59,14 +67,19 @@ pub struct HttpFlagsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CanvasFlagsConfig {
+    /// Enable the blueprint canvas view.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /// Show a minimap in the blueprint canvas.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub minimap: Option<bool>,
+    /// Render connection lines between nodes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connection_lines: Option<bool>,
+    /// Snap canvas nodes to the grid.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snap_to_grid: Option<bool>,
+    /// Show the canvas command bar.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub command_bar: Option<bool>,
 }
@@ -75,14 +88,19 @@ pub struct CanvasFlagsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ComprehensionFlagsConfig {
+    /// Detect drift between requests and specs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub drift_detection: Option<bool>,
+    /// Verify AI-generated requests against specs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ai_verification: Option<bool>,
+    /// Suggest semantic links across specs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_links: Option<bool>,
+    /// Track API changes over time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temporal_awareness: Option<bool>,
+    /// Bind requests to `OpenAPI` specs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub spec_binding: Option<bool>,
 }
@@ -91,14 +109,19 @@ pub struct ComprehensionFlagsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AiFlagsConfig {
+    /// Enable local Ollama models for suggestions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ollama_integration: Option<bool>,
+    /// Control runi with natural language commands.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub natural_language_commands: Option<bool>,
+    /// Generate MCP actions from AI prompts.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mcp_generation: Option<bool>,
+    /// Run agentic test flows across APIs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agentic_testing: Option<bool>,
+    /// Surface AI-suggested API integrations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ai_suggested_integrations: Option<bool>,
 }
@@ -107,12 +130,16 @@ pub struct AiFlagsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DebugFlagsConfig {
+    /// Enable verbose logging for troubleshooting.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verbose_logging: Option<bool>,
+    /// Show performance overlay metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub performance_overlay: Option<bool>,
+    /// Return mock responses for debugging.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mock_responses: Option<bool>,
+    /// Force-enable all experimental features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub force_all_experimental: Option<bool>,
 }
@@ -175,6 +202,7 @@ async fn read_config_if_exists(path: &Path) -> Result<Option<FeatureFlagsConfig>
 }
 
 /// Merge feature flag configurations with overlay precedence.
+#[must_use]
 pub fn merge_configs(base: FeatureFlagsConfig, overlay: FeatureFlagsConfig) -> FeatureFlagsConfig {
     FeatureFlagsConfig {
         schema: overlay.schema.or(base.schema),
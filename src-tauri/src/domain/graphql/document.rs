@@ -0,0 +1,802 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Parser for GraphQL executable documents (operations and fragments).
+//!
+//! Only what validation needs is kept: operations with their variables,
+//! fragments, and selection sets with field names, argument names and the
+//! variables each argument refers to. Literal values are checked for syntax
+//! and then dropped.
+
+use std::fmt;
+
+/// Line and column of a token, both starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    /// Line number.
+    pub line: usize,
+    /// Column number, counted in characters.
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A syntax error with its location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// What went wrong.
+    pub message: String,
+    /// Where it went wrong.
+    pub position: Position,
+}
+
+/// Kind of a GraphQL operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    /// A read.
+    Query,
+    /// A write.
+    Mutation,
+    /// A stream of results.
+    Subscription,
+}
+
+impl OperationKind {
+    /// Keyword of the operation kind.
+    #[must_use]
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Query => "query",
+            Self::Mutation => "mutation",
+            Self::Subscription => "subscription",
+        }
+    }
+}
+
+/// A parsed executable document.
+#[derive(Debug, Clone, Default)]
+pub struct Document {
+    /// Operations, in document order.
+    pub operations: Vec<Operation>,
+    /// Fragment definitions, in document order.
+    pub fragments: Vec<Fragment>,
+}
+
+/// An operation definition.
+#[derive(Debug, Clone)]
+pub struct Operation {
+    /// Operation kind; `{ ... }` shorthand is a query.
+    pub kind: OperationKind,
+    /// Operation name, absent for anonymous operations.
+    pub name: Option<String>,
+    /// Declared variables.
+    pub variables: Vec<VariableDefinition>,
+    /// Variables referenced by the operation's own directives.
+    pub directive_variables: Vec<VariableUsage>,
+    /// Top-level selections.
+    pub selections: Vec<Selection>,
+    /// Location of the definition.
+    pub position: Position,
+}
+
+/// A variable declared by an operation.
+#[derive(Debug, Clone)]
+pub struct VariableDefinition {
+    /// Name without the `$`.
+    pub name: String,
+    /// Type as written, e.g. `[ID!]!`.
+    pub type_ref: String,
+    /// Whether a default value is given.
+    pub has_default: bool,
+    /// Location of the definition.
+    pub position: Position,
+}
+
+/// A reference to a variable inside a value.
+#[derive(Debug, Clone)]
+pub struct VariableUsage {
+    /// Name without the `$`.
+    pub name: String,
+    /// Location of the reference.
+    pub position: Position,
+}
+
+/// A fragment definition.
+#[derive(Debug, Clone)]
+pub struct Fragment {
+    /// Fragment name.
+    pub name: String,
+    /// Type the fragment applies to.
+    pub type_condition: String,
+    /// Variables referenced by the fragment's own directives.
+    pub directive_variables: Vec<VariableUsage>,
+    /// Selections of the fragment.
+    pub selections: Vec<Selection>,
+    /// Location of the definition.
+    pub position: Position,
+}
+
+/// One entry of a selection set.
+#[derive(Debug, Clone)]
+pub enum Selection {
+    /// A field, possibly with a nested selection set.
+    Field(Field),
+    /// `...Name`.
+    FragmentSpread {
+        /// Name of the spread fragment.
+        name: String,
+        /// Variables referenced by directives on the spread.
+        variables: Vec<VariableUsage>,
+        /// Location of the spread.
+        position: Position,
+    },
+    /// `... on Type { ... }` or `... { ... }`.
+    InlineFragment {
+        /// Type condition, if any.
+        type_condition: Option<String>,
+        /// Variables referenced by directives on the fragment.
+        variables: Vec<VariableUsage>,
+        /// Selections of the fragment.
+        selections: Vec<Self>,
+        /// Location of the fragment.
+        position: Position,
+    },
+}
+
+/// A selected field.
+#[derive(Debug, Clone)]
+pub struct Field {
+    /// Field name (not the alias).
+    pub name: String,
+    /// Arguments passed to the field.
+    pub arguments: Vec<Argument>,
+    /// Variables referenced by directives on the field.
+    pub directive_variables: Vec<VariableUsage>,
+    /// Nested selections; empty for leaf fields.
+    pub selections: Vec<Selection>,
+    /// Whether a selection set was written, even an empty one.
+    pub has_selection_set: bool,
+    /// Location of the field name.
+    pub position: Position,
+}
+
+/// An argument passed to a field.
+#[derive(Debug, Clone)]
+pub struct Argument {
+    /// Argument name.
+    pub name: String,
+    /// Variables referenced in the argument value.
+    pub variables: Vec<VariableUsage>,
+    /// Location of the argument name.
+    pub position: Position,
+}
+
+impl Document {
+    /// Parse an executable document.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first syntax error, or an error if the document defines
+    /// neither an operation nor a fragment.
+    pub fn parse(source: &str) -> Result<Self, SyntaxError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            index: 0,
+            end: end_position(source),
+        };
+        let mut document = Self::default();
+        while parser.peek().is_some() {
+            if parser.peek_name("fragment") {
+                document.fragments.push(parser.fragment()?);
+            } else {
+                document.operations.push(parser.operation()?);
+            }
+        }
+        if document.operations.is_empty() && document.fragments.is_empty() {
+            return Err(SyntaxError {
+                message: "Document contains no operation".to_string(),
+                position: parser.end,
+            });
+        }
+        Ok(document)
+    }
+}
+
+/// A lexical token.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Punct(&'static str),
+    Name(String),
+    Int,
+    Float,
+    String,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Punct(p) => write!(f, "\"{p}\""),
+            Self::Name(name) => write!(f, "\"{name}\""),
+            Self::Int => write!(f, "an integer"),
+            Self::Float => write!(f, "a float"),
+            Self::String => write!(f, "a string"),
+        }
+    }
+}
+
+fn end_position(source: &str) -> Position {
+    let line = source.matches('\n').count() + 1;
+    let column = source
+        .rsplit('\n')
+        .next()
+        .map_or(0, |last| last.chars().count())
+        + 1;
+    Position { line, column }
+}
+
+/// Split `source` into tokens, skipping whitespace, commas and comments.
+#[allow(clippy::too_many_lines)] // One branch per token kind
+fn tokenize(source: &str) -> Result<Vec<(Token, Position)>, SyntaxError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line, mut line_start) = (0, 1, 0);
+    while i < chars.len() {
+        let position = Position {
+            line,
+            column: i - line_start + 1,
+        };
+        let error = |message: String| SyntaxError { message, position };
+        let c = chars[i];
+        match c {
+            '\n' => {
+                i += 1;
+                line += 1;
+                line_start = i;
+            }
+            ' ' | '\t' | '\r' | ',' | '\u{feff}' => i += 1,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '.' => {
+                if chars.get(i + 1) != Some(&'.') || chars.get(i + 2) != Some(&'.') {
+                    return Err(error("Expected \"...\"".to_string()));
+                }
+                tokens.push((Token::Punct("..."), position));
+                i += 3;
+            }
+            '!' | '$' | '&' | '(' | ')' | ':' | '=' | '@' | '[' | ']' | '{' | '|' | '}' => {
+                let punct = match c {
+                    '!' => "!",
+                    '$' => "$",
+                    '&' => "&",
+                    '(' => "(",
+                    ')' => ")",
+                    ':' => ":",
+                    '=' => "=",
+                    '@' => "@",
+                    '[' => "[",
+                    ']' => "]",
+                    '{' => "{",
+                    '|' => "|",
+                    _ => "}",
+                };
+                tokens.push((Token::Punct(punct), position));
+                i += 1;
+            }
+            '"' => {
+                let block = chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"');
+                i += if block { 3 } else { 1 };
+                loop {
+                    match chars.get(i) {
+                        None => return Err(error("Unterminated string".to_string())),
+                        Some('\n') if !block => {
+                            return Err(error("Unterminated string".to_string()));
+                        }
+                        Some('\n') => {
+                            i += 1;
+                            line += 1;
+                            line_start = i;
+                        }
+                        Some('\\') if block => {
+                            let escaped_quotes = chars.get(i + 1..i + 4) == Some(&['"', '"', '"']);
+                            i += if escaped_quotes { 4 } else { 1 };
+                        }
+                        Some('\\') => i += 2,
+                        Some('"') if !block => {
+                            i += 1;
+                            break;
+                        }
+                        Some('"')
+                            if chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') =>
+                        {
+                            i += 3;
+                            break;
+                        }
+                        Some(_) => i += 1,
+                    }
+                }
+                tokens.push((Token::String, position));
+            }
+            '-' | '0'..='9' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let mut float = false;
+                if chars.get(i) == Some(&'.') {
+                    float = true;
+                    i += 1;
+                    let digits = i;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    if i == digits {
+                        return Err(error("Invalid number".to_string()));
+                    }
+                }
+                if matches!(chars.get(i), Some('e' | 'E')) {
+                    float = true;
+                    i += 1;
+                    if matches!(chars.get(i), Some('+' | '-')) {
+                        i += 1;
+                    }
+                    let digits = i;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    if i == digits {
+                        return Err(error("Invalid number".to_string()));
+                    }
+                }
+                if i - start == 1 && c == '-' {
+                    return Err(error("Invalid number".to_string()));
+                }
+                tokens.push((if float { Token::Float } else { Token::Int }, position));
+            }
+            c if c == '_' || c.is_ascii_alphabetic() => {
+                let start = i;
+                while i < chars.len() && (chars[i] == '_' || chars[i].is_ascii_alphanumeric()) {
+                    i += 1;
+                }
+                tokens.push((Token::Name(chars[start..i].iter().collect()), position));
+            }
+            other => return Err(error(format!("Unexpected character \"{other}\""))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser over the token list.
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    index: usize,
+    /// Position just past the last character, for "unexpected end" errors.
+    end: Position,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn position(&self) -> Position {
+        self.tokens
+            .get(self.index)
+            .map_or(self.end, |(_, position)| *position)
+    }
+
+    fn peek_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token::Punct(p)) if *p == punct)
+    }
+
+    fn peek_name(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(Token::Name(n)) if n == name)
+    }
+
+    fn unexpected(&self, expected: &str) -> SyntaxError {
+        let found = self
+            .peek()
+            .map_or_else(|| "end of document".to_string(), ToString::to_string);
+        SyntaxError {
+            message: format!("Expected {expected}, found {found}"),
+            position: self.position(),
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<(), SyntaxError> {
+        if self.peek_punct(punct) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("\"{punct}\"")))
+        }
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let found = self.peek_punct(punct);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn name(&mut self) -> Result<(String, Position), SyntaxError> {
+        let position = self.position();
+        match self.peek() {
+            Some(Token::Name(name)) => {
+                let name = name.clone();
+                self.index += 1;
+                Ok((name, position))
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    fn operation(&mut self) -> Result<Operation, SyntaxError> {
+        let position = self.position();
+        if self.peek_punct("{") {
+            return Ok(Operation {
+                kind: OperationKind::Query,
+                name: None,
+                variables: Vec::new(),
+                directive_variables: Vec::new(),
+                selections: self.selection_set()?,
+                position,
+            });
+        }
+        let kind = match self.peek() {
+            Some(Token::Name(name)) if name == "query" => OperationKind::Query,
+            Some(Token::Name(name)) if name == "mutation" => OperationKind::Mutation,
+            Some(Token::Name(name)) if name == "subscription" => OperationKind::Subscription,
+            _ => return Err(self.unexpected("an operation or fragment")),
+        };
+        self.index += 1;
+        let name = if matches!(self.peek(), Some(Token::Name(_))) {
+            Some(self.name()?.0)
+        } else {
+            None
+        };
+        let variables = if self.peek_punct("(") {
+            self.variable_definitions()?
+        } else {
+            Vec::new()
+        };
+        let directive_variables = self.directives()?;
+        Ok(Operation {
+            kind,
+            name,
+            variables,
+            directive_variables,
+            selections: self.selection_set()?,
+            position,
+        })
+    }
+
+    fn fragment(&mut self) -> Result<Fragment, SyntaxError> {
+        let position = self.position();
+        self.index += 1;
+        let (name, name_position) = self.name()?;
+        if name == "on" {
+            return Err(SyntaxError {
+                message: "Fragments cannot be named \"on\"".to_string(),
+                position: name_position,
+            });
+        }
+        if !self.peek_name("on") {
+            return Err(self.unexpected("\"on\""));
+        }
+        self.index += 1;
+        let (type_condition, _) = self.name()?;
+        let directive_variables = self.directives()?;
+        Ok(Fragment {
+            name,
+            type_condition,
+            directive_variables,
+            selections: self.selection_set()?,
+            position,
+        })
+    }
+
+    fn variable_definitions(&mut self) -> Result<Vec<VariableDefinition>, SyntaxError> {
+        self.expect_punct("(")?;
+        let mut definitions = Vec::new();
+        while !self.eat_punct(")") {
+            let position = self.position();
+            self.expect_punct("$")?;
+            let (name, _) = self.name()?;
+            self.expect_punct(":")?;
+            let type_ref = self.type_ref()?;
+            let has_default = self.eat_punct("=");
+            if has_default {
+                let mut ignored = Vec::new();
+                self.value(true, &mut ignored)?;
+            }
+            self.directives()?;
+            definitions.push(VariableDefinition {
+                name,
+                type_ref,
+                has_default,
+                position,
+            });
+        }
+        if definitions.is_empty() {
+            return Err(SyntaxError {
+                message: "Expected at least one variable definition".to_string(),
+                position: self.position(),
+            });
+        }
+        Ok(definitions)
+    }
+
+    fn type_ref(&mut self) -> Result<String, SyntaxError> {
+        let mut rendered = if self.eat_punct("[") {
+            let inner = self.type_ref()?;
+            self.expect_punct("]")?;
+            format!("[{inner}]")
+        } else {
+            self.name()?.0
+        };
+        if self.eat_punct("!") {
+            rendered.push('!');
+        }
+        Ok(rendered)
+    }
+
+    /// Parse directives, returning the variables their arguments reference.
+    fn directives(&mut self) -> Result<Vec<VariableUsage>, SyntaxError> {
+        let mut variables = Vec::new();
+        while self.eat_punct("@") {
+            self.name()?;
+            if self.peek_punct("(") {
+                for argument in self.arguments()? {
+                    variables.extend(argument.variables);
+                }
+            }
+        }
+        Ok(variables)
+    }
+
+    fn arguments(&mut self) -> Result<Vec<Argument>, SyntaxError> {
+        self.expect_punct("(")?;
+        let mut arguments = Vec::new();
+        while !self.eat_punct(")") {
+            let (name, position) = self.name()?;
+            self.expect_punct(":")?;
+            let mut variables = Vec::new();
+            self.value(false, &mut variables)?;
+            arguments.push(Argument {
+                name,
+                variables,
+                position,
+            });
+        }
+        if arguments.is_empty() {
+            return Err(SyntaxError {
+                message: "Expected at least one argument".to_string(),
+                position: self.position(),
+            });
+        }
+        Ok(arguments)
+    }
+
+    /// Parse a value, collecting variable references into `variables`.
+    /// Constant values (defaults) may not reference variables.
+    fn value(
+        &mut self,
+        constant: bool,
+        variables: &mut Vec<VariableUsage>,
+    ) -> Result<(), SyntaxError> {
+        let position = self.position();
+        match self.peek() {
+            Some(Token::Punct("$")) if !constant => {
+                self.index += 1;
+                let (name, _) = self.name()?;
+                variables.push(VariableUsage { name, position });
+            }
+            Some(Token::Punct("[")) => {
+                self.index += 1;
+                while !self.eat_punct("]") {
+                    self.value(constant, variables)?;
+                }
+            }
+            Some(Token::Punct("{")) => {
+                self.index += 1;
+                while !self.eat_punct("}") {
+                    self.name()?;
+                    self.expect_punct(":")?;
+                    self.value(constant, variables)?;
+                }
+            }
+            Some(Token::Name(_) | Token::Int | Token::Float | Token::String) => self.index += 1,
+            _ => return Err(self.unexpected("a value")),
+        }
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Selection>, SyntaxError> {
+        self.expect_punct("{")?;
+        let mut selections = Vec::new();
+        while !self.eat_punct("}") {
+            selections.push(self.selection()?);
+        }
+        if selections.is_empty() {
+            return Err(SyntaxError {
+                message: "Selection sets cannot be empty".to_string(),
+                position: self.position(),
+            });
+        }
+        Ok(selections)
+    }
+
+    fn selection(&mut self) -> Result<Selection, SyntaxError> {
+        let position = self.position();
+        if self.eat_punct("...") {
+            if matches!(self.peek(), Some(Token::Name(name)) if name != "on") {
+                let (name, _) = self.name()?;
+                let variables = self.directives()?;
+                return Ok(Selection::FragmentSpread {
+                    name,
+                    variables,
+                    position,
+                });
+            }
+            let type_condition = if self.peek_name("on") {
+                self.index += 1;
+                Some(self.name()?.0)
+            } else {
+                None
+            };
+            let variables = self.directives()?;
+            return Ok(Selection::InlineFragment {
+                type_condition,
+                variables,
+                selections: self.selection_set()?,
+                position,
+            });
+        }
+
+        let (mut name, mut position) = self.name()?;
+        if self.eat_punct(":") {
+            (name, position) = self.name()?;
+        }
+        let arguments = if self.peek_punct("(") {
+            self.arguments()?
+        } else {
+            Vec::new()
+        };
+        let directive_variables = self.directives()?;
+        let has_selection_set = self.peek_punct("{");
+        let selections = if has_selection_set {
+            self.selection_set()?
+        } else {
+            Vec::new()
+        };
+        Ok(Selection::Field(Field {
+            name,
+            arguments,
+            directive_variables,
+            selections,
+            has_selection_set,
+            position,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_operations_fragments_and_variables() {
+        let document = Document::parse(
+            r#"
+            # Fetch a user
+            query GetUser($id: ID!, $first: Int = 10) {
+              user(id: $id) {
+                name
+                avatar: picture(size: 64)
+                friends(first: $first, filter: { tags: ["a", $tag] }) @include(if: $withFriends) {
+                  ...UserFields
+                }
+                ... on Admin { level }
+              }
+            }
+
+            fragment UserFields on User { id, name }
+            mutation { like(id: "1") { count } }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(document.operations.len(), 2);
+        assert_eq!(document.fragments.len(), 1);
+        let query = &document.operations[0];
+        assert_eq!(query.kind, OperationKind::Query);
+        assert_eq!(query.name.as_deref(), Some("GetUser"));
+        let vars: Vec<_> = query
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.type_ref.as_str(), v.has_default))
+            .collect();
+        assert_eq!(vars, [("id", "ID!", false), ("first", "Int", true)]);
+        assert_eq!(
+            query.position,
+            Position {
+                line: 3,
+                column: 13
+            }
+        );
+
+        let Selection::Field(user) = &query.selections[0] else {
+            panic!("expected a field");
+        };
+        assert_eq!(user.arguments[0].variables[0].name, "id");
+        let Selection::Field(picture) = &user.selections[1] else {
+            panic!("expected a field");
+        };
+        assert_eq!(picture.name, "picture");
+        let Selection::Field(friends) = &user.selections[2] else {
+            panic!("expected a field");
+        };
+        let referenced: Vec<_> = friends.arguments[1]
+            .variables
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(referenced, ["tag"]);
+        assert_eq!(friends.directive_variables[0].name, "withFriends");
+        assert!(matches!(
+            &friends.selections[0],
+            Selection::FragmentSpread { name, .. } if name == "UserFields"
+        ));
+        assert!(matches!(
+            &user.selections[3],
+            Selection::InlineFragment { type_condition: Some(t), .. } if t == "Admin"
+        ));
+        assert_eq!(document.operations[1].kind, OperationKind::Mutation);
+    }
+
+    #[test]
+    fn test_block_strings_and_numbers() {
+        let document = Document::parse(
+            "{ search(text: \"\"\"multi\n\\\"\"\" line\"\"\", min: -1.5e3, max: 20) { id } }",
+        )
+        .unwrap();
+        assert_eq!(document.operations.len(), 1);
+    }
+
+    #[test]
+    fn test_syntax_errors_have_positions() {
+        let error = Document::parse("query {\n  user(id: ) { id }\n}").unwrap_err();
+        assert_eq!(
+            error.position,
+            Position {
+                line: 2,
+                column: 12
+            }
+        );
+        assert!(
+            error.message.contains("Expected a value"),
+            "{}",
+            error.message
+        );
+
+        let error = Document::parse("{ user { id }").unwrap_err();
+        assert!(
+            error.message.contains("end of document"),
+            "{}",
+            error.message
+        );
+        assert!(Document::parse("  # nothing\n").is_err());
+        assert!(Document::parse("{ user { } }").is_err());
+        assert!(Document::parse("{ name(s: \"open) }").is_err());
+    }
+}
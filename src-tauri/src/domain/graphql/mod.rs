@@ -0,0 +1,154 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! GraphQL requests, schemas from introspection and query validation.
+
+/// Parser for GraphQL executable documents.
+pub mod document;
+/// Schema model built from an introspection result.
+pub mod schema;
+/// Validation of GraphQL requests against a schema.
+pub mod validate;
+
+pub use document::OperationKind;
+pub use schema::{GraphqlOperationInfo, GraphqlSchema, GraphqlSchemaCache, INTROSPECTION_QUERY};
+pub use validate::{GraphqlIssue, validate_query};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+#[cfg(test)]
+use ts_rs::TS;
+
+/// A GraphQL request: the document, its variables and the operation to run.
+///
+/// Stored on [`crate::domain::collection::RequestBody::graphql`] and
+/// serialized to the wire by [`Self::to_json_body`] (POST) or
+/// [`Self::apply_to_url`] (GET).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlQuery {
+    /// The GraphQL document.
+    pub query: String,
+    /// Variable values, as a JSON object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional, type = "Record<string, unknown>"))]
+    pub variables: Option<Value>,
+    /// Operation to run when the document defines several.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub operation_name: Option<String>,
+}
+
+impl GraphqlQuery {
+    /// Build a query from a legacy body: either the standard JSON envelope
+    /// (`{"query", "variables", "operationName"}`) or a bare document.
+    #[must_use]
+    pub fn from_body_text(text: &str) -> Self {
+        if let Ok(Value::Object(envelope)) = serde_json::from_str::<Value>(text)
+            && let Some(Value::String(query)) = envelope.get("query")
+        {
+            return Self {
+                query: query.clone(),
+                variables: envelope.get("variables").filter(|v| !v.is_null()).cloned(),
+                operation_name: envelope
+                    .get("operationName")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string),
+            };
+        }
+        Self {
+            query: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// The JSON body of a POST request, per the GraphQL-over-HTTP convention.
+    #[must_use]
+    pub fn to_json_body(&self) -> String {
+        let mut envelope = Map::new();
+        envelope.insert("query".to_string(), Value::String(self.query.clone()));
+        if let Some(variables) = &self.variables {
+            envelope.insert("variables".to_string(), variables.clone());
+        }
+        if let Some(name) = &self.operation_name {
+            envelope.insert("operationName".to_string(), Value::String(name.clone()));
+        }
+        Value::Object(envelope).to_string()
+    }
+
+    /// Append `query`, `variables` (JSON-encoded) and `operationName` to the
+    /// query string of `url`, for GET requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `url` is not an absolute URL.
+    pub fn apply_to_url(&self, url: &str) -> Result<String, String> {
+        let mut parsed = url::Url::parse(url).map_err(|e| format!("Invalid URL '{url}': {e}"))?;
+        {
+            let mut pairs = parsed.query_pairs_mut();
+            pairs.append_pair("query", &self.query);
+            if let Some(variables) = &self.variables {
+                pairs.append_pair("variables", &variables.to_string());
+            }
+            if let Some(name) = &self.operation_name {
+                pairs.append_pair("operationName", name);
+            }
+        }
+        Ok(parsed.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serializes_for_post_and_get() {
+        let query = GraphqlQuery {
+            query: "query User($id: ID!) { user(id: $id) { name } }".to_string(),
+            variables: Some(json!({ "id": "1" })),
+            operation_name: Some("User".to_string()),
+        };
+        let body: Value = serde_json::from_str(&query.to_json_body()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "query": "query User($id: ID!) { user(id: $id) { name } }",
+                "variables": { "id": "1" },
+                "operationName": "User"
+            })
+        );
+
+        let url = query.apply_to_url("https://api.test/graphql?v=2").unwrap();
+        let parsed = url::Url::parse(&url).unwrap();
+        let pairs: Vec<(String, String)> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(pairs[0], ("v".to_string(), "2".to_string()));
+        assert_eq!(pairs[1].1, query.query);
+        assert_eq!(
+            pairs[2],
+            ("variables".to_string(), r#"{"id":"1"}"#.to_string())
+        );
+        assert_eq!(pairs[3], ("operationName".to_string(), "User".to_string()));
+
+        let bare = GraphqlQuery {
+            query: "{ me { id } }".to_string(),
+            ..GraphqlQuery::default()
+        };
+        assert_eq!(bare.to_json_body(), r#"{"query":"{ me { id } }"}"#);
+    }
+
+    #[test]
+    fn test_reads_legacy_body_text() {
+        let envelope = GraphqlQuery::from_body_text(
+            r#"{"query": "{ me { id } }", "variables": null, "operationName": "Me"}"#,
+        );
+        assert_eq!(envelope.query, "{ me { id } }");
+        assert_eq!(envelope.variables, None);
+        assert_eq!(envelope.operation_name.as_deref(), Some("Me"));
+
+        let bare = GraphqlQuery::from_body_text("{ me { id } }");
+        assert_eq!(bare.query, "{ me { id } }");
+    }
+}
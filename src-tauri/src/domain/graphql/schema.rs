@@ -0,0 +1,537 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! GraphQL schema model built from an introspection result.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(test)]
+use ts_rs::TS;
+
+use super::document::OperationKind;
+
+/// Introspection query sent to discover a schema.
+///
+/// Type references are unwrapped seven levels deep, enough for any
+/// realistic `[[T!]!]!` nesting.
+pub const INTROSPECTION_QUERY: &str = r"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: true) {
+        name
+        description
+        args { ...InputValue }
+        type { ...TypeRef }
+        isDeprecated
+      }
+      inputFields { ...InputValue }
+      interfaces { name }
+      enumValues(includeDeprecated: true) { name }
+      possibleTypes { name }
+    }
+  }
+}
+
+fragment InputValue on __InputValue {
+  name
+  description
+  type { ...TypeRef }
+  defaultValue
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+    ofType { kind name ofType { kind name ofType { kind name } } } } } } }
+}";
+
+/// Kind of a named GraphQL type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum GraphqlTypeKind {
+    /// A leaf value such as `String` or a custom scalar.
+    Scalar,
+    /// An object with fields.
+    Object,
+    /// An abstract type with fields, implemented by objects.
+    Interface,
+    /// An abstract type listing its member objects.
+    Union,
+    /// A leaf value from a fixed set.
+    Enum,
+    /// A structured argument value.
+    InputObject,
+}
+
+impl GraphqlTypeKind {
+    /// Whether values of this kind need a selection set.
+    #[must_use]
+    pub const fn is_composite(self) -> bool {
+        matches!(self, Self::Object | Self::Interface | Self::Union)
+    }
+}
+
+/// An argument or input object field.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlInputValue {
+    /// Name of the argument or field.
+    pub name: String,
+    /// Type as written in SDL, e.g. `[ID!]!`.
+    pub type_ref: String,
+    /// Default value, as GraphQL source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub default_value: Option<String>,
+    /// Description from the schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub description: Option<String>,
+}
+
+impl GraphqlInputValue {
+    /// Whether a value must be given: non-null and without a default.
+    #[must_use]
+    pub fn is_required(&self) -> bool {
+        self.type_ref.ends_with('!') && self.default_value.is_none()
+    }
+}
+
+/// A field of an object or interface.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlField {
+    /// Field name.
+    pub name: String,
+    /// Type as written in SDL, e.g. `[User!]!`.
+    pub type_ref: String,
+    /// Arguments the field accepts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<GraphqlInputValue>>", optional))]
+    pub args: Vec<GraphqlInputValue>,
+    /// Description from the schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub description: Option<String>,
+    /// Whether the field is deprecated.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub deprecated: bool,
+}
+
+/// A named type of the schema.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlType {
+    /// Kind of the type.
+    pub kind: GraphqlTypeKind,
+    /// Description from the schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub description: Option<String>,
+    /// Fields of an object or interface.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<GraphqlField>>", optional))]
+    pub fields: Vec<GraphqlField>,
+    /// Fields of an input object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<GraphqlInputValue>>", optional))]
+    pub input_fields: Vec<GraphqlInputValue>,
+    /// Objects implementing an interface or belonging to a union.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub possible_types: Vec<String>,
+    /// Interfaces an object or interface implements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub interfaces: Vec<String>,
+    /// Values of an enum.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub enum_values: Vec<String>,
+}
+
+impl GraphqlType {
+    /// Field `name` of an object or interface.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&GraphqlField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// A GraphQL schema: root operation types and every named type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlSchema {
+    /// Root type of queries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub query_type: Option<String>,
+    /// Root type of mutations, if the schema supports them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub mutation_type: Option<String>,
+    /// Root type of subscriptions, if the schema supports them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub subscription_type: Option<String>,
+    /// Named types by name, introspection types (`__*`) excluded.
+    pub types: BTreeMap<String, GraphqlType>,
+}
+
+/// A schema fetched by introspection, as cached for a collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlSchemaCache {
+    /// URL the schema was introspected from.
+    pub endpoint: String,
+    /// When the schema was fetched (ISO 8601).
+    pub fetched_at: String,
+    /// The schema.
+    pub schema: GraphqlSchema,
+}
+
+/// A root field that can be called as a query, mutation or subscription.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlOperationInfo {
+    /// `query`, `mutation` or `subscription`.
+    pub kind: String,
+    /// Name of the root field.
+    pub name: String,
+    /// Arguments of the root field.
+    pub args: Vec<GraphqlInputValue>,
+    /// Return type as written in SDL.
+    pub type_ref: String,
+    /// Description from the schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub description: Option<String>,
+    /// Whether the field is deprecated.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub deprecated: bool,
+}
+
+/// Name of the named type inside a type reference: `[User!]!` → `User`.
+#[must_use]
+pub fn named_type(type_ref: &str) -> &str {
+    type_ref.trim_matches(|c| c == '[' || c == ']' || c == '!')
+}
+
+impl GraphqlSchema {
+    /// Build a schema from an introspection response.
+    ///
+    /// Accepts the full response (`{"data": {"__schema": ...}}`), its `data`
+    /// member, or the `__schema` object itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response carries GraphQL errors instead of a
+    /// schema, or the schema is malformed.
+    pub fn from_introspection(response: &Value) -> Result<Self, String> {
+        if let Some(errors) = response.get("errors").and_then(Value::as_array)
+            && response.get("data").is_none_or(Value::is_null)
+        {
+            let messages: Vec<_> = errors
+                .iter()
+                .filter_map(|error| error.get("message").and_then(Value::as_str))
+                .collect();
+            return Err(format!("Introspection failed: {}", messages.join("; ")));
+        }
+        let schema = response
+            .pointer("/data/__schema")
+            .or_else(|| response.get("__schema"))
+            .unwrap_or(response);
+        let types = schema
+            .get("types")
+            .and_then(Value::as_array)
+            .ok_or("Introspection result has no types; is introspection disabled?")?;
+
+        let root = |key: &str| {
+            schema
+                .get(key)
+                .and_then(|root| root.get("name"))
+                .and_then(Value::as_str)
+                .map(ToString::to_string)
+        };
+        let mut parsed = Self {
+            query_type: root("queryType"),
+            mutation_type: root("mutationType"),
+            subscription_type: root("subscriptionType"),
+            types: BTreeMap::new(),
+        };
+        for ty in types {
+            let name = string(ty, "name").ok_or("Introspection type without a name")?;
+            if name.starts_with("__") {
+                continue;
+            }
+            let kind = match ty.get("kind").and_then(Value::as_str) {
+                Some("SCALAR") => GraphqlTypeKind::Scalar,
+                Some("OBJECT") => GraphqlTypeKind::Object,
+                Some("INTERFACE") => GraphqlTypeKind::Interface,
+                Some("UNION") => GraphqlTypeKind::Union,
+                Some("ENUM") => GraphqlTypeKind::Enum,
+                Some("INPUT_OBJECT") => GraphqlTypeKind::InputObject,
+                other => return Err(format!("Type {name} has unknown kind {other:?}")),
+            };
+            let fields = array(ty, "fields")
+                .map(|field| {
+                    Ok(GraphqlField {
+                        name: string(field, "name").ok_or("Field without a name")?,
+                        type_ref: type_ref(field.get("type"))?,
+                        args: array(field, "args")
+                            .map(input_value)
+                            .collect::<Result<_, String>>()?,
+                        description: string(field, "description"),
+                        deprecated: field
+                            .get("isDeprecated")
+                            .and_then(Value::as_bool)
+                            .unwrap_or(false),
+                    })
+                })
+                .collect::<Result<_, String>>()?;
+            let names = |key| {
+                array(ty, key)
+                    .filter_map(|item| string(item, "name"))
+                    .collect()
+            };
+            parsed.types.insert(
+                name,
+                GraphqlType {
+                    kind,
+                    description: string(ty, "description"),
+                    fields,
+                    input_fields: array(ty, "inputFields")
+                        .map(input_value)
+                        .collect::<Result<_, String>>()?,
+                    possible_types: names("possibleTypes"),
+                    interfaces: names("interfaces"),
+                    enum_values: names("enumValues"),
+                },
+            );
+        }
+        if parsed.query_type.is_none() {
+            return Err("Introspection result has no query type".to_string());
+        }
+        Ok(parsed)
+    }
+
+    /// Root type of an operation kind, if the schema supports it.
+    #[must_use]
+    pub fn root_type(&self, kind: OperationKind) -> Option<&str> {
+        match kind {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        }
+    }
+
+    /// Root fields of every operation kind, queries first.
+    #[must_use]
+    pub fn operations(&self) -> Vec<GraphqlOperationInfo> {
+        [
+            OperationKind::Query,
+            OperationKind::Mutation,
+            OperationKind::Subscription,
+        ]
+        .into_iter()
+        .filter_map(|kind| {
+            let root = self.types.get(self.root_type(kind)?)?;
+            Some(root.fields.iter().map(move |field| GraphqlOperationInfo {
+                kind: kind.keyword().to_string(),
+                name: field.name.clone(),
+                args: field.args.clone(),
+                type_ref: field.type_ref.clone(),
+                description: field.description.clone(),
+                deprecated: field.deprecated,
+            }))
+        })
+        .flatten()
+        .collect()
+    }
+}
+
+fn string(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn input_value(value: &Value) -> Result<GraphqlInputValue, String> {
+    Ok(GraphqlInputValue {
+        name: string(value, "name").ok_or("Argument without a name")?,
+        type_ref: type_ref(value.get("type"))?,
+        default_value: string(value, "defaultValue"),
+        description: string(value, "description"),
+    })
+}
+
+/// Render an introspection type reference in SDL notation.
+fn type_ref(value: Option<&Value>) -> Result<String, String> {
+    let value = value.ok_or("Missing type reference")?;
+    match value.get("kind").and_then(Value::as_str) {
+        Some("NON_NULL") => Ok(format!("{}!", type_ref(value.get("ofType"))?)),
+        Some("LIST") => Ok(format!("[{}]", type_ref(value.get("ofType"))?)),
+        _ => string(value, "name").ok_or_else(|| "Type reference without a name".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn named(kind: &str, name: &str) -> Value {
+        json!({ "kind": kind, "name": name, "ofType": null })
+    }
+
+    fn non_null(inner: Value) -> Value {
+        let mut ty = json!({ "kind": "NON_NULL", "name": null });
+        ty["ofType"] = inner;
+        ty
+    }
+
+    fn list(inner: Value) -> Value {
+        let mut ty = json!({ "kind": "LIST", "name": null });
+        ty["ofType"] = inner;
+        ty
+    }
+
+    fn field(name: &str, ty: Value, args: Value) -> Value {
+        let mut field = json!({ "name": name, "description": null, "isDeprecated": false });
+        field["args"] = args;
+        field["type"] = ty;
+        field
+    }
+
+    fn arg(name: &str, ty: Value, default: Option<&str>) -> Value {
+        let mut arg = json!({ "name": name, "description": null, "defaultValue": default });
+        arg["type"] = ty;
+        arg
+    }
+
+    /// Introspection response of a small schema:
+    ///
+    /// ```graphql
+    /// type Query { user(id: ID!): User, users(first: Int = 10): [User!]!, search(text: String!): [SearchResult!]! }
+    /// type Mutation { like(id: ID!): Int! }
+    /// interface Node { id: ID! }
+    /// type User implements Node { id: ID!, name: String, friends(first: Int): [User!]! , role: Role }
+    /// type Post implements Node { id: ID!, title: String! }
+    /// union SearchResult = User | Post
+    /// enum Role { ADMIN, MEMBER }
+    /// ```
+    fn introspection() -> Value {
+        json!({ "data": { "__schema": {
+            "queryType": { "name": "Query" },
+            "mutationType": { "name": "Mutation" },
+            "subscriptionType": null,
+            "types": [
+                { "kind": "OBJECT", "name": "Query", "description": "Entry points", "fields": [
+                    field("user", named("OBJECT", "User"), json!([arg("id", non_null(named("SCALAR", "ID")), None)])),
+                    field("users", non_null(list(non_null(named("OBJECT", "User")))), json!([arg("first", named("SCALAR", "Int"), Some("10"))])),
+                    field("search", non_null(list(non_null(named("UNION", "SearchResult")))), json!([arg("text", non_null(named("SCALAR", "String")), None)])),
+                ], "inputFields": null, "interfaces": [], "enumValues": null, "possibleTypes": null },
+                { "kind": "OBJECT", "name": "Mutation", "fields": [
+                    field("like", non_null(named("SCALAR", "Int")), json!([arg("id", non_null(named("SCALAR", "ID")), None)])),
+                ], "interfaces": [] },
+                { "kind": "INTERFACE", "name": "Node", "fields": [
+                    field("id", non_null(named("SCALAR", "ID")), json!([])),
+                ], "possibleTypes": [{ "name": "User" }, { "name": "Post" }] },
+                { "kind": "OBJECT", "name": "User", "fields": [
+                    field("id", non_null(named("SCALAR", "ID")), json!([])),
+                    field("name", named("SCALAR", "String"), json!([])),
+                    field("friends", non_null(list(non_null(named("OBJECT", "User")))), json!([arg("first", named("SCALAR", "Int"), None)])),
+                    field("role", named("ENUM", "Role"), json!([])),
+                ], "interfaces": [{ "name": "Node" }] },
+                { "kind": "OBJECT", "name": "Post", "fields": [
+                    field("id", non_null(named("SCALAR", "ID")), json!([])),
+                    field("title", non_null(named("SCALAR", "String")), json!([])),
+                ], "interfaces": [{ "name": "Node" }] },
+                { "kind": "UNION", "name": "SearchResult", "possibleTypes": [{ "name": "User" }, { "name": "Post" }] },
+                { "kind": "ENUM", "name": "Role", "enumValues": [{ "name": "ADMIN" }, { "name": "MEMBER" }] },
+                { "kind": "SCALAR", "name": "ID" },
+                { "kind": "SCALAR", "name": "Int" },
+                { "kind": "SCALAR", "name": "String" },
+                { "kind": "OBJECT", "name": "__Schema", "fields": [] },
+            ]
+        } } })
+    }
+
+    #[test]
+    fn test_from_introspection_builds_types_and_type_refs() {
+        let schema = GraphqlSchema::from_introspection(&introspection()).unwrap();
+
+        assert_eq!(schema.query_type.as_deref(), Some("Query"));
+        assert_eq!(schema.mutation_type.as_deref(), Some("Mutation"));
+        assert!(schema.subscription_type.is_none());
+        assert!(!schema.types.contains_key("__Schema"));
+        let query = &schema.types["Query"];
+        assert_eq!(query.description.as_deref(), Some("Entry points"));
+        assert_eq!(query.field("users").unwrap().type_ref, "[User!]!");
+        assert_eq!(named_type("[User!]!"), "User");
+        assert!(query.field("user").unwrap().args[0].is_required());
+        assert!(!query.field("users").unwrap().args[0].is_required());
+        assert_eq!(
+            schema.types["SearchResult"].possible_types,
+            ["User", "Post"]
+        );
+        assert_eq!(schema.types["Role"].enum_values, ["ADMIN", "MEMBER"]);
+        assert_eq!(schema.types["User"].interfaces, ["Node"]);
+    }
+
+    #[test]
+    fn test_operations_list_root_fields_by_kind() {
+        let schema = GraphqlSchema::from_introspection(&introspection()).unwrap();
+        let operations: Vec<_> = schema
+            .operations()
+            .into_iter()
+            .map(|op| format!("{} {}: {}", op.kind, op.name, op.type_ref))
+            .collect();
+        assert_eq!(
+            operations,
+            [
+                "query user: User",
+                "query users: [User!]!",
+                "query search: [SearchResult!]!",
+                "mutation like: Int!",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_introspection_errors_are_reported() {
+        let response =
+            json!({ "data": null, "errors": [{ "message": "Introspection is disabled" }] });
+        let error = GraphqlSchema::from_introspection(&response).unwrap_err();
+        assert!(error.contains("Introspection is disabled"), "{error}");
+        assert!(GraphqlSchema::from_introspection(&json!({ "data": {} })).is_err());
+    }
+}
@@ -0,0 +1,563 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Validation of GraphQL requests against a schema.
+//!
+//! Covers the mistakes that account for most rejected requests: syntax
+//! errors, unknown fields, arguments and types, missing required arguments,
+//! leaf fields with selections (and the reverse), undefined fragments,
+//! undefined or unused variables, and required variables without a value.
+//! Value types are not checked; the server remains the final authority.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+#[cfg(test)]
+use ts_rs::TS;
+
+use super::GraphqlQuery;
+use super::document::{Document, Fragment, Operation, Position, Selection, VariableUsage};
+use super::schema::{GraphqlSchema, GraphqlType, GraphqlTypeKind, named_type};
+
+/// A problem found in a GraphQL request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct GraphqlIssue {
+    /// What is wrong.
+    pub message: String,
+    /// Line of the offending token, starting at 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub line: Option<usize>,
+    /// Column of the offending token, starting at 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub column: Option<usize>,
+}
+
+impl GraphqlIssue {
+    const fn at(position: Position, message: String) -> Self {
+        Self {
+            message,
+            line: Some(position.line),
+            column: Some(position.column),
+        }
+    }
+
+    const fn general(message: String) -> Self {
+        Self {
+            message,
+            line: None,
+            column: None,
+        }
+    }
+}
+
+impl std::fmt::Display for GraphqlIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{line}:{column}: {}", self.message),
+            _ => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Check `query` against `schema`. An empty result means the request is valid.
+#[must_use]
+pub fn validate_query(schema: &GraphqlSchema, query: &GraphqlQuery) -> Vec<GraphqlIssue> {
+    let document = match Document::parse(&query.query) {
+        Ok(document) => document,
+        Err(e) => return vec![GraphqlIssue::at(e.position, e.message)],
+    };
+    let mut validator = Validator {
+        schema,
+        fragments: HashMap::new(),
+        issues: Vec::new(),
+    };
+    for fragment in &document.fragments {
+        if validator
+            .fragments
+            .insert(fragment.name.as_str(), fragment)
+            .is_some()
+        {
+            validator.issue(
+                fragment.position,
+                format!("Fragment \"{}\" is defined more than once", fragment.name),
+            );
+        }
+    }
+
+    validator.check_operation_names(&document, query.operation_name.as_deref());
+    for operation in &document.operations {
+        validator.check_operation(operation, query);
+    }
+    for fragment in &document.fragments {
+        validator.check_fragment(fragment);
+    }
+    validator.issues
+}
+
+/// Validation state for one document.
+struct Validator<'a> {
+    schema: &'a GraphqlSchema,
+    fragments: HashMap<&'a str, &'a Fragment>,
+    issues: Vec<GraphqlIssue>,
+}
+
+impl<'a> Validator<'a> {
+    fn issue(&mut self, position: Position, message: String) {
+        self.issues.push(GraphqlIssue::at(position, message));
+    }
+
+    /// Operation names must be unique, anonymous operations must stand alone,
+    /// and `operation_name` must pick one operation when there are several.
+    fn check_operation_names(&mut self, document: &Document, operation_name: Option<&str>) {
+        let operations = &document.operations;
+        let mut seen = BTreeSet::new();
+        for operation in operations {
+            match &operation.name {
+                Some(name) if !seen.insert(name.as_str()) => self.issue(
+                    operation.position,
+                    format!("Operation \"{name}\" is defined more than once"),
+                ),
+                None if operations.len() > 1 => self.issue(
+                    operation.position,
+                    "An anonymous operation must be the only operation in the document".to_string(),
+                ),
+                _ => {}
+            }
+        }
+        match operation_name {
+            Some(name) if !seen.contains(name) => self.issues.push(GraphqlIssue::general(format!(
+                "Operation \"{name}\" is not defined in the document"
+            ))),
+            None if operations.len() > 1 => self.issues.push(GraphqlIssue::general(
+                "The document defines several operations; set operationName to pick one"
+                    .to_string(),
+            )),
+            _ => {}
+        }
+    }
+
+    fn check_operation(&mut self, operation: &'a Operation, query: &GraphqlQuery) {
+        let Some(root) = self
+            .schema
+            .root_type(operation.kind)
+            .and_then(|name| self.schema.types.get(name))
+        else {
+            self.issue(
+                operation.position,
+                format!("The schema does not support {}s", operation.kind.keyword()),
+            );
+            return;
+        };
+        let root_name = self.schema.root_type(operation.kind).unwrap_or_default();
+        self.check_selections(&operation.selections, root_name, root, true);
+
+        // Variables: declared once, known input types, all used, all declared
+        let mut declared = HashMap::new();
+        for definition in &operation.variables {
+            if declared
+                .insert(definition.name.as_str(), definition)
+                .is_some()
+            {
+                self.issue(
+                    definition.position,
+                    format!(
+                        "Variable \"${}\" is declared more than once",
+                        definition.name
+                    ),
+                );
+            }
+            let type_name = named_type(&definition.type_ref);
+            match self.schema.types.get(type_name) {
+                None => self.issue(
+                    definition.position,
+                    format!(
+                        "Unknown type \"{type_name}\" for variable \"${}\"",
+                        definition.name
+                    ),
+                ),
+                Some(ty) if ty.kind.is_composite() => self.issue(
+                    definition.position,
+                    format!(
+                        "Variable \"${}\" cannot be of output type \"{type_name}\"",
+                        definition.name
+                    ),
+                ),
+                Some(_) => {}
+            }
+        }
+        let mut used = Vec::new();
+        used.extend(operation.directive_variables.iter().cloned());
+        self.collect_variables(&operation.selections, &mut used, &mut BTreeSet::new());
+        let used_names: BTreeSet<&str> = used.iter().map(|usage| usage.name.as_str()).collect();
+        for usage in &used {
+            if !declared.contains_key(usage.name.as_str()) {
+                let operation_label = operation
+                    .name
+                    .as_ref()
+                    .map_or_else(String::new, |name| format!(" by operation \"{name}\""));
+                self.issue(
+                    usage.position,
+                    format!(
+                        "Variable \"${}\" is not declared{operation_label}",
+                        usage.name
+                    ),
+                );
+            }
+        }
+        for definition in &operation.variables {
+            if !used_names.contains(definition.name.as_str()) {
+                self.issue(
+                    definition.position,
+                    format!("Variable \"${}\" is never used", definition.name),
+                );
+            }
+        }
+
+        // Values of the operation that will run
+        let runs = query
+            .operation_name
+            .as_deref()
+            .is_none_or(|name| operation.name.as_deref() == Some(name));
+        if runs {
+            let values = query
+                .variables
+                .as_ref()
+                .and_then(serde_json::Value::as_object);
+            for definition in &operation.variables {
+                let value = values.and_then(|values| values.get(&definition.name));
+                if definition.type_ref.ends_with('!')
+                    && !definition.has_default
+                    && value.is_none_or(serde_json::Value::is_null)
+                {
+                    self.issue(
+                        definition.position,
+                        format!(
+                            "Variable \"${}\" of required type \"{}\" was not provided",
+                            definition.name, definition.type_ref
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_fragment(&mut self, fragment: &'a Fragment) {
+        let Some(ty) = self.schema.types.get(&fragment.type_condition) else {
+            self.issue(
+                fragment.position,
+                format!(
+                    "Fragment \"{}\" is on unknown type \"{}\"",
+                    fragment.name, fragment.type_condition
+                ),
+            );
+            return;
+        };
+        if !ty.kind.is_composite() {
+            self.issue(
+                fragment.position,
+                format!(
+                    "Fragment \"{}\" cannot be on leaf type \"{}\"",
+                    fragment.name, fragment.type_condition
+                ),
+            );
+            return;
+        }
+        self.check_selections(&fragment.selections, &fragment.type_condition, ty, false);
+    }
+
+    /// Check `selections` made on `parent`.
+    fn check_selections(
+        &mut self,
+        selections: &'a [Selection],
+        parent_name: &str,
+        parent: &'a GraphqlType,
+        is_query_root: bool,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    if field.name == "__typename" {
+                        continue;
+                    }
+                    if is_query_root && (field.name == "__schema" || field.name == "__type") {
+                        continue;
+                    }
+                    let Some(definition) = parent.field(&field.name) else {
+                        let hint = if parent.kind == GraphqlTypeKind::Union {
+                            "; select it through an inline fragment"
+                        } else {
+                            ""
+                        };
+                        self.issue(
+                            field.position,
+                            format!(
+                                "Cannot query field \"{}\" on type \"{parent_name}\"{hint}",
+                                field.name
+                            ),
+                        );
+                        continue;
+                    };
+
+                    for argument in &field.arguments {
+                        if !definition.args.iter().any(|arg| arg.name == argument.name) {
+                            self.issue(
+                                argument.position,
+                                format!(
+                                    "Unknown argument \"{}\" on field \"{parent_name}.{}\"",
+                                    argument.name, field.name
+                                ),
+                            );
+                        }
+                    }
+                    for arg in definition.args.iter().filter(|arg| arg.is_required()) {
+                        if !field.arguments.iter().any(|given| given.name == arg.name) {
+                            self.issue(
+                                field.position,
+                                format!(
+                                    "Field \"{parent_name}.{}\" requires argument \"{}\" of type \"{}\"",
+                                    field.name, arg.name, arg.type_ref
+                                ),
+                            );
+                        }
+                    }
+
+                    let type_name = named_type(&definition.type_ref);
+                    let Some(field_type) = self.schema.types.get(type_name) else {
+                        continue;
+                    };
+                    match (field_type.kind.is_composite(), field.has_selection_set) {
+                        (true, false) => self.issue(
+                            field.position,
+                            format!(
+                                "Field \"{}\" of type \"{}\" must have a selection of subfields",
+                                field.name, definition.type_ref
+                            ),
+                        ),
+                        (false, true) => self.issue(
+                            field.position,
+                            format!(
+                                "Field \"{}\" of leaf type \"{}\" cannot have a selection of subfields",
+                                field.name, definition.type_ref
+                            ),
+                        ),
+                        (true, true) => {
+                            self.check_selections(&field.selections, type_name, field_type, false);
+                        }
+                        (false, false) => {}
+                    }
+                }
+                Selection::FragmentSpread { name, position, .. } => {
+                    if !self.fragments.contains_key(name.as_str()) {
+                        self.issue(*position, format!("Unknown fragment \"{name}\""));
+                    }
+                }
+                Selection::InlineFragment {
+                    type_condition,
+                    selections,
+                    position,
+                    ..
+                } => match type_condition {
+                    None => self.check_selections(selections, parent_name, parent, is_query_root),
+                    Some(name) => match self.schema.types.get(name) {
+                        Some(ty) if ty.kind.is_composite() => {
+                            self.check_selections(selections, name, ty, false);
+                        }
+                        Some(_) => self.issue(
+                            *position,
+                            format!("Inline fragment cannot be on leaf type \"{name}\""),
+                        ),
+                        None => {
+                            self.issue(*position, format!("Unknown type \"{name}\""));
+                        }
+                    },
+                },
+            }
+        }
+    }
+
+    /// Collect variable references in `selections`, following fragment
+    /// spreads once each.
+    fn collect_variables(
+        &self,
+        selections: &'a [Selection],
+        used: &mut Vec<VariableUsage>,
+        visited: &mut BTreeSet<&'a str>,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    for argument in &field.arguments {
+                        used.extend(argument.variables.iter().cloned());
+                    }
+                    used.extend(field.directive_variables.iter().cloned());
+                    self.collect_variables(&field.selections, used, visited);
+                }
+                Selection::FragmentSpread {
+                    name, variables, ..
+                } => {
+                    used.extend(variables.iter().cloned());
+                    if let Some(fragment) = self.fragments.get(name.as_str())
+                        && visited.insert(fragment.name.as_str())
+                    {
+                        used.extend(fragment.directive_variables.iter().cloned());
+                        self.collect_variables(&fragment.selections, used, visited);
+                    }
+                }
+                Selection::InlineFragment {
+                    variables,
+                    selections,
+                    ..
+                } => {
+                    used.extend(variables.iter().cloned());
+                    self.collect_variables(selections, used, visited);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// The schema below, in the cached format:
+    ///
+    /// ```graphql
+    /// type Query { user(id: ID!): User, users(first: Int = 10): [User!]!, search(text: String!): [SearchResult!]! }
+    /// type User { id: ID!, name: String, friends(first: Int): [User!]!, role: Role }
+    /// type Post { id: ID!, title: String! }
+    /// union SearchResult = User | Post
+    /// enum Role { ADMIN, MEMBER }
+    /// ```
+    fn schema() -> GraphqlSchema {
+        serde_json::from_value(json!({
+            "query_type": "Query",
+            "types": {
+                "Query": { "kind": "object", "fields": [
+                    { "name": "user", "type_ref": "User", "args": [{ "name": "id", "type_ref": "ID!" }] },
+                    { "name": "users", "type_ref": "[User!]!", "args": [{ "name": "first", "type_ref": "Int", "default_value": "10" }] },
+                    { "name": "search", "type_ref": "[SearchResult!]!", "args": [{ "name": "text", "type_ref": "String!" }] }
+                ] },
+                "User": { "kind": "object", "fields": [
+                    { "name": "id", "type_ref": "ID!" },
+                    { "name": "name", "type_ref": "String" },
+                    { "name": "friends", "type_ref": "[User!]!", "args": [{ "name": "first", "type_ref": "Int" }] },
+                    { "name": "role", "type_ref": "Role" }
+                ] },
+                "Post": { "kind": "object", "fields": [
+                    { "name": "id", "type_ref": "ID!" },
+                    { "name": "title", "type_ref": "String!" }
+                ] },
+                "SearchResult": { "kind": "union", "possible_types": ["User", "Post"] },
+                "Role": { "kind": "enum", "enum_values": ["ADMIN", "MEMBER"] },
+                "ID": { "kind": "scalar" },
+                "Int": { "kind": "scalar" },
+                "String": { "kind": "scalar" }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn issues(
+        query: &str,
+        variables: serde_json::Value,
+        operation_name: Option<&str>,
+    ) -> Vec<String> {
+        validate_query(
+            &schema(),
+            &GraphqlQuery {
+                query: query.to_string(),
+                variables: Some(variables),
+                operation_name: operation_name.map(ToString::to_string),
+            },
+        )
+        .into_iter()
+        .map(|issue| issue.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_valid_document_has_no_issues() {
+        let query = r#"
+            query User($id: ID!, $first: Int) {
+              user(id: $id) { ...Basics friends(first: $first) { __typename name } }
+              search(text: "x") { ... on Post { title } ... on User { role } }
+            }
+            fragment Basics on User { id name }
+        "#;
+        assert_eq!(
+            issues(query, json!({ "id": "1" }), None),
+            Vec::<String>::new()
+        );
+        assert!(issues("{ __schema { types { name } } }", json!({}), None).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_fields_arguments_and_selections() {
+        let found = issues(
+            "{\n  user { nickname role { x } }\n  users(limit: 1)\n  search(text: \"a\") { title }\n}",
+            json!({}),
+            None,
+        );
+        assert_eq!(
+            found,
+            [
+                "2:3: Field \"Query.user\" requires argument \"id\" of type \"ID!\"",
+                "2:10: Cannot query field \"nickname\" on type \"User\"",
+                "2:19: Field \"role\" of leaf type \"Role\" cannot have a selection of subfields",
+                "3:9: Unknown argument \"limit\" on field \"Query.users\"",
+                "3:3: Field \"users\" of type \"[User!]!\" must have a selection of subfields",
+                "4:23: Cannot query field \"title\" on type \"SearchResult\"; select it through an inline fragment",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variables_must_be_declared_used_and_provided() {
+        let found = issues(
+            "query Q($id: ID!, $unused: String, $bad: User) { user(id: $id) { friends(first: $first) { id } } }",
+            json!({}),
+            None,
+        );
+        assert_eq!(
+            found,
+            [
+                "1:36: Variable \"$bad\" cannot be of output type \"User\"",
+                "1:81: Variable \"$first\" is not declared by operation \"Q\"",
+                "1:19: Variable \"$unused\" is never used",
+                "1:36: Variable \"$bad\" is never used",
+                "1:9: Variable \"$id\" of required type \"ID!\" was not provided",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operation_selection_and_fragments() {
+        let document =
+            "query A { users { id } } query B { users { ...Missing } } fragment F on Role { x }";
+        assert_eq!(
+            issues(document, json!({}), None),
+            [
+                "The document defines several operations; set operationName to pick one",
+                "1:44: Unknown fragment \"Missing\"",
+                "1:59: Fragment \"F\" cannot be on leaf type \"Role\"",
+            ]
+        );
+        assert!(
+            issues(document, json!({}), Some("C"))[0].contains("Operation \"C\" is not defined")
+        );
+        assert_eq!(
+            issues("mutation { like }", json!({}), None),
+            ["1:1: The schema does not support mutations"]
+        );
+        assert_eq!(
+            issues("{ users { id }", json!({}), None),
+            ["1:15: Expected a name, found end of document"]
+        );
+    }
+}
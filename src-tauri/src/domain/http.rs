@@ -3,10 +3,17 @@
 
 // HTTP domain models for request/response handling
 
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-#[cfg(test)]
+use super::certificates::CertificateChain;
+use super::cookies::CookieJarScope;
+use super::http_headers::HttpHeaders;
+use super::http_stream::StreamTranscript;
+use super::retry::{RetryAttempt, RetryPolicy};
+use super::websocket::WebSocketTranscript;
+
 use ts_rs::TS;
 
 /// Timing information for HTTP request phases.
@@ -38,6 +45,48 @@ pub struct RequestTiming {
     /// Time to first byte in milliseconds.
     #[cfg_attr(test, ts(type = "number | null"))]
     pub first_byte_ms: Option<u64>,
+    /// Time spent on redirects before the final request started, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub redirect_ms: Option<u64>,
+    /// Time from the connection being ready to the last byte received
+    /// (sending the request, server wait and download), in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub transfer_ms: Option<u64>,
+    /// Time from the first to the last response byte, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub download_ms: Option<u64>,
+}
+
+/// How a request reached the server and how much it transferred.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct ConnectionInfo {
+    /// HTTP version of the final response, e.g. `HTTP/1.1`, `HTTP/2` or `HTTP/3`.
+    pub http_version: String,
+    /// IP address of the server (or proxy) the final request was sent to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub remote_ip: Option<String>,
+    /// Port of the server (or proxy) the final request was sent to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub remote_port: Option<u16>,
+    /// Bytes of the issued requests as curl counts them, across redirects.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub request_bytes: u64,
+    /// Bytes of request body uploaded.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub upload_bytes: u64,
+    /// Bytes of response headers received, across redirects.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub response_header_bytes: u64,
+    /// Bytes of response body downloaded.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub download_bytes: u64,
 }
 
 /// Parameters for an HTTP request.
@@ -49,16 +98,168 @@ pub struct RequestParams {
     pub url: String,
     /// HTTP method (GET, POST, PUT, PATCH, DELETE, etc.).
     pub method: String,
-    /// Request headers as key-value pairs.
+    /// Request headers in the order they are sent; names may repeat.
     #[serde(default)]
-    #[cfg_attr(test, ts(type = "Record<string, string>"))]
-    pub headers: HashMap<String, String>,
+    pub headers: HttpHeaders,
     /// Optional request body.
     pub body: Option<String>,
+    /// File whose bytes are streamed from disk as the body, instead of `body`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub body_file: Option<String>,
+    /// `multipart/form-data` parts. When present they are sent instead of
+    /// `body` and `body_file`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<MultipartPart>>", optional))]
+    pub multipart: Vec<MultipartPart>,
     /// Request timeout in milliseconds (default: 30000).
     #[serde(default = "default_timeout")]
     #[cfg_attr(test, ts(type = "number"))]
     pub timeout_ms: u64,
+    /// TLS settings (client certificate, CA bundle, verification).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub tls: Option<TlsConfig>,
+    /// Proxy settings. When omitted, the global proxy settings apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub proxy: Option<ProxyConfig>,
+    /// Cookie jar to send cookies from and store `Set-Cookie` responses into.
+    /// Without one, the request runs with no cookie engine.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub cookie_jar: Option<CookieJarScope>,
+    /// Follow `Location` redirects (default: true).
+    #[serde(default = "default_follow_redirects")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub follow_redirects: bool,
+    /// Maximum number of redirects to follow before failing (default: 10).
+    #[serde(default = "default_max_redirects")]
+    #[cfg_attr(test, ts(as = "Option<u32>", optional))]
+    pub max_redirects: u32,
+    /// HTTP version to speak. Anything other than `auto` is enforced: the
+    /// request fails rather than falling back to another version.
+    #[serde(default, skip_serializing_if = "HttpVersion::is_auto")]
+    #[cfg_attr(test, ts(as = "Option<HttpVersion>", optional))]
+    pub http_version: HttpVersion,
+    /// Retry failed attempts under this policy. Without one, the request is
+    /// tried once. Not applied to streaming requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub retry: Option<RetryPolicy>,
+    /// Path of a Unix domain socket to connect through instead of TCP. The
+    /// URL still supplies the scheme, `Host` header and path, e.g.
+    /// `http://localhost/v1.45/containers/json` for the Docker API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub unix_socket: Option<String>,
+    /// Host names pinned to fixed addresses (curl `--resolve`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<ResolveOverride>>", optional))]
+    pub resolve: Vec<ResolveOverride>,
+    /// Hosts and ports connected to in place of requested ones (curl
+    /// `--connect-to`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<ConnectToOverride>>", optional))]
+    pub connect_to: Vec<ConnectToOverride>,
+    /// Capture a wire-level trace of the exchange in
+    /// [`HttpResponse::trace`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub verbose: bool,
+}
+
+impl Default for RequestParams {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            method: "GET".to_string(),
+            headers: HttpHeaders::new(),
+            body: None,
+            body_file: None,
+            multipart: Vec::new(),
+            timeout_ms: default_timeout(),
+            tls: None,
+            proxy: None,
+            cookie_jar: None,
+            follow_redirects: default_follow_redirects(),
+            max_redirects: default_max_redirects(),
+            http_version: HttpVersion::Auto,
+            retry: None,
+            unix_socket: None,
+            resolve: Vec::new(),
+            connect_to: Vec::new(),
+            verbose: false,
+        }
+    }
+}
+
+impl RequestParams {
+    /// Resolve relative `body_file` and multipart file paths against `base`.
+    pub fn resolve_file_paths(&mut self, base: &Path) {
+        let resolve = |path: &mut String| {
+            if Path::new(path.as_str()).is_relative() {
+                *path = base.join(path.as_str()).to_string_lossy().into_owned();
+            }
+        };
+        if let Some(path) = self.body_file.as_mut() {
+            resolve(path);
+        }
+        for path in self
+            .multipart
+            .iter_mut()
+            .filter_map(|part| part.file.as_mut())
+        {
+            resolve(path);
+        }
+    }
+}
+
+/// One part of a `multipart/form-data` body: a text field or a file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct MultipartPart {
+    /// Form field name.
+    pub name: String,
+    /// Inline text value. Exactly one of `value` and `file` must be set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub value: Option<String>,
+    /// Path of a file whose contents are streamed as the part.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub file: Option<String>,
+    /// Filename sent in `Content-Disposition`. Defaults to the file's name
+    /// for file parts; setting it on a text part sends the value as a file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub filename: Option<String>,
+    /// Content type of the part. Curl infers it from the extension for file
+    /// parts when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub content_type: Option<String>,
+}
+
+impl MultipartPart {
+    /// Check that the part has a name and exactly one of `value` and `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the offending part.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Multipart part name must not be empty".to_string());
+        }
+        match (&self.value, &self.file) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(format!(
+                "Multipart part '{}' needs exactly one of value or file",
+                self.name
+            )),
+        }
+    }
 }
 
 /// Default timeout of 30 seconds.
@@ -66,8 +267,667 @@ const fn default_timeout() -> u64 {
     30000
 }
 
+/// Redirects are followed unless a request opts out.
+const fn default_follow_redirects() -> bool {
+    true
+}
+
+/// Default redirect limit.
+const fn default_max_redirects() -> u32 {
+    10
+}
+
+/// HTTP version preference for a request.
+///
+/// Set per request on [`RequestParams::http_version`], or on a collection and
+/// its requests, where the request's preference wins.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub enum HttpVersion {
+    /// HTTP/2 when the server offers it over TLS, HTTP/1.1 otherwise.
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    /// HTTP/1.0 only.
+    #[serde(rename = "http1.0")]
+    Http10,
+    /// HTTP/1.1 only.
+    #[serde(rename = "http1.1")]
+    Http11,
+    /// HTTP/2 negotiated over TLS (ALPN); requires an `https` URL.
+    #[serde(rename = "http2")]
+    Http2,
+    /// Cleartext HTTP/2 with prior knowledge (h2c), without an `Upgrade` round trip.
+    #[serde(rename = "h2c")]
+    H2c,
+    /// HTTP/3 over QUIC, when libcurl is built with HTTP/3 support.
+    #[serde(rename = "http3")]
+    Http3,
+}
+
+impl HttpVersion {
+    /// Whether this is the negotiated default.
+    #[must_use]
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature required by `skip_serializing_if`
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    /// Human-readable name used in error messages.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Http10 => "HTTP/1.0",
+            Self::Http11 => "HTTP/1.1",
+            Self::Http2 => "HTTP/2",
+            Self::H2c => "HTTP/2 (h2c prior knowledge)",
+            Self::Http3 => "HTTP/3",
+        }
+    }
+}
+
+/// TLS settings for a request: client certificate (mTLS), trust store and verification.
+///
+/// Paths are read by curl when the request runs. Configured per request on
+/// [`RequestParams::tls`] or per environment on `CollectionEnvironment::tls`;
+/// see [`TlsConfig::merged_with`] for how the two combine.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, TS)]
+#[cfg_attr(test, ts(export))]
+pub struct TlsConfig {
+    /// Path to the client certificate: PEM, or a PKCS#12 bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub client_cert: Option<String>,
+    /// Format of `client_cert`. Inferred from the extension when omitted
+    /// (`.p12` / `.pfx` are PKCS#12, anything else PEM).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub client_cert_format: Option<CertFormat>,
+    /// Path to the client private key (PEM). Not needed for PKCS#12 bundles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub client_key: Option<String>,
+    /// Passphrase of the private key or PKCS#12 bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub client_key_password: Option<String>,
+    /// Path to a PEM CA bundle to trust instead of the system store (private CAs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub ca_bundle: Option<String>,
+    /// Skip certificate chain and hostname verification. Insecure; local endpoints only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[ts(as = "Option<bool>", optional)]
+    pub insecure_skip_verify: bool,
+    /// Minimum TLS protocol version to accept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub min_tls_version: Option<TlsVersion>,
+}
+
+impl TlsConfig {
+    /// Layer `overrides` on top of `self`; every field set in `overrides` wins.
+    ///
+    /// Used to apply request-level settings over the active environment's.
+    /// `insecure_skip_verify` is enabled if either side enables it.
+    #[must_use]
+    pub fn merged_with(&self, overrides: &Self) -> Self {
+        Self {
+            client_cert: overrides
+                .client_cert
+                .clone()
+                .or_else(|| self.client_cert.clone()),
+            client_cert_format: overrides.client_cert_format.or(self.client_cert_format),
+            client_key: overrides
+                .client_key
+                .clone()
+                .or_else(|| self.client_key.clone()),
+            client_key_password: overrides
+                .client_key_password
+                .clone()
+                .or_else(|| self.client_key_password.clone()),
+            ca_bundle: overrides
+                .ca_bundle
+                .clone()
+                .or_else(|| self.ca_bundle.clone()),
+            insecure_skip_verify: self.insecure_skip_verify || overrides.insecure_skip_verify,
+            min_tls_version: overrides.min_tls_version.or(self.min_tls_version),
+        }
+    }
+
+    /// Format of the client certificate, explicit or inferred from its extension.
+    #[must_use]
+    pub fn cert_format(&self) -> CertFormat {
+        self.client_cert_format.unwrap_or_else(|| {
+            let is_pkcs12 = self.client_cert.as_deref().is_some_and(|path| {
+                let ext = std::path::Path::new(path)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or_default();
+                ext.eq_ignore_ascii_case("p12") || ext.eq_ignore_ascii_case("pfx")
+            });
+            if is_pkcs12 {
+                CertFormat::Pkcs12
+            } else {
+                CertFormat::Pem
+            }
+        })
+    }
+}
+
+/// Encoding of a client certificate file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum CertFormat {
+    /// PEM certificate, with the key in a separate file.
+    Pem,
+    /// PKCS#12 bundle containing certificate and key.
+    Pkcs12,
+}
+
+/// TLS protocol version.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, TS)]
+#[cfg_attr(test, ts(export))]
+pub enum TlsVersion {
+    /// TLS 1.0 (deprecated).
+    #[serde(rename = "1.0")]
+    Tls1_0,
+    /// TLS 1.1 (deprecated).
+    #[serde(rename = "1.1")]
+    Tls1_1,
+    /// TLS 1.2.
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    /// TLS 1.3.
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// Proxy settings for outgoing requests.
+///
+/// Configured globally, per environment on `CollectionEnvironment::proxy`, or per
+/// request on [`RequestParams::proxy`]; the most specific one wins as a whole.
+/// A config without `url` and without `use_system` means "connect directly".
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, TS)]
+#[cfg_attr(test, ts(export))]
+pub struct ProxyConfig {
+    /// Proxy URL, e.g. `http://proxy:3128`, `https://proxy:443` or `socks5h://proxy:1080`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub url: Option<String>,
+    /// Proxy username.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub username: Option<String>,
+    /// Proxy password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub password: Option<String>,
+    /// Hosts that bypass the proxy: exact hosts, domain suffixes (`.corp.example`),
+    /// IP addresses, CIDR ranges, or `*` for everything.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[ts(as = "Option<Vec<String>>", optional)]
+    pub no_proxy: Vec<String>,
+    /// When `url` is unset, use the `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY` and
+    /// `NO_PROXY` environment variables.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[ts(as = "Option<bool>", optional)]
+    pub use_system: bool,
+}
+
+/// Proxy URL schemes supported by the HTTP engine.
+const PROXY_SCHEMES: &[&str] = &["http", "https", "socks4", "socks4a", "socks5", "socks5h"];
+
+/// A [`ProxyConfig`] resolved for one request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedProxy {
+    /// Proxy URL, or `None` to connect directly.
+    pub url: Option<String>,
+    /// Hosts that bypass the proxy.
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// Resolve the proxy to use for `target_url`.
+    ///
+    /// `env` looks up environment variables (both upper- and lower-case names are
+    /// tried) and is only consulted when `use_system` is set and `url` is not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy URL is malformed or uses an unsupported scheme.
+    pub fn resolve(
+        &self,
+        target_url: &str,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<ResolvedProxy, String> {
+        let lookup = |name: &str| {
+            env(name)
+                .or_else(|| env(&name.to_lowercase()))
+                .filter(|value| !value.trim().is_empty())
+        };
+        let mut no_proxy = self.no_proxy.clone();
+        let url = if self.url.is_some() {
+            self.url.clone()
+        } else if self.use_system {
+            if let Some(list) = lookup("NO_PROXY") {
+                no_proxy.extend(list.split(',').map(|h| h.trim().to_string()));
+            }
+            let scheme_var = if target_url.starts_with("https://") {
+                "HTTPS_PROXY"
+            } else {
+                "HTTP_PROXY"
+            };
+            lookup(scheme_var).or_else(|| lookup("ALL_PROXY"))
+        } else {
+            None
+        };
+        no_proxy.retain(|host| !host.is_empty());
+
+        if let Some(url) = &url {
+            let scheme = url
+                .split_once("://")
+                .map(|(scheme, _)| scheme.to_ascii_lowercase())
+                .ok_or_else(|| format!("Invalid proxy URL '{url}': missing scheme"))?;
+            if !PROXY_SCHEMES.contains(&scheme.as_str()) {
+                return Err(format!(
+                    "Unsupported proxy scheme '{scheme}' (expected one of: {})",
+                    PROXY_SCHEMES.join(", ")
+                ));
+            }
+        }
+        Ok(ResolvedProxy { url, no_proxy })
+    }
+}
+
+/// Pin a host name to fixed IP addresses, like curl `--resolve`.
+///
+/// TLS verification and the `Host` header still use the host name, so a
+/// staging host can be pointed at one backend without editing `/etc/hosts`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, TS)]
+#[cfg_attr(test, ts(export))]
+pub struct ResolveOverride {
+    /// Host name to pin, e.g. `api.staging.example`.
+    pub host: String,
+    /// Port the pin applies to. Defaults to the port of the request URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub port: Option<u16>,
+    /// IPv4 or IPv6 addresses to connect to, tried in order.
+    pub addresses: Vec<String>,
+}
+
+impl ResolveOverride {
+    /// Check the host and addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host is empty or malformed, or an address is
+    /// not an IP address.
+    pub fn validate(&self) -> Result<(), String> {
+        validate_override_host(&self.host)?;
+        if self.addresses.is_empty() {
+            return Err(format!("No addresses given for host '{}'", self.host));
+        }
+        for address in &self.addresses {
+            address.parse::<std::net::IpAddr>().map_err(|_| {
+                format!(
+                    "Invalid address '{address}' for host '{}': expected an IP address",
+                    self.host
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// The curl `HOST:PORT:ADDRESS[,ADDRESS]...` entry, using `default_port`
+    /// when no port is set.
+    #[must_use]
+    pub fn curl_entry(&self, default_port: u16) -> String {
+        let addresses: Vec<String> = self
+            .addresses
+            .iter()
+            .map(|address| bracket_ipv6(address))
+            .collect();
+        format!(
+            "{}:{}:{}",
+            self.host,
+            self.port.unwrap_or(default_port),
+            addresses.join(",")
+        )
+    }
+}
+
+/// Connect to another host or port in place of the requested one, like curl
+/// `--connect-to`.
+///
+/// Unlike [`ResolveOverride`] the target may be a host name. TLS
+/// verification and the `Host` header keep the requested name.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, TS)]
+#[cfg_attr(test, ts(export))]
+pub struct ConnectToOverride {
+    /// Requested host the override applies to; every host when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub host: Option<String>,
+    /// Requested port the override applies to; every port when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub port: Option<u16>,
+    /// Host to connect to instead; the requested host when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub to_host: Option<String>,
+    /// Port to connect to instead; the requested port when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub to_port: Option<u16>,
+}
+
+impl ConnectToOverride {
+    /// Check the hosts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a host is empty or malformed, or the override
+    /// changes neither the host nor the port.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(host) = &self.host {
+            validate_override_host(host)?;
+        }
+        if let Some(to_host) = &self.to_host
+            && to_host.parse::<std::net::IpAddr>().is_err()
+        {
+            validate_override_host(to_host)?;
+        }
+        if self.to_host.is_none() && self.to_port.is_none() {
+            return Err("A connect-to override needs to_host, to_port or both".to_string());
+        }
+        Ok(())
+    }
+
+    /// The curl `HOST:PORT:CONNECT-TO-HOST:CONNECT-TO-PORT` entry, with empty
+    /// fields for omitted values.
+    #[must_use]
+    pub fn curl_entry(&self) -> String {
+        let port = |port: Option<u16>| port.map(|p| p.to_string()).unwrap_or_default();
+        format!(
+            "{}:{}:{}:{}",
+            self.host.as_deref().unwrap_or_default(),
+            port(self.port),
+            self.to_host
+                .as_deref()
+                .map(bracket_ipv6)
+                .unwrap_or_default(),
+            port(self.to_port)
+        )
+    }
+}
+
+/// Check a set of resolve and connect-to overrides.
+///
+/// # Errors
+///
+/// Returns the first problem found, prefixed with the kind of override.
+pub fn validate_host_overrides(
+    resolve: &[ResolveOverride],
+    connect_to: &[ConnectToOverride],
+) -> Result<(), String> {
+    for entry in resolve {
+        entry
+            .validate()
+            .map_err(|e| format!("Invalid resolve override: {e}"))?;
+    }
+    for entry in connect_to {
+        entry
+            .validate()
+            .map_err(|e| format!("Invalid connect-to override: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Check a host name used in a resolve or connect-to override.
+fn validate_override_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        return Err("Host cannot be empty".to_string());
+    }
+    if host
+        .chars()
+        .any(|c| c == ':' || c == ',' || c == '/' || c.is_whitespace())
+    {
+        return Err(format!("Invalid host '{host}'"));
+    }
+    Ok(())
+}
+
+/// Wrap IPv6 addresses in brackets, as curl expects in host lists.
+fn bracket_ipv6(address: &str) -> String {
+    if address.contains(':') && !address.starts_with('[') {
+        format!("[{address}]")
+    } else {
+        address.to_string()
+    }
+}
+
+/// Detected kind of a response body.
+///
+/// Derived from the `Content-Type` header when present, otherwise sniffed from
+/// the payload bytes. Only textual kinds are decoded into `HttpResponse::body`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseBodyKind {
+    /// No body bytes were received.
+    Empty,
+    /// JSON (`application/json`, `application/*+json`).
+    Json,
+    /// XML (`application/xml`, `text/xml`, `application/*+xml`).
+    Xml,
+    /// HTML (`text/html`).
+    Html,
+    /// Any other textual payload (`text/*`, form data, JavaScript, etc.).
+    #[default]
+    Text,
+    /// Image payload (`image/*`).
+    Image,
+    /// Opaque binary payload (PDF, protobuf, archives, content-encoded bodies).
+    Binary,
+}
+
+impl ResponseBodyKind {
+    /// Detect the body kind from the response headers and raw bytes.
+    ///
+    /// A non-identity `Content-Encoding` (e.g. gzip) always yields `Binary`,
+    /// since the bytes on the wire are compressed regardless of the media type.
+    #[must_use]
+    pub fn detect(content_type: Option<&str>, content_encoding: Option<&str>, body: &[u8]) -> Self {
+        if body.is_empty() {
+            return Self::Empty;
+        }
+        if content_encoding.is_some_and(|enc| {
+            let enc = enc.trim();
+            !enc.is_empty() && !enc.eq_ignore_ascii_case("identity")
+        }) {
+            return Self::Binary;
+        }
+        content_type.map_or_else(|| Self::sniff(body), Self::from_content_type)
+    }
+
+    /// Classify a `Content-Type` header value (parameters such as `charset` are ignored).
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Self {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let (top, sub) = mime.split_once('/').unwrap_or((mime.as_str(), ""));
+
+        if sub == "json" || sub.ends_with("+json") || sub == "x-ndjson" {
+            Self::Json
+        } else if sub == "xml" || sub.ends_with("+xml") {
+            Self::Xml
+        } else if sub == "html" || sub == "xhtml" {
+            Self::Html
+        } else if top == "image" {
+            Self::Image
+        } else if top == "text"
+            || matches!(
+                sub,
+                "javascript"
+                    | "x-javascript"
+                    | "ecmascript"
+                    | "x-www-form-urlencoded"
+                    | "graphql"
+                    | "yaml"
+                    | "x-yaml"
+                    | "csv"
+            )
+        {
+            Self::Text
+        } else {
+            Self::Binary
+        }
+    }
+
+    /// Guess the kind of an untyped payload from its leading bytes.
+    fn sniff(body: &[u8]) -> Self {
+        const IMAGE_MAGIC: [&[u8]; 5] = [
+            b"\x89PNG\r\n\x1a\n",
+            b"\xff\xd8\xff",
+            b"GIF87a",
+            b"GIF89a",
+            b"RIFF",
+        ];
+        if IMAGE_MAGIC.iter().any(|magic| body.starts_with(magic)) {
+            return Self::Image;
+        }
+        match std::str::from_utf8(body) {
+            Ok(text) if !text.contains('\0') => {
+                let trimmed = text.trim_start();
+                if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                    Self::Json
+                } else if trimmed.starts_with('<') {
+                    Self::Xml
+                } else {
+                    Self::Text
+                }
+            }
+            _ => Self::Binary,
+        }
+    }
+
+    /// Whether bodies of this kind are decoded into a text representation.
+    #[must_use]
+    pub const fn is_text(self) -> bool {
+        matches!(self, Self::Json | Self::Xml | Self::Html | Self::Text)
+    }
+}
+
+/// A redirect response that was followed on the way to the final response.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RedirectHop {
+    /// URL requested in this hop.
+    pub url: String,
+    /// HTTP status code of the redirect (e.g. 301, 302, 307).
+    pub status: u16,
+    /// HTTP status text.
+    pub status_text: String,
+    /// Response headers of the redirect, in wire order.
+    pub headers: HttpHeaders,
+    /// Raw `Location` header value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub location: Option<String>,
+    /// Time from the start of this hop until its response headers arrived, in milliseconds.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub duration_ms: u64,
+}
+
+/// Kind of a [`WireTraceEntry`], after curl's debug callback.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum WireTraceKind {
+    /// Informational text from curl: name resolution, connection setup, TLS
+    /// handshake and certificate details.
+    Info,
+    /// Request line and headers exactly as sent, including those curl adds.
+    HeaderOut,
+    /// A raw response header line, status lines included.
+    HeaderIn,
+}
+
+impl WireTraceKind {
+    /// Line prefix used by `curl -v`.
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Info => "*",
+            Self::HeaderOut => ">",
+            Self::HeaderIn => "<",
+        }
+    }
+}
+
+/// One message of a [`WireTrace`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WireTraceEntry {
+    /// What the message records.
+    pub kind: WireTraceKind,
+    /// The message text, with line endings normalized to `\n`.
+    pub text: String,
+    /// Time since the transfer started, in milliseconds.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub elapsed_ms: u64,
+}
+
+/// Wire-level trace of a request, captured when [`RequestParams::verbose`]
+/// is set. Bodies are not recorded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WireTrace {
+    /// Messages in the order curl reported them.
+    pub entries: Vec<WireTraceEntry>,
+    /// Whether messages were dropped because the trace grew too large.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub truncated: bool,
+}
+
+impl WireTrace {
+    /// Render the trace the way `curl -v` prints it: `*` for information,
+    /// `>` for outgoing and `<` for incoming header lines.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut lines = Vec::new();
+        for entry in &self.entries {
+            let prefix = entry.kind.prefix();
+            lines.extend(entry.text.lines().map(|line| format!("{prefix} {line}")));
+        }
+        if self.truncated {
+            lines.push("* [trace truncated]".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
 /// Response from an HTTP request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Textual bodies are carried in `body`. Binary bodies leave `body` empty and
+/// carry the raw bytes base64-encoded in `body_base64`, so images, PDFs and
+/// compressed payloads survive the round trip unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[cfg_attr(test, derive(TS))]
 #[cfg_attr(test, ts(export))]
 pub struct HttpResponse {
@@ -75,13 +935,71 @@ pub struct HttpResponse {
     pub status: u16,
     /// HTTP status text (e.g., "OK", "Not Found").
     pub status_text: String,
-    /// Response headers as key-value pairs.
-    #[cfg_attr(test, ts(type = "Record<string, string>"))]
-    pub headers: HashMap<String, String>,
-    /// Response body as a string.
+    /// Response headers in wire order; repeated names (e.g. `Set-Cookie`) are kept.
+    pub headers: HttpHeaders,
+    /// Response body decoded as text (empty for binary bodies, and for
+    /// requests executed in streaming mode, whose body is in `stream`).
     pub body: String,
+    /// Raw response body, base64-encoded. Only set for non-text bodies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub body_base64: Option<String>,
+    /// Detected kind of the response body.
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<ResponseBodyKind>", optional))]
+    pub body_kind: ResponseBodyKind,
+    /// Size of the raw response body in bytes, as received.
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<u32>", optional))]
+    pub body_size: u64,
     /// Timing information for the request.
     pub timing: RequestTiming,
+    /// Decoded messages, for requests executed in streaming mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub stream: Option<StreamTranscript>,
+    /// Messages of a WebSocket session, for history entries of WebSocket requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub websocket: Option<WebSocketTranscript>,
+    /// Redirects followed before the final response, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<RedirectHop>>", optional))]
+    pub redirects: Vec<RedirectHop>,
+    /// Negotiated protocol, remote address and transfer sizes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub connection: Option<ConnectionInfo>,
+    /// Certificate chain the server presented, for HTTPS responses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub certificates: Option<CertificateChain>,
+    /// Every attempt made under the request's retry policy, in order. Empty
+    /// when no policy applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<RetryAttempt>>", optional))]
+    pub attempts: Vec<RetryAttempt>,
+    /// Wire-level trace, for requests sent with [`RequestParams::verbose`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub trace: Option<WireTrace>,
+}
+
+impl HttpResponse {
+    /// Raw body bytes, decoding `body_base64` for binary responses.
+    ///
+    /// Falls back to the UTF-8 bytes of `body` (e.g. for history entries
+    /// recorded before binary bodies were supported).
+    #[must_use]
+    pub fn body_bytes(&self) -> Vec<u8> {
+        use base64::Engine;
+        use base64::engine::general_purpose::STANDARD as BASE64;
+
+        self.body_base64
+            .as_deref()
+            .and_then(|encoded| BASE64.decode(encoded).ok())
+            .unwrap_or_else(|| self.body.as_bytes().to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -93,9 +1011,10 @@ mod tests {
         let params = RequestParams {
             url: "https://example.com".to_string(),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 30000,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -106,11 +1025,111 @@ mod tests {
         assert_eq!(parsed.timeout_ms, 30000);
     }
 
+    #[test]
+    fn test_wire_trace_renders_like_curl_verbose() {
+        let trace = WireTrace {
+            entries: vec![
+                WireTraceEntry {
+                    kind: WireTraceKind::Info,
+                    text: "Connected to api.example.com (10.0.0.12) port 443".to_string(),
+                    elapsed_ms: 3,
+                },
+                WireTraceEntry {
+                    kind: WireTraceKind::HeaderOut,
+                    text: "GET /users HTTP/1.1\nHost: api.example.com\nAccept: */*".to_string(),
+                    elapsed_ms: 41,
+                },
+                WireTraceEntry {
+                    kind: WireTraceKind::HeaderIn,
+                    text: "HTTP/1.1 401 Unauthorized".to_string(),
+                    elapsed_ms: 87,
+                },
+            ],
+            truncated: true,
+        };
+        assert_eq!(
+            trace.to_text(),
+            "* Connected to api.example.com (10.0.0.12) port 443\n\
+             > GET /users HTTP/1.1\n\
+             > Host: api.example.com\n\
+             > Accept: */*\n\
+             < HTTP/1.1 401 Unauthorized\n\
+             * [trace truncated]"
+        );
+
+        let json = serde_json::to_value(RequestParams::default()).unwrap();
+        assert!(json.get("verbose").is_none());
+    }
+
+    #[test]
+    fn test_request_params_redirect_defaults() {
+        let params: RequestParams = serde_json::from_str(
+            r#"{"url": "https://example.com", "method": "GET", "body": null}"#,
+        )
+        .unwrap();
+        assert!(params.follow_redirects);
+        assert_eq!(params.max_redirects, 10);
+        assert_eq!(params.http_version, HttpVersion::Auto);
+    }
+
+    #[test]
+    fn test_http_version_wire_names() {
+        let params: RequestParams = serde_json::from_str(
+            r#"{"url": "http://localhost:8080", "method": "GET", "body": null, "http_version": "h2c"}"#,
+        )
+        .unwrap();
+        assert_eq!(params.http_version, HttpVersion::H2c);
+        assert_eq!(
+            serde_json::to_value(HttpVersion::Http11).unwrap(),
+            serde_json::json!("http1.1")
+        );
+
+        let json = serde_json::to_value(RequestParams::default()).unwrap();
+        assert!(json.get("http_version").is_none());
+    }
+
+    #[test]
+    fn test_resolve_file_paths_only_touches_relative_paths() {
+        let absolute = std::env::temp_dir().join("avatar.png");
+        let mut params = RequestParams {
+            body_file: Some("fixtures/upload.bin".to_string()),
+            multipart: vec![
+                MultipartPart {
+                    name: "avatar".to_string(),
+                    value: None,
+                    file: Some(absolute.to_string_lossy().into_owned()),
+                    filename: None,
+                    content_type: Some("image/png".to_string()),
+                },
+                MultipartPart {
+                    name: "title".to_string(),
+                    value: Some("Profile".to_string()),
+                    file: None,
+                    filename: None,
+                    content_type: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        params.resolve_file_paths(Path::new("/collections"));
+
+        assert_eq!(
+            params.body_file.as_deref().map(Path::new),
+            Some(Path::new("/collections/fixtures/upload.bin"))
+        );
+        assert_eq!(
+            params.multipart[0].file.as_deref().map(Path::new),
+            Some(absolute.as_path())
+        );
+        assert_eq!(params.multipart[1].file, None);
+    }
+
     #[test]
     fn test_request_params_with_headers() {
-        let mut headers = HashMap::new();
-        headers.insert("Content-Type".to_string(), "application/json".to_string());
-        headers.insert("Authorization".to_string(), "Bearer token123".to_string());
+        let mut headers = HttpHeaders::new();
+        headers.append("Content-Type", "application/json");
+        headers.append("Authorization", "Bearer token123");
 
         let params = RequestParams {
             url: "https://api.example.com/data".to_string(),
@@ -118,33 +1137,37 @@ mod tests {
             headers,
             body: Some(r#"{"key": "value"}"#.to_string()),
             timeout_ms: 5000,
+            ..Default::default()
         };
 
         assert_eq!(params.headers.len(), 2);
-        assert_eq!(
-            params.headers.get("Content-Type"),
-            Some(&"application/json".to_string())
-        );
+        assert_eq!(params.headers.get("Content-Type"), Some("application/json"));
         assert!(params.body.is_some());
     }
 
     #[test]
     fn test_http_response_serialization() {
-        let mut headers = HashMap::new();
-        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        let mut headers = HttpHeaders::new();
+        headers.append("Content-Type", "application/json");
+        headers.append("Set-Cookie", "a=1");
+        headers.append("Set-Cookie", "b=2");
 
         let response = HttpResponse {
             status: 200,
             status_text: "OK".to_string(),
             headers,
             body: r#"{"result": "success"}"#.to_string(),
+            body_kind: ResponseBodyKind::Json,
+            body_size: 21,
             timing: RequestTiming {
                 total_ms: 150,
                 dns_ms: Some(10),
                 connect_ms: Some(20),
                 tls_ms: Some(30),
                 first_byte_ms: Some(50),
+                ..Default::default()
             },
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&response).unwrap();
@@ -152,10 +1175,265 @@ mod tests {
 
         assert_eq!(parsed.status, 200);
         assert_eq!(parsed.status_text, "OK");
+        assert_eq!(
+            parsed.headers.get_all("set-cookie").collect::<Vec<_>>(),
+            vec!["a=1", "b=2"]
+        );
         assert_eq!(parsed.timing.total_ms, 150);
         assert_eq!(parsed.timing.dns_ms, Some(10));
     }
 
+    #[test]
+    fn test_http_response_without_body_metadata_deserializes() {
+        // History files written before binary body support lack the new fields
+        let json = r#"{"status":200,"status_text":"OK","headers":{"Content-Type":"text/plain"},"body":"hi","timing":{"total_ms":1,"dns_ms":null,"connect_ms":null,"tls_ms":null,"first_byte_ms":null}}"#;
+        let parsed: HttpResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(parsed.body, "hi");
+        assert_eq!(parsed.headers.get("content-type"), Some("text/plain"));
+        assert!(parsed.body_base64.is_none());
+        assert_eq!(parsed.body_kind, ResponseBodyKind::Text);
+        assert_eq!(parsed.body_bytes(), b"hi");
+        assert!(parsed.connection.is_none());
+        assert!(parsed.timing.download_ms.is_none());
+    }
+
+    #[test]
+    fn test_body_kind_from_content_type() {
+        assert_eq!(
+            ResponseBodyKind::from_content_type("application/json; charset=utf-8"),
+            ResponseBodyKind::Json
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("application/problem+json"),
+            ResponseBodyKind::Json
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("text/html"),
+            ResponseBodyKind::Html
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("text/plain"),
+            ResponseBodyKind::Text
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("image/png"),
+            ResponseBodyKind::Image
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("application/pdf"),
+            ResponseBodyKind::Binary
+        );
+        assert_eq!(
+            ResponseBodyKind::from_content_type("application/x-protobuf"),
+            ResponseBodyKind::Binary
+        );
+    }
+
+    #[test]
+    fn test_body_kind_detect_content_encoding_is_binary() {
+        let kind = ResponseBodyKind::detect(Some("application/json"), Some("gzip"), b"\x1f\x8b");
+        assert_eq!(kind, ResponseBodyKind::Binary);
+        let kind = ResponseBodyKind::detect(Some("application/json"), Some("identity"), b"{}");
+        assert_eq!(kind, ResponseBodyKind::Json);
+    }
+
+    #[test]
+    fn test_body_kind_detect_sniffs_untyped_bodies() {
+        assert_eq!(
+            ResponseBodyKind::detect(None, None, b""),
+            ResponseBodyKind::Empty
+        );
+        assert_eq!(
+            ResponseBodyKind::detect(None, None, b"\x89PNG\r\n\x1a\n...."),
+            ResponseBodyKind::Image
+        );
+        assert_eq!(
+            ResponseBodyKind::detect(None, None, b" {\"a\":1}"),
+            ResponseBodyKind::Json
+        );
+        assert_eq!(
+            ResponseBodyKind::detect(None, None, &[0x00, 0xff, 0x10]),
+            ResponseBodyKind::Binary
+        );
+    }
+
+    #[test]
+    fn test_tls_config_merge_prefers_overrides() {
+        let env = TlsConfig {
+            ca_bundle: Some("/etc/corp-ca.pem".to_string()),
+            client_cert: Some("/certs/env.pem".to_string()),
+            min_tls_version: Some(TlsVersion::Tls1_2),
+            ..Default::default()
+        };
+        let request = TlsConfig {
+            client_cert: Some("/certs/request.p12".to_string()),
+            min_tls_version: Some(TlsVersion::Tls1_3),
+            ..Default::default()
+        };
+
+        let merged = env.merged_with(&request);
+
+        assert_eq!(merged.client_cert.as_deref(), Some("/certs/request.p12"));
+        assert_eq!(merged.ca_bundle.as_deref(), Some("/etc/corp-ca.pem"));
+        assert_eq!(merged.min_tls_version, Some(TlsVersion::Tls1_3));
+        assert_eq!(merged.cert_format(), CertFormat::Pkcs12);
+    }
+
+    #[test]
+    fn test_tls_config_serialization() {
+        let tls = TlsConfig {
+            client_cert: Some("client.pem".to_string()),
+            insecure_skip_verify: true,
+            min_tls_version: Some(TlsVersion::Tls1_2),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&tls).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "client_cert": "client.pem",
+                "insecure_skip_verify": true,
+                "min_tls_version": "1.2"
+            })
+        );
+        assert_eq!(tls.cert_format(), CertFormat::Pem);
+    }
+
+    #[test]
+    fn test_proxy_resolve_explicit_url() {
+        let proxy = ProxyConfig {
+            url: Some("socks5h://proxy.corp:1080".to_string()),
+            no_proxy: vec!["localhost".to_string(), String::new()],
+            use_system: true,
+            ..Default::default()
+        };
+
+        let resolved = proxy
+            .resolve("https://api.example.com", |_| {
+                Some("http://ignored:3128".to_string())
+            })
+            .unwrap();
+
+        assert_eq!(resolved.url.as_deref(), Some("socks5h://proxy.corp:1080"));
+        assert_eq!(resolved.no_proxy, vec!["localhost".to_string()]);
+    }
+
+    #[test]
+    fn test_proxy_resolve_system_env() {
+        let env = |name: &str| match name {
+            "https_proxy" => Some("http://secure-proxy:3128".to_string()),
+            "HTTP_PROXY" => Some("http://plain-proxy:3128".to_string()),
+            "NO_PROXY" => Some("internal.corp, 10.0.0.0/8".to_string()),
+            _ => None,
+        };
+        let proxy = ProxyConfig {
+            use_system: true,
+            no_proxy: vec!["localhost".to_string()],
+            ..Default::default()
+        };
+
+        let https = proxy.resolve("https://api.example.com", env).unwrap();
+        assert_eq!(https.url.as_deref(), Some("http://secure-proxy:3128"));
+        assert_eq!(
+            https.no_proxy,
+            vec!["localhost", "internal.corp", "10.0.0.0/8"]
+        );
+
+        let http = proxy.resolve("http://api.example.com", env).unwrap();
+        assert_eq!(http.url.as_deref(), Some("http://plain-proxy:3128"));
+
+        // Without `use_system` the environment is ignored: direct connection.
+        let direct = ProxyConfig::default()
+            .resolve("https://api.example.com", env)
+            .unwrap();
+        assert_eq!(direct.url, None);
+    }
+
+    #[test]
+    fn test_proxy_resolve_rejects_unknown_scheme() {
+        let proxy = ProxyConfig {
+            url: Some("ftp://proxy:21".to_string()),
+            ..Default::default()
+        };
+        let err = proxy
+            .resolve("https://api.example.com", |_| None)
+            .unwrap_err();
+        assert!(err.contains("Unsupported proxy scheme 'ftp'"));
+
+        let proxy = ProxyConfig {
+            url: Some("proxy:3128".to_string()),
+            ..Default::default()
+        };
+        let err = proxy
+            .resolve("https://api.example.com", |_| None)
+            .unwrap_err();
+        assert!(err.contains("missing scheme"));
+    }
+
+    #[test]
+    fn test_resolve_override_entries() {
+        let pin = ResolveOverride {
+            host: "api.staging.example".to_string(),
+            port: None,
+            addresses: vec!["10.0.0.7".to_string(), "::1".to_string()],
+        };
+        assert_eq!(pin.validate(), Ok(()));
+        assert_eq!(
+            pin.curl_entry(443),
+            "api.staging.example:443:10.0.0.7,[::1]"
+        );
+        let pinned_port = ResolveOverride {
+            port: Some(8443),
+            ..pin.clone()
+        };
+        assert_eq!(
+            pinned_port.curl_entry(443),
+            "api.staging.example:8443:10.0.0.7,[::1]"
+        );
+
+        let not_ip = ResolveOverride {
+            addresses: vec!["backend-2".to_string()],
+            ..pin.clone()
+        };
+        assert!(
+            not_ip
+                .validate()
+                .unwrap_err()
+                .contains("expected an IP address")
+        );
+        let bad_host = ResolveOverride {
+            host: "api:443".to_string(),
+            ..pin
+        };
+        assert!(bad_host.validate().is_err());
+    }
+
+    #[test]
+    fn test_connect_to_override_entries() {
+        let all_hosts = ConnectToOverride {
+            to_host: Some("backend-2.internal".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(all_hosts.validate(), Ok(()));
+        assert_eq!(all_hosts.curl_entry(), "::backend-2.internal:");
+
+        let port_only = ConnectToOverride {
+            host: Some("api.staging.example".to_string()),
+            port: Some(443),
+            to_host: Some("fe80::1".to_string()),
+            to_port: Some(8443),
+        };
+        assert_eq!(port_only.validate(), Ok(()));
+        assert_eq!(
+            port_only.curl_entry(),
+            "api.staging.example:443:[fe80::1]:8443"
+        );
+
+        assert!(ConnectToOverride::default().validate().is_err());
+    }
+
     #[test]
     fn test_request_timing_default() {
         let timing = RequestTiming::default();
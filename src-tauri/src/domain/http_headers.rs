@@ -0,0 +1,206 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// Ordered, multi-value HTTP header list
+
+use std::fmt;
+
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[cfg(test)]
+use ts_rs::TS;
+
+/// A single HTTP header field.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct HttpHeader {
+    /// Header name as sent or received (case preserved).
+    pub name: String,
+    /// Header value.
+    pub value: String,
+}
+
+/// HTTP header fields in wire order, keeping repeated names such as
+/// `Set-Cookie`, `Link`, `Vary` or `WWW-Authenticate`.
+///
+/// Serialized as a list of `{ name, value }` fields. An object mapping names
+/// to values (the format used before repeated headers were kept) is still
+/// accepted, so older history files load unchanged.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(transparent)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct HttpHeaders(Vec<HttpHeader>);
+
+impl HttpHeaders {
+    /// Create an empty header list.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Append a header field, keeping any existing fields with the same name.
+    pub fn append(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.push(HttpHeader {
+            name: name.into(),
+            value: value.into(),
+        });
+    }
+
+    /// Value of the first field named `name` (case-insensitive).
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    /// Values of all fields named `name` (case-insensitive), in order.
+    pub fn get_all<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a str> {
+        self.0
+            .iter()
+            .filter(move |header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+
+    /// Whether a field named `name` is present (case-insensitive).
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Iterate over the fields in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, HttpHeader> {
+        self.0.iter()
+    }
+
+    /// Number of header fields, counting repeated names separately.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no header fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for HttpHeaders {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut headers = Self::new();
+        for (name, value) in iter {
+            headers.append(name, value);
+        }
+        headers
+    }
+}
+
+impl<K: Into<String>, V: Into<String>, const N: usize> From<[(K, V); N]> for HttpHeaders {
+    fn from(fields: [(K, V); N]) -> Self {
+        fields.into_iter().collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a HttpHeaders {
+    type Item = &'a HttpHeader;
+    type IntoIter = std::slice::Iter<'a, HttpHeader>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for HttpHeaders {
+    type Item = HttpHeader;
+    type IntoIter = std::vec::IntoIter<HttpHeader>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpHeaders {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HeadersVisitor;
+
+        impl<'de> Visitor<'de> for HeadersVisitor {
+            type Value = HttpHeaders;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a list of { name, value } headers or a map of header names to values")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut headers = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(header) = seq.next_element::<HttpHeader>()? {
+                    headers.push(header);
+                }
+                Ok(HttpHeaders(headers))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut headers = HttpHeaders::new();
+                while let Some((name, value)) = map.next_entry::<String, String>()? {
+                    headers.append(name, value);
+                }
+                Ok(headers)
+            }
+        }
+
+        deserializer.deserialize_any(HeadersVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_headers_keep_order() {
+        let headers: HttpHeaders = [
+            ("Set-Cookie", "a=1"),
+            ("Content-Type", "text/plain"),
+            ("set-cookie", "b=2"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers.get("content-type"), Some("text/plain"));
+        assert_eq!(
+            headers.get_all("SET-COOKIE").collect::<Vec<_>>(),
+            vec!["a=1", "b=2"]
+        );
+        assert!(!headers.contains("Link"));
+    }
+
+    #[test]
+    fn test_serializes_as_ordered_list() {
+        let headers = HttpHeaders::from([("Link", "</a>; rel=next"), ("Link", "</z>; rel=last")]);
+
+        let json = serde_json::to_value(&headers).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"name": "Link", "value": "</a>; rel=next"},
+                {"name": "Link", "value": "</z>; rel=last"}
+            ])
+        );
+        let parsed: HttpHeaders = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, headers);
+    }
+
+    #[test]
+    fn test_deserializes_legacy_map_format() {
+        let yaml = "Content-Type: application/json\nX-Request-Id: abc\n";
+        let headers: HttpHeaders = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get("content-type"), Some("application/json"));
+        assert_eq!(headers.get("x-request-id"), Some("abc"));
+
+        let empty: HttpHeaders = serde_json::from_str("{}").unwrap();
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// Streaming response models: incremental SSE / NDJSON decoding and live stream events
+
+use serde::{Deserialize, Serialize};
+
+use super::http_headers::HttpHeaders;
+
+#[cfg(test)]
+use ts_rs::TS;
+
+/// Wire format of a streamed response body.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum StreamFormat {
+    /// Server-Sent Events (`text/event-stream`).
+    Sse,
+    /// Newline-delimited JSON (`application/x-ndjson`, `application/jsonl`, ...).
+    Ndjson,
+    /// Anything else; each received chunk becomes one message.
+    #[default]
+    Raw,
+}
+
+impl StreamFormat {
+    /// Pick the stream format from a response `Content-Type` header value.
+    #[must_use]
+    pub fn from_content_type(content_type: Option<&str>) -> Self {
+        let mime = content_type
+            .and_then(|ct| ct.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match mime.as_str() {
+            "text/event-stream" => Self::Sse,
+            "application/x-ndjson"
+            | "application/ndjson"
+            | "application/jsonl"
+            | "application/x-jsonlines"
+            | "application/json-seq"
+            | "application/stream+json" => Self::Ndjson,
+            _ => Self::Raw,
+        }
+    }
+}
+
+/// A single message decoded from a streamed response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct StreamMessage {
+    /// Zero-based position of the message in the stream.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub seq: u64,
+    /// Milliseconds since the request started when the message arrived.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub elapsed_ms: u64,
+    /// SSE `event:` field, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub event: Option<String>,
+    /// SSE `id:` field (last event ID), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub id: Option<String>,
+    /// Message payload: SSE data, one NDJSON line, or a raw chunk.
+    pub data: String,
+}
+
+/// Messages received while streaming a response, kept with the history entry.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct StreamTranscript {
+    /// Format the body was decoded as.
+    pub format: StreamFormat,
+    /// Decoded messages in arrival order.
+    pub messages: Vec<StreamMessage>,
+}
+
+/// Live update for a request executed in streaming mode.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StreamUpdate {
+    /// Response headers arrived; the body is about to stream.
+    Started {
+        /// HTTP status code.
+        status: u16,
+        /// HTTP status text.
+        status_text: String,
+        /// Response headers, in wire order.
+        headers: HttpHeaders,
+        /// Format the body is decoded as.
+        format: StreamFormat,
+    },
+    /// A message was decoded from the body.
+    Message {
+        /// The decoded message.
+        message: StreamMessage,
+    },
+    /// The stream closed normally.
+    Finished {
+        /// Total request duration in milliseconds.
+        #[cfg_attr(test, ts(type = "number"))]
+        total_ms: u64,
+        /// Number of messages received.
+        #[cfg_attr(test, ts(type = "number"))]
+        message_count: u64,
+    },
+    /// The request failed or was cancelled mid-stream.
+    Failed {
+        /// Error description.
+        error: String,
+    },
+}
+
+impl StreamUpdate {
+    /// Event name used for Tauri events and SSE topics (e.g. `stream:message`).
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        match self {
+            Self::Started { .. } => "stream:started",
+            Self::Message { .. } => "stream:message",
+            Self::Finished { .. } => "stream:finished",
+            Self::Failed { .. } => "stream:failed",
+        }
+    }
+}
+
+/// A [`StreamUpdate`] tagged with the correlation ID of its request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HttpStreamEvent {
+    /// Correlation ID of the streaming request.
+    pub correlation_id: String,
+    /// The update itself.
+    #[serde(flatten)]
+    pub update: StreamUpdate,
+}
+
+/// Incremental decoder turning streamed body bytes into [`StreamMessage`]s.
+///
+/// Bytes may be split anywhere (mid-line, mid-UTF-8 sequence); incomplete
+/// input is buffered until the next `feed` or the final `finish`.
+#[derive(Debug)]
+pub struct StreamDecoder {
+    format: StreamFormat,
+    buffer: Vec<u8>,
+    next_seq: u64,
+    /// Pending SSE `event:` field for the message being assembled.
+    event: Option<String>,
+    /// Last SSE event ID seen; persists across messages per the SSE spec.
+    last_id: Option<String>,
+    /// Pending SSE `data:` lines for the message being assembled.
+    data: Vec<String>,
+}
+
+impl StreamDecoder {
+    /// Create a decoder for the given format.
+    #[must_use]
+    pub const fn new(format: StreamFormat) -> Self {
+        Self {
+            format,
+            buffer: Vec::new(),
+            next_seq: 0,
+            event: None,
+            last_id: None,
+            data: Vec::new(),
+        }
+    }
+
+    /// Format this decoder parses.
+    #[must_use]
+    pub const fn format(&self) -> StreamFormat {
+        self.format
+    }
+
+    /// Feed a chunk of body bytes, returning every message it completes.
+    pub fn feed(&mut self, bytes: &[u8], elapsed_ms: u64) -> Vec<StreamMessage> {
+        self.buffer.extend_from_slice(bytes);
+        match self.format {
+            StreamFormat::Raw => self.take_raw(elapsed_ms, false),
+            StreamFormat::Sse | StreamFormat::Ndjson => {
+                let mut messages = Vec::new();
+                while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+                    self.process_line(&line[..pos], elapsed_ms, &mut messages);
+                }
+                messages
+            }
+        }
+    }
+
+    /// Flush whatever is still buffered once the body is complete.
+    pub fn finish(&mut self, elapsed_ms: u64) -> Vec<StreamMessage> {
+        let mut messages = Vec::new();
+        match self.format {
+            StreamFormat::Raw => return self.take_raw(elapsed_ms, true),
+            StreamFormat::Sse | StreamFormat::Ndjson => {
+                if !self.buffer.is_empty() {
+                    let line = std::mem::take(&mut self.buffer);
+                    self.process_line(&line, elapsed_ms, &mut messages);
+                }
+                if self.format == StreamFormat::Sse {
+                    // A final event without its terminating blank line is still delivered
+                    self.dispatch_sse(elapsed_ms, &mut messages);
+                }
+            }
+        }
+        messages
+    }
+
+    fn process_line(&mut self, line: &[u8], elapsed_ms: u64, messages: &mut Vec<StreamMessage>) {
+        let line = String::from_utf8_lossy(line);
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+        match self.format {
+            StreamFormat::Ndjson => {
+                if !line.trim().is_empty() {
+                    let message = self.message(None, elapsed_ms, line.to_string());
+                    messages.push(message);
+                }
+            }
+            StreamFormat::Sse => self.process_sse_line(line, elapsed_ms, messages),
+            StreamFormat::Raw => {}
+        }
+    }
+
+    fn process_sse_line(&mut self, line: &str, elapsed_ms: u64, messages: &mut Vec<StreamMessage>) {
+        if line.is_empty() {
+            self.dispatch_sse(elapsed_ms, messages);
+            return;
+        }
+        if line.starts_with(':') {
+            // Comment / keep-alive
+            return;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => self.data.push(value.to_string()),
+            "event" => self.event = Some(value.to_string()),
+            "id" if !value.contains('\0') => self.last_id = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn dispatch_sse(&mut self, elapsed_ms: u64, messages: &mut Vec<StreamMessage>) {
+        let event = self.event.take();
+        if self.data.is_empty() {
+            return;
+        }
+        let data = std::mem::take(&mut self.data).join("\n");
+        let mut message = self.message(event, elapsed_ms, data);
+        message.id.clone_from(&self.last_id);
+        messages.push(message);
+    }
+
+    fn take_raw(&mut self, elapsed_ms: u64, flush: bool) -> Vec<StreamMessage> {
+        // Keep an incomplete trailing UTF-8 sequence for the next chunk
+        let complete = match std::str::from_utf8(&self.buffer) {
+            Err(e) if e.error_len().is_none() && !flush => e.valid_up_to(),
+            _ => self.buffer.len(),
+        };
+        if complete == 0 {
+            return Vec::new();
+        }
+        let chunk: Vec<u8> = self.buffer.drain(..complete).collect();
+        let data = String::from_utf8_lossy(&chunk).into_owned();
+        vec![self.message(None, elapsed_ms, data)]
+    }
+
+    const fn message(
+        &mut self,
+        event: Option<String>,
+        elapsed_ms: u64,
+        data: String,
+    ) -> StreamMessage {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        StreamMessage {
+            seq,
+            elapsed_ms,
+            event,
+            id: None,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(messages: &[StreamMessage]) -> Vec<&str> {
+        messages.iter().map(|m| m.data.as_str()).collect()
+    }
+
+    #[test]
+    fn test_format_from_content_type() {
+        assert_eq!(
+            StreamFormat::from_content_type(Some("text/event-stream; charset=utf-8")),
+            StreamFormat::Sse
+        );
+        assert_eq!(
+            StreamFormat::from_content_type(Some("application/x-ndjson")),
+            StreamFormat::Ndjson
+        );
+        assert_eq!(
+            StreamFormat::from_content_type(Some("application/json")),
+            StreamFormat::Raw
+        );
+        assert_eq!(StreamFormat::from_content_type(None), StreamFormat::Raw);
+    }
+
+    #[test]
+    fn test_sse_events_split_across_chunks() {
+        let mut decoder = StreamDecoder::new(StreamFormat::Sse);
+
+        let first = decoder.feed(b"event: token\r\nid: 1\r\ndata: Hel", 5);
+        assert!(first.is_empty());
+        let second = decoder.feed(b"lo\r\n\r\n: keep-alive\n\ndata: a\ndata: b\n\n", 9);
+
+        assert_eq!(data(&second), vec!["Hello", "a\nb"]);
+        assert_eq!(second[0].event.as_deref(), Some("token"));
+        assert_eq!(second[0].id.as_deref(), Some("1"));
+        assert_eq!(second[0].elapsed_ms, 9);
+        // Event name resets per message, last event ID persists
+        assert_eq!(second[1].event, None);
+        assert_eq!(second[1].id.as_deref(), Some("1"));
+        assert_eq!(second[1].seq, 1);
+    }
+
+    #[test]
+    fn test_sse_finish_flushes_unterminated_event() {
+        let mut decoder = StreamDecoder::new(StreamFormat::Sse);
+        assert!(decoder.feed(b"data: [DONE]", 1).is_empty());
+        assert_eq!(data(&decoder.finish(2)), vec!["[DONE]"]);
+    }
+
+    #[test]
+    fn test_ndjson_lines() {
+        let mut decoder = StreamDecoder::new(StreamFormat::Ndjson);
+        let messages = decoder.feed(b"{\"a\":1}\n\n{\"b\"", 1);
+        assert_eq!(data(&messages), vec!["{\"a\":1}"]);
+        let messages = decoder.feed(b":2}\n{\"c\":3}", 2);
+        assert_eq!(data(&messages), vec!["{\"b\":2}"]);
+        assert_eq!(data(&decoder.finish(3)), vec!["{\"c\":3}"]);
+    }
+
+    #[test]
+    fn test_raw_keeps_split_utf8_sequence() {
+        let mut decoder = StreamDecoder::new(StreamFormat::Raw);
+        let euro = "€".as_bytes();
+        let messages = decoder.feed(&[b'a', euro[0]], 1);
+        assert_eq!(data(&messages), vec!["a"]);
+        let messages = decoder.feed(&euro[1..], 2);
+        assert_eq!(data(&messages), vec!["€"]);
+        assert!(decoder.finish(3).is_empty());
+    }
+
+    #[test]
+    fn test_stream_event_serializes_flat() {
+        let event = HttpStreamEvent {
+            correlation_id: "c1".to_string(),
+            update: StreamUpdate::Finished {
+                total_ms: 10,
+                message_count: 2,
+            },
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["correlation_id"], "c1");
+        assert_eq!(json["kind"], "finished");
+        assert_eq!(json["message_count"], 2);
+        assert_eq!(event.update.event_type(), "stream:finished");
+    }
+}
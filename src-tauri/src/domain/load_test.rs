@@ -0,0 +1,518 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Load tests: one request sent repeatedly by concurrent workers.
+//!
+//! This module holds the options, the sample accumulator and the statistics
+//! it produces (throughput, error rate, status distribution and latency
+//! percentiles per timing phase). The workers live in the application layer.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+#[cfg(test)]
+use ts_rs::TS;
+
+use super::http::HttpResponse;
+
+/// Upper bound for [`LoadTestOptions::concurrency`].
+pub const MAX_CONCURRENCY: u32 = 1_000;
+
+/// How a load test is run.
+///
+/// At least one of `iterations` and `duration_ms` must be set; with both, the
+/// test stops at whichever limit is reached first.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct LoadTestOptions {
+    /// Total number of requests to send.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub iterations: Option<u32>,
+    /// How long to keep sending requests, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub duration_ms: Option<u64>,
+    /// Number of workers sending requests at the same time (default: 1).
+    #[serde(default = "default_concurrency")]
+    #[cfg_attr(test, ts(as = "Option<u32>", optional))]
+    pub concurrency: u32,
+    /// Time over which workers are started one after another, in
+    /// milliseconds (default: 0, all at once).
+    #[serde(default)]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub ramp_up_ms: u64,
+    /// Timeout for each request, in milliseconds (default: 30000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub timeout_ms: Option<u64>,
+    /// Interval between live progress updates, in milliseconds (default: 1000).
+    #[serde(default = "default_progress_interval_ms")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub progress_interval_ms: u64,
+}
+
+impl Default for LoadTestOptions {
+    fn default() -> Self {
+        Self {
+            iterations: None,
+            duration_ms: None,
+            concurrency: default_concurrency(),
+            ramp_up_ms: 0,
+            timeout_ms: None,
+            progress_interval_ms: default_progress_interval_ms(),
+        }
+    }
+}
+
+impl LoadTestOptions {
+    /// Check that the options describe a test that ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither `iterations` nor `duration_ms` is set, if
+    /// either is zero, or if `concurrency` is outside `1..=MAX_CONCURRENCY`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.iterations.is_none() && self.duration_ms.is_none() {
+            return Err("Set iterations or duration_ms for the load test".to_string());
+        }
+        if self.iterations == Some(0) || self.duration_ms == Some(0) {
+            return Err("iterations and duration_ms must be greater than 0".to_string());
+        }
+        if !(1..=MAX_CONCURRENCY).contains(&self.concurrency) {
+            return Err(format!(
+                "concurrency must be between 1 and {MAX_CONCURRENCY}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Delay before worker `worker` (zero-based) starts, spreading the
+    /// workers evenly over the ramp-up.
+    #[must_use]
+    pub fn start_delay(&self, worker: u32) -> Duration {
+        if self.concurrency <= 1 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(self.ramp_up_ms * u64::from(worker) / u64::from(self.concurrency))
+    }
+}
+
+const fn default_concurrency() -> u32 {
+    1
+}
+
+const fn default_progress_interval_ms() -> u64 {
+    1_000
+}
+
+/// Latency distribution of one timing phase, in milliseconds.
+///
+/// Percentiles use the nearest-rank method.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct LatencyStats {
+    /// Number of samples.
+    pub count: u32,
+    /// Fastest sample.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub min_ms: u64,
+    /// Arithmetic mean, rounded down.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub mean_ms: u64,
+    /// Median.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub p50_ms: u64,
+    /// 90th percentile.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub p90_ms: u64,
+    /// 99th percentile.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub p99_ms: u64,
+    /// Slowest sample.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub max_ms: u64,
+}
+
+impl LatencyStats {
+    /// Compute the distribution of `samples`, which get sorted in place.
+    /// Returns `None` when there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &mut [u64]) -> Option<Self> {
+        let (&min_ms, &max_ms) = {
+            samples.sort_unstable();
+            (samples.first()?, samples.last()?)
+        };
+        let len = samples.len();
+        let sum: u128 = samples.iter().map(|&ms| u128::from(ms)).sum();
+        let percentile = |p: usize| samples[(p * len).div_ceil(100).max(1) - 1];
+        Some(Self {
+            count: u32::try_from(len).unwrap_or(u32::MAX),
+            min_ms,
+            mean_ms: u64::try_from(sum / len as u128).unwrap_or(u64::MAX),
+            p50_ms: percentile(50),
+            p90_ms: percentile(90),
+            p99_ms: percentile(99),
+            max_ms,
+        })
+    }
+}
+
+/// Latency distributions for each phase of [`RequestTiming`].
+///
+/// A phase is absent when no response reported it (e.g. `tls` for plain HTTP).
+///
+/// [`RequestTiming`]: super::http::RequestTiming
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct PhaseLatencies {
+    /// Total request duration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub total: Option<LatencyStats>,
+    /// DNS resolution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub dns: Option<LatencyStats>,
+    /// TCP connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub connect: Option<LatencyStats>,
+    /// TLS handshake.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub tls: Option<LatencyStats>,
+    /// Time to first byte.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub first_byte: Option<LatencyStats>,
+    /// Connection ready to last byte received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub transfer: Option<LatencyStats>,
+    /// First to last response byte.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub download: Option<LatencyStats>,
+}
+
+/// Aggregate results of a load test, so far or in total.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct LoadTestStats {
+    /// Requests completed, with or without a response.
+    pub requests: u32,
+    /// Responses with a status below 400.
+    pub succeeded: u32,
+    /// Responses with a 4xx or 5xx status.
+    pub failed: u32,
+    /// Requests that got no response (connection errors, timeouts).
+    pub errored: u32,
+    /// Share of requests that failed or errored, from 0 to 1.
+    pub error_rate: f64,
+    /// Completed requests per second of elapsed time.
+    pub throughput_rps: f64,
+    /// Time since the test started, in milliseconds.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub elapsed_ms: u64,
+    /// Number of responses per status code.
+    pub status_codes: BTreeMap<u16, u32>,
+    /// Number of errored requests per error code.
+    pub errors: BTreeMap<String, u32>,
+    /// Latency per timing phase, over requests that got a response.
+    pub latency: PhaseLatencies,
+}
+
+/// Raw samples collected while a load test runs.
+#[derive(Debug, Clone, Default)]
+pub struct LoadTestSamples {
+    succeeded: u32,
+    failed: u32,
+    status_codes: BTreeMap<u16, u32>,
+    errors: BTreeMap<String, u32>,
+    total: Vec<u64>,
+    dns: Vec<u64>,
+    connect: Vec<u64>,
+    tls: Vec<u64>,
+    first_byte: Vec<u64>,
+    transfer: Vec<u64>,
+    download: Vec<u64>,
+}
+
+impl LoadTestSamples {
+    /// Record a request that got a response.
+    pub fn record_response(&mut self, response: &HttpResponse) {
+        if response.status < 400 {
+            self.succeeded += 1;
+        } else {
+            self.failed += 1;
+        }
+        *self.status_codes.entry(response.status).or_default() += 1;
+        let timing = &response.timing;
+        self.total.push(timing.total_ms);
+        let phases = [
+            (&mut self.dns, timing.dns_ms),
+            (&mut self.connect, timing.connect_ms),
+            (&mut self.tls, timing.tls_ms),
+            (&mut self.first_byte, timing.first_byte_ms),
+            (&mut self.transfer, timing.transfer_ms),
+            (&mut self.download, timing.download_ms),
+        ];
+        for (samples, ms) in phases {
+            samples.extend(ms);
+        }
+    }
+
+    /// Record a request that failed without a response, by error code.
+    pub fn record_error(&mut self, code: &str) {
+        *self.errors.entry(code.to_string()).or_default() += 1;
+    }
+
+    /// Number of requests recorded so far.
+    #[must_use]
+    pub fn requests(&self) -> u32 {
+        self.succeeded + self.failed + self.errored()
+    }
+
+    fn errored(&self) -> u32 {
+        self.errors.values().sum()
+    }
+
+    /// Statistics over the samples recorded within `elapsed`, sorting the
+    /// samples in place.
+    #[must_use]
+    pub fn into_stats(mut self, elapsed: Duration) -> LoadTestStats {
+        let requests = self.requests();
+        let errored = self.errored();
+        let latency = |samples: &mut Vec<u64>| LatencyStats::from_samples(samples);
+        let seconds = elapsed.as_secs_f64();
+        LoadTestStats {
+            requests,
+            succeeded: self.succeeded,
+            failed: self.failed,
+            errored,
+            error_rate: if requests == 0 {
+                0.0
+            } else {
+                f64::from(self.failed + errored) / f64::from(requests)
+            },
+            throughput_rps: if seconds > 0.0 {
+                f64::from(requests) / seconds
+            } else {
+                0.0
+            },
+            elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+            latency: PhaseLatencies {
+                total: latency(&mut self.total),
+                dns: latency(&mut self.dns),
+                connect: latency(&mut self.connect),
+                tls: latency(&mut self.tls),
+                first_byte: latency(&mut self.first_byte),
+                transfer: latency(&mut self.transfer),
+                download: latency(&mut self.download),
+            },
+            status_codes: self.status_codes,
+            errors: self.errors,
+        }
+    }
+}
+
+/// Result of a load test, saved as a run artifact.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct LoadTestReport {
+    /// Load test ID; also the correlation ID of its requests.
+    pub load_test_id: String,
+    /// ID of the collection holding the request.
+    pub collection_id: String,
+    /// ID of the request that was sent.
+    pub request_id: String,
+    /// Name of the request.
+    pub request_name: String,
+    /// HTTP method.
+    pub method: String,
+    /// URL as sent, with variables resolved.
+    pub url: String,
+    /// RFC 3339 UTC timestamp of the start of the test.
+    pub started_at: String,
+    /// Options the test ran with.
+    pub options: LoadTestOptions,
+    /// Whether the test was stopped with `cancel_request` before its end.
+    pub cancelled: bool,
+    /// Final statistics.
+    pub stats: LoadTestStats,
+}
+
+/// Live update of a running load test.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LoadTestUpdate {
+    /// The test started.
+    Started {
+        /// URL being tested.
+        url: String,
+        /// Options the test runs with.
+        options: LoadTestOptions,
+    },
+    /// Statistics so far, sent every `progress_interval_ms`.
+    Progress {
+        /// Workers started and not yet done.
+        active_workers: u32,
+        /// Statistics over the requests completed so far.
+        stats: LoadTestStats,
+    },
+    /// The test is over.
+    Finished {
+        /// Whether the test was cancelled.
+        cancelled: bool,
+        /// Final statistics.
+        stats: LoadTestStats,
+    },
+}
+
+impl LoadTestUpdate {
+    /// Event name used for SSE topics (e.g. `load_test:progress`).
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        match self {
+            Self::Started { .. } => "load_test:started",
+            Self::Progress { .. } => "load_test:progress",
+            Self::Finished { .. } => "load_test:finished",
+        }
+    }
+}
+
+/// A [`LoadTestUpdate`] tagged with the ID of its test.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoadTestEvent {
+    /// ID of the load test.
+    pub load_test_id: String,
+    /// The update itself.
+    #[serde(flatten)]
+    pub update: LoadTestUpdate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::http::RequestTiming;
+
+    fn response(status: u16, total_ms: u64, tls_ms: Option<u64>) -> HttpResponse {
+        HttpResponse {
+            status,
+            timing: RequestTiming {
+                total_ms,
+                dns_ms: Some(1),
+                tls_ms,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_options_need_an_end() {
+        assert!(LoadTestOptions::default().validate().is_err());
+        let options = LoadTestOptions {
+            iterations: Some(10),
+            ..Default::default()
+        };
+        assert!(options.validate().is_ok());
+        let options = LoadTestOptions {
+            duration_ms: Some(5_000),
+            concurrency: MAX_CONCURRENCY + 1,
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+        let options = LoadTestOptions {
+            iterations: Some(0),
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn test_ramp_up_spreads_worker_starts() {
+        let options = LoadTestOptions {
+            concurrency: 4,
+            ramp_up_ms: 2_000,
+            ..Default::default()
+        };
+        let delays: Vec<u128> = (0..4).map(|w| options.start_delay(w).as_millis()).collect();
+        assert_eq!(delays, vec![0, 500, 1_000, 1_500]);
+        assert_eq!(LoadTestOptions::default().start_delay(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_percentiles_use_nearest_rank() {
+        let mut samples: Vec<u64> = (1..=100).rev().collect();
+        let stats = LatencyStats::from_samples(&mut samples).unwrap();
+        assert_eq!(
+            (
+                stats.min_ms,
+                stats.p50_ms,
+                stats.p90_ms,
+                stats.p99_ms,
+                stats.max_ms
+            ),
+            (1, 50, 90, 99, 100)
+        );
+        assert_eq!(stats.mean_ms, 50);
+        assert_eq!(stats.count, 100);
+
+        let single = LatencyStats::from_samples(&mut [42]).unwrap();
+        assert_eq!((single.p50_ms, single.p99_ms), (42, 42));
+        assert!(LatencyStats::from_samples(&mut []).is_none());
+    }
+
+    #[test]
+    fn test_stats_count_statuses_errors_and_phases() {
+        let mut samples = LoadTestSamples::default();
+        samples.record_response(&response(200, 10, None));
+        samples.record_response(&response(200, 30, None));
+        samples.record_response(&response(503, 20, Some(5)));
+        samples.record_error("HTTP_REQUEST_FAILED");
+
+        let stats = samples.into_stats(Duration::from_secs(2));
+
+        assert_eq!(
+            (stats.requests, stats.succeeded, stats.failed, stats.errored),
+            (4, 2, 1, 1)
+        );
+        assert!((stats.error_rate - 0.5).abs() < f64::EPSILON);
+        assert!((stats.throughput_rps - 2.0).abs() < f64::EPSILON);
+        assert_eq!(stats.status_codes.get(&200), Some(&2));
+        assert_eq!(stats.status_codes.get(&503), Some(&1));
+        assert_eq!(stats.errors.get("HTTP_REQUEST_FAILED"), Some(&1));
+        let total = stats.latency.total.unwrap();
+        assert_eq!((total.count, total.p50_ms, total.max_ms), (3, 20, 30));
+        assert_eq!(stats.latency.dns.unwrap().count, 3);
+        assert_eq!(stats.latency.tls.unwrap().count, 1);
+        assert!(stats.latency.connect.is_none());
+    }
+
+    #[test]
+    fn test_event_flattens_update() {
+        let event = LoadTestEvent {
+            load_test_id: "lt_1".to_string(),
+            update: LoadTestUpdate::Finished {
+                cancelled: false,
+                stats: LoadTestStats::default(),
+            },
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["load_test_id"], "lt_1");
+        assert_eq!(json["kind"], "finished");
+        assert_eq!(event.update.event_type(), "load_test:finished");
+        let back: LoadTestEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(back, event);
+    }
+}
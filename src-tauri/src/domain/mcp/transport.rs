@@ -46,6 +46,7 @@ mod tests {
     fn assert_send_sync<T: Send + Sync>() {}
 
     #[test]
+    #[cfg(feature = "desktop")]
     fn test_transport_is_send_sync() {
         // Compile-time check: Transport requires Send + Sync
         fn check<T: Transport>() {
@@ -3,19 +3,45 @@
 
 // Domain layer - Core business logic and models
 
+/// Declarative response assertions evaluated on every execution.
+pub mod assertion;
 /// Canvas state domain models and snapshots.
 pub mod canvas_state;
+/// Peer certificate chains of HTTPS responses.
+pub mod certificates;
 /// Collection domain models.
 pub mod collection;
+/// Cookie jar models scoped to a collection and environment.
+pub mod cookies;
+/// Structured errors with correlation IDs, shared with the frontend.
 pub mod errors;
+/// Response value extraction into variables for request chaining.
+pub mod extraction;
+/// Feature flag configuration.
 pub mod features;
+/// GraphQL requests, schemas from introspection and query validation.
+pub mod graphql;
+/// HTTP request parameters, responses and timing.
 pub mod http;
+/// Ordered, multi-value HTTP header list.
+pub mod http_headers;
+/// Streaming response decoding and live stream events.
+pub mod http_stream;
+/// Load test options, live updates and latency statistics.
+pub mod load_test;
 /// MCP domain models and protocol types.
 pub mod mcp;
+/// Greeting response of the `hello_world` smoke-test command.
 pub mod models;
 /// Participant identity and Lamport timestamps for multiplayer-like provenance.
 pub mod participant;
 /// Project context — persistent user working state for MCP and AI agents.
 pub mod project_context;
+/// Retry policy and attempt records for request execution.
+pub mod retry;
 /// AI suggestion domain models for the Vigilance Monitor.
 pub mod suggestion;
+/// Template variables: placeholders, dynamic values, defaults and nesting.
+pub mod variables;
+/// WebSocket framing, session transcripts and live session events.
+pub mod websocket;
@@ -5,13 +5,18 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Greeting returned by the `hello_world` command.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HelloWorldResponse {
+    /// Greeting text.
     pub message: String,
+    /// Unix time in seconds when the greeting was created.
     pub timestamp: u64,
 }
 
 impl HelloWorldResponse {
+    /// Create a greeting stamped with the current time.
+    #[must_use]
     pub fn new(message: String) -> Self {
         Self {
             message,
@@ -0,0 +1,257 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// Retry policy for request execution: which failures to retry and how long to wait
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(test)]
+use ts_rs::TS;
+
+/// When and how often a failed request is tried again.
+///
+/// Configured per request on `RequestParams::retry`, or on a collection and
+/// its requests, where the request's policy wins.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first (default: 3).
+    #[serde(default = "default_max_attempts")]
+    #[cfg_attr(test, ts(as = "Option<u32>", optional))]
+    pub max_attempts: u32,
+    /// Wait before the first retry, doubled for every further retry (default: 500).
+    #[serde(default = "default_initial_delay_ms")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub initial_delay_ms: u64,
+    /// Upper bound for any single wait, including one asked for by
+    /// `Retry-After` (default: 30000).
+    #[serde(default = "default_max_delay_ms")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub max_delay_ms: u64,
+    /// Randomize each backoff wait between half and all of its value (default: true).
+    #[serde(default = "default_true")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub jitter: bool,
+    /// Response statuses that are retried (default: 429, 502, 503, 504).
+    #[serde(default = "default_retry_statuses")]
+    #[cfg_attr(test, ts(as = "Option<Vec<u16>>", optional))]
+    pub retry_on_status: Vec<u16>,
+    /// Retry when the connection fails or breaks mid-transfer (default: true).
+    #[serde(default = "default_true")]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub retry_on_connection_error: bool,
+    /// Also retry methods that are not idempotent, such as POST and PATCH
+    /// (default: false).
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<bool>", optional))]
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_max_attempts(),
+            initial_delay_ms: default_initial_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            jitter: true,
+            retry_on_status: default_retry_statuses(),
+            retry_on_connection_error: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether requests with `method` may be retried under this policy.
+    #[must_use]
+    pub fn allows_method(&self, method: &str) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    /// Whether a response with `status` should be retried.
+    #[must_use]
+    pub fn retries_status(&self, status: u16) -> bool {
+        self.retry_on_status.contains(&status)
+    }
+
+    /// Wait before retry number `retry` (1 for the first retry).
+    ///
+    /// A `retry_after` from the server replaces the exponential backoff.
+    /// `random` is a sample from `[0, 1)` used for jitter. The result never
+    /// exceeds `max_delay_ms`.
+    #[must_use]
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>, random: f64) -> Duration {
+        let max = Duration::from_millis(self.max_delay_ms);
+        if let Some(wait) = retry_after {
+            return wait.min(max);
+        }
+        let backoff = Duration::from_millis(self.initial_delay_ms)
+            .saturating_mul(2_u32.saturating_pow(retry.saturating_sub(1)))
+            .min(max);
+        if self.jitter {
+            backoff.mul_f64(0.5 + random.clamp(0.0, 1.0) / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// One try of a request executed under a [`RetryPolicy`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct RetryAttempt {
+    /// Attempt number, starting at 1.
+    pub attempt: u32,
+    /// Response status, when the attempt got a response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub status: Option<u16>,
+    /// Error code and message, when the attempt failed without a response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub error: Option<String>,
+    /// How long the attempt took, in milliseconds.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub duration_ms: u64,
+    /// Wait before the next attempt, in milliseconds. Absent on the last attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(type = "number", optional))]
+    pub retry_delay_ms: Option<u64>,
+}
+
+/// Whether `method` is idempotent (RFC 9110 §9.2.2), so repeating it is safe.
+#[must_use]
+pub fn is_idempotent(method: &str) -> bool {
+    ["GET", "HEAD", "OPTIONS", "TRACE", "PUT", "DELETE"]
+        .iter()
+        .any(|idempotent| method.eq_ignore_ascii_case(idempotent))
+}
+
+/// Parse a `Retry-After` value: delay seconds or an HTTP date.
+///
+/// Dates in the past mean "now" and yield a zero wait.
+#[must_use]
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+const fn default_max_attempts() -> u32 {
+    3
+}
+
+const fn default_initial_delay_ms() -> u64 {
+    500
+}
+
+const fn default_max_delay_ms() -> u64 {
+    30_000
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+fn default_retry_statuses() -> Vec<u16> {
+    vec![429, 502, 503, 504]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_defaults_from_empty_object() {
+        let policy: RetryPolicy = serde_json::from_str("{}").unwrap();
+        assert_eq!(policy, RetryPolicy::default());
+        assert_eq!(policy.max_attempts, 3);
+        assert!(policy.retries_status(503));
+        assert!(!policy.retries_status(500));
+    }
+
+    #[test]
+    fn test_only_idempotent_methods_unless_overridden() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_method("get"));
+        assert!(policy.allows_method("PUT"));
+        assert!(!policy.allows_method("POST"));
+        assert!(!policy.allows_method("PATCH"));
+
+        let policy = RetryPolicy {
+            retry_non_idempotent: true,
+            ..Default::default()
+        };
+        assert!(policy.allows_method("POST"));
+    }
+
+    #[test]
+    fn test_exponential_backoff_is_capped() {
+        let policy = RetryPolicy {
+            initial_delay_ms: 100,
+            max_delay_ms: 1_000,
+            jitter: false,
+            ..Default::default()
+        };
+        let delays: Vec<u128> = (1..=6)
+            .map(|retry| policy.delay(retry, None, 0.0).as_millis())
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1_000, 1_000]);
+        assert_eq!(policy.delay(u32::MAX, None, 0.0).as_millis(), 1_000);
+    }
+
+    #[test]
+    fn test_jitter_stays_between_half_and_full_backoff() {
+        let policy = RetryPolicy {
+            initial_delay_ms: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(policy.delay(1, None, 0.0).as_millis(), 500);
+        assert_eq!(policy.delay(1, None, 0.5).as_millis(), 750);
+        assert!(policy.delay(1, None, 0.999).as_millis() < 1_000);
+    }
+
+    #[test]
+    fn test_retry_after_replaces_backoff_up_to_max_delay() {
+        let policy = RetryPolicy {
+            max_delay_ms: 5_000,
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2)), 0.0),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(90)), 0.0),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2026-10-17T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_retry_after(" 7 ", now), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Sat, 17 Oct 2026 12:01:30 GMT", now),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            parse_retry_after("Sat, 17 Oct 2026 11:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
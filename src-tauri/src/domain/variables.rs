@@ -0,0 +1,264 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Template variables: `{{name}}` placeholders in requests.
+//!
+//! A placeholder is one of:
+//!
+//! - `{{name}}` — a collection or environment variable. Its value may itself
+//!   reference other variables, which resolve in turn; a reference cycle, or
+//!   references nested too deeply, is an error.
+//! - `{{name:-fallback}}` — the variable, or the literal `fallback` when it is
+//!   unset or empty.
+//! - `{{$uuid}}`, `{{$timestamp}}`, `{{$isoDate}}`, `{{$randomInt}}` — a value
+//!   generated at send time, afresh for every occurrence.
+//! - `{{$env.NAME}}` — the `NAME` variable of the OS environment.
+//!
+//! Placeholders that resolve to nothing are collected, so a request fails
+//! with every missing key listed instead of being sent with literal braces.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::RngExt;
+
+/// How deeply variable values may reference other variables.
+const MAX_DEPTH: usize = 16;
+
+/// Resolves the placeholders of a request's templates against its variables.
+///
+/// Missing keys from every [`Self::resolve`] call are remembered and reported
+/// together by [`Self::finish`].
+pub struct TemplateResolver<'a> {
+    vars: &'a BTreeMap<String, String>,
+    missing: RefCell<BTreeSet<String>>,
+    error: RefCell<Option<String>>,
+}
+
+impl<'a> TemplateResolver<'a> {
+    /// Resolver over `vars`: the collection's variables merged with the
+    /// active environment's.
+    #[must_use]
+    pub const fn new(vars: &'a BTreeMap<String, String>) -> Self {
+        Self {
+            vars,
+            missing: RefCell::new(BTreeSet::new()),
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Substitute the placeholders of `template`. Unresolved placeholders are
+    /// left verbatim and recorded for [`Self::finish`].
+    #[must_use]
+    pub fn resolve(&self, template: &str) -> String {
+        self.resolve_in(template, &mut Vec::new())
+    }
+
+    /// Report the placeholders that could not be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first reference cycle or over-deep chain of
+    /// references found, or listing every unresolved variable.
+    pub fn finish(self) -> Result<(), String> {
+        if let Some(error) = self.error.into_inner() {
+            return Err(error);
+        }
+        let missing = self.missing.into_inner();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let keys: Vec<String> = missing.into_iter().collect();
+        Err(format!("Unresolved variables: {}", keys.join(", ")))
+    }
+
+    fn resolve_in(&self, template: &str, stack: &mut Vec<String>) -> String {
+        let mut result = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            result.push_str(&rest[..start]);
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find("}}") else {
+                result.push_str("{{");
+                continue;
+            };
+            let placeholder = &rest[..end];
+            rest = &rest[end + 2..];
+            if let Some(value) = self.placeholder(placeholder.trim(), stack) {
+                result.push_str(&value);
+            } else {
+                result.push_str("{{");
+                result.push_str(placeholder);
+                result.push_str("}}");
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Value of one placeholder, or `None` (recorded as missing) when it has
+    /// none.
+    fn placeholder(&self, placeholder: &str, stack: &mut Vec<String>) -> Option<String> {
+        let (name, fallback) = placeholder
+            .split_once(":-")
+            .map_or((placeholder, None), |(name, fallback)| {
+                (name.trim_end(), Some(fallback))
+            });
+        let value = name
+            .strip_prefix('$')
+            .map_or_else(|| self.variable(name, stack), dynamic_value);
+        match (value.filter(|value| !value.is_empty()), fallback) {
+            (Some(value), _) => Some(value),
+            (None, Some(fallback)) => Some(fallback.to_string()),
+            (None, None) => {
+                // An empty variable is a value; only unknown keys are missing
+                if name.starts_with('$') || !self.vars.contains_key(name) {
+                    self.missing.borrow_mut().insert(name.to_string());
+                    return None;
+                }
+                Some(String::new())
+            }
+        }
+    }
+
+    /// Value of the variable `name`, with its own references resolved.
+    fn variable(&self, name: &str, stack: &mut Vec<String>) -> Option<String> {
+        let value = self.vars.get(name)?;
+        let cycle = stack.iter().any(|entry| entry == name);
+        if cycle || stack.len() >= MAX_DEPTH {
+            let mut chain = stack.clone();
+            chain.push(name.to_string());
+            let chain = chain.join(" -> ");
+            self.error.borrow_mut().get_or_insert_with(|| {
+                if cycle {
+                    format!("Variable reference cycle: {chain}")
+                } else {
+                    format!("Variable references nested deeper than {MAX_DEPTH} levels: {chain}")
+                }
+            });
+            return Some(String::new());
+        }
+        stack.push(name.to_string());
+        let resolved = self.resolve_in(value, stack);
+        stack.pop();
+        Some(resolved)
+    }
+}
+
+/// Value of a dynamic variable (the part after `$`), or `None` when it is
+/// unknown or, for `env.NAME`, unset.
+fn dynamic_value(name: &str) -> Option<String> {
+    if let Some(env) = name.strip_prefix("env.") {
+        return std::env::var(env).ok();
+    }
+    match name {
+        "uuid" => Some(
+            uuid::Builder::from_random_bytes(rand::rng().random())
+                .into_uuid()
+                .to_string(),
+        ),
+        "timestamp" => Some(chrono::Utc::now().timestamp().to_string()),
+        "isoDate" => Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+        "randomInt" => Some(rand::rng().random_range(0..=1000).to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolves_nested_references_and_defaults() {
+        let vars = vars(&[
+            ("host", "api.example.com"),
+            ("baseUrl", "https://{{host}}/v2"),
+            ("empty", ""),
+        ]);
+        let resolver = TemplateResolver::new(&vars);
+        assert_eq!(
+            resolver.resolve("{{ baseUrl }}/users?limit={{limit:-20}}&tag={{empty:-none}}"),
+            "https://api.example.com/v2/users?limit=20&tag=none"
+        );
+        assert_eq!(
+            resolver.resolve("[{{empty}}] {{ unclosed"),
+            "[] {{ unclosed"
+        );
+        assert!(resolver.finish().is_ok());
+    }
+
+    #[test]
+    fn test_dynamic_variables() {
+        let vars = BTreeMap::new();
+        let resolver = TemplateResolver::new(&vars);
+
+        let uuid = resolver.resolve("{{$uuid}}");
+        let parsed = uuid::Uuid::parse_str(&uuid).unwrap();
+        assert_eq!(parsed.get_version_num(), 4);
+        assert_ne!(resolver.resolve("{{$uuid}}"), uuid);
+
+        let timestamp: i64 = resolver.resolve("{{$timestamp}}").parse().unwrap();
+        assert!((timestamp - chrono::Utc::now().timestamp()).abs() < 5);
+        assert!(chrono::DateTime::parse_from_rfc3339(&resolver.resolve("{{$isoDate}}")).is_ok());
+        let random: u32 = resolver.resolve("{{$randomInt}}").parse().unwrap();
+        assert!(random <= 1000);
+
+        let path = std::env::var("PATH").unwrap_or_default();
+        if !path.is_empty() {
+            assert_eq!(resolver.resolve("{{$env.PATH}}"), path);
+        }
+        assert_eq!(
+            resolver.resolve("{{$env.RUNI_TEST_SURELY_UNSET:-fallback}}"),
+            "fallback"
+        );
+        assert!(resolver.finish().is_ok());
+    }
+
+    #[test]
+    fn test_reports_every_unresolved_variable() {
+        let vars = vars(&[("token", "{{secret}}")]);
+        let resolver = TemplateResolver::new(&vars);
+        assert_eq!(
+            resolver.resolve("{{userId}}/{{token}}/{{$nope}}"),
+            "{{userId}}/{{secret}}/{{$nope}}"
+        );
+        let _ = resolver.resolve("{{userId}}");
+        assert_eq!(
+            resolver.finish().unwrap_err(),
+            "Unresolved variables: $nope, secret, userId"
+        );
+    }
+
+    #[test]
+    fn test_detects_reference_cycles() {
+        let vars = vars(&[("a", "x{{b}}"), ("b", "{{c}}"), ("c", "{{a}}")]);
+        let resolver = TemplateResolver::new(&vars);
+        let _ = resolver.resolve("{{a}}");
+        assert_eq!(
+            resolver.finish().unwrap_err(),
+            "Variable reference cycle: a -> b -> c -> a"
+        );
+    }
+
+    #[test]
+    fn test_reports_references_nested_too_deeply() {
+        let vars: BTreeMap<String, String> = (0..=MAX_DEPTH)
+            .map(|i| (format!("v{i}"), format!("{{{{v{}}}}}", i + 1)))
+            .collect();
+        let resolver = TemplateResolver::new(&vars);
+        let _ = resolver.resolve("{{v0}}");
+        let error = resolver.finish().unwrap_err();
+        assert!(
+            error.starts_with("Variable references nested deeper than 16 levels: v0 -> v1"),
+            "{error}"
+        );
+        assert!(error.ends_with(&format!("v{MAX_DEPTH}")), "{error}");
+    }
+}
@@ -0,0 +1,618 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// WebSocket protocol (RFC 6455): handshake key, frame codec, transcripts and live session events
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use super::http_headers::HttpHeaders;
+
+#[cfg(test)]
+use ts_rs::TS;
+
+/// GUID appended to the handshake key before hashing (RFC 6455 §1.3).
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Largest message accepted from a server, after reassembling fragments.
+pub const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// Close code sent when the client closes without choosing one.
+pub const NORMAL_CLOSURE: u16 = 1000;
+
+/// Frame opcodes (RFC 6455 §5.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    /// Continuation of a fragmented message.
+    Continuation,
+    /// UTF-8 text data.
+    Text,
+    /// Binary data.
+    Binary,
+    /// Connection close.
+    Close,
+    /// Ping.
+    Ping,
+    /// Pong.
+    Pong,
+}
+
+impl Opcode {
+    const fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0x0 => Some(Self::Continuation),
+            0x1 => Some(Self::Text),
+            0x2 => Some(Self::Binary),
+            0x8 => Some(Self::Close),
+            0x9 => Some(Self::Ping),
+            0xA => Some(Self::Pong),
+            _ => None,
+        }
+    }
+
+    const fn bits(self) -> u8 {
+        match self {
+            Self::Continuation => 0x0,
+            Self::Text => 0x1,
+            Self::Binary => 0x2,
+            Self::Close => 0x8,
+            Self::Ping => 0x9,
+            Self::Pong => 0xA,
+        }
+    }
+
+    const fn is_control(self) -> bool {
+        matches!(self, Self::Close | Self::Ping | Self::Pong)
+    }
+}
+
+/// A complete message: a control frame, or a data message with its
+/// fragments joined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// `Text`, `Binary`, `Close`, `Ping` or `Pong`; never `Continuation`.
+    pub opcode: Opcode,
+    /// Unmasked payload.
+    pub payload: Vec<u8>,
+}
+
+/// The `Sec-WebSocket-Accept` value a server must answer `key` with.
+#[must_use]
+pub fn accept_key(key: &str) -> String {
+    BASE64.encode(Sha1::digest(format!("{key}{HANDSHAKE_GUID}")))
+}
+
+/// Encode a single unfragmented frame.
+///
+/// Clients must mask every frame they send; servers must not mask theirs.
+#[must_use]
+pub fn encode_frame(opcode: Opcode, payload: &[u8], mask: Option<[u8; 4]>) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(payload.len() + 14);
+    frame.push(0x80 | opcode.bits());
+    let mask_bit = if mask.is_some() { 0x80 } else { 0 };
+    match payload.len() {
+        len @ 0..=125 => frame.push(mask_bit | u8::try_from(len).unwrap_or(125)),
+        len @ 126..=0xFFFF => {
+            frame.push(mask_bit | 0x7E);
+            frame.extend_from_slice(&u16::try_from(len).unwrap_or(u16::MAX).to_be_bytes());
+        }
+        len => {
+            frame.push(mask_bit | 0x7F);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    match mask {
+        Some(key) => {
+            frame.extend_from_slice(&key);
+            frame.extend(
+                payload
+                    .iter()
+                    .zip(key.iter().cycle())
+                    .map(|(byte, k)| byte ^ k),
+            );
+        }
+        None => frame.extend_from_slice(payload),
+    }
+    frame
+}
+
+/// Payload of a close frame: status code followed by a UTF-8 reason.
+#[must_use]
+pub fn close_payload(code: u16, reason: &str) -> Vec<u8> {
+    let mut payload = code.to_be_bytes().to_vec();
+    payload.extend_from_slice(reason.as_bytes());
+    payload
+}
+
+/// Split a close frame payload into its status code and reason.
+///
+/// An empty payload means the peer sent no status code.
+#[must_use]
+pub fn parse_close_payload(payload: &[u8]) -> (Option<u16>, String) {
+    match payload {
+        [high, low, reason @ ..] => (
+            Some(u16::from_be_bytes([*high, *low])),
+            String::from_utf8_lossy(reason).into_owned(),
+        ),
+        _ => (None, String::new()),
+    }
+}
+
+/// Incremental frame decoder.
+///
+/// Bytes may be split anywhere; incomplete frames are buffered until the next
+/// `feed`. Fragmented data messages are reassembled, and control frames that
+/// arrive between fragments are returned as they come.
+#[derive(Debug, Default)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    /// Opcode and payload of a fragmented message still being received.
+    fragments: Option<(Opcode, Vec<u8>)>,
+}
+
+impl FrameDecoder {
+    /// Create an empty decoder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed received bytes, returning every message they complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a protocol violation: an unknown opcode, a
+    /// fragmented or oversized control frame, a continuation without a
+    /// message to continue, or a message above [`MAX_MESSAGE_SIZE`].
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<Vec<Frame>, String> {
+        self.buffer.extend_from_slice(bytes);
+        let mut frames = Vec::new();
+        while let Some(RawFrame {
+            fin,
+            opcode,
+            payload,
+            consumed,
+        }) = self.next_frame()?
+        {
+            self.buffer.drain(..consumed);
+            if opcode.is_control() {
+                frames.push(Frame { opcode, payload });
+                continue;
+            }
+            let (opcode, payload) = match (opcode, self.fragments.take()) {
+                (Opcode::Continuation, Some((first, mut data))) => {
+                    data.extend_from_slice(&payload);
+                    (first, data)
+                }
+                (Opcode::Continuation, None) => {
+                    return Err("Continuation frame without a message to continue".to_string());
+                }
+                (_, Some(_)) => {
+                    return Err("New message started before the previous one ended".to_string());
+                }
+                (opcode, None) => (opcode, payload),
+            };
+            if payload.len() > MAX_MESSAGE_SIZE {
+                return Err(format!("Message exceeds the {MAX_MESSAGE_SIZE} byte limit"));
+            }
+            if fin {
+                frames.push(Frame { opcode, payload });
+            } else {
+                self.fragments = Some((opcode, payload));
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Parse the frame at the start of the buffer, if it is complete.
+    fn next_frame(&self) -> Result<Option<RawFrame>, String> {
+        let [first, second, ..] = self.buffer[..] else {
+            return Ok(None);
+        };
+        let fin = first & 0x80 != 0;
+        let opcode = Opcode::from_bits(first & 0x0F)
+            .ok_or_else(|| format!("Unknown opcode {:#x}", first & 0x0F))?;
+        let masked = second & 0x80 != 0;
+        let (len, mut offset) = match second & 0x7F {
+            126 => {
+                let Some(bytes) = self.buffer.get(2..4) else {
+                    return Ok(None);
+                };
+                (u64::from(u16::from_be_bytes([bytes[0], bytes[1]])), 4)
+            }
+            127 => {
+                let Some(bytes) = self.buffer.get(2..10) else {
+                    return Ok(None);
+                };
+                let mut len = [0u8; 8];
+                len.copy_from_slice(bytes);
+                (u64::from_be_bytes(len), 10)
+            }
+            len => (u64::from(len), 2),
+        };
+        if opcode.is_control() && (!fin || len > 125) {
+            return Err("Control frames must be unfragmented and at most 125 bytes".to_string());
+        }
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|&len| len <= MAX_MESSAGE_SIZE)
+            .ok_or_else(|| format!("Frame exceeds the {MAX_MESSAGE_SIZE} byte limit"))?;
+        let mask = if masked {
+            let Some(bytes) = self.buffer.get(offset..offset + 4) else {
+                return Ok(None);
+            };
+            offset += 4;
+            Some([bytes[0], bytes[1], bytes[2], bytes[3]])
+        } else {
+            None
+        };
+        let Some(payload) = self.buffer.get(offset..offset + len) else {
+            return Ok(None);
+        };
+        let payload = mask.map_or_else(
+            || payload.to_vec(),
+            |key| {
+                payload
+                    .iter()
+                    .zip(key.iter().cycle())
+                    .map(|(byte, k)| byte ^ k)
+                    .collect()
+            },
+        );
+        Ok(Some(RawFrame {
+            fin,
+            opcode,
+            payload,
+            consumed: offset + len,
+        }))
+    }
+}
+
+/// A single frame as read off the wire, before reassembly.
+struct RawFrame {
+    fin: bool,
+    opcode: Opcode,
+    /// Unmasked payload.
+    payload: Vec<u8>,
+    /// Bytes of the buffer the frame took up.
+    consumed: usize,
+}
+
+/// Kind of a WebSocket message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum WebSocketMessageKind {
+    /// UTF-8 text message.
+    #[default]
+    Text,
+    /// Binary message.
+    Binary,
+    /// Ping control frame.
+    Ping,
+    /// Pong control frame.
+    Pong,
+    /// Close control frame.
+    Close,
+}
+
+/// Which side sent a message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum WebSocketDirection {
+    /// Sent by runi.
+    Sent,
+    /// Received from the server.
+    Received,
+}
+
+/// A message sent or received during a WebSocket session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WebSocketMessage {
+    /// Zero-based position of the message in the session.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub seq: u64,
+    /// Milliseconds since the connection was opened.
+    #[cfg_attr(test, ts(type = "number"))]
+    pub elapsed_ms: u64,
+    /// Which side sent the message.
+    pub direction: WebSocketDirection,
+    /// Message kind.
+    pub kind: WebSocketMessageKind,
+    /// Payload: base64 for binary messages, the reason for close frames, and
+    /// text (lossily decoded for control frames) otherwise.
+    pub data: String,
+    /// Status code of a close frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub close_code: Option<u16>,
+}
+
+impl WebSocketMessage {
+    /// Build the transcript entry for `frame`.
+    #[must_use]
+    pub fn from_frame(
+        frame: &Frame,
+        direction: WebSocketDirection,
+        seq: u64,
+        elapsed_ms: u64,
+    ) -> Self {
+        let (kind, data, close_code) = match frame.opcode {
+            Opcode::Binary => (
+                WebSocketMessageKind::Binary,
+                BASE64.encode(&frame.payload),
+                None,
+            ),
+            Opcode::Close => {
+                let (code, reason) = parse_close_payload(&frame.payload);
+                (WebSocketMessageKind::Close, reason, code)
+            }
+            opcode => (
+                match opcode {
+                    Opcode::Ping => WebSocketMessageKind::Ping,
+                    Opcode::Pong => WebSocketMessageKind::Pong,
+                    _ => WebSocketMessageKind::Text,
+                },
+                String::from_utf8_lossy(&frame.payload).into_owned(),
+                None,
+            ),
+        };
+        Self {
+            seq,
+            elapsed_ms,
+            direction,
+            kind,
+            data,
+            close_code,
+        }
+    }
+}
+
+/// A message to send on an open session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WebSocketSend {
+    /// Message kind (default: text). Close frames are sent with
+    /// `close_websocket` instead.
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<WebSocketMessageKind>", optional))]
+    pub kind: WebSocketMessageKind,
+    /// Payload: text, or base64 for binary messages.
+    #[serde(default)]
+    pub data: String,
+}
+
+impl WebSocketSend {
+    /// Encode the message as a frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for close frames, invalid base64 in a binary message,
+    /// or a control frame payload above 125 bytes.
+    pub fn to_frame(&self) -> Result<Frame, String> {
+        let (opcode, payload) = match self.kind {
+            WebSocketMessageKind::Text => (Opcode::Text, self.data.as_bytes().to_vec()),
+            WebSocketMessageKind::Binary => (
+                Opcode::Binary,
+                BASE64
+                    .decode(&self.data)
+                    .map_err(|e| format!("Binary data must be base64: {e}"))?,
+            ),
+            WebSocketMessageKind::Ping => (Opcode::Ping, self.data.as_bytes().to_vec()),
+            WebSocketMessageKind::Pong => (Opcode::Pong, self.data.as_bytes().to_vec()),
+            WebSocketMessageKind::Close => {
+                return Err("Use close_websocket to close the session".to_string());
+            }
+        };
+        if opcode.is_control() && payload.len() > 125 {
+            return Err("Ping and pong payloads are limited to 125 bytes".to_string());
+        }
+        Ok(Frame { opcode, payload })
+    }
+}
+
+/// Messages of a WebSocket session, kept with its history entry.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WebSocketTranscript {
+    /// Subprotocol the server selected, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub protocol: Option<String>,
+    /// Messages in the order they were sent or received.
+    pub messages: Vec<WebSocketMessage>,
+    /// Close code of the session, once closed. Absent when the connection
+    /// dropped without a close frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub close_code: Option<u16>,
+    /// Close reason of the session, once closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub close_reason: Option<String>,
+}
+
+/// An open WebSocket session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct WebSocketSessionInfo {
+    /// Session ID, used to send messages and close the session.
+    pub session_id: String,
+    /// URL the session is connected to.
+    pub url: String,
+    /// Subprotocol the server selected, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub protocol: Option<String>,
+    /// Handshake response headers, in wire order.
+    pub headers: HttpHeaders,
+    /// RFC 3339 UTC timestamp of the handshake.
+    pub opened_at: String,
+}
+
+/// Live update of a WebSocket session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WebSocketUpdate {
+    /// The handshake succeeded.
+    Opened {
+        /// The new session.
+        session: WebSocketSessionInfo,
+    },
+    /// A message was sent or received.
+    Message {
+        /// The message.
+        message: WebSocketMessage,
+    },
+    /// The session ended after a close handshake or a dropped connection.
+    Closed {
+        /// Close code, when a close frame was received.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(test, ts(optional))]
+        code: Option<u16>,
+        /// Close reason.
+        reason: String,
+        /// Number of messages in the transcript.
+        #[cfg_attr(test, ts(type = "number"))]
+        message_count: u64,
+    },
+    /// The session ended on a protocol or transport error.
+    Failed {
+        /// Error description.
+        error: String,
+    },
+}
+
+impl WebSocketUpdate {
+    /// Event name used for SSE topics (e.g. `websocket:message`).
+    #[must_use]
+    pub const fn event_type(&self) -> &'static str {
+        match self {
+            Self::Opened { .. } => "websocket:opened",
+            Self::Message { .. } => "websocket:message",
+            Self::Closed { .. } => "websocket:closed",
+            Self::Failed { .. } => "websocket:failed",
+        }
+    }
+}
+
+/// A [`WebSocketUpdate`] tagged with the ID of its session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WebSocketEvent {
+    /// ID of the session.
+    pub session_id: String,
+    /// The update itself.
+    #[serde(flatten)]
+    pub update: WebSocketUpdate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key_matches_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn test_masked_frames_round_trip_at_every_length_encoding() {
+        for len in [0, 125, 126, 65_535, 65_536] {
+            let payload: Vec<u8> = (0..len).map(|i| u8::try_from(i % 251).unwrap()).collect();
+            let bytes = encode_frame(Opcode::Binary, &payload, Some([1, 2, 3, 4]));
+            let frames = FrameDecoder::new().feed(&bytes).unwrap();
+            assert_eq!(
+                frames,
+                vec![Frame {
+                    opcode: Opcode::Binary,
+                    payload
+                }],
+                "length {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_input_and_fragments_with_interleaved_ping() {
+        let mut bytes = vec![0x01, 3];
+        bytes.extend_from_slice(b"hel");
+        bytes.extend(encode_frame(Opcode::Ping, b"p", None));
+        bytes.extend([0x80, 2]);
+        bytes.extend_from_slice(b"lo");
+
+        let mut decoder = FrameDecoder::new();
+        let mut frames = Vec::new();
+        for chunk in bytes.chunks(2) {
+            frames.extend(decoder.feed(chunk).unwrap());
+        }
+
+        assert_eq!(
+            frames,
+            vec![
+                Frame {
+                    opcode: Opcode::Ping,
+                    payload: b"p".to_vec()
+                },
+                Frame {
+                    opcode: Opcode::Text,
+                    payload: b"hello".to_vec()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_protocol_violations_are_errors() {
+        assert!(FrameDecoder::new().feed(&[0x83, 0]).is_err());
+        assert!(FrameDecoder::new().feed(&[0x80, 0]).is_err());
+        assert!(FrameDecoder::new().feed(&[0x09, 0]).is_err());
+    }
+
+    #[test]
+    fn test_close_payload_and_transcript_entries() {
+        let frame = Frame {
+            opcode: Opcode::Close,
+            payload: close_payload(1001, "going away"),
+        };
+        let message = WebSocketMessage::from_frame(&frame, WebSocketDirection::Received, 3, 40);
+        assert_eq!(message.kind, WebSocketMessageKind::Close);
+        assert_eq!(message.close_code, Some(1001));
+        assert_eq!(message.data, "going away");
+        assert_eq!(parse_close_payload(&[]), (None, String::new()));
+
+        let binary = Frame {
+            opcode: Opcode::Binary,
+            payload: vec![0, 255],
+        };
+        let message = WebSocketMessage::from_frame(&binary, WebSocketDirection::Sent, 0, 0);
+        assert_eq!(message.data, "AP8=");
+    }
+
+    #[test]
+    fn test_send_encodes_binary_from_base64() {
+        let send: WebSocketSend =
+            serde_json::from_str(r#"{"kind":"binary","data":"AP8="}"#).unwrap();
+        assert_eq!(send.to_frame().unwrap().payload, vec![0, 255]);
+        let text: WebSocketSend = serde_json::from_str(r#"{"data":"hi"}"#).unwrap();
+        assert_eq!(text.to_frame().unwrap().opcode, Opcode::Text);
+        let close = WebSocketSend {
+            kind: WebSocketMessageKind::Close,
+            data: String::new(),
+        };
+        assert!(close.to_frame().is_err());
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Command-line arguments of `runi-cli`.
+//!
+//! Options take their value either as the next argument or after `=`
+//! (`--env staging`, `--env=staging`) and may appear anywhere after the
+//! command name.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::domain::collection::run::RunOptions;
+use crate::infrastructure::hurl::exporter::{HurlExportGrouping, HurlExportOptions};
+
+/// Usage text printed by `runi-cli help` and after usage errors.
+pub const USAGE: &str = "\
+Usage: runi-cli [--dir <path>] [--json] <command> [options]
+
+Commands:
+  list [<collection>]         List collections, or the requests and environments of one
+  run <collection>            Run a collection's requests in order
+      --request <id|name>     Only run this request (repeatable)
+      --env <name>            Run against this environment instead of the active one
+      --folder <folder>       Only run requests in this folder (repeatable)
+      --tag <tag>             Only run requests with this tag (repeatable)
+      --stop-on-failure       Skip the remaining requests after a failure
+      --delay <ms>            Pause between requests
+      --timeout <ms>          Timeout for each request (default: 30000)
+      --junit <path>          Also write the report as JUnit XML
+  import <url|file>           Import a collection from an OpenAPI spec
+      --name <name>           Name the collection instead of using the spec title
+  refresh <collection>        Re-fetch a collection's spec, save it and report drift
+      --spec <url|file>       Fetch from here instead of the tracked source
+  diff <collection>           Report drift against a spec without saving anything
+      --spec <url|file>       Compare with this spec instead of the tracked source
+      --pinned <version id>   Compare with a pinned spec version
+  export <collection>         Write the collection out as .hurl files
+      --out <dir>             Directory to write to (default: current directory)
+      --by <collection|tag>   One file per collection (default) or per tag
+      --env <name>            Export this environment's variables instead of the active one's
+  hurl <file.hurl>            Run a Hurl suite
+      --var <name=value>      Set a Hurl variable (repeatable)
+  help                        Show this help
+
+Global options:
+  --dir <path>                Collections directory (default: RUNI_COLLECTIONS_DIR,
+                              or ./collections)
+  --json                      Print results as JSON
+
+Collections are given by ID or name. Exits with 1 when a request fails or
+errors, a Hurl suite fails or a spec drifts in a breaking way, and with 2 on
+usage and other errors.
+";
+
+/// Parsed command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    /// Collections directory, overriding the default one.
+    pub dir: Option<PathBuf>,
+    /// Print results as JSON instead of text.
+    pub json: bool,
+    /// What to do.
+    pub command: Command,
+}
+
+/// A `runi-cli` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// List collections, or the requests and environments of one.
+    List {
+        /// Collection ID or name.
+        collection: Option<String>,
+    },
+    /// Run a collection.
+    Run {
+        /// Collection ID or name.
+        collection: String,
+        /// Environment to run against instead of the active one.
+        environment: Option<String>,
+        /// Which requests to run, and how.
+        options: RunOptions,
+        /// Where to write the `JUnit` XML report.
+        junit: Option<PathBuf>,
+    },
+    /// Import a collection from a spec.
+    Import {
+        /// Spec URL or file path.
+        source: String,
+        /// Collection name overriding the spec title.
+        name: Option<String>,
+    },
+    /// Refresh a collection's spec and report drift.
+    Refresh {
+        /// Collection ID or name.
+        collection: String,
+        /// Spec URL or file path overriding the tracked source.
+        spec: Option<String>,
+    },
+    /// Report drift without saving.
+    Diff {
+        /// Collection ID or name.
+        collection: String,
+        /// What to compare the collection with.
+        against: DiffTarget,
+    },
+    /// Export a collection to `.hurl` files.
+    Export {
+        /// Collection ID or name.
+        collection: String,
+        /// Directory to write the files to.
+        out: PathBuf,
+        /// How to split the requests into files, and which variables to export.
+        options: HurlExportOptions,
+    },
+    /// Run a Hurl suite.
+    Hurl {
+        /// Path of the `.hurl` file.
+        file: String,
+        /// Hurl variables.
+        variables: HashMap<String, String>,
+    },
+    /// Print usage.
+    Help,
+}
+
+/// What `diff` compares a collection with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffTarget {
+    /// A spec URL or file path, or the tracked source when `None`.
+    Spec(Option<String>),
+    /// A pinned spec version, by ID.
+    Pinned(String),
+}
+
+/// Options that take no value.
+const SWITCHES: &[&str] = &["json", "stop-on-failure"];
+
+/// Arguments split into positionals, valued options and switches.
+#[derive(Debug, Default)]
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    fn split(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = flag.split_once('=') {
+                parsed.options.push((name.to_string(), value.to_string()));
+            } else if SWITCHES.contains(&flag) {
+                parsed.switches.push(flag.to_string());
+            } else {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("--{flag} needs a value"))?;
+                parsed.options.push((flag.to_string(), value));
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Remove every value of option `name`.
+    fn take_all(&mut self, name: &str) -> Vec<String> {
+        let (taken, rest) = std::mem::take(&mut self.options)
+            .into_iter()
+            .partition(|(option, _)| option == name);
+        self.options = rest;
+        taken.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Remove option `name`, which may be given once.
+    fn take(&mut self, name: &str) -> Result<Option<String>, String> {
+        let mut values = self.take_all(name);
+        if values.len() > 1 {
+            return Err(format!("--{name} may only be given once"));
+        }
+        Ok(values.pop())
+    }
+
+    /// Remove option `name` and parse its value as a number.
+    fn take_number(&mut self, name: &str) -> Result<Option<u64>, String> {
+        self.take(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("--{name} must be a number, got {value}"))
+            })
+            .transpose()
+    }
+
+    /// Remove switch `name`.
+    fn switch(&mut self, name: &str) -> bool {
+        let before = self.switches.len();
+        self.switches.retain(|switch| switch != name);
+        self.switches.len() != before
+    }
+
+    /// Remove the next positional argument, naming it in the error if absent.
+    fn positional(&mut self, what: &str) -> Result<String, String> {
+        if self.positional.is_empty() {
+            return Err(format!("Missing {what}"));
+        }
+        Ok(self.positional.remove(0))
+    }
+
+    /// Fail on anything the command did not consume.
+    fn finish(self, command: &str) -> Result<(), String> {
+        if let Some(extra) = self.positional.first() {
+            return Err(format!("Unexpected argument for {command}: {extra}"));
+        }
+        if let Some(option) = self
+            .options
+            .iter()
+            .map(|(name, _)| name)
+            .chain(&self.switches)
+            .next()
+        {
+            return Err(format!("Unknown option for {command}: --{option}"));
+        }
+        Ok(())
+    }
+}
+
+/// Parse the arguments that follow the program name.
+///
+/// # Errors
+///
+/// Returns a usage error for missing commands and arguments, unknown
+/// options and malformed values.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Cli, String> {
+    let mut args = Args::split(args)?;
+    let dir = args.take("dir")?.map(PathBuf::from);
+    let json = args.switch("json");
+    let name = if args.positional.is_empty() {
+        "help".to_string()
+    } else {
+        args.positional.remove(0)
+    };
+    let command = match name.as_str() {
+        "list" => Command::List {
+            collection: (!args.positional.is_empty()).then(|| args.positional.remove(0)),
+        },
+        "run" => parse_run(&mut args)?,
+        "import" => Command::Import {
+            source: args.positional("spec URL or file")?,
+            name: args.take("name")?,
+        },
+        "refresh" => Command::Refresh {
+            collection: args.positional("collection")?,
+            spec: args.take("spec")?,
+        },
+        "diff" => {
+            let collection = args.positional("collection")?;
+            let against = match (args.take("spec")?, args.take("pinned")?) {
+                (spec, None) => DiffTarget::Spec(spec),
+                (None, Some(pinned)) => DiffTarget::Pinned(pinned),
+                (Some(_), Some(_)) => return Err("Give --spec or --pinned, not both".to_string()),
+            };
+            Command::Diff {
+                collection,
+                against,
+            }
+        }
+        "export" => Command::Export {
+            collection: args.positional("collection")?,
+            out: PathBuf::from(args.take("out")?.unwrap_or_else(|| ".".to_string())),
+            options: HurlExportOptions {
+                group_by: match args.take("by")?.as_deref() {
+                    None | Some("collection") => HurlExportGrouping::Collection,
+                    Some("tag") => HurlExportGrouping::Tag,
+                    Some(other) => {
+                        return Err(format!("--by must be collection or tag, got {other}"));
+                    }
+                },
+                environment: args.take("env")?,
+            },
+        },
+        "hurl" => {
+            let file = args.positional("Hurl file")?;
+            let variables = args
+                .take_all("var")
+                .into_iter()
+                .map(|var| {
+                    var.split_once('=')
+                        .map(|(name, value)| (name.to_string(), value.to_string()))
+                        .ok_or_else(|| format!("--var must be name=value, got {var}"))
+                })
+                .collect::<Result<_, _>>()?;
+            Command::Hurl { file, variables }
+        }
+        "help" => Command::Help,
+        other => return Err(format!("Unknown command: {other}")),
+    };
+    args.finish(&name)?;
+    Ok(Cli { dir, json, command })
+}
+
+fn parse_run(args: &mut Args) -> Result<Command, String> {
+    Ok(Command::Run {
+        collection: args.positional("collection")?,
+        environment: args.take("env")?,
+        options: RunOptions {
+            stop_on_failure: args.switch("stop-on-failure"),
+            delay_ms: args.take_number("delay")?.unwrap_or_default(),
+            folders: args.take_all("folder"),
+            tags: args.take_all("tag"),
+            requests: args.take_all("request"),
+            timeout_ms: args.take_number("timeout")?,
+        },
+        junit: args.take("junit")?.map(PathBuf::from),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(line: &str) -> Result<Cli, String> {
+        parse(line.split_whitespace().map(ToString::to_string))
+    }
+
+    #[test]
+    fn test_parses_run_options_in_any_order() {
+        let cli = parse_str(
+            "--dir ci/collections run Shop --tag smoke --env=staging --request Login \
+             --stop-on-failure --request req_me --timeout 5000 --json --junit out.xml",
+        )
+        .unwrap();
+        assert_eq!(cli.dir, Some(PathBuf::from("ci/collections")));
+        assert!(cli.json);
+        assert_eq!(
+            cli.command,
+            Command::Run {
+                collection: "Shop".to_string(),
+                environment: Some("staging".to_string()),
+                options: RunOptions {
+                    stop_on_failure: true,
+                    tags: vec!["smoke".to_string()],
+                    requests: vec!["Login".to_string(), "req_me".to_string()],
+                    timeout_ms: Some(5000),
+                    ..Default::default()
+                },
+                junit: Some(PathBuf::from("out.xml")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_the_other_commands() {
+        assert_eq!(parse_str("").unwrap().command, Command::Help);
+        assert_eq!(
+            parse_str("list").unwrap().command,
+            Command::List { collection: None }
+        );
+        assert_eq!(
+            parse_str("diff Shop --pinned pin_1").unwrap().command,
+            Command::Diff {
+                collection: "Shop".to_string(),
+                against: DiffTarget::Pinned("pin_1".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_str("export Shop --by tag --out ci").unwrap().command,
+            Command::Export {
+                collection: "Shop".to_string(),
+                out: PathBuf::from("ci"),
+                options: HurlExportOptions {
+                    group_by: HurlExportGrouping::Tag,
+                    environment: None,
+                },
+            }
+        );
+        assert_eq!(
+            parse_str("hurl smoke.hurl --var base=http://localhost:3000")
+                .unwrap()
+                .command,
+            Command::Hurl {
+                file: "smoke.hurl".to_string(),
+                variables: HashMap::from([(
+                    "base".to_string(),
+                    "http://localhost:3000".to_string()
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_malformed_command_lines() {
+        let err = |line| parse_str(line).unwrap_err();
+        assert_eq!(err("deploy"), "Unknown command: deploy");
+        assert_eq!(err("run"), "Missing collection");
+        assert_eq!(err("run Shop --env"), "--env needs a value");
+        assert_eq!(
+            err("run Shop --env a --env b"),
+            "--env may only be given once"
+        );
+        assert_eq!(
+            err("run Shop --delay soon"),
+            "--delay must be a number, got soon"
+        );
+        assert_eq!(
+            err("list Shop --tag smoke"),
+            "Unknown option for list: --tag"
+        );
+        assert_eq!(
+            err("refresh Shop extra"),
+            "Unexpected argument for refresh: extra"
+        );
+        assert_eq!(
+            err("diff Shop --spec a.yaml --pinned pin_1"),
+            "Give --spec or --pinned, not both"
+        );
+        assert_eq!(
+            err("hurl a.hurl --var base"),
+            "--var must be name=value, got base"
+        );
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Headless command-line interface, the entry point of the `runi-cli` binary.
+//!
+//! Runs collections, imports specs, checks them for drift, exports and runs
+//! Hurl suites from CI, through the same collection store, runner, importer and
+//! HTTP executor as the desktop app — without a display or the Tauri runtime.
+//! Results go to stdout, as text or JSON; the exit status tells CI whether
+//! anything failed.
+
+/// Command-line argument parsing and usage text.
+pub mod args;
+
+use std::io::Write;
+use std::path::Path;
+use std::process::ExitCode;
+
+use serde::Serialize;
+
+use crate::application::collection_runner::run_collection;
+use crate::application::import_service::{ImportOverrides, ImportService};
+use crate::application::spec_refresh::{
+    diff_collection_spec, diff_pinned_version, refresh_collection_spec_in_dir, spec_source,
+};
+use crate::domain::collection::Collection;
+use crate::domain::collection::drift::SpecRefreshResult;
+use crate::domain::collection::run::{
+    RunOptions, RunOutcome, RunReport, RunRequestResult, RunUpdate,
+};
+use crate::domain::collection::test_port::{TestRunConfig, TestRunner};
+use crate::infrastructure::git::GitCliAdapter;
+use crate::infrastructure::hurl::HurlRunner;
+use crate::infrastructure::hurl::exporter::{HurlExportOptions, export_collection_to_hurl};
+use crate::infrastructure::spec::http_fetcher::HttpContentFetcher;
+use crate::infrastructure::spec::openapi_parser::OpenApiParser;
+use crate::infrastructure::storage::collection_store::{
+    find_collection_by_name_in_dir, get_collections_dir, list_collections_in_dir,
+    load_collection_in_dir, save_collection_in_dir,
+};
+use args::{Cli, Command, DiffTarget, USAGE};
+
+/// Exit status when a request, assertion or Hurl entry failed, or a spec
+/// drifted in a breaking way.
+const EXIT_FAILED: u8 = 1;
+/// Exit status for usage and other errors.
+const EXIT_ERROR: u8 = 2;
+
+/// Parse `args` (without the program name), run the command and return the
+/// process exit status.
+pub async fn main(args: Vec<String>) -> ExitCode {
+    let cli = match args::parse(args) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+    match execute(&cli, &mut std::io::stdout()).await {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(EXIT_FAILED),
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+/// Run a parsed command, writing its output to `out`.
+///
+/// Returns whether everything passed.
+///
+/// # Errors
+///
+/// Returns an error if the command could not be carried out at all.
+pub async fn execute(cli: &Cli, out: &mut (dyn Write + Send)) -> Result<bool, String> {
+    if cli.command == Command::Help {
+        let _ = write!(out, "{USAGE}");
+        return Ok(true);
+    }
+    let dir = cli.dir.clone().map_or_else(get_collections_dir, Ok)?;
+    match &cli.command {
+        Command::List { collection } => list(&dir, collection.as_deref(), cli.json, out),
+        Command::Run {
+            collection,
+            environment,
+            options,
+            junit,
+        } => {
+            let mut collection = find_collection(&dir, collection)?;
+            if let Some(environment) = environment {
+                if !collection
+                    .environments
+                    .iter()
+                    .any(|e| e.name == *environment)
+                {
+                    return Err(format!(
+                        "Collection {} has no environment {environment}",
+                        collection.metadata.name
+                    ));
+                }
+                collection.active_environment = Some(environment.clone());
+            }
+            let report = run(&collection, &dir, options, cli.json, out).await?;
+            if let Some(path) = junit {
+                std::fs::write(path, report.to_junit_xml())
+                    .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+            }
+            Ok(report.success())
+        }
+        Command::Import { source, name } => import(&dir, source, name.clone(), cli.json, out).await,
+        Command::Refresh { collection, spec } => {
+            let collection = find_collection(&dir, collection)?;
+            let service = import_service(&collection);
+            let drift =
+                refresh_collection_spec_in_dir(&dir, &collection.id, &service, spec.as_deref())
+                    .await?;
+            print_drift(&drift, cli.json, out);
+            Ok(!drift.is_breaking())
+        }
+        Command::Diff {
+            collection,
+            against,
+        } => {
+            let collection = find_collection(&dir, collection)?;
+            let service = import_service(&collection);
+            let drift = match against {
+                DiffTarget::Spec(spec) => {
+                    diff_collection_spec(&collection, &service, spec.as_deref()).await?
+                }
+                DiffTarget::Pinned(version) => diff_pinned_version(&collection, version, &service)?,
+            };
+            print_drift(&drift, cli.json, out);
+            Ok(!drift.is_breaking())
+        }
+        Command::Export {
+            collection,
+            out: out_dir,
+            options,
+        } => {
+            let collection = find_collection(&dir, collection)?;
+            export(&collection, out_dir, options, cli.json, out)
+        }
+        Command::Hurl { file, variables } => {
+            let config = TestRunConfig {
+                file_path: file.clone(),
+                env_vars: variables.clone(),
+                timeout_secs: None,
+            };
+            // Run on a blocking thread since Command::output() blocks
+            let result = tokio::task::spawn_blocking(move || HurlRunner.run(&config))
+                .await
+                .map_err(|e| format!("Task join error: {e}"))??;
+            if cli.json {
+                print_json(out, &result.stdout);
+            } else {
+                eprint!("{}", result.stderr);
+                let _ = writeln!(
+                    out,
+                    "{} entries, {} failed in {} ms",
+                    result.test_count, result.failure_count, result.duration_ms
+                );
+            }
+            Ok(result.passed)
+        }
+        Command::Help => unreachable!("handled above"),
+    }
+}
+
+/// Load a collection from `dir` by ID, or else by name.
+fn find_collection(dir: &Path, key: &str) -> Result<Collection, String> {
+    if let Ok(collection) = load_collection_in_dir(key, dir) {
+        return Ok(collection);
+    }
+    let summary = find_collection_by_name_in_dir(key, dir)?
+        .ok_or_else(|| format!("Collection not found: {key}"))?;
+    load_collection_in_dir(&summary.id, dir)
+}
+
+/// The importer, with git metadata when the collection tracks a repository.
+fn import_service(collection: &Collection) -> ImportService {
+    if collection.source.repo_root.is_some() {
+        ImportService::with_git_metadata(
+            vec![Box::new(OpenApiParser)],
+            Box::new(HttpContentFetcher),
+            Box::new(GitCliAdapter),
+        )
+    } else {
+        ImportService::new(vec![Box::new(OpenApiParser)], Box::new(HttpContentFetcher))
+    }
+}
+
+fn list(
+    dir: &Path,
+    collection: Option<&str>,
+    json: bool,
+    out: &mut (dyn Write + Send),
+) -> Result<bool, String> {
+    let Some(key) = collection else {
+        let summaries = list_collections_in_dir(dir)?;
+        if json {
+            print_json(out, &summaries);
+        }
+        for summary in summaries.iter().filter(|_| !json) {
+            let _ = writeln!(
+                out,
+                "{}  {}  ({} requests)",
+                summary.id, summary.name, summary.request_count
+            );
+        }
+        return Ok(true);
+    };
+    let collection = find_collection(dir, key)?;
+    if json {
+        print_json(out, &collection);
+        return Ok(true);
+    }
+    let _ = writeln!(out, "{} ({})", collection.metadata.name, collection.id);
+    for request in collection.sorted_requests() {
+        let _ = writeln!(
+            out,
+            "  {} {}  {}  [{}]",
+            request.method, request.name, request.url, request.id
+        );
+    }
+    for environment in &collection.environments {
+        let active = collection.active_environment.as_ref() == Some(&environment.name);
+        let marker = if active { " (active)" } else { "" };
+        let _ = writeln!(out, "  env {}{marker}", environment.name);
+    }
+    Ok(true)
+}
+
+/// Run `collection`, printing each request as it finishes and a summary.
+async fn run(
+    collection: &Collection,
+    dir: &Path,
+    options: &RunOptions,
+    json: bool,
+    out: &mut (dyn Write + Send),
+) -> Result<RunReport, String> {
+    let run_id = format!("run-{}", uuid::Uuid::now_v7());
+    let (events, mut updates) = tokio::sync::mpsc::unbounded_channel();
+    let run = async {
+        let report = run_collection(collection, dir, options, &run_id, &events).await;
+        drop(events);
+        report
+    };
+    let progress = async {
+        while let Some(event) = updates.recv().await {
+            if let RunUpdate::RequestFinished { result, .. } = event.update
+                && !json
+            {
+                print_result(out, &result);
+            }
+        }
+    };
+    let (report, ()) = tokio::join!(run, progress);
+    if json {
+        print_json(out, &report);
+    } else {
+        let _ = writeln!(
+            out,
+            "{} passed, {} failed, {} errored, {} skipped in {} ms",
+            report.passed, report.failed, report.errored, report.skipped, report.duration_ms
+        );
+    }
+    Ok(report)
+}
+
+fn print_result(out: &mut (dyn Write + Send), result: &RunRequestResult) {
+    let label = match result.outcome {
+        RunOutcome::Passed => "PASS ",
+        RunOutcome::Failed => "FAIL ",
+        RunOutcome::Errored => "ERROR",
+        RunOutcome::Skipped => "SKIP ",
+    };
+    let status = result
+        .status
+        .map(|status| format!("  {status} {}", result.status_text.as_deref().unwrap_or("")))
+        .unwrap_or_default();
+    let _ = writeln!(
+        out,
+        "{label} {} {}{}  ({} ms)",
+        result.method,
+        result.name,
+        status.trim_end(),
+        result.duration_ms
+    );
+    if let Some(error) = &result.error {
+        let _ = writeln!(out, "      {error}");
+    }
+    for message in result
+        .assertions
+        .iter()
+        .filter(|assertion| !assertion.passed)
+        .filter_map(|assertion| assertion.message.as_deref())
+    {
+        let _ = writeln!(out, "      {message}");
+    }
+}
+
+async fn import(
+    dir: &Path,
+    source: &str,
+    name: Option<String>,
+    json: bool,
+    out: &mut (dyn Write + Send),
+) -> Result<bool, String> {
+    let service = ImportService::new(vec![Box::new(OpenApiParser)], Box::new(HttpContentFetcher));
+    let overrides = ImportOverrides {
+        display_name: name,
+        ..Default::default()
+    };
+    let collection = service.import(spec_source(source), overrides).await?;
+    if let Some(existing) = find_collection_by_name_in_dir(&collection.metadata.name, dir)? {
+        return Err(format!(
+            "A collection named {} already exists ({}); refresh it instead",
+            existing.name, existing.id
+        ));
+    }
+    save_collection_in_dir(&collection, dir)?;
+    if json {
+        print_json(out, &collection);
+    } else {
+        let _ = writeln!(
+            out,
+            "Imported {} ({}) with {} requests",
+            collection.metadata.name,
+            collection.id,
+            collection.requests.len()
+        );
+    }
+    Ok(true)
+}
+
+fn export(
+    collection: &Collection,
+    out_dir: &Path,
+    options: &HurlExportOptions,
+    json: bool,
+    out: &mut (dyn Write + Send),
+) -> Result<bool, String> {
+    let export = export_collection_to_hurl(collection, out_dir, options)?;
+    if json {
+        print_json(out, &export);
+        return Ok(true);
+    }
+    for file in &export.files {
+        let _ = writeln!(out, "Wrote {} ({} requests)", file.path, file.request_count);
+    }
+    if let Some(path) = &export.variables_file {
+        let _ = writeln!(out, "Wrote {path}");
+    }
+    for warning in &export.warnings {
+        eprintln!("warning: {warning}");
+    }
+    Ok(true)
+}
+
+fn print_drift(drift: &SpecRefreshResult, json: bool, out: &mut (dyn Write + Send)) {
+    if json {
+        print_json(out, drift);
+        return;
+    }
+    if !drift.changed {
+        let _ = writeln!(out, "No drift");
+        return;
+    }
+    for op in &drift.operations_added {
+        let _ = writeln!(out, "  + {} {}", op.method, op.path);
+    }
+    for op in &drift.operations_removed {
+        let _ = writeln!(out, "  - {} {}", op.method, op.path);
+    }
+    for op in &drift.operations_changed {
+        let _ = writeln!(
+            out,
+            "  ~ {} {}: {}",
+            op.method,
+            op.path,
+            op.changes.join(", ")
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{} added, {} removed, {} changed{}",
+        drift.operations_added.len(),
+        drift.operations_removed.len(),
+        drift.operations_changed.len(),
+        if drift.is_breaking() {
+            " (breaking)"
+        } else {
+            ""
+        }
+    );
+}
+
+fn print_json(out: &mut (dyn Write + Send), value: &(impl Serialize + ?Sized)) {
+    let text = serde_json::to_string_pretty(value).unwrap_or_else(|_| "null".to_string());
+    let _ = writeln!(out, "{text}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::assertion::Assertion;
+    use crate::domain::collection::{CollectionEnvironment, CollectionRequest};
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use tempfile::TempDir;
+
+    /// Serve `count` requests, answering `/status/<code>` with that status.
+    fn start_server(count: usize) -> Option<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+        let addr = listener.local_addr().ok()?;
+        std::thread::spawn(move || {
+            for _ in 0..count {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                let _ = reader.read_line(&mut request_line);
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                    line.clear();
+                }
+                let status = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|path| path.strip_prefix("/status/"))
+                    .unwrap_or("200")
+                    .to_string();
+                let _ = write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Test\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+            }
+        });
+        Some(format!("http://{addr}"))
+    }
+
+    async fn execute_line(dir: &Path, line: &str) -> (Result<bool, String>, String) {
+        let args = ["--dir", dir.to_str().unwrap()]
+            .into_iter()
+            .chain(line.split_whitespace())
+            .map(ToString::to_string);
+        let cli = args::parse(args).unwrap();
+        let mut out = Vec::new();
+        let result = execute(&cli, &mut out).await;
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    fn shop(base: &str) -> Collection {
+        let mut collection = Collection::new("Shop");
+        collection.environments.push(CollectionEnvironment {
+            name: "local".to_string(),
+            variables: [("base".to_string(), base.to_string())].into(),
+            ..Default::default()
+        });
+        for (seq, (name, path, expected)) in
+            [("health", "/status/200", 200), ("gone", "/status/410", 404)]
+                .into_iter()
+                .enumerate()
+        {
+            collection.requests.push(CollectionRequest {
+                id: format!("req_{name}"),
+                name: name.to_string(),
+                seq: u32::try_from(seq).unwrap(),
+                method: "GET".to_string(),
+                url: format!("{{{{base}}}}{path}"),
+                assertions: vec![Assertion::StatusEquals { status: expected }],
+                ..Default::default()
+            });
+        }
+        collection
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_failures_and_writes_junit() {
+        let Some(base) = start_server(3) else {
+            return;
+        };
+        let dir = TempDir::new().unwrap();
+        save_collection_in_dir(&shop(&base), dir.path()).unwrap();
+        let junit = dir.path().join("report.xml");
+
+        let (result, out) = execute_line(
+            dir.path(),
+            &format!("run Shop --env local --junit {}", junit.display()),
+        )
+        .await;
+
+        assert_eq!(result, Ok(false));
+        assert!(out.contains("PASS  GET health  200 Test"), "{out}");
+        assert!(out.contains("FAIL  GET gone  410 Test"), "{out}");
+        assert!(
+            out.contains("      Expected status to be 404, got 410"),
+            "{out}"
+        );
+        assert!(
+            out.contains("1 passed, 1 failed, 0 errored, 0 skipped"),
+            "{out}"
+        );
+        assert!(
+            std::fs::read_to_string(&junit)
+                .unwrap()
+                .contains("failures=\"1\"")
+        );
+
+        let (result, out) = execute_line(dir.path(), "run Shop --env local --request health").await;
+        assert_eq!(result, Ok(true));
+        assert!(!out.contains("gone"), "{out}");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_collections_and_environments_are_errors() {
+        let dir = TempDir::new().unwrap();
+        save_collection_in_dir(&shop("http://127.0.0.1:1"), dir.path()).unwrap();
+
+        let (result, _) = execute_line(dir.path(), "run Nope").await;
+        assert_eq!(result, Err("Collection not found: Nope".to_string()));
+        let (result, _) = execute_line(dir.path(), "run Shop --env prod").await;
+        assert_eq!(
+            result,
+            Err("Collection Shop has no environment prod".to_string())
+        );
+
+        let (result, out) = execute_line(dir.path(), "list Shop").await;
+        assert_eq!(result, Ok(true));
+        assert!(
+            out.contains("  GET health  {{base}}/status/200  [req_health]"),
+            "{out}"
+        );
+        assert!(out.contains("  env local"), "{out}");
+    }
+
+    #[tokio::test]
+    async fn test_breaking_drift_fails_diff() {
+        let dir = TempDir::new().unwrap();
+        let mut collection = shop("http://127.0.0.1:1");
+        for request in &mut collection.requests {
+            request.url = request.url.replace("{{base}}", "");
+        }
+        save_collection_in_dir(&collection, dir.path()).unwrap();
+        let spec = dir.path().join("spec.json");
+        std::fs::write(
+            &spec,
+            r#"{"openapi":"3.0.0","info":{"title":"Shop","version":"2.0.0"},"paths":{
+                "/status/200":{"get":{"summary":"health","responses":{"200":{"description":"ok"}}}},
+                "/orders":{"post":{"summary":"order","responses":{"201":{"description":"ok"}}}}}}"#,
+        )
+        .unwrap();
+
+        let (result, out) =
+            execute_line(dir.path(), &format!("diff Shop --spec {}", spec.display())).await;
+
+        assert_eq!(result, Ok(false));
+        assert!(out.contains("  + POST /orders"), "{out}");
+        assert!(out.contains("  - GET /status/410"), "{out}");
+        assert!(out.contains("(breaking)"), "{out}");
+    }
+}
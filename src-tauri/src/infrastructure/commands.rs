@@ -14,29 +14,75 @@ use tokio::sync::{Mutex, RwLock};
 use tracing::error;
 use ts_rs::TS;
 
+use crate::application::collection_runner::run_collection;
+use crate::application::graphql_service::{
+    format_graphql_issues, introspect_schema, list_graphql_operations_in_dir,
+    validate_graphql_request_in_dir,
+};
 use crate::application::import_service::{ImportOverrides, ImportService};
+use crate::application::load_tester::run_load_test;
 use crate::application::proxy_service::ProxyService;
+use crate::application::request_resolver::collection_request_params;
+use crate::application::spec_refresh::{
+    build_parsed_spec_from_collection, diff_pinned_version, refresh_collection_spec_in_dir,
+};
+use crate::application::variable_extraction::extract_variables_in_dir;
+use crate::domain::assertion::evaluate_assertions;
 use crate::domain::canvas_state::CanvasStateSnapshot;
+use crate::domain::collection::run::{CollectionRunEvent, RunOptions, RunReport};
 use crate::domain::collection::spec_port::SpecSource;
 use crate::domain::collection::{
     BodyType, Collection, CollectionEnvironment, CollectionRequest, CollectionSource,
     PinnedSpecVersion, PinnedVersionRole, RequestBody, SpecBinding,
 };
+use crate::domain::cookies::{Cookie, CookieJarScope, remove_cookies, upsert_cookie};
+use crate::domain::extraction::{CollectionRequestExecution, variable_changes};
 use crate::domain::features::config as feature_config;
-use crate::domain::http::{HttpResponse, RequestParams};
+use crate::domain::graphql::{
+    GraphqlIssue, GraphqlOperationInfo, GraphqlQuery, GraphqlSchemaCache,
+};
+use crate::domain::http::{
+    ConnectToOverride, HttpResponse, ProxyConfig, RequestParams, ResolveOverride, TlsConfig,
+    validate_host_overrides,
+};
+use crate::domain::http_stream::HttpStreamEvent;
+use crate::domain::load_test::{LoadTestEvent, LoadTestOptions, LoadTestReport};
 use crate::domain::mcp::events::{Actor, EventEmitter, EventEnvelope};
 use crate::domain::models::HelloWorldResponse;
+use crate::domain::websocket::{
+    WebSocketEvent, WebSocketSend, WebSocketSessionInfo, WebSocketTranscript,
+};
 use crate::infrastructure::git::GitCliAdapter;
+use crate::infrastructure::http::{execute_http_request, execute_streaming_http_request};
+use crate::infrastructure::hurl::exporter::{
+    HurlExport, HurlExportOptions, export_collection_to_hurl,
+};
 use crate::infrastructure::mcp::events::TauriEventEmitter;
+use crate::infrastructure::mcp::server::sse_broadcaster::{SseBroadcaster, SseEvent};
 use crate::infrastructure::spec::http_fetcher::HttpContentFetcher;
 use crate::infrastructure::spec::openapi_parser::OpenApiParser;
 use crate::infrastructure::storage::collection_store::{
-    CollectionSummary, delete_collection, find_collection_by_name, list_collections,
-    load_collection, open_collection_file, save_collection,
+    CollectionSummary, delete_collection, find_collection_by_name, get_collections_dir,
+    list_collections, load_collection, open_collection_file, save_collection,
 };
+use crate::infrastructure::storage::cookie_store::{load_cookies, update_cookies};
+use crate::infrastructure::storage::graphql_schema_store::load_graphql_schema;
 use crate::infrastructure::storage::history::HistoryEntry;
+use crate::infrastructure::storage::load_test_store::{
+    list_load_test_reports, load_load_test_report, save_load_test_report,
+};
 use crate::infrastructure::storage::memory_storage::MemoryHistoryStorage;
+use crate::infrastructure::storage::network_settings::{
+    NetworkSettings, load_network_settings, network_settings_path, save_network_settings,
+};
+use crate::infrastructure::storage::runtime_variable_store::{
+    clear_runtime_variables, runtime_variables,
+};
 use crate::infrastructure::storage::traits::HistoryStorage;
+use crate::infrastructure::websocket::{
+    close_websocket, connect_websocket, list_websocket_sessions, send_websocket_message,
+    websocket_transcript,
+};
 
 /// Global history storage instance (in-memory by default).
 ///
@@ -213,117 +259,6 @@ pub async fn cmd_import_collection(
     Ok(result)
 }
 
-/// Extract the path portion from a URL or template URL.
-///
-/// Handles various input formats:
-/// - Full URLs: `https://api.example.com/users` → `/users`
-/// - Template URLs: `{{baseUrl}}/users` → `/users`
-/// - Paths: `/users` → `/users` (passthrough)
-/// - Root URLs: `https://api.example.com` → `/`
-///
-/// This is used when building old endpoints from collection requests for drift detection,
-/// ensuring path-only comparisons match the new spec's path format.
-fn extract_path_from_url(url: &str) -> String {
-    let raw = {
-        // 1. If already starts with `/`, it's a path — return as-is
-        if url.starts_with('/') {
-            url.to_string()
-        }
-        // 2. If starts with `{{`, extract from first `/` after `}}`
-        else if url.starts_with("{{") {
-            url.find("}}").map_or_else(
-                || format!("/{url}"),
-                |end_idx| {
-                    let after_template = &url[end_idx + 2..];
-                    after_template.find('/').map_or_else(
-                        || format!("/{after_template}"),
-                        |slash_idx| after_template[slash_idx..].to_string(),
-                    )
-                },
-            )
-        }
-        // 3. If contains `://` (any scheme), extract path after authority
-        else if let Some(scheme_end) = url.find("://") {
-            let after_scheme = &url[scheme_end + 3..];
-            // Find first `/` after authority (host:port)
-            after_scheme.find('/').map_or_else(
-                || "/".to_string(),
-                |slash_idx| after_scheme[slash_idx..].to_string(),
-            )
-        }
-        // 4. Fallback: prepend `/` to make it a path
-        else {
-            format!("/{url}")
-        }
-    };
-
-    // Strip query string and fragment (OpenAPI paths never include these)
-    raw.split(['?', '#']).next().unwrap_or(&raw).to_string()
-}
-
-/// Build a [`ParsedSpec`] from a collection's current requests.
-///
-/// Maps each `CollectionRequest` to a `ParsedEndpoint`, using binding metadata
-/// (path, method, operation ID) when available and falling back to request fields.
-/// The resulting `ParsedSpec` represents the "old" spec state for drift comparisons.
-fn build_parsed_spec_from_collection(
-    collection: &Collection,
-) -> crate::domain::collection::spec_port::ParsedSpec {
-    use crate::domain::collection::spec_port::{
-        ParameterLocation, ParsedEndpoint, ParsedParameter, ParsedSpec,
-    };
-
-    let endpoints: Vec<ParsedEndpoint> = collection
-        .requests
-        .iter()
-        .map(|req| {
-            let path = req
-                .binding
-                .path
-                .clone()
-                .unwrap_or_else(|| extract_path_from_url(&req.url));
-            let method = req
-                .binding
-                .method
-                .clone()
-                .unwrap_or_else(|| req.method.clone());
-            ParsedEndpoint {
-                operation_id: req.binding.operation_id.clone(),
-                method,
-                path,
-                summary: Some(req.name.clone()),
-                description: req.docs.clone(),
-                tags: req.tags.clone(),
-                parameters: req
-                    .params
-                    .iter()
-                    .map(|p| ParsedParameter {
-                        name: p.key.clone(),
-                        location: ParameterLocation::Query,
-                        required: false,
-                        schema_type: None,
-                        default_value: Some(p.value.clone()),
-                        description: None,
-                    })
-                    .collect(),
-                request_body: None,
-                deprecated: false,
-                is_streaming: req.is_streaming,
-            }
-        })
-        .collect();
-
-    ParsedSpec {
-        title: collection.metadata.name.clone(),
-        version: collection.source.spec_version.clone(),
-        description: collection.metadata.description.clone(),
-        base_urls: vec![],
-        endpoints,
-        auth_schemes: vec![],
-        variables: std::collections::BTreeMap::new(),
-    }
-}
-
 /// Refresh a collection's spec from its tracked source (core logic, no `AppHandle`).
 ///
 /// Re-fetches the spec, computes hash for fast-path skip, then structural drift
@@ -347,96 +282,20 @@ pub async fn refresh_collection_spec_inner(
 
 /// Refresh a collection spec, optionally overriding the source with a new path.
 ///
-/// If `override_source` is provided it is used instead of the stored source
-/// (useful for "compare v1 collection against v2.json").
+/// See [`refresh_collection_spec_in_dir`]; the collection is loaded from and
+/// saved to the collections directory.
 ///
 /// # Errors
 ///
-/// Returns an error if:
-/// - The collection cannot be found or loaded
-/// - No source can be resolved (`override_source` is `None` and the collection has no stored URL)
-/// - Re-fetching the spec content fails
-/// - Re-parsing the new spec content fails
-/// - The collection cannot be saved after the update
+/// Returns an error if the collections directory cannot be resolved or the
+/// refresh fails.
 pub async fn refresh_collection_spec_inner_with_source(
     collection_id: &str,
     service: &ImportService,
     override_source: Option<&str>,
 ) -> Result<crate::domain::collection::drift::SpecRefreshResult, String> {
-    use crate::domain::collection::drift::compute_drift;
-    use crate::domain::collection::spec_port::SpecSource;
-    use crate::infrastructure::spec::hasher::compute_spec_hash;
-
-    // 1. Load collection
-    let mut collection = load_collection(collection_id)?;
-
-    // 2. Resolve source — override > stored url/path
-    let source_str = override_source
-        .or(collection.source.url.as_deref())
-        .ok_or_else(|| "Collection has no tracked spec source".to_string())?;
-
-    // 3. Route to File or URL: treat anything containing a scheme separator as a URL
-    let spec_source = if source_str.contains("://") {
-        SpecSource::Url(source_str.to_string())
-    } else {
-        SpecSource::File(std::path::PathBuf::from(source_str))
-    };
-
-    // 4. Re-fetch content
-    let fetch_result = service.fetcher().fetch(&spec_source).await?;
-
-    // 5. Compute new hash — fast path if unchanged
-    let new_hash = format!("sha256:{}", compute_spec_hash(&fetch_result.content));
-    if collection.source.hash.as_deref() == Some(&new_hash) {
-        // Fast path: hash unchanged, but update metadata
-        let now = chrono::Utc::now();
-        collection.source.fetched_at = now.format("%Y-%m-%dT%H:%M:%SZ").to_string();
-
-        // Update source_commit if repo_root is tracked
-        if let Some(repo_root) = &collection.source.repo_root {
-            if let Some(sha) =
-                service.resolve_git_commit(repo_root, collection.source.ref_name.as_deref())
-            {
-                collection.source.source_commit = Some(sha);
-            }
-        }
-
-        save_collection(&collection)?;
-        return Ok(crate::domain::collection::drift::SpecRefreshResult {
-            changed: false,
-            operations_added: vec![],
-            operations_removed: vec![],
-            operations_changed: vec![],
-        });
-    }
-
-    // 5. Re-parse new content into ParsedSpec
-    let new_spec = service.parse_content(&fetch_result.content)?;
-
-    // 6. Reconstruct old spec from collection's requests for drift comparison
-    let old_spec = build_parsed_spec_from_collection(&collection);
-
-    // 7. Compute drift
-    let drift = compute_drift(&old_spec, &new_spec);
-
-    // 8. Update source metadata
-    let now = chrono::Utc::now();
-    collection.source.hash = Some(new_hash);
-    collection.source.fetched_at = now.format("%Y-%m-%dT%H:%M:%SZ").to_string();
-
-    // 8b. Update source_commit if repo_root is tracked
-    if let Some(repo_root) = &collection.source.repo_root {
-        if let Some(sha) =
-            service.resolve_git_commit(repo_root, collection.source.ref_name.as_deref())
-        {
-            collection.source.source_commit = Some(sha);
-        }
-    }
-
-    // 9. Persist
-    save_collection(&collection)?;
-
-    Ok(drift)
+    let dir = get_collections_dir()?;
+    refresh_collection_spec_in_dir(&dir, collection_id, service, override_source).await
 }
 
 /// Refresh a collection's spec and compute drift delta.
@@ -489,9 +348,10 @@ pub async fn cmd_refresh_collection_spec(
     Ok(result)
 }
 
-/// Create suggestions from drift results — one `drift_fix` per removed operation
-/// (breaking change) and one `optimization` per changed operation.
-/// Emits `suggestion:created` for each suggestion so the frontend updates in real-time.
+/// Create suggestions from drift results.
+///
+/// One `drift_fix` per removed operation (breaking change) and one
+/// `optimization` per changed operation. Emits `suggestion:created` for each suggestion so the frontend updates in real-time.
 pub fn auto_create_drift_suggestions(
     result: &crate::domain::collection::drift::SpecRefreshResult,
     collection_id: &str,
@@ -611,6 +471,7 @@ fn emit_collection_event(
 }
 
 /// Initialize the proxy service
+#[must_use]
 pub fn create_proxy_service() -> Arc<Mutex<ProxyService>> {
     Arc::new(Mutex::new(ProxyService::new()))
 }
@@ -694,13 +555,20 @@ pub fn get_process_startup_time(
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemSpecs {
+    /// CPU brand string.
     pub cpu_model: String,
+    /// Logical CPU count.
     pub cpu_cores: usize,
+    /// Total RAM in GB.
     pub total_memory_gb: f64,
+    /// Operating system.
     pub platform: String,
+    /// CPU architecture.
     pub architecture: String,
-    pub build_mode: String,  // "dev" or "release"
-    pub bundle_size_mb: f64, // Application bundle size in MB
+    /// "dev" or "release".
+    pub build_mode: String,
+    /// Application bundle size in MB.
+    pub bundle_size_mb: f64,
 }
 
 /// Get detailed system specifications.
@@ -1034,6 +902,8 @@ fn update_request_inner(
             body_type: bt,
             content: Some(b),
             file: None,
+            parts: Vec::new(),
+            graphql: None,
         });
     } else if let Some(bt) = body_type {
         if let Some(ref mut b) = request.body {
@@ -1281,6 +1151,8 @@ fn save_tab_to_collection_inner(
         body_type: effective_body_type,
         content: Some(content.to_string()),
         file: None,
+        parts: Vec::new(),
+        graphql: None,
     });
     let request = CollectionRequest {
         id: CollectionRequest::generate_id(name),
@@ -1309,10 +1181,10 @@ fn infer_body_type_from_headers(headers: &BTreeMap<String, String>) -> BodyType
     let normalized = content_type.to_ascii_lowercase();
     if normalized.contains("application/json") {
         BodyType::Json
-    } else if normalized.contains("application/x-www-form-urlencoded")
-        || normalized.contains("multipart/form-data")
-    {
+    } else if normalized.contains("application/x-www-form-urlencoded") {
         BodyType::Form
+    } else if normalized.contains("multipart/form-data") {
+        BodyType::Multipart
     } else if normalized.contains("application/graphql")
         || normalized.contains("application/graphql+json")
     {
@@ -1591,6 +1463,88 @@ pub async fn save_request_history(
     Ok(id)
 }
 
+/// An event [`publish_events`] forwards, broadcast to MCP SSE subscribers on
+/// the topic its update names.
+pub trait PublishedEvent: Serialize {
+    /// SSE topic of the event, e.g. `stream:message`.
+    fn topic(&self) -> &'static str;
+}
+
+/// Forward live updates to the UI and to MCP SSE subscribers.
+///
+/// Each event is emitted as a Tauri event named `event_name` and broadcast on
+/// the `SseBroadcaster` topic named by [`PublishedEvent::topic`], so MCP
+/// clients can follow along with e.g. `?topics=stream:*`. Returns once the
+/// sender side is dropped.
+pub async fn publish_events<E: PublishedEvent>(
+    app: Option<tauri::AppHandle>,
+    broadcaster: Option<Arc<SseBroadcaster>>,
+    event_name: &'static str,
+    mut events: tokio::sync::mpsc::UnboundedReceiver<E>,
+) {
+    while let Some(event) = events.recv().await {
+        if let Some(Err(e)) = app.as_ref().map(|app| app.emit(event_name, &event)) {
+            tracing::warn!("Failed to emit {event_name} event: {e}");
+        }
+        if let Some(broadcaster) = &broadcaster {
+            let data = serde_json::to_value(&event).unwrap_or_else(|_| json!({}));
+            broadcaster
+                .broadcast_to_topic(SseEvent::new(event.topic().to_string(), data))
+                .await;
+        }
+    }
+}
+
+/// Tauri event carrying live stream updates.
+pub const HTTP_STREAM_EVENT: &str = "http:stream";
+
+/// Stream updates go out on the `stream:started`, `stream:message`,
+/// `stream:finished` and `stream:failed` topics.
+impl PublishedEvent for HttpStreamEvent {
+    fn topic(&self) -> &'static str {
+        self.update.event_type()
+    }
+}
+
+/// Execute a request in streaming mode, publishing chunks as they arrive.
+///
+/// Intended for requests flagged `is_streaming` (SSE, NDJSON, token streams).
+/// Live updates go out as `http:stream` events and on the `stream:*` SSE topics
+/// (see [`publish_events`]). Once the stream closes, the response —
+/// including the decoded transcript — is saved to history like any other.
+///
+/// The request can be aborted with `cancel_request` using the same correlation ID.
+///
+/// # Errors
+///
+/// Returns a JSON-serialized `AppError` string if the request fails, or an
+/// error if the history entry cannot be saved.
+#[tauri::command]
+pub async fn execute_streaming_request(
+    app: tauri::AppHandle,
+    broadcaster: tauri::State<'_, crate::infrastructure::mcp::commands::SseBroadcasterHandle>,
+    params: RequestParams,
+    correlation_id: Option<String>,
+) -> Result<HttpResponse, String> {
+    let correlation_id =
+        correlation_id.unwrap_or_else(|| format!("stream-{}", uuid::Uuid::now_v7()));
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        Some(app.clone()),
+        Some(broadcaster.inner().clone()),
+        HTTP_STREAM_EVENT,
+        rx,
+    ));
+
+    let result = execute_streaming_http_request(params.clone(), correlation_id, tx).await;
+    // The sender is gone once the request finished; let the publisher drain
+    let _ = publisher.await;
+
+    let response = result?;
+    save_request_history(app, params, response.clone()).await?;
+    Ok(response)
+}
+
 /// Load recent history entries.
 ///
 /// Uses the configured storage backend (default: in-memory).
@@ -2293,6 +2247,538 @@ pub async fn cmd_run_hurl_suite(request: RunHurlSuiteRequest) -> Result<HurlSuit
     })
 }
 
+/// Exports a collection to `.hurl` files in `output_dir`, one per collection
+/// or per tag, with a variables file for the chosen environment.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_export_collection_hurl(
+    collection_id: String,
+    output_dir: String,
+    options: Option<HurlExportOptions>,
+) -> Result<HurlExport, String> {
+    let collection = load_collection(&collection_id)?;
+    export_collection_to_hurl(
+        &collection,
+        std::path::Path::new(&output_dir),
+        &options.unwrap_or_default(),
+    )
+}
+
+// ── Collection runs ──────────────────────────────────────────────────
+
+/// Tauri event carrying collection run progress.
+pub const COLLECTION_RUN_EVENT: &str = "collection:run";
+
+/// Run progress goes out on the `run:started`, `run:request_started`,
+/// `run:request_finished` and `run:finished` topics.
+impl PublishedEvent for CollectionRunEvent {
+    fn topic(&self) -> &'static str {
+        self.update.event_type()
+    }
+}
+
+/// Resolve a saved request into the parameters it is sent with (core logic, no `AppHandle`).
+fn load_collection_request(
+    collection_id: &str,
+    request_id: &str,
+) -> Result<(Collection, CollectionRequest), String> {
+    let collection = load_collection(collection_id)?;
+    let request = collection
+        .requests
+        .iter()
+        .find(|r| r.id == request_id)
+        .cloned()
+        .ok_or_else(|| format!("Request not found: {request_id}"))?;
+    Ok((collection, request))
+}
+
+/// Unsaved edits of a saved request, as shown in its request tab.
+///
+/// Sent with [`cmd_execute_collection_request`] in place of the saved
+/// method, URL, headers and body; everything else comes from the saved
+/// request and the collection.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct RequestDraft {
+    /// HTTP method.
+    pub method: String,
+    /// URL, variables unresolved.
+    pub url: String,
+    /// Headers, variables unresolved.
+    pub headers: BTreeMap<String, String>,
+    /// Inline body content; empty for none.
+    pub body: String,
+}
+
+impl RequestDraft {
+    /// Replace the edited fields of `request`. File, multipart and `GraphQL`
+    /// bodies, which the tab shows no content for, are kept.
+    fn apply_to(self, request: &mut CollectionRequest) {
+        request.method = self.method;
+        request.url = self.url;
+        request.headers = self.headers;
+        match &mut request.body {
+            Some(body) if body.content.is_some() || !self.body.is_empty() => {
+                body.content = Some(self.body).filter(|content| !content.is_empty());
+            }
+            None if !self.body.is_empty() => {
+                request.body = Some(RequestBody {
+                    body_type: BodyType::Raw,
+                    content: Some(self.body),
+                    file: None,
+                    parts: Vec::new(),
+                    graphql: None,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+fn resolve_collection_request_inner(
+    collection_id: &str,
+    request_id: &str,
+    draft: Option<RequestDraft>,
+    timeout_ms: Option<u64>,
+) -> Result<RequestParams, String> {
+    let (collection, mut request) = load_collection_request(collection_id, request_id)?;
+    if let Some(draft) = draft {
+        draft.apply_to(&mut request);
+    }
+    let dir = get_collections_dir()?;
+    collection_request_params(&collection, &request, &dir, timeout_ms.unwrap_or(30_000))
+}
+
+/// Resolve a saved request the way MCP `execute_request` and the collection
+/// runner send it.
+///
+/// Variables are substituted in the URL, query params, headers and body,
+/// enabled query params appended, auth inherited from the collection and
+/// rendered, and the active environment's settings applied. The tab's
+/// unsaved edits in `draft` are applied first. The result is ready for
+/// `execute_request` or `execute_streaming_request`.
+///
+/// # Errors
+///
+/// Returns an error if the collection cannot be loaded, has no such request,
+/// or the request's variables cannot be resolved.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_resolve_collection_request(
+    collection_id: String,
+    request_id: String,
+    draft: Option<RequestDraft>,
+    timeout_ms: Option<u64>,
+) -> Result<RequestParams, String> {
+    resolve_collection_request_inner(&collection_id, &request_id, draft, timeout_ms)
+}
+
+async fn execute_collection_request_inner(
+    collection_id: &str,
+    request_id: &str,
+    draft: Option<RequestDraft>,
+    timeout_ms: Option<u64>,
+    correlation_id: Option<String>,
+) -> Result<CollectionRequestExecution, String> {
+    let (collection, mut request) = load_collection_request(collection_id, request_id)?;
+    if let Some(draft) = draft {
+        draft.apply_to(&mut request);
+    }
+    let dir = get_collections_dir()?;
+    let issues = validate_graphql_request_in_dir(&dir, collection_id, &request)?;
+    if !issues.is_empty() {
+        return Err(format_graphql_issues(&issues));
+    }
+    let params =
+        collection_request_params(&collection, &request, &dir, timeout_ms.unwrap_or(30_000))?;
+    let response = execute_http_request(params.clone(), correlation_id).await?;
+    let assertions = evaluate_assertions(&request.assertions, &response);
+    // The request went out; a failure to store its values must not lose the response
+    let extracted = extract_variables_in_dir(&dir, collection_id, request_id, &response)
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to apply extraction rules of {request_id}: {e}");
+            Vec::new()
+        });
+    Ok(CollectionRequestExecution {
+        request: params,
+        response,
+        extracted,
+        assertions,
+    })
+}
+
+/// Send a saved request, apply its extraction rules to the response and
+/// evaluate its assertions.
+///
+/// The request is resolved like [`cmd_resolve_collection_request`], with the
+/// tab's unsaved edits in `draft` applied first, and sent like
+/// `execute_request`, cancellable by `correlation_id`. Captured values
+/// are stored in the collection's runtime variables or active environment,
+/// and each one is announced with a `variable:changed` event carrying a
+/// [`VariableChanged`](crate::domain::extraction::VariableChanged).
+///
+/// # Errors
+///
+/// Returns an error if the request cannot be resolved or its GraphQL query
+/// fails validation against the cached schema, or a JSON-serialized
+/// `AppError` string if the transfer fails. Failed extraction rules and
+/// assertions are reported in the result instead.
+#[tauri::command]
+pub async fn cmd_execute_collection_request(
+    app: tauri::AppHandle,
+    collection_id: String,
+    request_id: String,
+    draft: Option<RequestDraft>,
+    timeout_ms: Option<u64>,
+    correlation_id: Option<String>,
+) -> Result<CollectionRequestExecution, String> {
+    let execution = execute_collection_request_inner(
+        &collection_id,
+        &request_id,
+        draft,
+        timeout_ms,
+        correlation_id,
+    )
+    .await?;
+    for change in variable_changes(&collection_id, &request_id, &execution.extracted) {
+        let envelope = EventEnvelope {
+            actor: Actor::User,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            correlation_id: None,
+            lamport: None,
+            payload: serde_json::to_value(&change).unwrap_or_else(|_| json!({})),
+        };
+        if let Err(e) = app.emit("variable:changed", &envelope) {
+            tracing::warn!("Failed to emit variable:changed event: {e}");
+        }
+    }
+    Ok(execution)
+}
+
+/// Runtime variables of a collection: the values its requests' extraction
+/// rules captured this session.
+#[tauri::command]
+#[must_use]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_get_runtime_variables(collection_id: String) -> BTreeMap<String, String> {
+    runtime_variables(&collection_id)
+}
+
+/// Forget the runtime variables of a collection.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_clear_runtime_variables(collection_id: String) {
+    clear_runtime_variables(&collection_id);
+}
+
+/// Run a collection's requests in `seq` order against its active environment.
+///
+/// Progress goes out as `collection:run` events (see [`publish_events`]).
+/// The run can be stopped with `cancel_request` using the run ID, which
+/// defaults to a fresh `run-<uuid>`.
+///
+/// # Errors
+///
+/// Returns an error if the collection cannot be loaded. Failed requests are
+/// reported in the [`RunReport`], not as errors.
+#[tauri::command]
+pub async fn cmd_run_collection(
+    app: tauri::AppHandle,
+    broadcaster: tauri::State<'_, crate::infrastructure::mcp::commands::SseBroadcasterHandle>,
+    collection_id: String,
+    options: Option<RunOptions>,
+    run_id: Option<String>,
+) -> Result<RunReport, String> {
+    let collection = load_collection(&collection_id)?;
+    let dir = get_collections_dir()?;
+    let run_id = run_id.unwrap_or_else(|| format!("run-{}", uuid::Uuid::now_v7()));
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        Some(app),
+        Some(broadcaster.inner().clone()),
+        COLLECTION_RUN_EVENT,
+        rx,
+    ));
+
+    let report = run_collection(
+        &collection,
+        &dir,
+        &options.unwrap_or_default(),
+        &run_id,
+        &tx,
+    )
+    .await;
+    drop(tx);
+    let _ = publisher.await;
+    Ok(report)
+}
+
+/// Render a collection run report as `JUnit` XML.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+#[must_use]
+pub fn cmd_run_report_junit(report: RunReport) -> String {
+    report.to_junit_xml()
+}
+
+// ── Load tests ───────────────────────────────────────────────────────
+
+/// Tauri event carrying load test progress.
+pub const LOAD_TEST_EVENT: &str = "load_test";
+
+/// Load test progress goes out on the `load_test:started`,
+/// `load_test:progress` and `load_test:finished` topics.
+impl PublishedEvent for LoadTestEvent {
+    fn topic(&self) -> &'static str {
+        self.update.event_type()
+    }
+}
+
+/// Load test a collection request and save the report.
+///
+/// Progress goes out as `load_test` events (see [`publish_events`]).
+/// The test can be stopped with `cancel_request` using the load test ID,
+/// which defaults to a fresh `load-<uuid>`. The report is saved as a run
+/// artifact; failing to save it is logged, not returned.
+///
+/// # Errors
+///
+/// Returns an error if the collection or request cannot be found or the
+/// options are invalid. Failed requests are counted in the report.
+#[tauri::command]
+pub async fn cmd_run_load_test(
+    app: tauri::AppHandle,
+    broadcaster: tauri::State<'_, crate::infrastructure::mcp::commands::SseBroadcasterHandle>,
+    collection_id: String,
+    request_id: String,
+    options: LoadTestOptions,
+    load_test_id: Option<String>,
+) -> Result<LoadTestReport, String> {
+    let collection = load_collection(&collection_id)?;
+    let request = collection
+        .requests
+        .iter()
+        .find(|r| r.id == request_id)
+        .ok_or_else(|| format!("Request not found: {request_id}"))?;
+    let dir = get_collections_dir()?;
+    let load_test_id = load_test_id.unwrap_or_else(|| format!("load-{}", uuid::Uuid::now_v7()));
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        Some(app),
+        Some(broadcaster.inner().clone()),
+        LOAD_TEST_EVENT,
+        rx,
+    ));
+
+    let report = run_load_test(&collection, request, &dir, &options, &load_test_id, &tx).await;
+    drop(tx);
+    let _ = publisher.await;
+    let report = report?;
+    if let Err(e) = save_load_test_report(&report) {
+        tracing::warn!("Failed to save load test report: {e}");
+    }
+    Ok(report)
+}
+
+/// List saved load test reports, newest first.
+///
+/// # Errors
+///
+/// Returns an error if the reports directory cannot be read.
+#[tauri::command]
+pub fn cmd_list_load_tests() -> Result<Vec<LoadTestReport>, String> {
+    list_load_test_reports()
+}
+
+/// Get a saved load test report by ID.
+///
+/// # Errors
+///
+/// Returns an error if the report does not exist or cannot be parsed.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_get_load_test(load_test_id: String) -> Result<LoadTestReport, String> {
+    load_load_test_report(&load_test_id)
+}
+
+// ── GraphQL ──────────────────────────────────────────────────────────
+
+/// Introspect the endpoint of a collection request and cache its schema for
+/// the collection.
+///
+/// # Errors
+///
+/// Returns an error if the collection or request cannot be found, or the
+/// introspection fails (see [`introspect_schema`]).
+#[tauri::command]
+pub async fn cmd_graphql_introspect(
+    collection_id: String,
+    request_id: String,
+) -> Result<GraphqlSchemaCache, String> {
+    let collection = load_collection(&collection_id)?;
+    let request = collection
+        .requests
+        .iter()
+        .find(|r| r.id == request_id)
+        .ok_or_else(|| format!("Request not found: {request_id}"))?;
+    introspect_schema(&collection, request, &get_collections_dir()?).await
+}
+
+/// Get the GraphQL schema cached for a collection, if any.
+///
+/// # Errors
+///
+/// Returns an error if the cache exists but cannot be read.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_graphql_schema(collection_id: String) -> Result<Option<GraphqlSchemaCache>, String> {
+    load_graphql_schema(&collection_id)
+}
+
+/// Validate a GraphQL query against the schema cached for a collection.
+///
+/// Returns no issues when no schema is cached.
+///
+/// # Errors
+///
+/// Returns an error if the cache exists but cannot be read.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_graphql_validate(
+    collection_id: String,
+    query: GraphqlQuery,
+) -> Result<Vec<GraphqlIssue>, String> {
+    let request = CollectionRequest {
+        body: Some(RequestBody {
+            body_type: BodyType::Graphql,
+            content: None,
+            file: None,
+            parts: Vec::new(),
+            graphql: Some(query),
+        }),
+        ..CollectionRequest::default()
+    };
+    validate_graphql_request_in_dir(&get_collections_dir()?, &collection_id, &request)
+}
+
+/// List the operations of the schema cached for a collection, optionally
+/// limited to one kind (`query`, `mutation` or `subscription`).
+///
+/// # Errors
+///
+/// Returns an error if no schema is cached or the cache cannot be read.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_graphql_operations(
+    collection_id: String,
+    kind: Option<String>,
+) -> Result<Vec<GraphqlOperationInfo>, String> {
+    list_graphql_operations_in_dir(&get_collections_dir()?, &collection_id, kind.as_deref())
+}
+
+// ── WebSocket ────────────────────────────────────────────────────────
+
+/// Tauri event carrying WebSocket session updates.
+pub const WEBSOCKET_EVENT: &str = "websocket";
+
+/// Session updates go out on the `websocket:opened`, `websocket:message`,
+/// `websocket:closed` and `websocket:failed` topics.
+impl PublishedEvent for WebSocketEvent {
+    fn topic(&self) -> &'static str {
+        self.update.event_type()
+    }
+}
+
+/// Open a WebSocket session to `params.url`.
+///
+/// `params.headers` are sent with the handshake and `subprotocols` are
+/// offered to the server. Frames go out as `websocket` events (see
+/// [`publish_events`]) until the session ends; its transcript is
+/// then saved to history. The session ID defaults to a fresh `ws-<uuid>`.
+///
+/// # Errors
+///
+/// Returns a JSON-serialized `AppError` string if the connection or the
+/// handshake fails.
+#[tauri::command]
+pub async fn cmd_websocket_connect(
+    app: tauri::AppHandle,
+    broadcaster: tauri::State<'_, crate::infrastructure::mcp::commands::SseBroadcasterHandle>,
+    params: RequestParams,
+    subprotocols: Option<Vec<String>>,
+    session_id: Option<String>,
+) -> Result<WebSocketSessionInfo, String> {
+    let session_id = session_id.unwrap_or_else(|| format!("ws-{}", uuid::Uuid::now_v7()));
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(publish_events(
+        Some(app.clone()),
+        Some(broadcaster.inner().clone()),
+        WEBSOCKET_EVENT,
+        rx,
+    ));
+
+    let opened = connect_websocket(
+        params.clone(),
+        subprotocols.unwrap_or_default(),
+        session_id,
+        tx,
+    )
+    .await?;
+    tokio::spawn(async move {
+        if let Ok(response) = opened.closed.await
+            && let Err(e) = save_request_history(app, params, response).await
+        {
+            tracing::warn!("Failed to save WebSocket transcript: {e}");
+        }
+    });
+    Ok(opened.info)
+}
+
+/// Send a text, binary (base64 `data`), ping or pong message on an open session.
+///
+/// # Errors
+///
+/// Returns an error if the message is invalid or the session is not open.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_websocket_send(session_id: String, message: WebSocketSend) -> Result<(), String> {
+    send_websocket_message(&session_id, &message)
+}
+
+/// Close an open session with `code` (default 1000) and `reason`.
+///
+/// # Errors
+///
+/// Returns an error if the code or reason is invalid or the session is not open.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_websocket_close(
+    session_id: String,
+    code: Option<u16>,
+    reason: Option<String>,
+) -> Result<(), String> {
+    close_websocket(&session_id, code, reason.as_deref().unwrap_or_default())
+}
+
+/// Get the transcript of an open or recently closed session.
+///
+/// # Errors
+///
+/// Returns an error if the session is unknown.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_websocket_transcript(session_id: String) -> Result<WebSocketTranscript, String> {
+    websocket_transcript(&session_id)
+        .ok_or_else(|| format!("WebSocket session not found: {session_id}"))
+}
+
+/// List open WebSocket sessions, oldest first.
+#[tauri::command]
+#[must_use]
+pub fn cmd_list_websocket_sessions() -> Vec<WebSocketSessionInfo> {
+    list_websocket_sessions()
+}
+
 // ── Project Context ─────────────────────────────────────────────────
 
 /// Managed state type for the project context service.
@@ -2501,6 +2987,59 @@ pub fn create_suggestion_service() -> Result<SuggestionServiceHandle, String> {
     Ok(Arc::new(svc))
 }
 
+// ── Network settings ───────────────────────────────────────────────────────
+
+/// Load the persisted network settings and apply the global proxy.
+///
+/// Called once at startup. A missing settings file means no global proxy.
+///
+/// # Errors
+///
+/// Returns an error if the settings file exists but cannot be read or parsed.
+pub fn init_network_settings() -> Result<(), String> {
+    let settings = load_network_settings(&network_settings_path()?)?;
+    crate::infrastructure::http::set_global_proxy(settings.proxy);
+    Ok(())
+}
+
+/// Validate and persist the global proxy settings (core logic, no `AppHandle`).
+///
+/// Does not touch the in-memory global proxy; the command applies it after saving.
+fn save_proxy_settings_inner(
+    path: &std::path::Path,
+    proxy: Option<ProxyConfig>,
+) -> Result<NetworkSettings, String> {
+    if let Some(proxy) = &proxy {
+        proxy.resolve("https://example.com", |_| None)?;
+    }
+    let mut settings = load_network_settings(path)?;
+    settings.proxy = proxy;
+    save_network_settings(path, &settings)?;
+    Ok(settings)
+}
+
+/// Get the global proxy settings.
+#[tauri::command]
+#[must_use]
+pub fn cmd_get_proxy_settings() -> Option<ProxyConfig> {
+    crate::infrastructure::http::global_proxy()
+}
+
+/// Set the global proxy settings, used by requests whose environment has none.
+///
+/// Pass `proxy: null` to connect directly. Persisted to `network.toml` in the data directory.
+///
+/// # Errors
+///
+/// Returns an error if the proxy URL is invalid or the settings cannot be saved.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_set_proxy_settings(proxy: Option<ProxyConfig>) -> Result<(), String> {
+    let settings = save_proxy_settings_inner(&network_settings_path()?, proxy)?;
+    crate::infrastructure::http::set_global_proxy(settings.proxy);
+    Ok(())
+}
+
 // ── Environment commands ───────────────────────────────────────────────────
 
 /// Upsert a named environment on a collection (core logic, no `AppHandle`).
@@ -2518,6 +3057,7 @@ fn upsert_environment_inner(
         collection.environments.push(CollectionEnvironment {
             name: name.to_string(),
             variables,
+            ..Default::default()
         });
     }
     save_collection(&collection)?;
@@ -2637,11 +3177,265 @@ pub async fn cmd_set_active_environment(
     };
     emit_collection_event(
         &app,
-        event_name,
+        event_name,
+        &Actor::User,
+        json!({"collection_id": &collection_id, "name": &name}),
+    );
+    Ok(())
+}
+
+/// Set or clear the TLS settings of a named environment (core logic, no `AppHandle`).
+fn set_environment_tls_inner(
+    collection_id: &str,
+    name: &str,
+    tls: Option<TlsConfig>,
+) -> Result<Collection, String> {
+    let mut collection = load_collection(collection_id)?;
+    let env = collection
+        .environments
+        .iter_mut()
+        .find(|e| e.name == name)
+        .ok_or_else(|| format!("Environment not found: {name}"))?;
+    env.tls = tls.filter(|tls| *tls != TlsConfig::default());
+    save_collection(&collection)?;
+    Ok(collection)
+}
+
+/// Set the TLS settings (client certificate, CA bundle, verification) of an environment.
+///
+/// Pass `tls: null` to clear them. Emits `collection:environment-updated` with
+/// `Actor::User` on success.
+///
+/// # Errors
+///
+/// Returns an error if the collection cannot be loaded, the environment does not exist,
+/// or the collection cannot be saved.
+#[tauri::command]
+pub async fn cmd_set_environment_tls(
+    app: tauri::AppHandle,
+    collection_id: String,
+    name: String,
+    tls: Option<TlsConfig>,
+) -> Result<(), String> {
+    set_environment_tls_inner(&collection_id, &name, tls)?;
+    emit_collection_event(
+        &app,
+        "collection:environment-updated",
+        &Actor::User,
+        json!({"collection_id": &collection_id, "name": &name}),
+    );
+    Ok(())
+}
+
+/// Set or clear the proxy settings of a named environment (core logic, no `AppHandle`).
+fn set_environment_proxy_inner(
+    collection_id: &str,
+    name: &str,
+    proxy: Option<ProxyConfig>,
+) -> Result<Collection, String> {
+    if let Some(proxy) = &proxy {
+        proxy.resolve("https://example.com", |_| None)?;
+    }
+    let mut collection = load_collection(collection_id)?;
+    let env = collection
+        .environments
+        .iter_mut()
+        .find(|e| e.name == name)
+        .ok_or_else(|| format!("Environment not found: {name}"))?;
+    env.proxy = proxy;
+    save_collection(&collection)?;
+    Ok(collection)
+}
+
+/// Set the proxy settings of an environment, overriding the global proxy while it is active.
+///
+/// Pass `proxy: null` to fall back to the global proxy. Emits
+/// `collection:environment-updated` with `Actor::User` on success.
+///
+/// # Errors
+///
+/// Returns an error if the proxy URL is invalid, the environment does not exist,
+/// or the collection cannot be loaded or saved.
+#[tauri::command]
+pub async fn cmd_set_environment_proxy(
+    app: tauri::AppHandle,
+    collection_id: String,
+    name: String,
+    proxy: Option<ProxyConfig>,
+) -> Result<(), String> {
+    set_environment_proxy_inner(&collection_id, &name, proxy)?;
+    emit_collection_event(
+        &app,
+        "collection:environment-updated",
+        &Actor::User,
+        json!({"collection_id": &collection_id, "name": &name}),
+    );
+    Ok(())
+}
+
+/// Replace the host overrides of a named environment (core logic, no `AppHandle`).
+fn set_environment_host_overrides_inner(
+    collection_id: &str,
+    name: &str,
+    resolve: Vec<ResolveOverride>,
+    connect_to: Vec<ConnectToOverride>,
+) -> Result<Collection, String> {
+    validate_host_overrides(&resolve, &connect_to)?;
+    let mut collection = load_collection(collection_id)?;
+    let env = collection
+        .environments
+        .iter_mut()
+        .find(|e| e.name == name)
+        .ok_or_else(|| format!("Environment not found: {name}"))?;
+    env.resolve = resolve;
+    env.connect_to = connect_to;
+    save_collection(&collection)?;
+    Ok(collection)
+}
+
+/// Set the resolve (`--resolve`) and connect-to (`--connect-to`) host
+/// overrides of an environment, applied to every request sent while it is
+/// active.
+///
+/// Pass empty lists to clear them. Emits `collection:environment-updated`
+/// with `Actor::User` on success.
+///
+/// # Errors
+///
+/// Returns an error if an override is invalid, the environment does not
+/// exist, or the collection cannot be loaded or saved.
+#[tauri::command]
+pub async fn cmd_set_environment_host_overrides(
+    app: tauri::AppHandle,
+    collection_id: String,
+    name: String,
+    resolve: Vec<ResolveOverride>,
+    connect_to: Vec<ConnectToOverride>,
+) -> Result<(), String> {
+    set_environment_host_overrides_inner(&collection_id, &name, resolve, connect_to)?;
+    emit_collection_event(
+        &app,
+        "collection:environment-updated",
+        &Actor::User,
+        json!({"collection_id": &collection_id, "name": &name}),
+    );
+    Ok(())
+}
+
+// ── Cookie jar commands ────────────────────────────────────────────────────
+
+/// Resolve the jar of `collection_id` for `environment`, defaulting to the
+/// collection's active environment.
+fn cookie_jar_scope(
+    collection_id: &str,
+    environment: Option<String>,
+) -> Result<CookieJarScope, String> {
+    let environment = match environment {
+        Some(name) => Some(name),
+        None => load_collection(collection_id)?.active_environment,
+    };
+    Ok(CookieJarScope {
+        collection_id: collection_id.to_string(),
+        environment,
+    })
+}
+
+/// List the cookies stored for a collection and environment.
+///
+/// When `environment` is omitted, the collection's active environment is used.
+///
+/// # Errors
+///
+/// Returns an error if the collection or its cookie jar cannot be loaded.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cmd_list_cookies(
+    collection_id: String,
+    environment: Option<String>,
+) -> Result<Vec<Cookie>, String> {
+    load_cookies(&cookie_jar_scope(&collection_id, environment)?)
+}
+
+/// Insert or replace a cookie (core logic, no `AppHandle`).
+fn set_cookie_inner(
+    collection_id: &str,
+    environment: Option<String>,
+    cookie: Cookie,
+) -> Result<Vec<Cookie>, String> {
+    if cookie.name.trim().is_empty() || cookie.domain.trim().is_empty() {
+        return Err("Cookie name and domain are required".to_string());
+    }
+    let scope = cookie_jar_scope(collection_id, environment)?;
+    update_cookies(&scope, |jar| upsert_cookie(jar, cookie))
+}
+
+/// Insert or replace a cookie in a collection's jar, keyed by name, domain and path.
+///
+/// Returns the updated jar and emits `collection:cookies-updated` with `Actor::User`.
+///
+/// # Errors
+///
+/// Returns an error if the cookie has no name or domain, or the jar cannot be
+/// loaded or saved.
+#[tauri::command]
+pub async fn cmd_set_cookie(
+    app: tauri::AppHandle,
+    collection_id: String,
+    environment: Option<String>,
+    cookie: Cookie,
+) -> Result<Vec<Cookie>, String> {
+    let cookies = set_cookie_inner(&collection_id, environment, cookie)?;
+    emit_collection_event(
+        &app,
+        "collection:cookies-updated",
+        &Actor::User,
+        json!({"collection_id": &collection_id}),
+    );
+    Ok(cookies)
+}
+
+/// Delete matching cookies (core logic, no `AppHandle`).
+fn delete_cookies_inner(
+    collection_id: &str,
+    environment: Option<String>,
+    name: Option<&str>,
+    domain: Option<&str>,
+) -> Result<Vec<Cookie>, String> {
+    let scope = cookie_jar_scope(collection_id, environment)?;
+    update_cookies(&scope, |jar| {
+        remove_cookies(jar, name, domain);
+    })
+}
+
+/// Delete cookies from a collection's jar by name and/or domain.
+///
+/// Omitting both clears the jar. Returns the remaining cookies and emits
+/// `collection:cookies-updated` with `Actor::User`.
+///
+/// # Errors
+///
+/// Returns an error if the jar cannot be loaded or saved.
+#[tauri::command]
+pub async fn cmd_delete_cookies(
+    app: tauri::AppHandle,
+    collection_id: String,
+    environment: Option<String>,
+    name: Option<String>,
+    domain: Option<String>,
+) -> Result<Vec<Cookie>, String> {
+    let cookies = delete_cookies_inner(
+        &collection_id,
+        environment,
+        name.as_deref(),
+        domain.as_deref(),
+    )?;
+    emit_collection_event(
+        &app,
+        "collection:cookies-updated",
         &Actor::User,
-        json!({"collection_id": &collection_id, "name": &name}),
+        json!({"collection_id": &collection_id}),
     );
-    Ok(())
+    Ok(cookies)
 }
 
 // ── Pinned spec version commands ─────────────────────────────────────────────
@@ -2908,32 +3702,9 @@ pub fn compare_spec_versions_inner(
     collection_id: &str,
     pinned_version_id: &str,
 ) -> Result<crate::domain::collection::drift::SpecRefreshResult, String> {
-    use crate::domain::collection::drift::compute_drift;
-
     let collection = load_collection(collection_id)?;
-
-    let staged = collection
-        .pinned_versions
-        .iter()
-        .find(|v| v.id == pinned_version_id)
-        .ok_or_else(|| format!("Pinned version not found: {pinned_version_id}"))?;
-
-    // Guard: archived versions have no stored spec content
-    if staged.spec_content.is_empty() {
-        return Err(format!(
-            "Pinned version {pinned_version_id} has no stored spec content (role: {:?})",
-            staged.role
-        ));
-    }
-
-    // Reconstruct old spec from current requests
-    let old_parsed_spec = build_parsed_spec_from_collection(&collection);
-
-    // Parse staged spec
     let service = ImportService::new(vec![Box::new(OpenApiParser)], Box::new(HttpContentFetcher));
-    let new_parsed_spec = service.parse_content(&staged.spec_content)?;
-
-    Ok(compute_drift(&old_parsed_spec, &new_parsed_spec))
+    diff_pinned_version(&collection, pinned_version_id, &service)
 }
 
 /// Compare a pinned spec version against the active spec and return drift.
@@ -2953,10 +3724,10 @@ pub async fn cmd_compare_spec_versions(
 mod tests {
     use super::*;
     use crate::domain::http::{HttpResponse, RequestParams, RequestTiming};
+    use crate::domain::http_headers::HttpHeaders;
     use crate::infrastructure::storage::collection_store::with_collections_dir_override_async;
     use async_trait::async_trait;
     use serial_test::serial;
-    use std::collections::HashMap;
     use tempfile::TempDir;
     use uuid::{NoContext, Timestamp, Uuid};
 
@@ -3061,9 +3832,10 @@ mod tests {
         RequestParams {
             url: format!("https://api.example.com/test-{suffix}-{unique_id}"),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 30000,
+            ..Default::default()
         }
     }
 
@@ -3072,9 +3844,10 @@ mod tests {
         HttpResponse {
             status: 200,
             status_text: "OK".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: r#"{"test": true}"#.to_string(),
             timing: RequestTiming::default(),
+            ..Default::default()
         }
     }
 
@@ -4267,79 +5040,6 @@ mod tests {
         .await;
     }
 
-    // ── Tests for extract_path_from_url ──
-
-    #[test]
-    fn test_extract_path_from_full_url() {
-        assert_eq!(
-            extract_path_from_url("https://api.example.com/users"),
-            "/users"
-        );
-        assert_eq!(
-            extract_path_from_url("https://api.example.com/users/123"),
-            "/users/123"
-        );
-        assert_eq!(
-            extract_path_from_url("http://localhost:8080/api/v1/items"),
-            "/api/v1/items"
-        );
-    }
-
-    #[test]
-    fn test_extract_path_from_template_url() {
-        assert_eq!(extract_path_from_url("{{baseUrl}}/users"), "/users");
-        assert_eq!(
-            extract_path_from_url("{{baseUrl}}/users/{{id}}"),
-            "/users/{{id}}"
-        );
-    }
-
-    #[test]
-    fn test_extract_path_already_a_path() {
-        assert_eq!(extract_path_from_url("/users"), "/users");
-        assert_eq!(extract_path_from_url("/users/123"), "/users/123");
-    }
-
-    #[test]
-    fn test_extract_path_root() {
-        assert_eq!(extract_path_from_url("https://api.example.com"), "/");
-        assert_eq!(extract_path_from_url("https://api.example.com/"), "/");
-    }
-
-    #[test]
-    fn test_extract_path_with_query_string() {
-        assert_eq!(
-            extract_path_from_url("https://api.example.com/users?limit=10"),
-            "/users"
-        );
-        assert_eq!(
-            extract_path_from_url("{{baseUrl}}/users?limit=10"),
-            "/users"
-        );
-    }
-
-    #[test]
-    fn test_extract_path_with_fragment() {
-        assert_eq!(
-            extract_path_from_url("https://api.example.com/users#section"),
-            "/users"
-        );
-        assert_eq!(extract_path_from_url("{{baseUrl}}/users#section"), "/users");
-    }
-
-    #[test]
-    fn test_extract_path_edge_cases() {
-        // Custom scheme
-        assert_eq!(
-            extract_path_from_url("custom://api.example.com/users"),
-            "/users"
-        );
-        // No path after authority
-        assert_eq!(extract_path_from_url("https://api.example.com"), "/");
-        // Fallback for malformed input
-        assert_eq!(extract_path_from_url("malformed"), "/malformed");
-    }
-
     #[tokio::test]
     #[serial]
     async fn test_create_collection_inner_creates_and_persists() {
@@ -4866,6 +5566,251 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_resolve_collection_request_inner_applies_auth_and_params() {
+        use crate::domain::collection::{AuthConfig, AuthType, RequestParam};
+
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let mut collection = Collection::new("Resolve Test");
+            collection.auth = Some(AuthConfig {
+                auth_type: AuthType::ApiKey,
+                token: Some("{{apiKey}}".to_string()),
+                username: None,
+                password: None,
+                header: None,
+                query_param: Some("key".to_string()),
+            });
+            collection
+                .variables
+                .insert("apiKey".to_string(), "abc123".to_string());
+            collection.requests.push(CollectionRequest {
+                id: "req_search".to_string(),
+                name: "Search".to_string(),
+                method: "GET".to_string(),
+                url: "https://api.example.com/search".to_string(),
+                params: vec![RequestParam {
+                    key: "q".to_string(),
+                    value: "rust lang".to_string(),
+                    enabled: true,
+                }],
+                ..Default::default()
+            });
+            save_collection(&collection).unwrap();
+
+            let params =
+                resolve_collection_request_inner(&collection.id, "req_search", None, None).unwrap();
+            assert_eq!(
+                params.url,
+                "https://api.example.com/search?q=rust+lang&key=abc123"
+            );
+            assert_eq!(params.timeout_ms, 30_000);
+
+            let err = resolve_collection_request_inner(&collection.id, "req_missing", None, None)
+                .unwrap_err();
+            assert!(err.contains("Request not found"));
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_request_draft_replaces_edited_fields_and_keeps_file_bodies() {
+        let draft = |body: &str| RequestDraft {
+            method: "PUT".to_string(),
+            url: "{{baseUrl}}/users/{{id}}".to_string(),
+            headers: BTreeMap::from([("X-Trace".to_string(), "{{$uuid}}".to_string())]),
+            body: body.to_string(),
+        };
+        let mut request = CollectionRequest {
+            method: "GET".to_string(),
+            url: "{{baseUrl}}/users".to_string(),
+            headers: BTreeMap::from([("Accept".to_string(), "*/*".to_string())]),
+            ..Default::default()
+        };
+
+        draft("").apply_to(&mut request);
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.url, "{{baseUrl}}/users/{{id}}");
+        assert_eq!(request.headers.keys().collect::<Vec<_>>(), ["X-Trace"]);
+        assert!(request.body.is_none());
+
+        draft(r#"{"name":"Ada"}"#).apply_to(&mut request);
+        let body = request.body.as_ref().unwrap();
+        assert_eq!(body.body_type, BodyType::Raw);
+        assert_eq!(body.content.as_deref(), Some(r#"{"name":"Ada"}"#));
+
+        request.body = Some(RequestBody {
+            body_type: BodyType::Binary,
+            content: None,
+            file: Some("upload.bin".to_string()),
+            parts: Vec::new(),
+            graphql: None,
+        });
+        draft("").apply_to(&mut request);
+        let body = request.body.as_ref().unwrap();
+        assert_eq!(body.file.as_deref(), Some("upload.bin"));
+        assert!(body.content.is_none());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_execute_collection_request_inner_chains_extracted_values() {
+        use crate::domain::assertion::Assertion;
+        use crate::domain::extraction::{ExtractionRule, ExtractionSource, VariableScope};
+        use std::io::{Read, Write};
+
+        let listener = match std::net::TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                eprintln!("[TEST] Skipping: {err}");
+                return;
+            }
+            Err(err) => panic!("bind: {err}"),
+        };
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0u8; 4096]);
+            let body = r#"{"access_token":"t0k"}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let mut collection = Collection::new("Chain Test");
+            collection.requests.push(CollectionRequest {
+                id: "req_login".to_string(),
+                name: "Login".to_string(),
+                method: "POST".to_string(),
+                url: format!("{base}/login"),
+                extract: vec![ExtractionRule {
+                    variable: "accessToken".to_string(),
+                    source: ExtractionSource::JsonPath {
+                        path: "$.access_token".to_string(),
+                    },
+                    scope: VariableScope::Runtime,
+                }],
+                assertions: vec![Assertion::HeaderMatches {
+                    name: "content-type".to_string(),
+                    pattern: "^application/json".to_string(),
+                }],
+                ..Default::default()
+            });
+            collection.requests.push(CollectionRequest {
+                id: "req_me".to_string(),
+                name: "Me".to_string(),
+                method: "GET".to_string(),
+                url: format!("{base}/me"),
+                headers: BTreeMap::from([(
+                    "Authorization".to_string(),
+                    "Bearer {{accessToken}}".to_string(),
+                )]),
+                ..Default::default()
+            });
+            save_collection(&collection).unwrap();
+
+            let execution =
+                execute_collection_request_inner(&collection.id, "req_login", None, None, None)
+                    .await
+                    .unwrap();
+            assert_eq!(execution.response.status, 200);
+            assert_eq!(execution.extracted[0].value.as_deref(), Some("t0k"));
+            assert!(execution.assertions[0].passed);
+            assert_eq!(
+                execution.assertions[0].actual.as_deref(),
+                Some("application/json")
+            );
+
+            let params =
+                resolve_collection_request_inner(&collection.id, "req_me", None, None).unwrap();
+            assert_eq!(params.headers.get("Authorization"), Some("Bearer t0k"));
+            assert_eq!(
+                cmd_get_runtime_variables(collection.id.clone())["accessToken"],
+                "t0k"
+            );
+            cmd_clear_runtime_variables(collection.id.clone());
+            assert!(
+                resolve_collection_request_inner(&collection.id, "req_me", None, None).is_err()
+            );
+        })
+        .await;
+        server.join().unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_execute_collection_request_inner_validates_graphql() {
+        use crate::infrastructure::storage::graphql_schema_store::save_graphql_schema;
+
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let query = |query: &str| GraphqlQuery {
+                query: query.to_string(),
+                ..GraphqlQuery::default()
+            };
+            let mut collection = Collection::new("GraphQL Test");
+            collection.requests.push(CollectionRequest {
+                id: "req_me".to_string(),
+                name: "Me".to_string(),
+                method: "POST".to_string(),
+                // Never reached: validation fails before the request is sent
+                url: "http://127.0.0.1:1/graphql".to_string(),
+                body: Some(RequestBody {
+                    body_type: BodyType::Graphql,
+                    content: None,
+                    file: None,
+                    parts: Vec::new(),
+                    graphql: Some(query("{ you }")),
+                }),
+                ..Default::default()
+            });
+            save_collection(&collection).unwrap();
+            let schema = serde_json::from_value(json!({
+                "query_type": "Query",
+                "types": {
+                    "Query": { "kind": "object", "fields": [{ "name": "me", "type_ref": "String" }] },
+                    "String": { "kind": "scalar" }
+                }
+            }))
+            .unwrap();
+            save_graphql_schema(
+                &collection.id,
+                &GraphqlSchemaCache {
+                    endpoint: "http://127.0.0.1:1/graphql".to_string(),
+                    fetched_at: "2026-10-17T12:00:00Z".to_string(),
+                    schema,
+                },
+            )
+            .unwrap();
+
+            let err = execute_collection_request_inner(&collection.id, "req_me", None, None, None)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                err,
+                "GraphQL validation failed:\n1:3: Cannot query field \"you\" on type \"Query\""
+            );
+            assert_eq!(
+                cmd_graphql_validate(collection.id.clone(), query("{ you }"))
+                    .unwrap()
+                    .len(),
+                1
+            );
+            assert!(
+                cmd_graphql_validate(collection.id.clone(), query("{ me }"))
+                    .unwrap()
+                    .is_empty()
+            );
+        })
+        .await;
+    }
+
     // ── Environment command tests ─────────────────────────────────────────
 
     #[tokio::test]
@@ -4894,6 +5839,213 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_set_environment_tls_inner_sets_and_clears() {
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let collection = Collection::new("Env TLS Test");
+            save_collection(&collection).unwrap();
+            upsert_environment_inner(&collection.id, "internal", BTreeMap::new()).unwrap();
+
+            let tls = TlsConfig {
+                ca_bundle: Some("/certs/corp-ca.pem".to_string()),
+                insecure_skip_verify: true,
+                ..Default::default()
+            };
+            set_environment_tls_inner(&collection.id, "internal", Some(tls.clone())).unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert_eq!(loaded.environments[0].tls.as_ref(), Some(&tls));
+
+            // Updating variables keeps the TLS settings.
+            upsert_environment_inner(&collection.id, "internal", BTreeMap::new()).unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert_eq!(loaded.environments[0].tls.as_ref(), Some(&tls));
+
+            set_environment_tls_inner(&collection.id, "internal", None).unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert!(loaded.environments[0].tls.is_none());
+
+            let err = set_environment_tls_inner(&collection.id, "missing", None).unwrap_err();
+            assert!(err.contains("Environment not found"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_environment_host_overrides_inner_validates_and_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let collection = Collection::new("Env Overrides Test");
+            save_collection(&collection).unwrap();
+            upsert_environment_inner(&collection.id, "staging", BTreeMap::new()).unwrap();
+
+            let resolve = vec![ResolveOverride {
+                host: "api.example.com".to_string(),
+                port: Some(443),
+                addresses: vec!["10.0.0.12".to_string()],
+            }];
+            let connect_to = vec![ConnectToOverride {
+                host: Some("auth.example.com".to_string()),
+                port: None,
+                to_host: Some("auth-canary.internal".to_string()),
+                to_port: None,
+            }];
+            set_environment_host_overrides_inner(
+                &collection.id,
+                "staging",
+                resolve.clone(),
+                connect_to.clone(),
+            )
+            .unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert_eq!(loaded.environments[0].resolve, resolve);
+            assert_eq!(loaded.environments[0].connect_to, connect_to);
+
+            let invalid = vec![ResolveOverride {
+                host: "api.example.com".to_string(),
+                port: None,
+                addresses: vec!["not-an-ip".to_string()],
+            }];
+            assert!(
+                set_environment_host_overrides_inner(
+                    &collection.id,
+                    "staging",
+                    invalid,
+                    Vec::new()
+                )
+                .is_err()
+            );
+            let loaded = load_collection(&collection.id).unwrap();
+            assert_eq!(loaded.environments[0].resolve, resolve);
+
+            set_environment_host_overrides_inner(&collection.id, "staging", Vec::new(), Vec::new())
+                .unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert!(loaded.environments[0].resolve.is_empty());
+            assert!(loaded.environments[0].connect_to.is_empty());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_set_environment_proxy_inner_validates_and_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let collection = Collection::new("Env Proxy Test");
+            save_collection(&collection).unwrap();
+            upsert_environment_inner(&collection.id, "corp", BTreeMap::new()).unwrap();
+
+            let invalid = ProxyConfig {
+                url: Some("gopher://proxy:70".to_string()),
+                ..Default::default()
+            };
+            let err =
+                set_environment_proxy_inner(&collection.id, "corp", Some(invalid)).unwrap_err();
+            assert!(err.contains("Unsupported proxy scheme"));
+
+            let proxy = ProxyConfig {
+                url: Some("socks5h://proxy.corp:1080".to_string()),
+                no_proxy: vec![".corp".to_string()],
+                ..Default::default()
+            };
+            set_environment_proxy_inner(&collection.id, "corp", Some(proxy.clone())).unwrap();
+            let loaded = load_collection(&collection.id).unwrap();
+            assert_eq!(loaded.environments[0].proxy.as_ref(), Some(&proxy));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_cookie_commands_edit_active_environment_jar() {
+        let temp_dir = TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let mut collection = Collection::new("Cookie Jar Test");
+            collection.active_environment = Some("staging".to_string());
+            save_collection(&collection).unwrap();
+            let cookie = Cookie {
+                name: "session".to_string(),
+                value: "abc123".to_string(),
+                domain: "api.example.com".to_string(),
+                path: "/".to_string(),
+                include_subdomains: false,
+                secure: false,
+                http_only: true,
+                expires: None,
+            };
+
+            set_cookie_inner(&collection.id, None, cookie.clone()).unwrap();
+            set_cookie_inner(
+                &collection.id,
+                None,
+                Cookie {
+                    name: "theme".to_string(),
+                    ..cookie.clone()
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                cmd_list_cookies(collection.id.clone(), Some("staging".to_string()))
+                    .unwrap()
+                    .len(),
+                2
+            );
+            assert!(
+                cmd_list_cookies(collection.id.clone(), Some("prod".to_string()))
+                    .unwrap()
+                    .is_empty()
+            );
+
+            let remaining =
+                delete_cookies_inner(&collection.id, None, Some("theme"), None).unwrap();
+            assert_eq!(remaining, vec![cookie.clone()]);
+            delete_cookies_inner(&collection.id, None, None, None).unwrap();
+            assert!(
+                cmd_list_cookies(collection.id.clone(), None)
+                    .unwrap()
+                    .is_empty()
+            );
+
+            let err = set_cookie_inner(
+                &collection.id,
+                None,
+                Cookie {
+                    domain: String::new(),
+                    ..cookie
+                },
+            )
+            .unwrap_err();
+            assert!(err.contains("name and domain are required"));
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_save_proxy_settings_inner_persists_global_proxy() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("network.toml");
+        let proxy = ProxyConfig {
+            url: Some("http://proxy.corp:3128".to_string()),
+            username: Some("alice".to_string()),
+            ..Default::default()
+        };
+
+        save_proxy_settings_inner(&path, Some(proxy.clone())).unwrap();
+        assert_eq!(load_network_settings(&path).unwrap().proxy, Some(proxy));
+
+        save_proxy_settings_inner(&path, None).unwrap();
+        assert_eq!(load_network_settings(&path).unwrap().proxy, None);
+
+        let invalid = ProxyConfig {
+            url: Some("proxy.corp:3128".to_string()),
+            ..Default::default()
+        };
+        assert!(save_proxy_settings_inner(&path, Some(invalid)).is_err());
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_upsert_environment_inner_updates_existing_environment() {
@@ -4905,6 +6057,7 @@ mod tests {
             collection.environments.push(CollectionEnvironment {
                 name: "local".to_string(),
                 variables: vars,
+                ..Default::default()
             });
             save_collection(&collection).unwrap();
 
@@ -4936,6 +6089,7 @@ mod tests {
             collection.environments.push(CollectionEnvironment {
                 name: "local".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             collection.active_environment = Some("local".to_string());
             save_collection(&collection).unwrap();
@@ -4976,10 +6130,12 @@ mod tests {
             collection.environments.push(CollectionEnvironment {
                 name: "local".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             collection.environments.push(CollectionEnvironment {
                 name: "staging".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             collection.active_environment = Some("local".to_string());
             save_collection(&collection).unwrap();
@@ -5001,10 +6157,12 @@ mod tests {
             collection.environments.push(CollectionEnvironment {
                 name: "local".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             collection.environments.push(CollectionEnvironment {
                 name: "staging".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             save_collection(&collection).unwrap();
 
@@ -5026,6 +6184,7 @@ mod tests {
             collection.environments.push(CollectionEnvironment {
                 name: "local".to_string(),
                 variables: BTreeMap::new(),
+                ..Default::default()
             });
             collection.active_environment = Some("local".to_string());
             save_collection(&collection).unwrap();
@@ -3,13 +3,304 @@
 
 // HTTP execution command handler using curl for detailed timing
 
+use crate::domain::certificates::CertificateChain;
+use crate::domain::cookies::{Cookie, CookieJarScope, merge_cookie_changes};
 use crate::domain::errors::{AppError, ToAppError};
-use crate::domain::http::{HttpResponse, RequestParams, RequestTiming};
-use curl::easy::{Easy2, Handler, List, WriteError};
+use crate::domain::http::{
+    CertFormat, ConnectionInfo, HttpResponse, HttpVersion, MultipartPart, ProxyConfig, RedirectHop,
+    RequestParams, RequestTiming, ResponseBodyKind, TlsConfig, TlsVersion, WireTrace,
+    WireTraceEntry, WireTraceKind, validate_host_overrides,
+};
+use crate::domain::http_headers::HttpHeaders;
+use crate::domain::http_stream::{
+    HttpStreamEvent, StreamDecoder, StreamFormat, StreamMessage, StreamTranscript, StreamUpdate,
+};
+use crate::domain::retry::{RetryAttempt, RetryPolicy, parse_retry_after};
+use crate::infrastructure::storage::cookie_store;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use chrono::Utc;
+use curl::easy::{
+    Easy2, Form, Handler, InfoType, List, ReadError, SeekResult, SslVersion, WriteError,
+};
+use rand::RngExt;
 use serde_json;
 use std::collections::HashMap;
-use std::time::Duration;
-use tracing::{debug, error, instrument};
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{debug, error, info, instrument};
+
+/// Cancellation flags of in-flight requests, keyed by correlation ID.
+///
+/// Several requests may share a correlation ID; cancelling it aborts all of them.
+static IN_FLIGHT_REQUESTS: LazyLock<Mutex<HashMap<String, Vec<Arc<AtomicBool>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registration of an in-flight request in [`IN_FLIGHT_REQUESTS`].
+///
+/// Unregisters itself when dropped, so a request is cancellable exactly as
+/// long as its transfer is running.
+struct InFlightGuard {
+    correlation_id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl InFlightGuard {
+    fn register(correlation_id: &str) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        IN_FLIGHT_REQUESTS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .entry(correlation_id.to_string())
+            .or_default()
+            .push(Arc::clone(&cancelled));
+        Self {
+            correlation_id: correlation_id.to_string(),
+            cancelled,
+        }
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut registry = IN_FLIGHT_REQUESTS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(flags) = registry.get_mut(&self.correlation_id) {
+            flags.retain(|flag| !Arc::ptr_eq(flag, &self.cancelled));
+            if flags.is_empty() {
+                registry.remove(&self.correlation_id);
+            }
+        }
+    }
+}
+
+/// Abort every in-flight request registered under `correlation_id`.
+///
+/// The transfer is stopped from curl's progress callback, so the pending
+/// `execute_http_request` call returns a `REQUEST_CANCELLED` error shortly after.
+/// Returns `false` if no request with that correlation ID is running.
+pub fn cancel_in_flight_request(correlation_id: &str) -> bool {
+    let count = IN_FLIGHT_REQUESTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(correlation_id)
+        .map_or(0, |flags| {
+            for flag in flags {
+                flag.store(true, Ordering::SeqCst);
+            }
+            flags.len()
+        });
+    if count == 0 {
+        return false;
+    }
+    info!(correlation_id = %correlation_id, count, "Cancelling in-flight request");
+    true
+}
+
+/// Whether a request with the given correlation ID is currently in flight.
+pub fn is_request_in_flight(correlation_id: &str) -> bool {
+    IN_FLIGHT_REQUESTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .contains_key(correlation_id)
+}
+
+/// How often [`Cancellation::sleep`] checks whether it was cancelled.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Registration of a long-running operation, such as a collection run, under
+/// the correlation ID its requests are sent with.
+///
+/// While it lives, [`cancel_in_flight_request`] with that ID succeeds and
+/// marks it cancelled, even between two requests when none is in flight.
+pub struct Cancellation(InFlightGuard);
+
+impl Cancellation {
+    /// Register `correlation_id` until the returned value is dropped.
+    #[must_use]
+    pub fn register(correlation_id: &str) -> Self {
+        Self(InFlightGuard::register(correlation_id))
+    }
+
+    /// Whether the operation has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Sleep for `duration`, returning early once the operation is cancelled.
+    pub async fn sleep(&self, duration: Duration) {
+        let deadline = tokio::time::Instant::now() + duration;
+        while !self.is_cancelled() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep_until(
+                deadline.min(tokio::time::Instant::now() + CANCEL_POLL_INTERVAL),
+            )
+            .await;
+        }
+    }
+}
+
+/// Proxy settings applied to requests that do not carry their own.
+///
+/// Loaded from the network settings at startup and replaced when the user
+/// changes them.
+static GLOBAL_PROXY: LazyLock<RwLock<Option<ProxyConfig>>> = LazyLock::new(|| RwLock::new(None));
+
+/// Replace the global proxy settings.
+pub fn set_global_proxy(proxy: Option<ProxyConfig>) {
+    *GLOBAL_PROXY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = proxy;
+}
+
+/// Current global proxy settings.
+pub fn global_proxy() -> Option<ProxyConfig> {
+    GLOBAL_PROXY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Live decoding state for a request executed in streaming mode.
+struct StreamSink {
+    correlation_id: String,
+    events: UnboundedSender<HttpStreamEvent>,
+    started: Instant,
+    /// Created when the first body bytes arrive, once the final headers are known.
+    decoder: Option<StreamDecoder>,
+    messages: Vec<StreamMessage>,
+    /// Body bytes received; the body itself is only kept as decoded messages.
+    bytes: u64,
+}
+
+impl StreamSink {
+    fn new(correlation_id: String, events: UnboundedSender<HttpStreamEvent>) -> Self {
+        Self {
+            correlation_id,
+            events,
+            started: Instant::now(),
+            decoder: None,
+            messages: Vec::new(),
+            bytes: 0,
+        }
+    }
+
+    fn send(&self, update: StreamUpdate) {
+        // A dropped receiver only means nobody is watching; the transfer goes on
+        let _ = self.events.send(HttpStreamEvent {
+            correlation_id: self.correlation_id.clone(),
+            update,
+        });
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        duration_to_ms(self.started.elapsed())
+    }
+
+    fn publish(&mut self, messages: Vec<StreamMessage>) {
+        for message in messages {
+            self.send(StreamUpdate::Message {
+                message: message.clone(),
+            });
+            self.messages.push(message);
+        }
+    }
+}
+
+/// Redirect hops recorded while curl follows `Location` headers.
+struct RedirectTracker {
+    /// URL requested by the current hop.
+    url: String,
+    /// When the current hop started.
+    hop_started: Instant,
+    /// When the headers of the latest response were complete.
+    headers_done: Instant,
+    hops: Vec<RedirectHop>,
+}
+
+impl RedirectTracker {
+    fn new(url: &str) -> Self {
+        let now = Instant::now();
+        Self {
+            url: url.to_string(),
+            hop_started: now,
+            headers_done: now,
+            hops: Vec::new(),
+        }
+    }
+
+    /// Record a completed redirect response and move on to its target.
+    fn record(&mut self, status: u32, status_text: &str, headers: &HttpHeaders) {
+        let location = headers.get_all("location").last().map(str::to_string);
+        let Some(location) = location.filter(|_| (300..400).contains(&status)) else {
+            return;
+        };
+        let next_url = url::Url::parse(&self.url)
+            .and_then(|base| base.join(&location))
+            .map_or_else(|_| location.clone(), String::from);
+        self.hops.push(RedirectHop {
+            url: std::mem::replace(&mut self.url, next_url),
+            status: u16::try_from(status).unwrap_or(500),
+            status_text: status_text.to_string(),
+            headers: headers.clone(),
+            location: Some(location),
+            duration_ms: duration_to_ms(self.headers_done.duration_since(self.hop_started)),
+        });
+        self.hop_started = self.headers_done;
+    }
+}
+
+/// Upper bound on the text kept in a wire trace, so a long redirect chain or
+/// a chatty TLS stack cannot grow a history entry without limit.
+const MAX_TRACE_BYTES: usize = 256 * 1024;
+
+/// Builds a [`WireTrace`] from curl's debug callback.
+struct TraceRecorder {
+    started: Instant,
+    bytes: usize,
+    trace: WireTrace,
+}
+
+impl TraceRecorder {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            bytes: 0,
+            trace: WireTrace::default(),
+        }
+    }
+
+    /// Record one debug message. Bodies and raw TLS records are skipped.
+    fn record(&mut self, info: InfoType, data: &[u8]) {
+        let kind = match info {
+            InfoType::Text => WireTraceKind::Info,
+            InfoType::HeaderOut => WireTraceKind::HeaderOut,
+            InfoType::HeaderIn => WireTraceKind::HeaderIn,
+            _ => return,
+        };
+        let text = String::from_utf8_lossy(data).replace("\r\n", "\n");
+        let text = text.trim_end();
+        if text.is_empty() || self.trace.truncated {
+            return;
+        }
+        if self.bytes + text.len() > MAX_TRACE_BYTES {
+            self.trace.truncated = true;
+            return;
+        }
+        self.bytes += text.len();
+        self.trace.entries.push(WireTraceEntry {
+            kind,
+            text: text.to_string(),
+            elapsed_ms: duration_to_ms(self.started.elapsed()),
+        });
+    }
+}
 
 /// Handler for collecting HTTP response data from curl.
 struct ResponseCollector {
@@ -17,49 +308,174 @@ struct ResponseCollector {
     status_code: u32,
     /// HTTP status text (e.g., "OK", "Not Found").
     status_text: String,
-    /// Response headers as key-value pairs.
-    headers: Vec<(String, String)>,
+    /// Protocol from the status line (e.g., "HTTP/1.1", "HTTP/2").
+    http_version: String,
+    /// Response headers in wire order.
+    headers: HttpHeaders,
     /// Response body bytes.
     body: Vec<u8>,
     /// Whether we've parsed the status line.
     status_parsed: bool,
+    /// Set by [`cancel_in_flight_request`] to abort the transfer.
+    cancelled: Option<Arc<AtomicBool>>,
+    /// Present when the body is decoded and published as it arrives.
+    stream: Option<StreamSink>,
+    /// Redirect responses seen before the current one.
+    redirects: RedirectTracker,
+    /// File streamed as the request body, if any.
+    upload: Option<File>,
+    /// Present when the request asked for a wire trace.
+    trace: Option<TraceRecorder>,
 }
 
 impl ResponseCollector {
-    const fn new() -> Self {
+    fn new(url: &str, cancelled: Option<Arc<AtomicBool>>, stream: Option<StreamSink>) -> Self {
         Self {
             status_code: 0,
             status_text: String::new(),
-            headers: Vec::new(),
+            http_version: String::new(),
+            headers: HttpHeaders::new(),
             body: Vec::new(),
             status_parsed: false,
+            cancelled,
+            stream,
+            redirects: RedirectTracker::new(url),
+            upload: None,
+            trace: None,
+        }
+    }
+
+    /// Record the current response as a redirect hop if it is one.
+    fn record_redirect(&mut self) {
+        if self.status_parsed {
+            self.redirects
+                .record(self.status_code, &self.status_text, &self.headers);
+        }
+    }
+
+    /// Announce the stream once, using the final response status and headers.
+    fn start_stream(&mut self) {
+        let Some(sink) = self.stream.as_mut() else {
+            return;
+        };
+        if sink.decoder.is_some() {
+            return;
+        }
+        let content_type = self.headers.get_all("content-type").last();
+        let format = StreamFormat::from_content_type(content_type);
+        sink.decoder = Some(StreamDecoder::new(format));
+        sink.send(StreamUpdate::Started {
+            status: u16::try_from(self.status_code).unwrap_or(500),
+            status_text: self.status_text.clone(),
+            headers: self.headers.clone(),
+            format,
+        });
+    }
+
+    fn stream_chunk(&mut self, data: &[u8]) {
+        self.start_stream();
+        if let Some(sink) = self.stream.as_mut() {
+            sink.bytes += data.len() as u64;
+            let elapsed_ms = sink.elapsed_ms();
+            let messages = sink
+                .decoder
+                .as_mut()
+                .map(|decoder| decoder.feed(data, elapsed_ms))
+                .unwrap_or_default();
+            sink.publish(messages);
         }
     }
+
+    /// Flush the decoder and announce the end of the stream.
+    ///
+    /// Returns the transcript of everything received, or `None` when the
+    /// request was not executed in streaming mode.
+    fn finish_stream(&mut self, total_ms: u64) -> Option<StreamTranscript> {
+        self.start_stream();
+        let sink = self.stream.as_mut()?;
+        let elapsed_ms = sink.elapsed_ms();
+        let mut decoder = sink.decoder.take()?;
+        let remaining = decoder.finish(elapsed_ms);
+        sink.publish(remaining);
+        sink.send(StreamUpdate::Finished {
+            total_ms,
+            message_count: sink.messages.len() as u64,
+        });
+        Some(StreamTranscript {
+            format: decoder.format(),
+            messages: std::mem::take(&mut sink.messages),
+        })
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    }
+
+    fn take_trace(&mut self) -> Option<WireTrace> {
+        self.trace.take().map(|recorder| recorder.trace)
+    }
 }
 
 impl Handler for ResponseCollector {
+    fn progress(&mut self, _dltotal: f64, _dlnow: f64, _ultotal: f64, _ulnow: f64) -> bool {
+        // Returning false makes curl abort the transfer with CURLE_ABORTED_BY_CALLBACK
+        !self.is_cancelled()
+    }
+
+    fn read(&mut self, data: &mut [u8]) -> Result<usize, ReadError> {
+        self.upload
+            .as_mut()
+            .map_or(Ok(0), |file| file.read(data).map_err(|_| ReadError::Abort))
+    }
+
+    fn seek(&mut self, whence: SeekFrom) -> SeekResult {
+        // Curl rewinds the body when it has to send it again, e.g. on a 307
+        match self.upload.as_mut().map(|file| file.seek(whence)) {
+            Some(Ok(_)) => SeekResult::Ok,
+            Some(Err(_)) => SeekResult::Fail,
+            None => SeekResult::CantSeek,
+        }
+    }
+
     fn write(&mut self, data: &[u8]) -> Result<usize, WriteError> {
-        self.body.extend_from_slice(data);
+        // A stream may run for hours; keep its decoded messages, not its bytes
+        if self.stream.is_some() {
+            self.stream_chunk(data);
+        } else {
+            self.body.extend_from_slice(data);
+        }
         Ok(data.len())
     }
 
+    fn debug(&mut self, kind: InfoType, data: &[u8]) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.record(kind, data);
+        }
+    }
+
     fn header(&mut self, data: &[u8]) -> bool {
         // Parse header line
         if let Ok(line) = std::str::from_utf8(data) {
             let line = line.trim();
 
-            // Skip empty lines
+            // The blank line ends a response's headers
             if line.is_empty() {
+                self.redirects.headers_done = Instant::now();
                 return true;
             }
 
             // Check for HTTP status line (e.g., "HTTP/1.1 200 OK" or "HTTP/2 200")
             if line.starts_with("HTTP/") {
-                // Reset for potential redirects - we want the final status
+                // A new response follows: keep the previous one if it was a
+                // redirect, then reset - we want the final status
+                self.record_redirect();
                 self.status_parsed = false;
-                self.headers.clear();
+                self.headers = HttpHeaders::new();
 
                 let parts: Vec<&str> = line.splitn(3, ' ').collect();
+                self.http_version = parts[0].to_string();
                 if parts.len() >= 2 {
                     if let Ok(code) = parts[1].parse::<u32>() {
                         self.status_code = code;
@@ -78,8 +494,7 @@ impl Handler for ResponseCollector {
             // Regular header line (key: value)
             if self.status_parsed {
                 if let Some((key, value)) = line.split_once(':') {
-                    self.headers
-                        .push((key.trim().to_string(), value.trim().to_string()));
+                    self.headers.append(key.trim(), value.trim());
                 }
             }
         }
@@ -125,11 +540,93 @@ fn status_text_for_code(code: u32) -> String {
     .to_string()
 }
 
-/// Configure the HTTP method on the curl handle.
+/// Request body in the form it is handed to curl.
+enum Payload<'a> {
+    /// Inline body, if any.
+    Text(Option<&'a str>),
+    /// Body read from [`ResponseCollector::upload`]; carries its size in bytes.
+    File(u64),
+    /// `multipart/form-data` body.
+    Multipart(Form),
+}
+
+/// Build the request payload: multipart parts, a body file, or inline text.
+///
+/// Files are opened up front so a missing one surfaces as a clear error
+/// naming it rather than as a failed transfer.
+fn build_payload<'a>(
+    collector: &mut ResponseCollector,
+    params: &'a RequestParams,
+) -> Result<Payload<'a>, String> {
+    if !params.multipart.is_empty() {
+        return multipart_form(&params.multipart).map(Payload::Multipart);
+    }
+    if let Some(path) = &params.body_file {
+        let file = File::open(path).map_err(|e| format!("Cannot read body file '{path}': {e}"))?;
+        let size = file
+            .metadata()
+            .map_err(|e| format!("Cannot read body file '{path}': {e}"))?
+            .len();
+        collector.upload = Some(file);
+        return Ok(Payload::File(size));
+    }
+    Ok(Payload::Text(params.body.as_deref()))
+}
+
+/// Build a curl form from multipart parts. File parts are streamed from disk.
+fn multipart_form(parts: &[MultipartPart]) -> Result<Form, String> {
+    let mut form = Form::new();
+    for part in parts {
+        part.validate()?;
+        let mut builder = form.part(&part.name);
+        if let Some(value) = &part.value {
+            builder.contents(value.as_bytes());
+        }
+        if let Some(path) = &part.file {
+            if !Path::new(path).is_file() {
+                return Err(format!(
+                    "Cannot read file '{path}' for multipart part '{}'",
+                    part.name
+                ));
+            }
+            builder.file(path);
+        }
+        if let Some(filename) = &part.filename {
+            builder.filename(filename);
+        }
+        if let Some(content_type) = &part.content_type {
+            builder.content_type(content_type);
+        }
+        builder
+            .add()
+            .map_err(|e| format!("Failed to add multipart part '{}': {e}", part.name))?;
+    }
+    Ok(form)
+}
+
+/// Attach a non-POST body to the curl handle; the method is already set.
+fn attach_payload(easy: &mut Easy2<ResponseCollector>, payload: Payload) -> Result<(), String> {
+    match payload {
+        Payload::Text(None) => {}
+        Payload::Text(Some(b)) => easy
+            .post_fields_copy(b.as_bytes())
+            .map_err(|e| format!("Failed to set body: {e}"))?,
+        Payload::File(size) => easy
+            .upload(true)
+            .and_then(|()| easy.in_filesize(size))
+            .map_err(|e| format!("Failed to set body: {e}"))?,
+        Payload::Multipart(form) => easy
+            .httppost(form)
+            .map_err(|e| format!("Failed to set multipart body: {e}"))?,
+    }
+    Ok(())
+}
+
+/// Configure the HTTP method and body on the curl handle.
 fn configure_method(
     easy: &mut Easy2<ResponseCollector>,
     method: &str,
-    body: Option<&str>,
+    payload: Payload,
 ) -> Result<(), String> {
     let method_upper = method.to_uppercase();
 
@@ -147,19 +644,23 @@ fn configure_method(
         "POST" => {
             easy.post(true)
                 .map_err(|e| format!("Failed to set POST: {e}"))?;
-            let data = body.unwrap_or("");
-            easy.post_fields_copy(data.as_bytes())
-                .map_err(|e| format!("Failed to set body: {e}"))?;
+            match payload {
+                Payload::Text(body) => easy
+                    .post_fields_copy(body.unwrap_or("").as_bytes())
+                    .map_err(|e| format!("Failed to set body: {e}"))?,
+                // A sized POST without post fields reads its body from the read callback
+                Payload::File(size) => easy
+                    .post_field_size(size)
+                    .map_err(|e| format!("Failed to set body: {e}"))?,
+                Payload::Multipart(_) => attach_payload(easy, payload)?,
+            }
         }
         "PUT" => {
             easy.upload(true)
                 .map_err(|e| format!("Failed to set PUT: {e}"))?;
             easy.custom_request("PUT")
                 .map_err(|e| format!("Failed to set PUT: {e}"))?;
-            if let Some(b) = body {
-                easy.post_fields_copy(b.as_bytes())
-                    .map_err(|e| format!("Failed to set body: {e}"))?;
-            }
+            attach_payload(easy, payload)?;
         }
         "HEAD" => easy
             .nobody(true)
@@ -168,15 +669,181 @@ fn configure_method(
             // PATCH, DELETE, OPTIONS, and extension methods
             easy.custom_request(&method_upper)
                 .map_err(|e| format!("Failed to set {method_upper}: {e}"))?;
-            if let Some(b) = body {
-                easy.post_fields_copy(b.as_bytes())
-                    .map_err(|e| format!("Failed to set body: {e}"))?;
-            }
+            attach_payload(easy, payload)?;
+        }
+    }
+    Ok(())
+}
+
+/// Configure client certificate, trust store and verification on the curl handle.
+///
+/// Configured files are checked up front so a typo surfaces as a clear error
+/// rather than an opaque handshake failure.
+fn configure_tls<H: Handler>(easy: &mut Easy2<H>, tls: &TlsConfig) -> Result<(), String> {
+    let files = [
+        ("client certificate", &tls.client_cert),
+        ("client key", &tls.client_key),
+        ("CA bundle", &tls.ca_bundle),
+    ];
+    for (label, path) in files {
+        if let Some(path) = path
+            .as_deref()
+            .filter(|p| !std::path::Path::new(p).is_file())
+        {
+            return Err(format!("TLS {label} not found: {path}"));
+        }
+    }
+
+    if let Some(cert) = &tls.client_cert {
+        easy.ssl_cert(cert)
+            .map_err(|e| format!("Failed to set client certificate: {e}"))?;
+        let cert_type = match tls.cert_format() {
+            CertFormat::Pem => "PEM",
+            CertFormat::Pkcs12 => "P12",
+        };
+        easy.ssl_cert_type(cert_type)
+            .map_err(|e| format!("Failed to set client certificate type: {e}"))?;
+    }
+    if let Some(key) = &tls.client_key {
+        easy.ssl_key(key)
+            .map_err(|e| format!("Failed to set client key: {e}"))?;
+    }
+    if let Some(password) = &tls.client_key_password {
+        easy.key_password(password)
+            .map_err(|e| format!("Failed to set client key password: {e}"))?;
+    }
+    if let Some(ca_bundle) = &tls.ca_bundle {
+        easy.cainfo(ca_bundle)
+            .map_err(|e| format!("Failed to set CA bundle: {e}"))?;
+    }
+    if tls.insecure_skip_verify {
+        easy.ssl_verify_peer(false)
+            .map_err(|e| format!("Failed to disable peer verification: {e}"))?;
+        easy.ssl_verify_host(false)
+            .map_err(|e| format!("Failed to disable host verification: {e}"))?;
+    }
+    if let Some(min_version) = tls.min_tls_version {
+        let min = match min_version {
+            TlsVersion::Tls1_0 => SslVersion::Tlsv10,
+            TlsVersion::Tls1_1 => SslVersion::Tlsv11,
+            TlsVersion::Tls1_2 => SslVersion::Tlsv12,
+            TlsVersion::Tls1_3 => SslVersion::Tlsv13,
+        };
+        easy.ssl_min_max_version(min, SslVersion::Default)
+            .map_err(|e| format!("Failed to set minimum TLS version: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Configure the proxy on the curl handle.
+///
+/// An explicit direct connection also clears curl's own `*_proxy` environment
+/// lookup, so system proxies apply only when `use_system` asks for them.
+fn configure_proxy<H: Handler>(
+    easy: &mut Easy2<H>,
+    proxy: &ProxyConfig,
+    target_url: &str,
+) -> Result<(), String> {
+    let resolved = proxy.resolve(target_url, |name| std::env::var(name).ok())?;
+    easy.proxy(resolved.url.as_deref().unwrap_or(""))
+        .map_err(|e| format!("Failed to set proxy: {e}"))?;
+    if resolved.url.is_none() {
+        return Ok(());
+    }
+    if !resolved.no_proxy.is_empty() {
+        easy.noproxy(&resolved.no_proxy.join(","))
+            .map_err(|e| format!("Failed to set no_proxy list: {e}"))?;
+    }
+    if let Some(username) = &proxy.username {
+        easy.proxy_username(username)
+            .map_err(|e| format!("Failed to set proxy username: {e}"))?;
+    }
+    if let Some(password) = &proxy.password {
+        easy.proxy_password(password)
+            .map_err(|e| format!("Failed to set proxy password: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Route the connection through the request's Unix domain socket, and apply
+/// its resolve (`--resolve`) and connect-to (`--connect-to`) host overrides.
+fn configure_routing<H: Handler>(
+    easy: &mut Easy2<H>,
+    params: &RequestParams,
+) -> Result<(), String> {
+    if let Some(path) = &params.unix_socket {
+        if !Path::new(path).exists() {
+            return Err(format!("Unix socket not found: {path}"));
         }
+        easy.unix_socket(path)
+            .map_err(|e| format!("Failed to set Unix socket: {e}"))?;
+    }
+    validate_host_overrides(&params.resolve, &params.connect_to)?;
+    if !params.resolve.is_empty() {
+        let default_port = url::Url::parse(&params.url)
+            .ok()
+            .and_then(|url| url.port_or_known_default())
+            .unwrap_or(80);
+        let mut list = List::new();
+        for entry in &params.resolve {
+            list.append(&entry.curl_entry(default_port))
+                .map_err(|e| format!("Failed to add resolve override for '{}': {e}", entry.host))?;
+        }
+        easy.resolve(list)
+            .map_err(|e| format!("Failed to set resolve overrides: {e}"))?;
+    }
+    if !params.connect_to.is_empty() {
+        let mut list = List::new();
+        for entry in &params.connect_to {
+            list.append(&entry.curl_entry())
+                .map_err(|e| format!("Failed to add connect-to override: {e}"))?;
+        }
+        easy.connect_to(list)
+            .map_err(|e| format!("Failed to set connect-to overrides: {e}"))?;
     }
     Ok(())
 }
 
+/// Enable curl's cookie engine and load the request's cookie jar into it.
+///
+/// Returns the cookies as loaded, for [`store_cookies`] to diff against.
+fn configure_cookies(
+    easy: &mut Easy2<ResponseCollector>,
+    scope: &CookieJarScope,
+) -> Result<Vec<Cookie>, String> {
+    let cookies = cookie_store::load_cookies(scope)?;
+    // An empty file name enables the cookie engine without reading a file
+    easy.cookie_file("")
+        .map_err(|e| format!("Failed to enable cookie engine: {e}"))?;
+    for cookie in &cookies {
+        easy.cookie_list(&cookie.to_netscape_line())
+            .map_err(|e| format!("Failed to load cookie '{}': {e}", cookie.name))?;
+    }
+    Ok(cookies)
+}
+
+/// Persist the cookies this transfer changed, including any `Set-Cookie`
+/// received along the redirect chain, to the request's cookie jar.
+///
+/// Only the difference from `loaded` is merged into the stored jar, so
+/// concurrent requests on the same jar do not overwrite each other.
+fn store_cookies(
+    easy: &mut Easy2<ResponseCollector>,
+    scope: &CookieJarScope,
+    loaded: &[Cookie],
+) -> Result<(), String> {
+    let list = easy
+        .cookies()
+        .map_err(|e| format!("Failed to read cookies: {e}"))?;
+    let cookies: Vec<Cookie> = list
+        .iter()
+        .filter_map(|line| std::str::from_utf8(line).ok())
+        .filter_map(Cookie::from_netscape_line)
+        .collect();
+    cookie_store::update_cookies(scope, |jar| merge_cookie_changes(jar, loaded, &cookies))
+        .map(|_| ())
+}
+
 /// Extract timing information from completed curl handle.
 #[allow(clippy::too_many_lines)]
 fn extract_timing(easy: &Easy2<ResponseCollector>) -> RequestTiming {
@@ -301,12 +968,41 @@ fn extract_timing(easy: &Easy2<ResponseCollector>) -> RequestTiming {
 
     let total_ms = duration_to_ms(total_time);
 
+    // Phases measured back from the end of the transfer
+    let since = |start: Duration| duration_to_ms(total_time.saturating_sub(start));
+    let transfer_ms = easy.pretransfer_time().ok().map(since);
+    let download_ms = easy.starttransfer_time().ok().map(since);
+
     RequestTiming {
         total_ms,
         dns_ms,
         connect_ms,
         tls_ms,
         first_byte_ms,
+        redirect_ms: easy.redirect_time().ok().map(duration_to_ms),
+        transfer_ms,
+        download_ms,
+    }
+}
+
+/// Read the negotiated protocol, remote address and transfer sizes.
+fn extract_connection(easy: &Easy2<ResponseCollector>) -> ConnectionInfo {
+    // Curl reports transfer sizes as doubles holding whole byte counts
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bytes = |size: Result<f64, curl::Error>| size.map_or(0, |size| size.max(0.0) as u64);
+    ConnectionInfo {
+        http_version: easy.get_ref().http_version.clone(),
+        remote_ip: easy
+            .primary_ip()
+            .ok()
+            .flatten()
+            .filter(|ip| !ip.is_empty())
+            .map(str::to_string),
+        remote_port: easy.primary_port().ok().filter(|port| *port != 0),
+        request_bytes: easy.request_size().unwrap_or_default(),
+        upload_bytes: bytes(easy.upload_size()),
+        response_header_bytes: easy.header_size().unwrap_or_default(),
+        download_bytes: bytes(easy.download_size()),
     }
 }
 
@@ -314,14 +1010,23 @@ fn extract_timing(easy: &Easy2<ResponseCollector>) -> RequestTiming {
 fn build_response(easy: &Easy2<ResponseCollector>, timing: RequestTiming) -> HttpResponse {
     let collector = easy.get_ref();
 
-    // Convert headers to HashMap
-    let headers: HashMap<String, String> = collector
-        .headers
-        .iter()
-        .map(|(k, v)| (k.clone(), v.clone()))
-        .collect();
+    let header_value = |name: &str| collector.headers.get_all(name).last();
+    let body_kind = ResponseBodyKind::detect(
+        header_value("content-type"),
+        header_value("content-encoding"),
+        &collector.body,
+    );
+    // Only textual bodies are decoded; everything else is carried as raw bytes
+    let (body, body_base64) = if body_kind.is_text() || body_kind == ResponseBodyKind::Empty {
+        (String::from_utf8_lossy(&collector.body).to_string(), None)
+    } else {
+        (String::new(), Some(BASE64.encode(&collector.body)))
+    };
+    let body_size = collector
+        .stream
+        .as_ref()
+        .map_or(collector.body.len() as u64, |sink| sink.bytes);
 
-    let body = String::from_utf8_lossy(&collector.body).to_string();
     let status_code = easy.response_code().unwrap_or(collector.status_code);
     let status = u16::try_from(status_code).unwrap_or(500);
     let status_text = if collector.status_text.is_empty() {
@@ -333,18 +1038,205 @@ fn build_response(easy: &Easy2<ResponseCollector>, timing: RequestTiming) -> Htt
     HttpResponse {
         status,
         status_text,
-        headers,
+        headers: collector.headers.clone(),
         body,
+        body_base64,
+        body_kind,
+        body_size,
         timing,
+        stream: None,
+        websocket: None,
+        redirects: Vec::new(),
+        connection: Some(extract_connection(easy)),
+        certificates: extract_certificates(easy),
+        attempts: Vec::new(),
+        trace: None,
+    }
+}
+
+/// Build the certificate chain of an HTTPS response and check it against the
+/// host that was finally requested.
+fn extract_certificates(easy: &Easy2<ResponseCollector>) -> Option<CertificateChain> {
+    let certinfo = peer_certinfo(easy);
+    let url = easy.effective_url().ok().flatten()?;
+    let host = url::Url::parse(url).ok()?.host_str()?.to_string();
+    CertificateChain::from_certinfo(&certinfo, &host, Utc::now())
+}
+
+/// Read `CURLINFO_CERTINFO`: for each certificate of the last connection's
+/// chain (leaf first), curl's `Name:value` fields.
+///
+/// The curl crate can enable certinfo but has no getter for it, so the list is
+/// read through curl-sys and copied out before the handle is touched again.
+#[allow(unsafe_code)]
+fn peer_certinfo(easy: &Easy2<ResponseCollector>) -> Vec<Vec<String>> {
+    let mut info: *mut curl_sys::curl_certinfo = std::ptr::null_mut();
+    // SAFETY: CURLINFO_CERTINFO takes a `struct curl_certinfo **` and stores a
+    // pointer owned by the handle, valid until its next transfer or cleanup.
+    // `easy` is borrowed for the whole function, so neither can happen.
+    let code =
+        unsafe { curl_sys::curl_easy_getinfo(easy.raw(), curl_sys::CURLINFO_CERTINFO, &mut info) };
+    if code != curl_sys::CURLE_OK || info.is_null() {
+        return Vec::new();
+    }
+    // SAFETY: non-null and owned by the handle, see above.
+    let info = unsafe { &*info };
+    let count = usize::try_from(info.num_of_certs).unwrap_or(0);
+    (0..count)
+        .map(|index| {
+            let mut fields = Vec::new();
+            // SAFETY: `certinfo` holds `num_of_certs` list heads.
+            let mut node = unsafe { *info.certinfo.add(index) };
+            while !node.is_null() {
+                // SAFETY: a curl_slist node with a NUL-terminated `data` string.
+                let entry = unsafe { &*node };
+                if !entry.data.is_null() {
+                    let data = unsafe { CStr::from_ptr(entry.data) };
+                    fields.push(data.to_string_lossy().into_owned());
+                }
+                node = entry.next;
+            }
+            fields
+        })
+        .collect()
+}
+
+/// Apply the per-request session options: routing, TLS, proxy, cookie jar
+/// and wire trace.
+///
+/// Returns the cookie jar as loaded; empty when the request has none.
+fn configure_session(
+    easy: &mut Easy2<ResponseCollector>,
+    params: &RequestParams,
+    corr_id: &str,
+) -> Result<Vec<Cookie>, AppError> {
+    configure_routing(easy, params)
+        .map_err(|e| AppError::new(corr_id.to_string(), "ROUTING_CONFIG_ERROR", e))?;
+    if let Some(tls) = &params.tls {
+        configure_tls(easy, tls)
+            .map_err(|e| AppError::new(corr_id.to_string(), "TLS_CONFIG_ERROR", e))?;
+    }
+    // Certificate inspection is informational; TLS backends without it just report none
+    if let Err(e) = easy.certinfo(true) {
+        debug!(error = %e, "certinfo not supported - certificates not available");
+    }
+    if let Some(proxy) = params.proxy.clone().or_else(global_proxy) {
+        configure_proxy(easy, &proxy, &params.url)
+            .map_err(|e| AppError::new(corr_id.to_string(), "PROXY_CONFIG_ERROR", e))?;
+    }
+    let cookies = match &params.cookie_jar {
+        Some(scope) => configure_cookies(easy, scope)
+            .map_err(|e| AppError::new(corr_id.to_string(), "COOKIE_JAR_ERROR", e))?,
+        None => Vec::new(),
+    };
+    if params.verbose {
+        easy.get_mut().trace = Some(TraceRecorder::new());
+        easy.verbose(true).map_err(|e| {
+            AppError::new(
+                corr_id.to_string(),
+                "TRACE_CONFIG_ERROR",
+                format!("Failed to enable wire trace: {e}"),
+            )
+        })?;
+    }
+    Ok(cookies)
+}
+
+/// Build the curl header list for a request.
+fn request_header_list(params: &RequestParams, corr_id: &str) -> Result<List, AppError> {
+    let mut header_list = List::new();
+    for header in &params.headers {
+        header_list
+            .append(&format!("{}: {}", header.name, header.value))
+            .map_err(|e| {
+                AppError::new(
+                    corr_id.to_string(),
+                    "HEADER_ERROR",
+                    format!("Failed to add header '{}': {e}", header.name),
+                )
+            })?;
+    }
+    // Curl would label a file body as form data; send it as opaque bytes instead
+    if params.body_file.is_some()
+        && params.multipart.is_empty()
+        && !params.headers.contains("content-type")
+    {
+        header_list
+            .append("Content-Type: application/octet-stream")
+            .map_err(|e| {
+                AppError::new(
+                    corr_id.to_string(),
+                    "HEADER_ERROR",
+                    format!("Failed to add header 'Content-Type': {e}"),
+                )
+            })?;
+    }
+    Ok(header_list)
+}
+
+/// Curl handler for a connection driven with `send` and `recv` rather than
+/// a transfer.
+pub struct RawConnection;
+
+impl Handler for RawConnection {}
+
+/// Open a connection to the host of `params.url` without sending a request.
+///
+/// `http` URLs get a plain TCP connection and `https` URLs a TLS one, set up
+/// with the request's TLS settings. The request's Unix socket and host
+/// overrides apply, and a proxy, explicit or global, is tunnelled through
+/// with `CONNECT`. The returned handle exchanges raw bytes through
+/// `send` and `recv`, neither of which blocks.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the handle cannot be configured or the
+/// connection cannot be established within `params.timeout_ms`.
+pub fn open_connection(
+    params: &RequestParams,
+    corr_id: &str,
+) -> Result<Easy2<RawConnection>, AppError> {
+    let mut easy = Easy2::new(RawConnection);
+    easy.url(&params.url).map_err(|e| {
+        AppError::new(
+            corr_id.to_string(),
+            "INVALID_URL",
+            format!("Invalid URL '{}': {e}", params.url),
+        )
+    })?;
+    easy.connect_only(true)
+        .and_then(|()| easy.connect_timeout(Duration::from_millis(params.timeout_ms)))
+        .and_then(|()| easy.http_proxy_tunnel(true))
+        .map_err(|e| {
+            AppError::new(
+                corr_id.to_string(),
+                "CONNECTION_CONFIG_ERROR",
+                format!("Failed to configure connection: {e}"),
+            )
+        })?;
+    configure_routing(&mut easy, params)
+        .map_err(|e| AppError::new(corr_id.to_string(), "ROUTING_CONFIG_ERROR", e))?;
+    if let Some(tls) = &params.tls {
+        configure_tls(&mut easy, tls)
+            .map_err(|e| AppError::new(corr_id.to_string(), "TLS_CONFIG_ERROR", e))?;
     }
+    if let Some(proxy) = params.proxy.clone().or_else(global_proxy) {
+        configure_proxy(&mut easy, &proxy, &params.url)
+            .map_err(|e| AppError::new(corr_id.to_string(), "PROXY_CONFIG_ERROR", e))?;
+    }
+    easy.perform()
+        .map_err(|e| e.to_app_error(corr_id.to_string()))?;
+    Ok(easy)
 }
 
 /// Execute an HTTP request synchronously using curl.
 fn execute_request_sync(
     params: &RequestParams,
     correlation_id: Option<String>,
+    cancelled: Option<Arc<AtomicBool>>,
+    stream: Option<StreamSink>,
 ) -> Result<HttpResponse, AppError> {
-    let mut easy = Easy2::new(ResponseCollector::new());
+    let mut easy = Easy2::new(ResponseCollector::new(&params.url, cancelled, stream));
     let corr_id = correlation_id.unwrap_or_else(|| "unknown".to_string());
 
     // Configure connection
@@ -363,24 +1255,16 @@ fn execute_request_sync(
                 format!("Failed to set timeout: {e}"),
             )
         })?;
-    // Try HTTP/2 over TLS first, fall back to HTTP/1.1 if not supported (e.g., curl compiled without HTTP/2 support)
-    if easy.http_version(curl::easy::HttpVersion::V2TLS).is_err() {
-        easy.http_version(curl::easy::HttpVersion::V11)
-            .map_err(|e| {
-                AppError::new(
-                    corr_id.clone(),
-                    "HTTP_VERSION_ERROR",
-                    format!("Failed to set HTTP version (fallback to HTTP/1.1): {e}"),
-                )
-            })?;
-    }
-    easy.follow_location(true).map_err(|e| {
-        AppError::new(
-            corr_id.clone(),
-            "REDIRECT_CONFIG_ERROR",
-            format!("Failed to configure redirects: {e}"),
-        )
-    })?;
+    configure_http_version(&mut easy, params, &corr_id)?;
+    easy.follow_location(params.follow_redirects)
+        .and_then(|()| easy.max_redirections(params.max_redirects))
+        .map_err(|e| {
+            AppError::new(
+                corr_id.clone(),
+                "REDIRECT_CONFIG_ERROR",
+                format!("Failed to configure redirects: {e}"),
+            )
+        })?;
 
     // Force a fresh connection to ensure timing information is available
     // Connection reuse can cause timing info to be unavailable or inaccurate
@@ -392,23 +1276,15 @@ fn execute_request_sync(
         )
     })?;
 
+    let loaded_cookies = configure_session(&mut easy, params, &corr_id)?;
+
     // Configure method and body
-    configure_method(&mut easy, &params.method, params.body.as_deref())
+    let payload = build_payload(easy.get_mut(), params)
+        .map_err(|e| AppError::new(corr_id.clone(), "REQUEST_BODY_ERROR", e))?;
+    configure_method(&mut easy, &params.method, payload)
         .map_err(|e| AppError::new(corr_id.clone(), "METHOD_CONFIG_ERROR", e))?;
 
-    // Set headers
-    let mut header_list = List::new();
-    for (key, value) in &params.headers {
-        header_list
-            .append(&format!("{key}: {value}"))
-            .map_err(|e| {
-                AppError::new(
-                    corr_id.clone(),
-                    "HEADER_ERROR",
-                    format!("Failed to add header '{key}': {e}"),
-                )
-            })?;
-    }
+    let header_list = request_header_list(params, &corr_id)?;
     easy.http_headers(header_list).map_err(|e| {
         AppError::new(
             corr_id.clone(),
@@ -417,13 +1293,228 @@ fn execute_request_sync(
         )
     })?;
 
+    // Enable the progress callback so cancellation can abort the transfer
+    easy.progress(true).map_err(|e| {
+        AppError::new(
+            corr_id.clone(),
+            "PROGRESS_CONFIG_ERROR",
+            format!("Failed to enable progress callback: {e}"),
+        )
+    })?;
+
     // Perform request
-    easy.perform()
-        .map_err(|e| e.to_app_error(corr_id.clone()))?;
+    easy.perform().map_err(|e| {
+        let error = if easy.get_ref().is_cancelled() {
+            AppError::new(
+                corr_id.clone(),
+                "REQUEST_CANCELLED",
+                "Request was cancelled",
+            )
+        } else if e.is_too_many_redirects() {
+            too_many_redirects_error(easy.get_mut(), params.max_redirects, corr_id.clone())
+        } else if refused_http_version(params.http_version, &e) {
+            http_version_error(
+                corr_id.clone(),
+                "HTTP_VERSION_NOT_NEGOTIATED",
+                params.http_version,
+                format!("Server did not accept {}: {e}", params.http_version.label()),
+            )
+        } else {
+            e.to_app_error(corr_id.clone())
+        };
+        with_trace(error, easy.get_mut().take_trace())
+    })?;
+
+    if let Some(scope) = &params.cookie_jar {
+        store_cookies(&mut easy, scope, &loaded_cookies)
+            .map_err(|e| AppError::new(corr_id.clone(), "COOKIE_JAR_ERROR", e))?;
+    }
+
+    check_negotiated_version(params.http_version, &easy.get_ref().http_version).map_err(
+        |message| {
+            http_version_error(
+                corr_id.clone(),
+                "HTTP_VERSION_NOT_NEGOTIATED",
+                params.http_version,
+                message,
+            )
+        },
+    )?;
 
     // Extract timing and build response
     let timing = extract_timing(&easy);
-    Ok(build_response(&easy, timing))
+    let transcript = easy.get_mut().finish_stream(timing.total_ms);
+    let mut response = build_response(&easy, timing);
+    response.stream = transcript;
+    response.redirects = std::mem::take(&mut easy.get_mut().redirects.hops);
+    response.trace = easy.get_mut().take_trace();
+    Ok(response)
+}
+
+/// Attach the wire trace of a failed transfer to the error's details, so a
+/// refused handshake or dropped connection shows what was exchanged.
+fn with_trace(mut error: AppError, trace: Option<WireTrace>) -> AppError {
+    let Some(trace) = trace else {
+        return error;
+    };
+    let trace = serde_json::to_value(trace).unwrap_or_default();
+    match &mut error.details {
+        Some(serde_json::Value::Object(details)) => {
+            details.insert("trace".to_string(), trace);
+        }
+        None => error.details = Some(serde_json::json!({ "trace": trace })),
+        Some(_) => {}
+    }
+    error
+}
+
+/// Apply the requested HTTP version.
+///
+/// `auto` prefers HTTP/2 over TLS and quietly settles for HTTP/1.1. Explicit
+/// versions are checked against the URL scheme and this libcurl's features
+/// up front, so an impossible request fails instead of being downgraded.
+fn configure_http_version(
+    easy: &mut Easy2<ResponseCollector>,
+    params: &RequestParams,
+    corr_id: &str,
+) -> Result<(), AppError> {
+    use curl::easy::HttpVersion as CurlVersion;
+
+    let requested = params.http_version;
+    let unsupported = |message: String| {
+        http_version_error(
+            corr_id.to_string(),
+            "HTTP_VERSION_UNSUPPORTED",
+            requested,
+            message,
+        )
+    };
+    let features = curl::Version::get();
+    let https = params
+        .url
+        .get(..8)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"));
+
+    let version = match requested {
+        HttpVersion::Auto => {
+            // Fall back to HTTP/1.1 if curl was compiled without HTTP/2 support
+            if easy.http_version(CurlVersion::V2TLS).is_err() {
+                easy.http_version(CurlVersion::V11).map_err(|e| {
+                    AppError::new(
+                        corr_id.to_string(),
+                        "HTTP_VERSION_ERROR",
+                        format!("Failed to set HTTP version (fallback to HTTP/1.1): {e}"),
+                    )
+                })?;
+            }
+            return Ok(());
+        }
+        HttpVersion::Http10 => CurlVersion::V10,
+        HttpVersion::Http11 => CurlVersion::V11,
+        HttpVersion::Http2 | HttpVersion::H2c if !features.feature_http2() => {
+            return Err(unsupported(
+                "This build of libcurl has no HTTP/2 support".to_string(),
+            ));
+        }
+        HttpVersion::Http2 if !https => {
+            return Err(unsupported(
+                "HTTP/2 over TLS needs an https:// URL; use h2c for cleartext HTTP/2".to_string(),
+            ));
+        }
+        HttpVersion::Http2 => CurlVersion::V2TLS,
+        HttpVersion::H2c if https => {
+            return Err(unsupported(
+                "h2c is cleartext HTTP/2 and needs an http:// URL; use http2 for https://"
+                    .to_string(),
+            ));
+        }
+        HttpVersion::H2c => CurlVersion::V2PriorKnowledge,
+        HttpVersion::Http3 if !features.feature_http3() => {
+            return Err(unsupported(
+                "This build of libcurl has no HTTP/3 support".to_string(),
+            ));
+        }
+        HttpVersion::Http3 if !https => {
+            return Err(unsupported("HTTP/3 needs an https:// URL".to_string()));
+        }
+        HttpVersion::Http3 => CurlVersion::V3,
+    };
+    easy.http_version(version)
+        .map_err(|e| unsupported(format!("libcurl rejected {}: {e}", requested.label())))
+}
+
+/// Whether a transfer error means the server would not speak the required
+/// HTTP/2 or HTTP/3 (as opposed to an unrelated network failure).
+fn refused_http_version(requested: HttpVersion, error: &curl::Error) -> bool {
+    matches!(
+        requested,
+        HttpVersion::Http2 | HttpVersion::H2c | HttpVersion::Http3
+    ) && (error.is_http2_error()
+        || error.is_http2_stream_error()
+        || error.is_got_nothing()
+        // CURLE_WEIRD_SERVER_REPLY, still named after FTP in curl-sys
+        || error.code() == curl_sys::CURLE_FTP_WEIRD_SERVER_REPLY)
+}
+
+/// Check the final response's HTTP version against an explicit HTTP/2 or
+/// HTTP/3 requirement; curl may otherwise settle for HTTP/1.1.
+///
+/// libcurl offers no strict HTTP/2-over-TLS mode and curl-rs does not expose
+/// `CURL_HTTP_VERSION_3ONLY`, so a fallback can only be detected once the
+/// response is in: by then the request has been sent and may have had side
+/// effects on the server. Only h2c is strict up front, since prior knowledge
+/// never falls back.
+fn check_negotiated_version(requested: HttpVersion, negotiated: &str) -> Result<(), String> {
+    let expected = match requested {
+        HttpVersion::Http2 | HttpVersion::H2c => "HTTP/2",
+        HttpVersion::Http3 => "HTTP/3",
+        HttpVersion::Auto | HttpVersion::Http10 | HttpVersion::Http11 => return Ok(()),
+    };
+    if negotiated == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "Requested {} but the server answered with {negotiated}",
+            requested.label()
+        ))
+    }
+}
+
+/// Error for an HTTP version that could not be used, naming the requested version.
+fn http_version_error(
+    corr_id: String,
+    code: &str,
+    requested: HttpVersion,
+    message: String,
+) -> AppError {
+    AppError::with_details(
+        corr_id,
+        code,
+        message,
+        serde_json::json!({ "http_version": requested }),
+    )
+}
+
+/// Error for a transfer that hit `max_redirects`, carrying the chain so far
+/// so redirect loops can be diagnosed.
+fn too_many_redirects_error(
+    collector: &mut ResponseCollector,
+    max_redirects: u32,
+    corr_id: String,
+) -> AppError {
+    // The redirect curl refused to follow is still the current response
+    collector.record_redirect();
+    let hops = std::mem::take(&mut collector.redirects.hops);
+    let next = hops
+        .last()
+        .and_then(|hop| hop.location.as_deref())
+        .unwrap_or_default();
+    AppError::with_details(
+        corr_id,
+        "TOO_MANY_REDIRECTS",
+        format!("Stopped after {max_redirects} redirects; next location was '{next}'"),
+        serde_json::json!({ "max_redirects": max_redirects, "redirects": hops }),
+    )
 }
 
 /// Convert Duration to milliseconds as u64.
@@ -438,13 +1529,54 @@ fn duration_to_ms(d: Duration) -> u64 {
 /// and the MCP server dispatcher. Uses curl on a blocking thread for
 /// detailed timing breakdown (DNS, TCP, TLS, TTFB).
 ///
+/// While running, the request can be aborted with [`cancel_in_flight_request`]
+/// using the same `correlation_id`.
+///
 /// # Errors
 ///
 /// Returns an error string if the request fails.
-#[instrument(skip(params), fields(correlation_id = %correlation_id.as_deref().unwrap_or("unknown"), url = %params.url, method = %params.method))]
 pub async fn execute_http_request(
     params: RequestParams,
     correlation_id: Option<String>,
+) -> Result<HttpResponse, String> {
+    perform_request(params, correlation_id, None).await
+}
+
+/// Execute an HTTP request in streaming mode.
+///
+/// Like [`execute_http_request`], but decodes the body while it arrives
+/// (SSE events, NDJSON lines, or raw chunks) and sends an [`HttpStreamEvent`]
+/// on `events` for each update: `started`, one `message` per decoded item,
+/// then `finished` or `failed`. The body is not buffered: the returned
+/// response carries the decoded transcript in `stream` and an empty `body`.
+///
+/// # Errors
+///
+/// Returns an error string if the request fails.
+pub async fn execute_streaming_http_request(
+    params: RequestParams,
+    correlation_id: String,
+    events: UnboundedSender<HttpStreamEvent>,
+) -> Result<HttpResponse, String> {
+    let failure_events = events.clone();
+    let sink = StreamSink::new(correlation_id.clone(), events);
+    let result = perform_request(params, Some(correlation_id.clone()), Some(sink)).await;
+    if let Err(e) = &result {
+        let error = serde_json::from_str::<AppError>(e).map_or_else(|_| e.clone(), |e| e.message);
+        let _ = failure_events.send(HttpStreamEvent {
+            correlation_id,
+            update: StreamUpdate::Failed { error },
+        });
+    }
+    result
+}
+
+/// Shared implementation of [`execute_http_request`] and [`execute_streaming_http_request`].
+#[instrument(skip(params, stream), fields(correlation_id = %correlation_id.as_deref().unwrap_or("unknown"), url = %params.url, method = %params.method))]
+async fn perform_request(
+    params: RequestParams,
+    correlation_id: Option<String>,
+    stream: Option<StreamSink>,
 ) -> Result<HttpResponse, String> {
     let corr_id = correlation_id
         .clone()
@@ -457,21 +1589,20 @@ pub async fn execute_http_request(
         "Executing HTTP request"
     );
 
-    // Move blocking curl operation to a dedicated thread
-    let result = tokio::task::spawn_blocking(move || execute_request_sync(&params, correlation_id))
-        .await
-        .map_err(|e| {
-            let app_error = AppError::new(
-                corr_id.clone(),
-                "TASK_EXECUTION_ERROR",
-                format!("Task execution failed: {e}"),
-            );
-            serde_json::to_string(&app_error).unwrap_or_else(|_| {
-                format!(
-                    "{{\"correlation_id\":\"{corr_id}\",\"code\":\"TASK_EXECUTION_ERROR\",\"message\":\"Task execution failed\"}}"
-                )
-            })
-        })?;
+    // Register before spawning so a cancel issued right away is not lost
+    let guard = correlation_id.as_deref().map(InFlightGuard::register);
+    let cancelled = guard.as_ref().map(|g| Arc::clone(&g.cancelled));
+
+    // Streams are delivered live, so a streaming request is never replayed
+    let policy = params
+        .retry
+        .clone()
+        .filter(|policy| stream.is_none() && policy.allows_method(&params.method));
+    let params = Arc::new(params);
+    let result = match policy {
+        Some(policy) => execute_with_retries(params, &policy, correlation_id, cancelled).await,
+        None => run_attempt(params, correlation_id, cancelled, stream).await,
+    };
 
     match result {
         Ok(response) => {
@@ -500,6 +1631,173 @@ pub async fn execute_http_request(
     }
 }
 
+/// Run one attempt of a request on the blocking thread pool.
+async fn run_attempt(
+    params: Arc<RequestParams>,
+    correlation_id: Option<String>,
+    cancelled: Option<Arc<AtomicBool>>,
+    stream: Option<StreamSink>,
+) -> Result<HttpResponse, AppError> {
+    let corr_id = correlation_id
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
+    tokio::task::spawn_blocking(move || {
+        execute_request_sync(&params, correlation_id, cancelled, stream)
+    })
+    .await
+    .unwrap_or_else(|e| {
+        Err(AppError::new(
+            corr_id,
+            "TASK_EXECUTION_ERROR",
+            format!("Task execution failed: {e}"),
+        ))
+    })
+}
+
+/// Run a request under `policy`, recording every attempt.
+///
+/// Once attempts run out, the last response or error is returned as it came,
+/// with the attempt log attached: `attempts` on a response, `details.attempts`
+/// on an error.
+async fn execute_with_retries(
+    params: Arc<RequestParams>,
+    policy: &RetryPolicy,
+    correlation_id: Option<String>,
+    cancelled: Option<Arc<AtomicBool>>,
+) -> Result<HttpResponse, AppError> {
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempts = Vec::new();
+    let mut attempt = 1;
+    loop {
+        let started = Instant::now();
+        let result = run_attempt(
+            Arc::clone(&params),
+            correlation_id.clone(),
+            cancelled.clone(),
+            None,
+        )
+        .await;
+        let mut record = RetryAttempt {
+            attempt,
+            status: result.as_ref().ok().map(|response| response.status),
+            error: result
+                .as_ref()
+                .err()
+                .map(|e| format!("[{}] {}", e.code, e.message)),
+            duration_ms: duration_to_ms(started.elapsed()),
+            retry_delay_ms: None,
+        };
+
+        // `Some(retry_after)` when this outcome is worth another attempt
+        let retry = match &result {
+            Ok(response) if policy.retries_status(response.status) => Some(retry_after(response)),
+            Err(e) if policy.retry_on_connection_error && is_connection_error(e) => Some(None),
+            _ => None,
+        };
+        let retry = retry.filter(|_| attempt < max_attempts && !is_set(cancelled.as_deref()));
+        let Some(retry_after) = retry else {
+            attempts.push(record);
+            return attach_attempts(result, attempts);
+        };
+
+        let delay = policy.delay(attempt, retry_after, rand::rng().random());
+        record.retry_delay_ms = Some(duration_to_ms(delay));
+        info!(
+            correlation_id = %correlation_id.as_deref().unwrap_or("unknown"),
+            attempt,
+            status = ?record.status,
+            error = ?record.error,
+            delay_ms = duration_to_ms(delay),
+            "Retrying HTTP request"
+        );
+        attempts.push(record);
+
+        if !sleep_unless_cancelled(delay, cancelled.as_deref()).await {
+            let cancelled = AppError::new(
+                correlation_id.unwrap_or_else(|| "unknown".to_string()),
+                "REQUEST_CANCELLED",
+                "Request was cancelled",
+            );
+            return attach_attempts(Err(cancelled), attempts);
+        }
+        attempt += 1;
+    }
+}
+
+/// Wait the server asked for on a 429 or 503 response, if any.
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    if !matches!(response.status, 429 | 503) {
+        return None;
+    }
+    response
+        .headers
+        .get("retry-after")
+        .and_then(|value| parse_retry_after(value, Utc::now()))
+}
+
+/// Whether `error` is a failure to connect or a connection that broke
+/// mid-transfer, as opposed to a problem with the request itself.
+fn is_connection_error(error: &AppError) -> bool {
+    const TRANSIENT: [curl_sys::CURLcode; 7] = [
+        curl_sys::CURLE_COULDNT_CONNECT,
+        curl_sys::CURLE_OPERATION_TIMEDOUT,
+        curl_sys::CURLE_GOT_NOTHING,
+        curl_sys::CURLE_SEND_ERROR,
+        curl_sys::CURLE_RECV_ERROR,
+        curl_sys::CURLE_PARTIAL_FILE,
+        curl_sys::CURLE_HTTP2_STREAM,
+    ];
+    error.code == "HTTP_REQUEST_FAILED"
+        && error
+            .details
+            .as_ref()
+            .and_then(|details| details.get("error_code"))
+            .and_then(serde_json::Value::as_i64)
+            .is_some_and(|code| TRANSIENT.iter().any(|&t| i64::from(t) == code))
+}
+
+fn is_set(flag: Option<&AtomicBool>) -> bool {
+    flag.is_some_and(|flag| flag.load(Ordering::SeqCst))
+}
+
+/// Sleep for `delay`, waking early if `cancelled` gets set.
+///
+/// Returns `false` if the wait was cut short by cancellation.
+async fn sleep_unless_cancelled(delay: Duration, cancelled: Option<&AtomicBool>) -> bool {
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+    let deadline = tokio::time::Instant::now() + delay;
+    loop {
+        if is_set(cancelled) {
+            return false;
+        }
+        let now = tokio::time::Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        tokio::time::sleep((deadline - now).min(POLL_INTERVAL)).await;
+    }
+}
+
+/// Attach the attempt log to the final outcome of a retried request.
+fn attach_attempts(
+    result: Result<HttpResponse, AppError>,
+    attempts: Vec<RetryAttempt>,
+) -> Result<HttpResponse, AppError> {
+    match result {
+        Ok(mut response) => {
+            response.attempts = attempts;
+            Ok(response)
+        }
+        Err(mut error) => {
+            let details = error.details.get_or_insert_with(|| serde_json::json!({}));
+            if let Some(details) = details.as_object_mut() {
+                details.insert("attempts".to_string(), serde_json::json!(attempts));
+            }
+            Err(error)
+        }
+    }
+}
+
 /// Execute an HTTP request — Tauri command wrapper.
 ///
 /// Thin wrapper around [`execute_http_request`] for Tauri v2 command compatibility.
@@ -507,7 +1805,7 @@ pub async fn execute_http_request(
 /// # Errors
 ///
 /// Returns a JSON-serialized `AppError` string on failure.
-#[tauri::command]
+#[cfg_attr(feature = "desktop", tauri::command)]
 pub async fn execute_request(
     params: RequestParams,
     correlation_id: Option<String>,
@@ -515,10 +1813,21 @@ pub async fn execute_request(
     execute_http_request(params, correlation_id).await
 }
 
+/// Cancel an in-flight HTTP request by its correlation ID.
+///
+/// Returns `true` if a running request was found and signalled to abort.
+#[cfg_attr(feature = "desktop", tauri::command)]
+#[must_use]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned types
+pub fn cancel_request(correlation_id: String) -> bool {
+    cancel_in_flight_request(&correlation_id)
+}
+
 #[cfg(test)]
 #[allow(clippy::significant_drop_tightening)]
 mod tests {
     use super::*;
+    use crate::domain::http::{ConnectToOverride, ResolveOverride};
     use std::io::{Read, Write};
     use std::net::{TcpListener, TcpStream};
     use std::sync::{Arc, Mutex};
@@ -529,6 +1838,7 @@ mod tests {
     struct TestRequest {
         method: String,
         path: String,
+        version: String,
         headers: HashMap<String, String>,
         body: String,
     }
@@ -537,9 +1847,12 @@ mod tests {
         status: u16,
         status_text: &'static str,
         headers: Vec<(String, String)>,
-        body: String,
+        body: Vec<u8>,
     }
 
+    /// PNG signature followed by bytes that are not valid UTF-8.
+    const PNG_BYTES: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\xff\xfe\x80";
+
     struct TestServer {
         base_url: String,
     }
@@ -587,7 +1900,7 @@ mod tests {
                         status: 404,
                         status_text: "Not Found",
                         headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
-                        body: "Not Found".to_string(),
+                        body: b"Not Found".to_vec(),
                     },
                     "/headers" => {
                         let header_value = request
@@ -599,26 +1912,86 @@ mod tests {
                             status: 200,
                             status_text: "OK",
                             headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
-                            body: format!("X-Custom-Header: {header_value}"),
+                            body: format!("X-Custom-Header: {header_value}").into_bytes(),
                         }
                     }
                     "/post" => TestResponse {
                         status: 200,
                         status_text: "OK",
                         headers: vec![("Content-Type".to_string(), "application/json".to_string())],
-                        body: request.body,
+                        body: request.body.into_bytes(),
+                    },
+                    "/stream" => TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: vec![(
+                            "Content-Type".to_string(),
+                            "text/event-stream".to_string(),
+                        )],
+                        body: b"data: hello\n\nevent: done\ndata: [DONE]\n\n".to_vec(),
+                    },
+                    "/login" => TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: vec![
+                            ("Content-Type".to_string(), "text/plain".to_string()),
+                            (
+                                "Set-Cookie".to_string(),
+                                "session=abc123; Path=/; HttpOnly".to_string(),
+                            ),
+                        ],
+                        body: b"logged in".to_vec(),
+                    },
+                    "/whoami" => TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+                        body: request
+                            .headers
+                            .get("cookie")
+                            .cloned()
+                            .unwrap_or_default()
+                            .into_bytes(),
+                    },
+                    "/sso/start" => redirect_response(302, "Found", "/sso/callback?code=xyz"),
+                    "/sso/callback?code=xyz" => redirect_response(303, "See Other", "/get"),
+                    "/loop" => redirect_response(302, "Found", "/loop"),
+                    "/links" => TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: vec![
+                            ("Link".to_string(), "</page/2>; rel=next".to_string()),
+                            ("Vary".to_string(), "Accept".to_string()),
+                            ("Link".to_string(), "</page/9>; rel=last".to_string()),
+                        ],
+                        body: b"[]".to_vec(),
+                    },
+                    "/image" => TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: vec![("Content-Type".to_string(), "image/png".to_string())],
+                        body: PNG_BYTES.to_vec(),
                     },
                     _ => TestResponse {
                         status: 200,
                         status_text: "OK",
                         headers: vec![("Content-Type".to_string(), "application/json".to_string())],
-                        body: "{\"ok\":true}".to_string(),
+                        body: b"{\"ok\":true}".to_vec(),
                     },
                 }
             }),
         )
     }
 
+    fn redirect_response(status: u16, status_text: &'static str, location: &str) -> TestResponse {
+        TestResponse {
+            status,
+            status_text,
+            headers: vec![("Location".to_string(), location.to_string())],
+            body: Vec::new(),
+        }
+    }
+
     fn read_request(stream: &mut TcpStream) -> TestRequest {
         let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
         let mut buffer = Vec::new();
@@ -656,6 +2029,7 @@ mod tests {
         let mut request_parts = request_line.split_whitespace();
         let method = request_parts.next().unwrap_or_default().to_string();
         let path = request_parts.next().unwrap_or_default().to_string();
+        let version = request_parts.next().unwrap_or_default().to_string();
 
         let mut headers = HashMap::new();
         for line in lines {
@@ -677,6 +2051,7 @@ mod tests {
         TestRequest {
             method,
             path,
+            version,
             headers,
             body,
         }
@@ -702,7 +2077,7 @@ mod tests {
 
     fn write_response(stream: &mut TcpStream, response: TestResponse) {
         use std::fmt::Write;
-        let body_bytes = response.body.as_bytes();
+        let body_bytes = response.body.as_slice();
         let mut response_text = format!(
             "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
             response.status,
@@ -729,9 +2104,10 @@ mod tests {
         let params = RequestParams {
             url: format!("{}/get", server.base_url),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -748,14 +2124,115 @@ mod tests {
         assert_eq!(recorded[0].path, "/get");
     }
 
+    #[tokio::test]
+    async fn test_response_reports_connection_details() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/post", server.base_url),
+            method: "POST".to_string(),
+            body: Some("ping".to_string()),
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("request succeeds");
+
+        let connection = response.connection.expect("connection details");
+        let port = server
+            .base_url
+            .rsplit(':')
+            .next()
+            .unwrap()
+            .parse::<u16>()
+            .unwrap();
+        assert_eq!(connection.http_version, "HTTP/1.1");
+        assert_eq!(connection.remote_ip.as_deref(), Some("127.0.0.1"));
+        assert_eq!(connection.remote_port, Some(port));
+        assert_eq!(connection.upload_bytes, 4);
+        assert_eq!(connection.download_bytes, response.body_size);
+        assert!(connection.request_bytes > 0);
+        assert!(connection.response_header_bytes > 0);
+        assert!(response.timing.transfer_ms.is_some());
+        assert!(response.timing.download_ms.is_some());
+        assert_eq!(response.timing.redirect_ms, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_forced_http_1_0_is_sent() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/get", server.base_url),
+            http_version: HttpVersion::Http10,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("request succeeds");
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            requests.lock().expect("lock requests")[0].version,
+            "HTTP/1.0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_h2c_against_http1_server_is_an_error() {
+        if !curl::Version::get().feature_http2() {
+            return;
+        }
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/get", server.base_url),
+            http_version: HttpVersion::H2c,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "HTTP_VERSION_NOT_NEGOTIATED");
+        // Prior knowledge skips the upgrade: the connection opens with the HTTP/2 preface
+        assert_eq!(requests.lock().expect("lock requests")[0].method, "PRI");
+    }
+
+    #[tokio::test]
+    async fn test_http_version_incompatible_with_scheme_fails_before_connecting() {
+        for (url, version) in [
+            ("http://127.0.0.1:1/get", HttpVersion::Http2),
+            ("https://127.0.0.1:1/get", HttpVersion::H2c),
+            ("http://127.0.0.1:1/get", HttpVersion::Http3),
+        ] {
+            let params = RequestParams {
+                url: url.to_string(),
+                http_version: version,
+                ..Default::default()
+            };
+
+            let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+            assert_eq!(error.code, "HTTP_VERSION_UNSUPPORTED", "{version:?}");
+        }
+    }
+
     #[tokio::test]
     async fn test_execute_request_post_with_body() {
         let requests = Arc::new(Mutex::new(Vec::new()));
         let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
             return;
         };
-        let mut headers = HashMap::new();
-        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        let mut headers = HttpHeaders::new();
+        headers.append("Content-Type", "application/json");
 
         let params = RequestParams {
             url: format!("{}/post", server.base_url),
@@ -763,6 +2240,7 @@ mod tests {
             headers,
             body: Some(r#"{"test": "data"}"#.to_string()),
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -779,47 +2257,331 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_execute_request_with_headers() {
+    async fn test_multipart_body_sends_text_and_file_parts() {
         let requests = Arc::new(Mutex::new(Vec::new()));
         let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
             return;
         };
-        let mut headers = HashMap::new();
-        headers.insert("X-Custom-Header".to_string(), "test-value".to_string());
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let avatar = dir.path().join("avatar.png");
+        std::fs::write(&avatar, "fake-png-bytes").expect("write upload");
 
         let params = RequestParams {
-            url: format!("{}/headers", server.base_url),
-            method: "GET".to_string(),
-            headers,
-            body: None,
+            url: format!("{}/post", server.base_url),
+            method: "POST".to_string(),
+            multipart: vec![
+                MultipartPart {
+                    name: "title".to_string(),
+                    value: Some("Profile".to_string()),
+                    file: None,
+                    filename: None,
+                    content_type: None,
+                },
+                MultipartPart {
+                    name: "avatar".to_string(),
+                    value: None,
+                    file: Some(avatar.to_string_lossy().into_owned()),
+                    filename: Some("me.png".to_string()),
+                    content_type: Some("image/png".to_string()),
+                },
+            ],
             timeout_ms: 10000,
+            ..Default::default()
         };
 
-        let result = execute_request(params, None).await;
-        assert!(result.is_ok(), "Request should succeed: {result:?}");
-        let response = result.unwrap();
+        let response = execute_request(params, None)
+            .await
+            .expect("request succeeds");
         assert_eq!(response.status, 200);
-        assert!(response.body.contains("X-Custom-Header"));
 
         let recorded = requests.lock().expect("lock requests");
-        assert_eq!(recorded.len(), 1);
+        let content_type = recorded[0]
+            .headers
+            .get("content-type")
+            .expect("content type");
+        assert!(content_type.starts_with("multipart/form-data; boundary="));
+        let body = &recorded[0].body;
+        assert!(body.contains("name=\"title\"\r\n\r\nProfile\r\n"));
+        assert!(body.contains("name=\"avatar\"; filename=\"me.png\""));
+        assert!(body.contains("Content-Type: image/png\r\n\r\nfake-png-bytes\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_body_file_is_streamed_from_disk() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let upload = dir.path().join("firmware.bin");
+        std::fs::write(&upload, b"\x00\x01firmware\x02").expect("write upload");
+
+        let params = RequestParams {
+            url: format!("{}/upload", server.base_url),
+            method: "PUT".to_string(),
+            body_file: Some(upload.to_string_lossy().into_owned()),
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("request succeeds");
+        assert_eq!(response.status, 200);
+
+        let recorded = requests.lock().expect("lock requests");
+        assert_eq!(recorded[0].method, "PUT");
+        assert_eq!(recorded[0].body, "\x00\x01firmware\x02");
+        assert_eq!(
+            recorded[0].headers.get("content-type").map(String::as_str),
+            Some("application/octet-stream")
+        );
         assert_eq!(
             recorded[0]
                 .headers
-                .get("x-custom-header")
+                .get("content-length")
                 .map(String::as_str),
-            Some("test-value")
+            Some("11")
         );
     }
 
+    #[tokio::test]
+    async fn test_missing_body_file_is_reported() {
+        let params = RequestParams {
+            url: "http://127.0.0.1:9/upload".to_string(),
+            method: "POST".to_string(),
+            body_file: Some("/nonexistent/runi/upload.bin".to_string()),
+            ..Default::default()
+        };
+
+        let err = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(err.code, "REQUEST_BODY_ERROR");
+        assert!(err.message.contains("/nonexistent/runi/upload.bin"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_request_binary_body_round_trips() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/image", server.base_url),
+            method: "GET".to_string(),
+            headers: HttpHeaders::new(),
+            body: None,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("Request should succeed");
+
+        assert_eq!(response.body_kind, ResponseBodyKind::Image);
+        assert_eq!(response.body_size, PNG_BYTES.len() as u64);
+        assert!(
+            response.body.is_empty(),
+            "binary body must not be lossily decoded"
+        );
+        assert_eq!(response.body_bytes(), PNG_BYTES);
+    }
+
+    #[tokio::test]
+    async fn test_execute_request_text_body_kind() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/get", server.base_url),
+            method: "GET".to_string(),
+            headers: HttpHeaders::new(),
+            body: None,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("Request should succeed");
+
+        assert_eq!(response.body_kind, ResponseBodyKind::Json);
+        assert_eq!(response.body, "{\"ok\":true}");
+        assert!(response.body_base64.is_none());
+        assert_eq!(response.body_size, 11);
+    }
+
+    #[tokio::test]
+    async fn test_execute_streaming_request_publishes_events() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/stream", server.base_url),
+            method: "GET".to_string(),
+            headers: HttpHeaders::new(),
+            body: None,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let response = execute_streaming_http_request(params, "test-stream".to_string(), tx)
+            .await
+            .expect("Request should succeed");
+
+        let mut kinds = Vec::new();
+        while let Some(event) = rx.recv().await {
+            assert_eq!(event.correlation_id, "test-stream");
+            kinds.push(event.update.event_type());
+        }
+        assert_eq!(
+            kinds,
+            vec![
+                "stream:started",
+                "stream:message",
+                "stream:message",
+                "stream:finished"
+            ]
+        );
+
+        let transcript = response.stream.expect("streamed response has a transcript");
+        assert_eq!(transcript.format, StreamFormat::Sse);
+        let data: Vec<&str> = transcript
+            .messages
+            .iter()
+            .map(|m| m.data.as_str())
+            .collect();
+        assert_eq!(data, vec!["hello", "[DONE]"]);
+        assert_eq!(transcript.messages[1].event.as_deref(), Some("done"));
+        assert!(response.body.is_empty());
+        assert!(response.body_size > 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_in_flight_request_aborts_transfer() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                eprintln!("[TEST] Skipping HTTP server bind: {err}");
+                return;
+            }
+            Err(err) => panic!("bind test server: {err}"),
+        };
+        let addr = listener.local_addr().expect("resolve test server addr");
+        // Accept the request but never answer, like a long-poll endpoint
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept test request");
+            let _ = read_request(&mut stream);
+            thread::sleep(Duration::from_secs(10));
+        });
+
+        let params = RequestParams {
+            url: format!("http://{addr}/poll"),
+            method: "GET".to_string(),
+            headers: HttpHeaders::new(),
+            body: None,
+            timeout_ms: 30_000,
+            ..Default::default()
+        };
+        let correlation_id = "test-cancel-in-flight";
+        let started = std::time::Instant::now();
+        let handle = tokio::spawn(execute_request(params, Some(correlation_id.to_string())));
+
+        while !is_request_in_flight(correlation_id) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(cancel_request(correlation_id.to_string()));
+
+        let err_json = handle
+            .await
+            .expect("join request task")
+            .expect_err("cancelled request should fail");
+        let err: AppError = serde_json::from_str(&err_json).expect("parse error JSON");
+        assert_eq!(err.code, "REQUEST_CANCELLED");
+        assert_eq!(err.correlation_id, correlation_id);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(!is_request_in_flight(correlation_id));
+    }
+
+    #[test]
+    fn test_cancel_unknown_request_returns_false() {
+        assert!(!cancel_in_flight_request("test-no-such-request"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_request_with_headers() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, Arc::clone(&requests)) else {
+            return;
+        };
+        let mut headers = HttpHeaders::new();
+        headers.append("X-Custom-Header", "test-value");
+
+        let params = RequestParams {
+            url: format!("{}/headers", server.base_url),
+            method: "GET".to_string(),
+            headers,
+            body: None,
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let result = execute_request(params, None).await;
+        assert!(result.is_ok(), "Request should succeed: {result:?}");
+        let response = result.unwrap();
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("X-Custom-Header"));
+
+        let recorded = requests.lock().expect("lock requests");
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(
+            recorded[0]
+                .headers
+                .get("x-custom-header")
+                .map(String::as_str),
+            Some("test-value")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_repeated_response_headers_are_preserved_in_order() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/links", server.base_url),
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None).await.expect("links request");
+        assert_eq!(
+            response.headers.get_all("link").collect::<Vec<_>>(),
+            vec!["</page/2>; rel=next", "</page/9>; rel=last"]
+        );
+        let names: Vec<&str> = response
+            .headers
+            .iter()
+            .map(|header| header.name.as_str())
+            .filter(|name| matches!(*name, "Link" | "Vary"))
+            .collect();
+        assert_eq!(names, vec!["Link", "Vary", "Link"]);
+    }
+
     #[tokio::test]
     async fn test_execute_request_invalid_url() {
         let params = RequestParams {
             url: "not-a-valid-url".to_string(),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 5000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -833,9 +2595,10 @@ mod tests {
         let params = RequestParams {
             url: "http://127.0.0.1:1/get".to_string(),
             method: "GET POST".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 5000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -851,6 +2614,124 @@ mod tests {
         );
     }
 
+    /// Server that answers 503 with `Retry-After: 0` until `failures` requests
+    /// have been served, then 200.
+    fn start_flaky_server(failures: usize, expected_requests: usize) -> Option<TestServer> {
+        let served = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        start_test_server(
+            expected_requests,
+            Arc::new(move |_request| {
+                if served.fetch_add(1, Ordering::SeqCst) < failures {
+                    TestResponse {
+                        status: 503,
+                        status_text: "Service Unavailable",
+                        headers: vec![("Retry-After".to_string(), "0".to_string())],
+                        body: b"busy".to_vec(),
+                    }
+                } else {
+                    TestResponse {
+                        status: 200,
+                        status_text: "OK",
+                        headers: Vec::new(),
+                        body: b"ok".to_vec(),
+                    }
+                }
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success_records_attempts() {
+        let Some(server) = start_flaky_server(2, 3) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/flaky", server.base_url),
+            timeout_ms: 5000,
+            retry: Some(RetryPolicy {
+                initial_delay_ms: 10_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let started = Instant::now();
+        let response = execute_request(params, None).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "ok");
+        let statuses: Vec<_> = response.attempts.iter().map(|a| a.status).collect();
+        assert_eq!(statuses, vec![Some(503), Some(503), Some(200)]);
+        // Retry-After: 0 replaces the 10s backoff
+        assert_eq!(response.attempts[0].retry_delay_ms, Some(0));
+        assert_eq!(response.attempts[2].retry_delay_ms, None);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let Some(server) = start_flaky_server(2, 2) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/flaky", server.base_url),
+            timeout_ms: 5000,
+            retry: Some(RetryPolicy {
+                max_attempts: 2,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None).await.unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(response.attempts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_method_is_not_retried() {
+        let Some(server) = start_flaky_server(1, 1) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/flaky", server.base_url),
+            method: "POST".to_string(),
+            body: Some("{}".to_string()),
+            timeout_ms: 5000,
+            retry: Some(RetryPolicy::default()),
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None).await.unwrap();
+        assert_eq!(response.status, 503);
+        assert!(response.attempts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connection_error_is_retried_and_reported() {
+        let params = RequestParams {
+            url: "http://127.0.0.1:1/get".to_string(),
+            timeout_ms: 5000,
+            retry: Some(RetryPolicy {
+                initial_delay_ms: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let err = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(err.code, "HTTP_REQUEST_FAILED");
+        let details = err.details.expect("error details");
+        assert_eq!(details["error_type"], "curl_error");
+        let attempts: Vec<RetryAttempt> =
+            serde_json::from_value(details["attempts"].clone()).unwrap();
+        assert_eq!(attempts.len(), 3);
+        assert!(
+            attempts
+                .iter()
+                .all(|a| a.status.is_none() && a.error.is_some())
+        );
+    }
+
     #[tokio::test]
     async fn test_execute_request_404() {
         let requests = Arc::new(Mutex::new(Vec::new()));
@@ -860,9 +2741,10 @@ mod tests {
         let params = RequestParams {
             url: format!("{}/status/404", server.base_url),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -895,9 +2777,10 @@ mod tests {
             let params = RequestParams {
                 url: format!("{}/{}", server.base_url, method.to_lowercase()),
                 method: (*method).to_string(),
-                headers: HashMap::new(),
+                headers: HttpHeaders::new(),
                 body: None,
                 timeout_ms: 10000,
+                ..Default::default()
             };
 
             let result = execute_request(params, None).await;
@@ -927,9 +2810,10 @@ mod tests {
         let params = RequestParams {
             url: format!("{}/get", server.base_url),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -968,9 +2852,10 @@ mod tests {
         let params = RequestParams {
             url: format!("{}/get", server.base_url),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -993,9 +2878,10 @@ mod tests {
         let params = RequestParams {
             url: format!("{}/get", server.base_url),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 10000,
+            ..Default::default()
         };
 
         let result = execute_request(params, None).await;
@@ -1029,4 +2915,739 @@ mod tests {
             eprintln!("[DEBUG TEST] WARNING: first_byte_ms is None (curl returned error)");
         }
     }
+
+    // ── Wire trace ───────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_verbose_request_records_wire_trace() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(2, requests) else {
+            return;
+        };
+        let mut headers = HttpHeaders::new();
+        headers.append("X-Trace-Me", "yes");
+        let params = RequestParams {
+            url: format!("{}/status/404", server.base_url),
+            headers,
+            timeout_ms: 10000,
+            verbose: true,
+            ..Default::default()
+        };
+
+        let response = execute_request(params.clone(), None)
+            .await
+            .expect("verbose request");
+        assert_eq!(response.status, 404);
+        let trace = response.trace.expect("wire trace");
+        let sent = trace
+            .entries
+            .iter()
+            .find(|entry| entry.kind == WireTraceKind::HeaderOut)
+            .expect("outgoing headers");
+        assert!(sent.text.starts_with("GET /status/404 HTTP/1.1\n"));
+        assert!(sent.text.contains("\nHost: 127.0.0.1:"));
+        assert!(sent.text.contains("\nX-Trace-Me: yes"));
+        assert!(!sent.text.contains('\r'));
+        assert!(trace.entries.iter().any(|entry| {
+            entry.kind == WireTraceKind::HeaderIn && entry.text.starts_with("HTTP/1.1 404")
+        }));
+        assert!(
+            trace
+                .entries
+                .iter()
+                .any(|entry| entry.kind == WireTraceKind::Info)
+        );
+        assert!(trace.to_text().contains("> GET /status/404 HTTP/1.1"));
+
+        let quiet = RequestParams {
+            verbose: false,
+            ..params
+        };
+        let response = execute_request(quiet, None).await.expect("quiet request");
+        assert!(response.trace.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_verbose_request_carries_trace_in_error_details() {
+        let params = RequestParams {
+            url: "http://127.0.0.1:1/get".to_string(),
+            timeout_ms: 5000,
+            verbose: true,
+            ..Default::default()
+        };
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        let trace: WireTrace =
+            serde_json::from_value(error.details.expect("error details")["trace"].clone())
+                .expect("trace in details");
+        assert!(
+            trace
+                .entries
+                .iter()
+                .all(|entry| entry.kind == WireTraceKind::Info)
+        );
+        assert!(!trace.entries.is_empty());
+    }
+
+    // ── Cookies ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_cookie_jar_persists_session_across_requests() {
+        use crate::infrastructure::storage::collection_store::with_collections_dir_override_async;
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(3, requests) else {
+            return;
+        };
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        with_collections_dir_override_async(temp_dir.path().to_path_buf(), || async {
+            let request = |path: &str, environment: &str| RequestParams {
+                url: format!("{}{path}", server.base_url),
+                timeout_ms: 10000,
+                cookie_jar: Some(CookieJarScope {
+                    collection_id: "col_cookies_test".to_string(),
+                    environment: Some(environment.to_string()),
+                }),
+                ..Default::default()
+            };
+
+            execute_request(request("/login", "staging"), None)
+                .await
+                .expect("login");
+            let whoami = execute_request(request("/whoami", "staging"), None)
+                .await
+                .expect("whoami");
+            assert_eq!(whoami.body, "session=abc123");
+
+            let stored = cookie_store::load_cookies(&CookieJarScope {
+                collection_id: "col_cookies_test".to_string(),
+                environment: Some("staging".to_string()),
+            })
+            .unwrap();
+            assert_eq!(stored.len(), 1);
+            assert_eq!(stored[0].name, "session");
+            assert_eq!(stored[0].domain, "127.0.0.1");
+            assert!(stored[0].http_only);
+            assert!(
+                temp_dir
+                    .path()
+                    .join("col_cookies_test.cookies.json")
+                    .exists()
+            );
+
+            // Another environment has its own, empty jar.
+            let other = execute_request(request("/whoami", "production"), None)
+                .await
+                .expect("whoami in production");
+            assert_eq!(other.body, "");
+        })
+        .await;
+    }
+
+    // ── Redirects ────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_redirect_chain_is_recorded() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(3, requests.clone()) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/sso/start", server.base_url),
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("redirected request");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "{\"ok\":true}");
+        assert_eq!(response.redirects.len(), 2);
+
+        let first = &response.redirects[0];
+        assert_eq!(first.url, format!("{}/sso/start", server.base_url));
+        assert_eq!(first.status, 302);
+        assert_eq!(first.status_text, "Found");
+        assert_eq!(first.location.as_deref(), Some("/sso/callback?code=xyz"));
+        assert_eq!(
+            first.headers.get("Location"),
+            Some("/sso/callback?code=xyz")
+        );
+        let second = &response.redirects[1];
+        assert_eq!(
+            second.url,
+            format!("{}/sso/callback?code=xyz", server.base_url)
+        );
+        assert_eq!(second.status, 303);
+        assert_eq!(second.location.as_deref(), Some("/get"));
+
+        let paths: Vec<String> = requests
+            .lock()
+            .expect("lock requests")
+            .iter()
+            .map(|r| r.path.clone())
+            .collect();
+        assert_eq!(paths, vec!["/sso/start", "/sso/callback?code=xyz", "/get"]);
+    }
+
+    #[tokio::test]
+    async fn test_redirects_not_followed_when_disabled() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/sso/start", server.base_url),
+            timeout_ms: 10000,
+            follow_redirects: false,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("redirect response");
+        assert_eq!(response.status, 302);
+        assert_eq!(
+            response.headers.get("Location"),
+            Some("/sso/callback?code=xyz")
+        );
+        assert!(response.redirects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_max_redirects_reports_chain() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(3, requests) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/loop", server.base_url),
+            timeout_ms: 10000,
+            max_redirects: 2,
+            ..Default::default()
+        };
+
+        let err = execute_request(params, None)
+            .await
+            .expect_err("redirect loop should fail");
+        let err = parse_app_error(&err);
+        assert_eq!(err.code, "TOO_MANY_REDIRECTS");
+        assert!(err.message.contains("Stopped after 2 redirects"));
+        let details = err.details.expect("redirect details");
+        let hops = details["redirects"].as_array().expect("redirect hops");
+        assert_eq!(hops.len(), 3);
+        assert!(hops.iter().all(|hop| hop["status"] == 302));
+    }
+
+    // ── Proxy ────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_request_through_http_proxy_with_credentials() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(proxy) = start_http_test_server(1, requests.clone()) else {
+            return;
+        };
+        let params = RequestParams {
+            url: "http://runi-proxy-test.invalid/get".to_string(),
+            timeout_ms: 10000,
+            proxy: Some(ProxyConfig {
+                url: Some(proxy.base_url.clone()),
+                username: Some("alice".to_string()),
+                password: Some("s3cret".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("proxied request");
+        assert_eq!(response.status, 200);
+
+        let requests = requests.lock().expect("lock requests");
+        assert_eq!(requests[0].path, "http://runi-proxy-test.invalid/get");
+        let expected_auth = format!("Basic {}", BASE64.encode("alice:s3cret"));
+        assert_eq!(
+            requests[0].headers.get("proxy-authorization"),
+            Some(&expected_auth)
+        );
+        drop(requests);
+    }
+
+    #[tokio::test]
+    async fn test_no_proxy_host_bypasses_proxy() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests.clone()) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/get", server.base_url),
+            timeout_ms: 10000,
+            proxy: Some(ProxyConfig {
+                // Nothing listens here; the request only succeeds if it goes direct.
+                url: Some("http://127.0.0.1:1".to_string()),
+                no_proxy: vec!["127.0.0.1".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None).await.expect("direct request");
+        assert_eq!(response.status, 200);
+        assert_eq!(requests.lock().expect("lock requests")[0].path, "/get");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_proxy_is_config_error() {
+        let params = RequestParams {
+            url: "http://127.0.0.1:1/get".to_string(),
+            proxy: Some(ProxyConfig {
+                url: Some("ftp://proxy:21".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "PROXY_CONFIG_ERROR");
+    }
+
+    // ── Routing ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_resolve_override_pins_host_to_address() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests.clone()) else {
+            return;
+        };
+        let port = server.base_url.rsplit(':').next().unwrap();
+        let params = RequestParams {
+            url: format!("http://staging.runi.test:{port}/get"),
+            timeout_ms: 10000,
+            resolve: vec![ResolveOverride {
+                host: "staging.runi.test".to_string(),
+                port: None,
+                addresses: vec!["127.0.0.1".to_string()],
+            }],
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None).await.expect("pinned request");
+        assert_eq!(response.status, 200);
+        let requests = requests.lock().expect("lock requests");
+        assert_eq!(
+            requests[0].headers.get("host").map(String::as_str),
+            Some(format!("staging.runi.test:{port}").as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_to_override_redirects_connection() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests.clone()) else {
+            return;
+        };
+        let port: u16 = server.base_url.rsplit(':').next().unwrap().parse().unwrap();
+        let params = RequestParams {
+            url: "http://api.runi.test/get".to_string(),
+            timeout_ms: 10000,
+            connect_to: vec![ConnectToOverride {
+                host: Some("api.runi.test".to_string()),
+                port: None,
+                to_host: Some("127.0.0.1".to_string()),
+                to_port: Some(port),
+            }],
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("redirected request");
+        assert_eq!(response.status, 200);
+        let requests = requests.lock().expect("lock requests");
+        assert_eq!(
+            requests[0].headers.get("host").map(String::as_str),
+            Some("api.runi.test")
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_request_over_unix_socket() {
+        use std::os::unix::net::UnixListener;
+
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let socket = dir.path().join("docker.sock");
+        let listener = UnixListener::bind(&socket).expect("bind unix socket");
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let mut received = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !received.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).expect("read request");
+                if n == 0 {
+                    break;
+                }
+                received.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]")
+                .expect("write response");
+            String::from_utf8(received).expect("utf-8 request")
+        });
+
+        let params = RequestParams {
+            url: "http://localhost/v1.45/containers/json".to_string(),
+            timeout_ms: 10000,
+            unix_socket: Some(socket.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        let response = execute_request(params, None)
+            .await
+            .expect("unix socket request");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "[]");
+        let received = server.join().expect("server thread");
+        assert!(received.starts_with("GET /v1.45/containers/json HTTP/1.1\r\n"));
+        assert!(received.contains("Host: localhost\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_unix_socket_and_bad_override_are_config_errors() {
+        let params = RequestParams {
+            url: "http://localhost/_ping".to_string(),
+            unix_socket: Some("/nonexistent/runi.sock".to_string()),
+            ..Default::default()
+        };
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "ROUTING_CONFIG_ERROR");
+        assert!(error.message.contains("Unix socket not found"));
+
+        let params = RequestParams {
+            url: "http://api.runi.test/get".to_string(),
+            resolve: vec![ResolveOverride {
+                host: "api.runi.test".to_string(),
+                port: None,
+                addresses: vec!["backend-2".to_string()],
+            }],
+            ..Default::default()
+        };
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "ROUTING_CONFIG_ERROR");
+    }
+
+    // ── TLS ──────────────────────────────────────────────────────────────
+
+    fn tls_fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/tls/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    /// Start a TLS server on localhost using the fixture certificates.
+    ///
+    /// With `require_client_cert`, clients must present a certificate signed by
+    /// the test CA. `versions` restricts the protocol versions the server accepts.
+    /// Each connection is answered with `client-cert: present|absent`; failed
+    /// handshakes are dropped.
+    fn start_tls_test_server(
+        expected_connections: usize,
+        require_client_cert: bool,
+        versions: &[&'static rustls::SupportedProtocolVersion],
+    ) -> Option<TestServer> {
+        use rustls::pki_types::pem::PemObject;
+        use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let certs = CertificateDer::pem_file_iter(tls_fixture("server.pem"))
+            .expect("open server cert")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("parse server cert");
+        let key = PrivateKeyDer::from_pem_file(tls_fixture("server.key")).expect("server key");
+        let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(versions)
+            .expect("protocol versions");
+        let builder = if require_client_cert {
+            let mut roots = rustls::RootCertStore::empty();
+            roots
+                .add(CertificateDer::from_pem_file(tls_fixture("ca.pem")).expect("CA cert"))
+                .expect("add CA cert");
+            let verifier =
+                rustls::server::WebPkiClientVerifier::builder_with_provider(roots.into(), provider)
+                    .build()
+                    .expect("client verifier");
+            builder.with_client_cert_verifier(verifier)
+        } else {
+            builder.with_no_client_auth()
+        };
+        let config = Arc::new(builder.with_single_cert(certs, key).expect("server config"));
+
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                eprintln!("[TEST] Skipping TLS server bind: {err}");
+                return None;
+            }
+            Err(err) => panic!("bind TLS test server: {err}"),
+        };
+        let port = listener
+            .local_addr()
+            .expect("resolve TLS server addr")
+            .port();
+
+        thread::spawn(move || {
+            for _ in 0..expected_connections {
+                let (tcp, _) = listener.accept().expect("accept TLS connection");
+                let _ = tcp.set_read_timeout(Some(Duration::from_secs(2)));
+                let conn = rustls::ServerConnection::new(config.clone()).expect("TLS session");
+                let mut tls = rustls::StreamOwned::new(conn, tcp);
+
+                let mut buffer = Vec::new();
+                let mut temp = [0u8; 1024];
+                while find_header_end(&buffer).is_none() {
+                    match tls.read(&mut temp) {
+                        Ok(read) if read > 0 => buffer.extend_from_slice(&temp[..read]),
+                        _ => break,
+                    }
+                }
+                if find_header_end(&buffer).is_none() {
+                    continue; // handshake failed or client hung up
+                }
+
+                let client_cert = if tls.conn.peer_certificates().is_some() {
+                    "present"
+                } else {
+                    "absent"
+                };
+                let body = format!("client-cert: {client_cert}");
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = tls.write_all(response.as_bytes());
+                tls.conn.send_close_notify();
+                let _ = tls.flush();
+            }
+        });
+
+        Some(TestServer {
+            base_url: format!("https://localhost:{port}"),
+        })
+    }
+
+    fn tls_request(server: &TestServer, tls: TlsConfig) -> RequestParams {
+        RequestParams {
+            url: format!("{}/tls", server.base_url),
+            timeout_ms: 10000,
+            tls: Some(tls),
+            ..Default::default()
+        }
+    }
+
+    fn parse_app_error(error: &str) -> AppError {
+        serde_json::from_str(error).expect("AppError JSON")
+    }
+
+    #[tokio::test]
+    async fn test_mtls_with_pem_client_certificate() {
+        let Some(server) = start_tls_test_server(1, true, rustls::ALL_VERSIONS) else {
+            return;
+        };
+        let params = tls_request(
+            &server,
+            TlsConfig {
+                client_cert: Some(tls_fixture("client.pem")),
+                client_key: Some(tls_fixture("client.key")),
+                ca_bundle: Some(tls_fixture("ca.pem")),
+                ..Default::default()
+            },
+        );
+
+        let response = execute_request(params, None).await.expect("mTLS request");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "client-cert: present");
+    }
+
+    #[tokio::test]
+    async fn test_http2_not_offered_by_server_is_an_error() {
+        if !curl::Version::get().feature_http2() {
+            return;
+        }
+        // The test server only speaks HTTP/1.1 (no ALPN), which curl would accept
+        let Some(server) = start_tls_test_server(1, false, rustls::ALL_VERSIONS) else {
+            return;
+        };
+        let mut params = tls_request(
+            &server,
+            TlsConfig {
+                ca_bundle: Some(tls_fixture("ca.pem")),
+                ..Default::default()
+            },
+        );
+        params.http_version = HttpVersion::Http2;
+
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "HTTP_VERSION_NOT_NEGOTIATED");
+        assert!(error.message.contains("HTTP/1.1"), "{}", error.message);
+        assert_eq!(error.details.unwrap()["http_version"], "http2");
+    }
+
+    #[tokio::test]
+    async fn test_https_response_reports_certificate_chain() {
+        use rustls::pki_types::CertificateDer;
+        use rustls::pki_types::pem::PemObject;
+        use sha2::{Digest, Sha256};
+
+        let Some(server) = start_tls_test_server(1, false, rustls::ALL_VERSIONS) else {
+            return;
+        };
+        let params = tls_request(
+            &server,
+            TlsConfig {
+                ca_bundle: Some(tls_fixture("ca.pem")),
+                ..Default::default()
+            },
+        );
+
+        let response = execute_request(params, None).await.expect("TLS request");
+
+        let chain = response.certificates.expect("certificate chain");
+        assert!(chain.issues.is_empty(), "{:?}", chain.issues);
+        let leaf = &chain.certificates[0];
+        assert_eq!(leaf.common_name(), Some("localhost"));
+        assert_eq!(leaf.issuer, "CN = runi test CA");
+        assert_eq!(
+            leaf.subject_alt_names,
+            vec!["DNS:localhost", "IP Address:127.0.0.1"]
+        );
+        assert!(leaf.days_until_expiry.unwrap() > 365);
+        let der = CertificateDer::from_pem_file(tls_fixture("server.pem")).expect("server cert");
+        let expected = hex::encode_upper(Sha256::digest(der.as_ref()));
+        assert_eq!(
+            leaf.sha256_fingerprint
+                .as_deref()
+                .map(|f| f.replace(':', "")),
+            Some(expected)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plain_http_response_has_no_certificates() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let Some(server) = start_http_test_server(1, requests) else {
+            return;
+        };
+        let params = RequestParams {
+            url: format!("{}/get", server.base_url),
+            timeout_ms: 10000,
+            ..Default::default()
+        };
+
+        let response = execute_request(params, None)
+            .await
+            .expect("request succeeds");
+        assert!(response.certificates.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_mtls_with_pkcs12_client_certificate() {
+        let Some(server) = start_tls_test_server(1, true, rustls::ALL_VERSIONS) else {
+            return;
+        };
+        let params = tls_request(
+            &server,
+            TlsConfig {
+                client_cert: Some(tls_fixture("client.p12")),
+                client_key_password: Some("runi-test".to_string()),
+                ca_bundle: Some(tls_fixture("ca.pem")),
+                ..Default::default()
+            },
+        );
+
+        let response = execute_request(params, None).await.expect("mTLS request");
+        assert_eq!(response.body, "client-cert: present");
+    }
+
+    #[tokio::test]
+    async fn test_mtls_without_client_certificate_fails() {
+        let Some(server) = start_tls_test_server(1, true, rustls::ALL_VERSIONS) else {
+            return;
+        };
+        let params = tls_request(
+            &server,
+            TlsConfig {
+                ca_bundle: Some(tls_fixture("ca.pem")),
+                ..Default::default()
+            },
+        );
+
+        let error = execute_request(params, None).await.unwrap_err();
+        assert_eq!(parse_app_error(&error).code, "HTTP_REQUEST_FAILED");
+    }
+
+    #[tokio::test]
+    async fn test_private_ca_requires_ca_bundle_or_skip_verify() {
+        let Some(server) = start_tls_test_server(2, false, rustls::ALL_VERSIONS) else {
+            return;
+        };
+
+        // The test CA is not in the system store.
+        let untrusted = tls_request(&server, TlsConfig::default());
+        assert!(execute_request(untrusted, None).await.is_err());
+
+        let insecure = tls_request(
+            &server,
+            TlsConfig {
+                insecure_skip_verify: true,
+                ..Default::default()
+            },
+        );
+        let response = execute_request(insecure, None)
+            .await
+            .expect("request with verification disabled");
+        assert_eq!(response.body, "client-cert: absent");
+    }
+
+    #[tokio::test]
+    async fn test_min_tls_version_rejects_older_server() {
+        let Some(server) = start_tls_test_server(2, false, &[&rustls::version::TLS12]) else {
+            return;
+        };
+        let with_min = |version| {
+            tls_request(
+                &server,
+                TlsConfig {
+                    ca_bundle: Some(tls_fixture("ca.pem")),
+                    min_tls_version: Some(version),
+                    ..Default::default()
+                },
+            )
+        };
+
+        let response = execute_request(with_min(TlsVersion::Tls1_2), None)
+            .await
+            .expect("TLS 1.2 allowed");
+        assert_eq!(response.status, 200);
+
+        assert!(
+            execute_request(with_min(TlsVersion::Tls1_3), None)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_tls_file_is_config_error() {
+        let params = RequestParams {
+            url: "https://localhost:1/".to_string(),
+            tls: Some(TlsConfig {
+                client_cert: Some(tls_fixture("missing.pem")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let error = parse_app_error(&execute_request(params, None).await.unwrap_err());
+        assert_eq!(error.code, "TLS_CONFIG_ERROR");
+        assert!(error.message.contains("client certificate not found"));
+    }
 }
@@ -0,0 +1,986 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Hurl export — writes collections out as `.hurl` suites for CI.
+//!
+//! Each file holds one entry per request, in run order, with the request's
+//! method, URL, query parameters, headers, auth and body, and its assertions
+//! and extraction rules as Hurl asserts and captures. Requests without
+//! assertions are judged the way the collection runner judges them: any
+//! status below 400 passes.
+//!
+//! runi's `{{name}}` placeholders are Hurl templates too, so they stay in
+//! place. Their values — the collection's variables merged with an
+//! environment's — go to a `<collection>.env` file next to the suites, for
+//! `hurl --test --variables-file`. Whatever Hurl cannot express (WebSocket
+//! requests, schema assertions, ...) is reported as a warning and, inside an
+//! entry, left as a comment.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(test)]
+use ts_rs::TS;
+
+use crate::application::request_resolver::{AuthPlacement, auth_placement};
+use crate::domain::assertion::{Assertion, JsonType};
+use crate::domain::collection::{
+    AuthConfig, BodyType, Collection, CollectionRequest, RequestBody, RequestKind,
+};
+use crate::domain::extraction::{ExtractionRule, ExtractionSource, compile_regex};
+use crate::domain::variables::TemplateResolver;
+
+/// How exported requests are split into files.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum HurlExportGrouping {
+    /// One file for the whole collection.
+    #[default]
+    Collection,
+    /// One file per tag, with every request carrying that tag; untagged
+    /// requests go to `<collection>-untagged.hurl`.
+    Tag,
+}
+
+/// Options of a Hurl export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct HurlExportOptions {
+    /// How requests are split into files (default: one per collection).
+    #[serde(default)]
+    #[cfg_attr(test, ts(as = "Option<HurlExportGrouping>", optional))]
+    pub group_by: HurlExportGrouping,
+    /// Environment whose variables are exported (default: the active one).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub environment: Option<String>,
+}
+
+/// A `.hurl` file written by an export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct HurlExportFile {
+    /// Path of the file.
+    pub path: String,
+    /// Number of requests in the file.
+    pub request_count: u32,
+}
+
+/// What a Hurl export wrote.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(TS))]
+#[cfg_attr(test, ts(export))]
+pub struct HurlExport {
+    /// The `.hurl` files, one per collection or tag.
+    pub files: Vec<HurlExportFile>,
+    /// Path of the variables file, when there are variables to export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(test, ts(optional))]
+    pub variables_file: Option<String>,
+    /// What could not be carried over to Hurl.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(test, ts(as = "Option<Vec<String>>", optional))]
+    pub warnings: Vec<String>,
+}
+
+/// Export `collection` to `.hurl` files in `out_dir`, creating it if needed.
+///
+/// # Errors
+///
+/// Returns an error if `options` names an environment the collection does not
+/// have, or a file cannot be written.
+pub fn export_collection_to_hurl(
+    collection: &Collection,
+    out_dir: &Path,
+    options: &HurlExportOptions,
+) -> Result<HurlExport, String> {
+    let mut renderer = Renderer::new(collection);
+    let stem = file_stem(&collection.metadata.name, &collection.id);
+    let requests: Vec<&CollectionRequest> = collection
+        .sorted_requests()
+        .into_iter()
+        .filter(|request| {
+            let http = request.kind == RequestKind::Http;
+            if !http {
+                renderer.request.clone_from(&request.name);
+                renderer.warn("Hurl has no WebSocket support; not exported");
+            }
+            http
+        })
+        .collect();
+    let groups = match options.group_by {
+        HurlExportGrouping::Collection => vec![(stem.clone(), requests)],
+        HurlExportGrouping::Tag => group_by_tag(&stem, &requests),
+    };
+
+    std::fs::create_dir_all(out_dir)
+        .map_err(|e| format!("Failed to create {}: {e}", out_dir.display()))?;
+    let variables_name = format!("{stem}.env");
+    let mut files = Vec::new();
+    let mut suites = Vec::new();
+    for (name, requests) in groups {
+        let path = out_dir.join(format!("{name}.hurl"));
+        suites.push((path.clone(), renderer.suite(&requests)));
+        files.push(HurlExportFile {
+            path: path.display().to_string(),
+            request_count: u32::try_from(requests.len()).unwrap_or(u32::MAX),
+        });
+    }
+    // Placeholder fallbacks are only known once every suite is rendered
+    let variables = renderer.variables_file(options.environment.as_deref())?;
+    let variables_path = out_dir.join(&variables_name);
+    for (path, mut suite) in suites {
+        if !variables.is_empty() {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            suite.insert_str(
+                0,
+                &format!("# hurl --test --variables-file {variables_name} {file_name}\n"),
+            );
+        }
+        write_file(&path, &suite)?;
+    }
+    if !variables.is_empty() {
+        write_file(&variables_path, &variables)?;
+    }
+    Ok(HurlExport {
+        files,
+        variables_file: (!variables.is_empty()).then(|| variables_path.display().to_string()),
+        warnings: renderer.warnings,
+    })
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+/// Requests per tag, in tag order, then the untagged ones.
+fn group_by_tag<'a>(
+    stem: &str,
+    requests: &[&'a CollectionRequest],
+) -> Vec<(String, Vec<&'a CollectionRequest>)> {
+    let tags: BTreeSet<&str> = requests
+        .iter()
+        .flat_map(|request| request.tags.iter().map(String::as_str))
+        .collect();
+    let mut groups: Vec<_> = tags
+        .into_iter()
+        .map(|tag| {
+            let tagged = requests
+                .iter()
+                .copied()
+                .filter(|request| request.tags.iter().any(|t| t == tag))
+                .collect();
+            (format!("{stem}-{}", file_stem(tag, "tag")), tagged)
+        })
+        .collect();
+    let untagged: Vec<_> = requests
+        .iter()
+        .copied()
+        .filter(|request| request.tags.is_empty())
+        .collect();
+    if !untagged.is_empty() {
+        groups.push((format!("{stem}-untagged"), untagged));
+    }
+    groups
+}
+
+/// Lowercase `name` with runs of other characters turned into `-`, or
+/// `fallback` when nothing is left.
+fn file_stem(name: &str, fallback: &str) -> String {
+    let stem = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if stem.is_empty() {
+        fallback.to_string()
+    } else {
+        stem
+    }
+}
+
+/// Renders a collection's requests as Hurl entries, collecting warnings.
+struct Renderer<'a> {
+    collection: &'a Collection,
+    /// Name of the request being rendered, for warnings.
+    request: String,
+    /// Fallbacks of `{{name:-fallback}}` placeholders, exported as the values
+    /// of variables that have none.
+    defaults: BTreeMap<String, String>,
+    warnings: Vec<String>,
+}
+
+impl<'a> Renderer<'a> {
+    const fn new(collection: &'a Collection) -> Self {
+        Self {
+            collection,
+            request: String::new(),
+            defaults: BTreeMap::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn warn(&mut self, message: impl std::fmt::Display) {
+        let warning = format!("{}: {message}", self.request);
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// A `.hurl` file with one entry per request.
+    fn suite(&mut self, requests: &[&CollectionRequest]) -> String {
+        requests
+            .iter()
+            .map(|request| self.entry(request))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The variables file: the collection's variables merged with the
+    /// environment's, with references between them resolved, since Hurl
+    /// variables hold plain values.
+    fn variables_file(&mut self, environment: Option<&str>) -> Result<String, String> {
+        let mut vars = self.collection.variables.clone();
+        let env = match environment {
+            Some(name) => Some(
+                self.collection
+                    .environments
+                    .iter()
+                    .find(|env| env.name == name)
+                    .ok_or_else(|| {
+                        format!(
+                            "Collection {} has no environment {name}",
+                            self.collection.metadata.name
+                        )
+                    })?,
+            ),
+            None => self.collection.active_env(),
+        };
+        if let Some(env) = env {
+            vars.extend(env.variables.clone());
+        }
+        let resolver = TemplateResolver::new(&vars);
+        let mut values: BTreeMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.clone(), resolver.resolve(value)))
+            .collect();
+        for (name, fallback) in &self.defaults {
+            values
+                .entry(name.clone())
+                .or_insert_with(|| fallback.clone());
+        }
+        self.request = "Variables".to_string();
+        let mut file = String::new();
+        for (name, value) in values {
+            if value.contains('\n') {
+                self.warn(format!("{name} spans several lines; not exported"));
+            } else {
+                let _ = writeln!(file, "{name}={value}");
+            }
+        }
+        Ok(file)
+    }
+
+    /// One Hurl entry: a request and the checks on its response.
+    fn entry(&mut self, request: &CollectionRequest) -> String {
+        self.request.clone_from(&request.name);
+        let mut out = format!("# {}\n", request.name.replace('\n', " "));
+        let mut query: Vec<(String, String)> = request
+            .params
+            .iter()
+            .filter(|param| param.enabled)
+            .map(|param| (self.template(&param.key), self.template(&param.value)))
+            .collect();
+        let mut headers: Vec<(String, String)> = request
+            .headers
+            .iter()
+            .map(|(name, value)| (self.template(name), self.template(value)))
+            .collect();
+        let mut sections = String::new();
+        if let Some(auth) = request.auth.as_ref().or(self.collection.auth.as_ref()) {
+            self.auth(auth, &mut headers, &mut query, &mut sections);
+        }
+        let body = request.body.as_ref().and_then(|body| {
+            self.body(
+                &request.method,
+                body,
+                &mut headers,
+                &mut query,
+                &mut sections,
+            )
+        });
+
+        let _ = writeln!(
+            out,
+            "{} {}",
+            request.method,
+            value(&self.template(&request.url))
+        );
+        for (name, header) in &headers {
+            let _ = writeln!(out, "{}: {}", key(name), value(header));
+        }
+        if !query.is_empty() {
+            out.push_str("[Query]\n");
+            for (name, param) in &query {
+                let _ = writeln!(out, "{}: {}", key(name), value(param));
+            }
+        }
+        out.push_str(&sections);
+        // runi follows redirects unless the request opts out; Hurl only does when asked
+        if request.follow_redirects.unwrap_or(true) {
+            out.push_str("[Options]\nlocation: true\n");
+        }
+        if let Some(body) = body {
+            out.push_str(&body);
+        }
+        out.push('\n');
+        self.response(request, &mut out);
+        out
+    }
+
+    /// Render `auth` the way the request resolver sends it. A header the
+    /// request sets explicitly wins over the one auth would add.
+    fn auth(
+        &mut self,
+        auth: &AuthConfig,
+        headers: &mut Vec<(String, String)>,
+        query: &mut Vec<(String, String)>,
+        sections: &mut String,
+    ) {
+        let placement = auth_placement(auth, |value| {
+            (!value.is_empty()).then(|| self.template(value))
+        });
+        match placement {
+            Some(AuthPlacement::Query(name, key)) => query.push((name, key)),
+            Some(AuthPlacement::Header(name, header)) if !has_header(headers, &name) => {
+                headers.push((name, header));
+            }
+            Some(AuthPlacement::Basic { username, password })
+                if !has_header(headers, "Authorization") =>
+            {
+                let _ = writeln!(
+                    sections,
+                    "[BasicAuth]\n{}: {}",
+                    key(&username),
+                    value(&password)
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// The body of a request, or its `[Multipart]` section (added to
+    /// `sections`), or the query parameters of a GraphQL GET.
+    fn body(
+        &mut self,
+        method: &str,
+        body: &RequestBody,
+        headers: &mut Vec<(String, String)>,
+        query: &mut Vec<(String, String)>,
+        sections: &mut String,
+    ) -> Option<String> {
+        if let Some(graphql) = body.graphql_query() {
+            if method.eq_ignore_ascii_case("GET") {
+                query.push(("query".to_string(), self.template(&graphql.query)));
+                if let Some(variables) = &graphql.variables {
+                    query.push((
+                        "variables".to_string(),
+                        self.template(&variables.to_string()),
+                    ));
+                }
+                if let Some(name) = &graphql.operation_name {
+                    query.push(("operationName".to_string(), name.clone()));
+                }
+                return None;
+            }
+            if !has_header(headers, "Content-Type") {
+                headers.push(("Content-Type".to_string(), "application/json".to_string()));
+            }
+            return Some(self.string_body(&graphql.to_json_body()));
+        }
+        if body.body_type == BodyType::Multipart {
+            sections.push_str("[Multipart]\n");
+            for part in &body.parts {
+                let name = key(&self.template(&part.name));
+                if part.filename.is_some() {
+                    self.warn(format!(
+                        "Hurl sends multipart part {} under its file's own name",
+                        part.name
+                    ));
+                }
+                if let Some(file) = &part.file {
+                    let content_type = part
+                        .content_type
+                        .as_ref()
+                        .map(|content_type| format!(" {content_type}"))
+                        .unwrap_or_default();
+                    let file = value(&self.template(file));
+                    let _ = writeln!(sections, "{name}: file,{file};{content_type}");
+                } else {
+                    let text = value(&self.template(part.value.as_deref().unwrap_or_default()));
+                    let _ = writeln!(sections, "{name}: {text}");
+                }
+            }
+            return None;
+        }
+        if let Some(content) = &body.content {
+            return Some(self.string_body(content));
+        }
+        let file = body.file.as_deref()?;
+        Some(format!("file,{};\n", value(&self.template(file))))
+    }
+
+    /// A text body: a oneline string when it fits on one line, a multiline
+    /// string otherwise.
+    fn string_body(&mut self, content: &str) -> String {
+        let content = self.template(content);
+        if !content.contains('\n') {
+            return format!("`{}`\n", content.replace('\\', "\\\\").replace('`', "\\`"));
+        }
+        if content.contains("```") {
+            self.warn("Body contains ``` and breaks the Hurl multiline string");
+        }
+        let newline = if content.ends_with('\n') { "" } else { "\n" };
+        format!("```\n{content}{newline}```\n")
+    }
+
+    /// The response section: expected status, captures and asserts.
+    fn response(&mut self, request: &CollectionRequest, out: &mut String) {
+        let mut status = "*".to_string();
+        let mut asserts = Vec::new();
+        for assertion in &request.assertions {
+            match assertion {
+                Assertion::StatusEquals { status: expected } if status == "*" => {
+                    status = expected.to_string();
+                }
+                _ => asserts.extend(self.assertion(assertion)),
+            }
+        }
+        if request.assertions.is_empty() {
+            // What the collection runner judges by when there are no assertions
+            asserts.push("status < 400".to_string());
+        }
+        let captures: Vec<String> = request
+            .extract
+            .iter()
+            .filter_map(|rule| self.capture(rule))
+            .collect();
+
+        let _ = writeln!(out, "HTTP {status}");
+        if !captures.is_empty() {
+            out.push_str("[Captures]\n");
+            for capture in captures {
+                let _ = writeln!(out, "{capture}");
+            }
+        }
+        out.push_str("[Asserts]\n");
+        for assert in asserts {
+            let _ = writeln!(out, "{assert}");
+        }
+    }
+
+    /// Hurl asserts for `assertion`, or a comment when Hurl has no equivalent.
+    fn assertion(&mut self, assertion: &Assertion) -> Vec<String> {
+        let one = |assert: String| vec![assert];
+        let unsupported = match assertion {
+            Assertion::StatusEquals { status } => return one(format!("status == {status}")),
+            Assertion::StatusInRange { min, max } => {
+                return vec![format!("status >= {min}"), format!("status <= {max}")];
+            }
+            Assertion::HeaderExists { name } => {
+                return one(format!("header {} exists", quoted(name)));
+            }
+            Assertion::HeaderMatches { name, pattern } => {
+                return one(format!(
+                    "header {} matches {}",
+                    quoted(name),
+                    regex_literal(pattern)
+                ));
+            }
+            Assertion::JsonPathEquals { path, value } => match json_literal(value) {
+                Some(literal) => return one(format!("jsonpath {} == {literal}", quoted(path))),
+                None => "Hurl cannot compare with arrays or objects",
+            },
+            Assertion::JsonPathContains { path, value } => match (value, json_literal(value)) {
+                (Value::String(text), _) => {
+                    return one(format!(
+                        "jsonpath {} contains {}",
+                        quoted(path),
+                        quoted(text)
+                    ));
+                }
+                (_, Some(literal)) => {
+                    return one(format!("jsonpath {} includes {literal}", quoted(path)));
+                }
+                (_, None) => "Hurl cannot look for arrays or objects",
+            },
+            Assertion::JsonPathType { path, json_type } => {
+                let predicate = match json_type {
+                    JsonType::String => "isString",
+                    JsonType::Number => "isNumber",
+                    JsonType::Boolean => "isBoolean",
+                    JsonType::Array => "isList",
+                    JsonType::Object => "isObject",
+                    JsonType::Null => "== null",
+                };
+                return one(format!("jsonpath {} {predicate}", quoted(path)));
+            }
+            Assertion::BodyMatches { pattern } => {
+                return one(format!("body matches {}", regex_literal(pattern)));
+            }
+            Assertion::ResponseTimeBelow { max_ms } => return one(format!("duration < {max_ms}")),
+            Assertion::MatchesSchema { .. } => "Hurl has no JSON Schema validation",
+        };
+        let expectation = assertion.expectation().replace('\n', " ");
+        self.warn(format!(
+            "expected {expectation}: {unsupported}; not exported"
+        ));
+        one(format!("# Not exported: expected {expectation}"))
+    }
+
+    /// The Hurl capture for `rule`, if Hurl can express it.
+    fn capture(&mut self, rule: &ExtractionRule) -> Option<String> {
+        let query = match &rule.source {
+            ExtractionSource::JsonPath { path } => format!("jsonpath {}", quoted(path)),
+            ExtractionSource::Header { name } => format!("header {}", quoted(name)),
+            ExtractionSource::Status => "status".to_string(),
+            ExtractionSource::Regex { pattern, group } => {
+                // Hurl captures the first group of a regex
+                let groups = compile_regex(pattern).map_or(0, |regex| regex.captures_len() - 1);
+                match group.unwrap_or_else(|| usize::from(groups > 0)) {
+                    0 => format!("regex {}", regex_literal(&format!("({pattern})"))),
+                    1 => format!("regex {}", regex_literal(pattern)),
+                    group => {
+                        self.warn(format!(
+                            "Hurl only captures the first regex group, not group {group}; {} not captured",
+                            rule.variable
+                        ));
+                        return None;
+                    }
+                }
+            }
+        };
+        Some(format!("{}: {query}", key(&rule.variable)))
+    }
+
+    /// Rewrite the placeholders of `text` as Hurl templates.
+    fn template(&mut self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            result.push_str(&rest[..start]);
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find("}}") else {
+                result.push_str("{{");
+                continue;
+            };
+            let variable = self.variable(rest[..end].trim());
+            rest = &rest[end + 2..];
+            let _ = write!(result, "{{{{{variable}}}}}");
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// The Hurl variable or function standing in for a placeholder.
+    fn variable(&mut self, placeholder: &str) -> String {
+        let (name, fallback) = placeholder
+            .split_once(":-")
+            .map_or((placeholder, None), |(name, fallback)| {
+                (name.trim_end(), Some(fallback))
+            });
+        let Some(dynamic) = name.strip_prefix('$') else {
+            if let Some(fallback) = fallback {
+                self.defaults
+                    .entry(name.to_string())
+                    .or_insert_with(|| fallback.to_string());
+            }
+            return name.to_string();
+        };
+        match dynamic {
+            "uuid" => "newUuid".to_string(),
+            "isoDate" => "newDate".to_string(),
+            _ => {
+                let variable = dynamic.strip_prefix("env.").unwrap_or(dynamic);
+                self.warn(format!(
+                    "{{{{{name}}}}} has no Hurl equivalent; pass {variable} with --variable"
+                ));
+                variable.to_string()
+            }
+        }
+    }
+}
+
+fn has_header(headers: &[(String, String)], name: &str) -> bool {
+    headers
+        .iter()
+        .any(|(header, _)| header.eq_ignore_ascii_case(name))
+}
+
+/// Escape `text` as the value of a Hurl line (after a method, header name or
+/// key), where `#` starts a comment.
+fn value(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('#', "\\#")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// Escape `text` as a Hurl key, which also ends at `:`.
+fn key(text: &str) -> String {
+    value(text).replace(':', "\\:")
+}
+
+/// `text` as a quoted Hurl string.
+fn quoted(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{escaped}\"")
+}
+
+/// `pattern` as a Hurl regex literal, `/.../`.
+fn regex_literal(pattern: &str) -> String {
+    let mut literal = String::with_capacity(pattern.len() + 2);
+    literal.push('/');
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // Escapes pass through, including an already escaped `/`
+            '\\' => {
+                literal.push(c);
+                literal.extend(chars.next());
+            }
+            '/' => literal.push_str("\\/"),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('/');
+    literal
+}
+
+/// A JSON scalar as a Hurl predicate value; `None` for arrays and objects.
+fn json_literal(value: &Value) -> Option<String> {
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        Value::String(text) => Some(quoted(text)),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::collection::{AuthType, CollectionEnvironment, RequestParam};
+    use crate::domain::extraction::VariableScope;
+    use crate::domain::graphql::GraphqlQuery;
+    use crate::domain::http::MultipartPart;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn request(name: &str, method: &str, url: &str) -> CollectionRequest {
+        CollectionRequest {
+            id: format!("req_{name}"),
+            name: name.to_string(),
+            method: method.to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn render(collection: &Collection, request: &CollectionRequest) -> (String, Vec<String>) {
+        let mut renderer = Renderer::new(collection);
+        let entry = renderer.entry(request);
+        (entry, renderer.warnings)
+    }
+
+    #[test]
+    fn test_renders_request_with_auth_body_captures_and_asserts() {
+        let mut collection = Collection::new("Shop");
+        collection.auth = Some(AuthConfig {
+            auth_type: AuthType::Bearer,
+            token: Some("{{token}}".to_string()),
+            username: None,
+            password: None,
+            header: None,
+            query_param: None,
+        });
+        let mut order = request("Create order #1", "POST", "{{baseUrl}}/orders");
+        order.params = vec![
+            RequestParam {
+                key: "dry_run".to_string(),
+                value: "{{dryRun:-false}}".to_string(),
+                enabled: true,
+            },
+            RequestParam {
+                key: "debug".to_string(),
+                value: "1".to_string(),
+                enabled: false,
+            },
+        ];
+        order.headers = BTreeMap::from([("X-Request-Id".to_string(), "{{$uuid}}".to_string())]);
+        order.body = Some(RequestBody {
+            body_type: BodyType::Json,
+            content: Some(r#"{"sku": "A-1", "note": "`x`"}"#.to_string()),
+            file: None,
+            parts: vec![],
+            graphql: None,
+        });
+        order.extract = vec![
+            ExtractionRule {
+                variable: "orderId".to_string(),
+                source: ExtractionSource::JsonPath {
+                    path: "$.id".to_string(),
+                },
+                scope: VariableScope::Runtime,
+            },
+            ExtractionRule {
+                variable: "version".to_string(),
+                source: ExtractionSource::Regex {
+                    pattern: r"v\d+/".to_string(),
+                    group: None,
+                },
+                scope: VariableScope::Environment,
+            },
+        ];
+        order.assertions = vec![
+            Assertion::StatusEquals { status: 201 },
+            Assertion::HeaderMatches {
+                name: "Location".to_string(),
+                pattern: "^/orders/".to_string(),
+            },
+            Assertion::JsonPathEquals {
+                path: "$.sku".to_string(),
+                value: json!("A-1"),
+            },
+            Assertion::JsonPathContains {
+                path: "$.tags".to_string(),
+                value: json!(3),
+            },
+            Assertion::JsonPathType {
+                path: "$.items".to_string(),
+                json_type: JsonType::Array,
+            },
+            Assertion::ResponseTimeBelow { max_ms: 500 },
+        ];
+
+        let (entry, warnings) = render(&collection, &order);
+
+        assert_eq!(
+            entry,
+            "# Create order #1\n\
+             POST {{baseUrl}}/orders\n\
+             X-Request-Id: {{newUuid}}\n\
+             Authorization: Bearer {{token}}\n\
+             [Query]\n\
+             dry_run: {{dryRun}}\n\
+             [Options]\n\
+             location: true\n\
+             `{\"sku\": \"A-1\", \"note\": \"\\`x\\`\"}`\n\
+             \n\
+             HTTP 201\n\
+             [Captures]\n\
+             orderId: jsonpath \"$.id\"\n\
+             version: regex /(v\\d+\\/)/\n\
+             [Asserts]\n\
+             header \"Location\" matches /^\\/orders\\//\n\
+             jsonpath \"$.sku\" == \"A-1\"\n\
+             jsonpath \"$.tags\" includes 3\n\
+             jsonpath \"$.items\" isList\n\
+             duration < 500\n"
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn test_renders_other_bodies_and_auth() {
+        let collection = Collection::new("Shop");
+        let mut upload = request("upload", "PUT", "https://example.com/files");
+        upload.follow_redirects = Some(false);
+        upload.auth = Some(AuthConfig {
+            auth_type: AuthType::Basic,
+            token: None,
+            username: Some("{{user}}".to_string()),
+            password: Some("p#ss".to_string()),
+            header: None,
+            query_param: None,
+        });
+        upload.body = Some(RequestBody {
+            body_type: BodyType::Multipart,
+            content: None,
+            file: None,
+            parts: vec![
+                MultipartPart {
+                    name: "title".to_string(),
+                    value: Some("Q3 report".to_string()),
+                    file: None,
+                    filename: None,
+                    content_type: None,
+                },
+                MultipartPart {
+                    name: "file".to_string(),
+                    value: None,
+                    file: Some("reports/q3.pdf".to_string()),
+                    filename: None,
+                    content_type: Some("application/pdf".to_string()),
+                },
+            ],
+            graphql: None,
+        });
+
+        let (entry, _) = render(&collection, &upload);
+        assert!(
+            entry.contains(
+                "PUT https://example.com/files\n\
+                 [BasicAuth]\n\
+                 {{user}}: p\\#ss\n\
+                 [Multipart]\n\
+                 title: Q3 report\n\
+                 file: file,reports/q3.pdf; application/pdf\n"
+            ),
+            "{entry}"
+        );
+        assert!(!entry.contains("[Options]"), "{entry}");
+        assert!(
+            entry.ends_with("HTTP *\n[Asserts]\nstatus < 400\n"),
+            "{entry}"
+        );
+
+        let mut search = request("search", "GET", "{{baseUrl}}/graphql");
+        search.auth = Some(AuthConfig {
+            auth_type: AuthType::ApiKey,
+            token: Some("{{apiKey}}".to_string()),
+            username: None,
+            password: None,
+            header: None,
+            query_param: Some("key".to_string()),
+        });
+        search.body = Some(RequestBody {
+            body_type: BodyType::Graphql,
+            content: None,
+            file: None,
+            parts: vec![],
+            graphql: Some(GraphqlQuery {
+                query: "{ me { name } }".to_string(),
+                variables: None,
+                operation_name: None,
+            }),
+        });
+        search.assertions = vec![Assertion::MatchesSchema {
+            schema: json!({"type": "object"}),
+        }];
+
+        let (entry, warnings) = render(&collection, &search);
+        assert!(
+            entry.contains("[Query]\nkey: {{apiKey}}\nquery: { me { name } }\n[Options]"),
+            "{entry}"
+        );
+        assert!(
+            entry.ends_with(
+                "HTTP *\n[Asserts]\n# Not exported: expected body to match the schema\n"
+            ),
+            "{entry}"
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                "search: expected body to match the schema: Hurl has no JSON Schema validation; \
+                 not exported"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exports_one_file_per_tag_with_variables() {
+        let mut collection = Collection::new("Pet Store");
+        collection.variables = BTreeMap::from([
+            ("baseUrl".to_string(), "https://{{host}}/v1".to_string()),
+            ("host".to_string(), "example.com".to_string()),
+        ]);
+        collection.environments.push(CollectionEnvironment {
+            name: "staging".to_string(),
+            variables: BTreeMap::from([("host".to_string(), "staging.example.com".to_string())]),
+            ..Default::default()
+        });
+        let mut list = request("list", "GET", "{{baseUrl}}/pets?limit={{limit:-20}}");
+        list.tags = vec!["pets".to_string(), "smoke".to_string()];
+        let mut health = request("health", "GET", "{{baseUrl}}/health");
+        health.seq = 1;
+        let mut feed = request("feed", "GET", "wss://example.com/feed");
+        feed.kind = RequestKind::WebSocket;
+        collection.requests = vec![health, list, feed];
+        let dir = TempDir::new().unwrap();
+        let options = HurlExportOptions {
+            group_by: HurlExportGrouping::Tag,
+            environment: Some("staging".to_string()),
+        };
+
+        let export = export_collection_to_hurl(&collection, dir.path(), &options).unwrap();
+
+        let names: Vec<_> = export
+            .files
+            .iter()
+            .map(|file| {
+                let name = Path::new(&file.path).file_name().unwrap().to_string_lossy();
+                (name.to_string(), file.request_count)
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("pet-store-pets.hurl".to_string(), 1),
+                ("pet-store-smoke.hurl".to_string(), 1),
+                ("pet-store-untagged.hurl".to_string(), 1),
+            ]
+        );
+        let smoke = std::fs::read_to_string(dir.path().join("pet-store-smoke.hurl")).unwrap();
+        assert!(
+            smoke.starts_with(
+                "# hurl --test --variables-file pet-store.env pet-store-smoke.hurl\n\
+                 # list\n\
+                 GET {{baseUrl}}/pets?limit={{limit}}\n"
+            ),
+            "{smoke}"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("pet-store.env")).unwrap(),
+            "baseUrl=https://staging.example.com/v1\nhost=staging.example.com\nlimit=20\n"
+        );
+        assert_eq!(
+            export.warnings,
+            vec!["feed: Hurl has no WebSocket support; not exported".to_string()]
+        );
+
+        let missing = HurlExportOptions {
+            environment: Some("prod".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            export_collection_to_hurl(&collection, dir.path(), &missing),
+            Err("Collection Pet Store has no environment prod".to_string())
+        );
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert_eq!(value(r"a#b\c"), r"a\#b\\c");
+        assert_eq!(key("x:y"), r"x\:y");
+        assert_eq!(quoted("say \"hi\""), r#""say \"hi\"""#);
+        assert_eq!(regex_literal(r"a/b\/c\d"), r"/a\/b\/c\d/");
+        assert_eq!(file_stem("  Pet Store (v2) ", "col"), "pet-store-v2");
+        assert_eq!(file_stem("!!", "col"), "col");
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Implements the `TestRunner` domain port for the [Hurl](https://hurl.dev) CLI tool.
 //! Uses `std::process::Command` with explicit argument arrays — **never shell interpolation**.
+//! Also exports collections as `.hurl` suites for it to run.
 
+/// Export of collections to `.hurl` files.
+pub mod exporter;
 /// Hurl CLI runner implementation.
 pub mod runner;
 
@@ -54,6 +54,12 @@ impl FakeTransport {
     }
 }
 
+impl Default for FakeTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl Transport for FakeTransport {
     async fn send(&self, message: String) -> Result<(), String> {
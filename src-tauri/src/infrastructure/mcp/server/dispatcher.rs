@@ -5,24 +5,58 @@
 //!
 //! Routes incoming JSON-RPC requests to the appropriate handler based on method name.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
 
 use serde_json::json;
 use tauri::Emitter;
 use tokio::sync::RwLock;
 
+use crate::application::collection_runner::run_collection;
+use crate::application::graphql_service::introspect_schema;
+use crate::application::load_tester::run_load_test;
 use crate::application::mcp_server_service::McpServerService;
+use crate::application::mcp_server_service::PreparedExecution;
+use crate::domain::assertion::{AssertionResult, evaluate_assertions};
 #[cfg(test)]
 use crate::domain::canvas_state::CanvasStateSnapshot;
+use crate::domain::collection::RequestKind;
+use crate::domain::extraction::ExtractedVariable;
+use crate::domain::http::HttpResponse;
 use crate::domain::mcp::events::{Actor, EventEnvelope};
 use crate::domain::mcp::jsonrpc::{JsonRpcError, JsonRpcId, JsonRpcRequest, JsonRpcResponse};
 use crate::domain::mcp::protocol::{
     InitializeResult, ToolCallParams, ToolCallResult, ToolResponseContent, ToolsListResult,
 };
+use crate::domain::websocket::WebSocketSend;
+use crate::infrastructure::commands::{
+    COLLECTION_RUN_EVENT, HTTP_STREAM_EVENT, LOAD_TEST_EVENT, WEBSOCKET_EVENT, publish_events,
+    save_request_history,
+};
 use crate::infrastructure::commands::{
     CanvasStateHandle, DriftReviewStore, ProjectContextHandle, SuggestionServiceHandle,
 };
-use crate::infrastructure::http::execute_http_request;
+use crate::infrastructure::http::{
+    cancel_in_flight_request, execute_http_request, execute_streaming_http_request,
+};
+use crate::infrastructure::mcp::commands::SseBroadcasterHandle;
+use crate::infrastructure::storage::load_test_store::save_load_test_report;
+use crate::infrastructure::websocket::{
+    close_websocket, connect_websocket, is_websocket_open, send_websocket_message,
+    websocket_transcript,
+};
+
+/// Correlation IDs of running `execute_request` calls, keyed by JSON-RPC request ID.
+///
+/// Lets a `notifications/cancelled` from the client abort the HTTP transfer
+/// behind the tool call it cancels.
+static PENDING_EXECUTIONS: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Key for [`PENDING_EXECUTIONS`]; keeps numeric and string IDs distinct.
+fn pending_execution_key(id: &serde_json::Value) -> String {
+    id.to_string()
+}
 
 /// Dispatch a JSON-RPC request string to the appropriate handler.
 ///
@@ -51,7 +85,7 @@ pub async fn dispatch(
 
     // Notifications have no id and expect no response
     if request.is_notification() {
-        handle_notification(&request.method);
+        handle_notification(&request);
         return None;
     }
 
@@ -81,13 +115,27 @@ pub async fn dispatch(
     Some(serde_json::to_string(&response).unwrap_or_default())
 }
 
-fn handle_notification(method: &str) {
-    match method {
+fn handle_notification(request: &JsonRpcRequest) {
+    match request.method.as_str() {
         "notifications/initialized" => {
             tracing::info!("MCP client initialized");
         }
         "notifications/cancelled" => {
             tracing::info!("MCP client cancelled a request");
+            let correlation_id = request
+                .params
+                .as_ref()
+                .and_then(|params| params.get("requestId"))
+                .and_then(|request_id| {
+                    PENDING_EXECUTIONS
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .get(&pending_execution_key(request_id))
+                        .cloned()
+                });
+            if let Some(correlation_id) = correlation_id {
+                cancel_in_flight_request(&correlation_id);
+            }
         }
         other => {
             tracing::debug!("Unknown notification: {other}");
@@ -119,6 +167,7 @@ async fn handle_tools_list(
 }
 
 #[allow(clippy::too_many_arguments)] // All parameters required: MCP JSON-RPC bridge + multiple Tauri service handles
+#[allow(clippy::too_many_lines)] // One routing branch per dispatcher-handled tool
 async fn handle_tools_call(
     id: Option<JsonRpcId>,
     request: &JsonRpcRequest,
@@ -144,7 +193,10 @@ async fn handle_tools_call(
     // execute_request needs async HTTP — handle it specially to avoid
     // holding the RwLock read guard across an .await point.
     if params.name == "execute_request" {
-        return handle_execute_request(id, params.arguments, service).await;
+        return handle_execute_request(id, params.arguments, service, app_handle).await;
+    }
+    if params.name == "cancel_request" {
+        return handle_cancel_request(id, params.arguments);
     }
 
     // open_collection_request needs app_handle to emit Tauri event
@@ -168,6 +220,20 @@ async fn handle_tools_call(
     if params.name == "run_hurl_suite" {
         return handle_run_hurl_suite(id, params.arguments).await;
     }
+    if params.name == "run_collection" {
+        return handle_run_collection(id, params.arguments, service, app_handle).await;
+    }
+    if params.name == "run_load_test" {
+        return handle_run_load_test(id, params.arguments, service, app_handle).await;
+    }
+    if params.name == "graphql_introspect" {
+        return handle_graphql_introspect(id, params.arguments, service).await;
+    }
+    // WebSocket tools drive sessions that outlive the tool call
+    if params.name.starts_with("websocket_") {
+        return handle_websocket_tool(id, &params.name, params.arguments, service, app_handle)
+            .await;
+    }
     // Pinned spec version tools — async I/O (spec fetch/parse)
     if params.name == "pin_spec_version" {
         return handle_pin_spec_version(id, params.arguments, app_handle).await;
@@ -243,11 +309,57 @@ async fn handle_tools_call(
     }
 }
 
+/// Handle `cancel_request` tool — abort a running `execute_request` call.
+fn handle_cancel_request(
+    id: Option<JsonRpcId>,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+) -> JsonRpcResponse {
+    let args = arguments.unwrap_or_default();
+    let arg = |name: &str| args.get(name).and_then(serde_json::Value::as_str);
+
+    let correlation_id = match (
+        arg("correlation_id"),
+        arg("collection_id"),
+        arg("request_id"),
+    ) {
+        (Some(correlation_id), _, _) => correlation_id.to_string(),
+        (None, Some(collection_id), Some(request_id)) => {
+            format!("mcp-{collection_id}-{request_id}")
+        }
+        _ => {
+            let error_result = ToolCallResult {
+                content: vec![ToolResponseContent::Text {
+                    text: "Missing required parameter: correlation_id (or collection_id and request_id)"
+                        .to_string(),
+                }],
+                is_error: true,
+            };
+            return JsonRpcResponse::success(
+                id,
+                serde_json::to_value(error_result).unwrap_or_else(|_| json!({})),
+            );
+        }
+    };
+
+    let cancelled = cancel_in_flight_request(&correlation_id);
+    let result = ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: json!({ "correlation_id": correlation_id, "cancelled": cancelled }).to_string(),
+        }],
+        is_error: false,
+    };
+    JsonRpcResponse::success(
+        id,
+        serde_json::to_value(result).unwrap_or_else(|_| json!({})),
+    )
+}
+
 /// Handle `execute_request` tool — async HTTP execution outside the lock.
 async fn handle_execute_request(
     id: Option<JsonRpcId>,
     arguments: Option<serde_json::Map<String, serde_json::Value>>,
     service: &Arc<RwLock<McpServerService>>,
+    app_handle: Option<&tauri::AppHandle>,
 ) -> JsonRpcResponse {
     let args = arguments.unwrap_or_default();
 
@@ -255,9 +367,18 @@ async fn handle_execute_request(
     let prepare_result = {
         let svc = service.read().await;
         svc.prepare_execute_request(&args)
-    };
+    }
+    .and_then(|prepared| {
+        if prepared.kind == RequestKind::WebSocket {
+            return Err(format!(
+                "Request {} is a websocket request; open it with websocket_connect",
+                prepared.request_id
+            ));
+        }
+        Ok(prepared)
+    });
 
-    let (request_params, collection_id, request_id) = match prepare_result {
+    let prepared = match prepare_result {
         Ok(result) => result,
         Err(e) => {
             let error_result = ToolCallResult {
@@ -272,28 +393,41 @@ async fn handle_execute_request(
     };
 
     // Phase 2: Execute HTTP request without holding any lock.
-    let correlation_id = format!("mcp-{collection_id}-{request_id}");
-    match execute_http_request(request_params, Some(correlation_id)).await {
+    let correlation_id = args
+        .get("correlation_id")
+        .and_then(serde_json::Value::as_str)
+        .map_or_else(
+            || format!("mcp-{}-{}", prepared.collection_id, prepared.request_id),
+            ToString::to_string,
+        );
+    let pending_key = id
+        .as_ref()
+        .and_then(|id| serde_json::to_value(id).ok())
+        .map(|id| pending_execution_key(&id));
+    if let Some(key) = &pending_key {
+        PENDING_EXECUTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(key.clone(), correlation_id.clone());
+    }
+    let outcome = execute_prepared(&prepared, correlation_id, app_handle).await;
+    if let Some(key) = &pending_key {
+        PENDING_EXECUTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(key);
+    }
+    match outcome {
         Ok(response) => {
-            // Emit event for UI update
-            {
+            // Emit event for UI update, then store what the extraction rules capture
+            let extracted = {
                 let mut svc = service.write().await;
-                svc.emit_execute_event(&collection_id, &request_id, &response);
-            }
+                svc.emit_execute_event(&prepared.collection_id, &prepared.request_id, &response);
+                svc.apply_extractions(&prepared.collection_id, &prepared.request_id, &response)
+            };
 
-            let result_json = json!({
-                "status": response.status,
-                "status_text": response.status_text,
-                "headers": response.headers,
-                "body": response.body,
-                "timing": {
-                    "total_ms": response.timing.total_ms,
-                    "dns_ms": response.timing.dns_ms,
-                    "connect_ms": response.timing.connect_ms,
-                    "tls_ms": response.timing.tls_ms,
-                    "first_byte_ms": response.timing.first_byte_ms,
-                }
-            });
+            let assertions = evaluate_assertions(&prepared.assertions, &response);
+            let result_json = execute_result_json(&response, &extracted, &assertions);
 
             let result = ToolCallResult {
                 content: vec![ToolResponseContent::Text {
@@ -321,6 +455,82 @@ async fn handle_execute_request(
     }
 }
 
+/// Tool result of a completed `execute_request` call.
+fn execute_result_json(
+    response: &HttpResponse,
+    extracted: &[ExtractedVariable],
+    assertions: &[AssertionResult],
+) -> serde_json::Value {
+    let mut result_json = json!({
+        "status": response.status,
+        "status_text": response.status_text,
+        "headers": response.headers,
+        "body": response.body,
+        "body_kind": response.body_kind,
+        "body_size": response.body_size,
+        "timing": response.timing,
+    });
+    if let Some(connection) = &response.connection {
+        result_json["connection"] = serde_json::to_value(connection).unwrap_or_default();
+    }
+    if let Some(certificates) = &response.certificates {
+        result_json["certificates"] = serde_json::to_value(certificates).unwrap_or_default();
+    }
+    // Binary bodies are not decoded; hand the raw bytes back as base64
+    if let Some(body_base64) = &response.body_base64 {
+        result_json["body_base64"] = json!(body_base64);
+    }
+    if let Some(stream) = &response.stream {
+        result_json["stream"] = serde_json::to_value(stream).unwrap_or_default();
+    }
+    if !response.redirects.is_empty() {
+        result_json["redirects"] = serde_json::to_value(&response.redirects).unwrap_or_default();
+    }
+    if !response.attempts.is_empty() {
+        result_json["attempts"] = serde_json::to_value(&response.attempts).unwrap_or_default();
+    }
+    if let Some(trace) = &response.trace {
+        result_json["trace"] = json!(trace.to_text());
+    }
+    if !extracted.is_empty() {
+        result_json["extracted"] = serde_json::to_value(extracted).unwrap_or_default();
+    }
+    if !assertions.is_empty() {
+        result_json["assertions"] = serde_json::to_value(assertions).unwrap_or_default();
+    }
+    result_json
+}
+
+/// Run a prepared request, streaming it when the request is flagged `is_streaming`.
+///
+/// Streamed chunks are published live to the UI (`http:stream`) and to MCP
+/// SSE subscribers on the `stream:*` topics; the tool result carries the
+/// transcript once the stream closes, in place of the unbuffered body.
+async fn execute_prepared(
+    prepared: &PreparedExecution,
+    correlation_id: String,
+    app_handle: Option<&tauri::AppHandle>,
+) -> Result<HttpResponse, String> {
+    let params = prepared.params.clone();
+    if !prepared.is_streaming {
+        return execute_http_request(params, Some(correlation_id)).await;
+    }
+
+    let broadcaster = app_handle
+        .and_then(tauri::Manager::try_state::<SseBroadcasterHandle>)
+        .map(|state| state.inner().clone());
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        app_handle.cloned(),
+        broadcaster,
+        HTTP_STREAM_EVENT,
+        rx,
+    ));
+    let result = execute_streaming_http_request(params, correlation_id, tx).await;
+    let _ = publisher.await;
+    result
+}
+
 /// Handle `open_collection_request` tool — emit Tauri event with request data.
 async fn handle_open_collection_request(
     id: Option<JsonRpcId>,
@@ -700,6 +910,408 @@ async fn handle_run_hurl_suite(
     }
 }
 
+/// Handle `run_collection` tool — run a collection's requests outside the lock.
+///
+/// Progress is published live (see [`publish_events`]); the tool result
+/// is the run report as JSON, or as `JUnit` XML with `format: "junit"`.
+async fn handle_run_collection(
+    id: Option<JsonRpcId>,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    service: &Arc<RwLock<McpServerService>>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> JsonRpcResponse {
+    let args = arguments.unwrap_or_default();
+    let prepare_result = {
+        let svc = service.read().await;
+        svc.prepare_run_collection(&args)
+    };
+    let prepared = match prepare_result {
+        Ok(prepared) => prepared,
+        Err(e) => {
+            let error_result = ToolCallResult {
+                content: vec![ToolResponseContent::Text { text: e }],
+                is_error: true,
+            };
+            return JsonRpcResponse::success(
+                id,
+                serde_json::to_value(error_result).unwrap_or_else(|_| json!({})),
+            );
+        }
+    };
+    let run_id = args
+        .get("run_id")
+        .and_then(serde_json::Value::as_str)
+        .map_or_else(
+            || format!("run-{}", uuid::Uuid::now_v7()),
+            ToString::to_string,
+        );
+
+    let broadcaster = app_handle
+        .and_then(tauri::Manager::try_state::<SseBroadcasterHandle>)
+        .map(|state| state.inner().clone());
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        app_handle.cloned(),
+        broadcaster,
+        COLLECTION_RUN_EVENT,
+        rx,
+    ));
+    let report = run_collection(
+        &prepared.collection,
+        &prepared.dir,
+        &prepared.options,
+        &run_id,
+        &tx,
+    )
+    .await;
+    drop(tx);
+    let _ = publisher.await;
+
+    let text = if args.get("format").and_then(serde_json::Value::as_str) == Some("junit") {
+        report.to_junit_xml()
+    } else {
+        serde_json::to_string(&report).unwrap_or_else(|_| "{}".to_string())
+    };
+    let result = ToolCallResult {
+        content: vec![ToolResponseContent::Text { text }],
+        is_error: false,
+    };
+    JsonRpcResponse::success(
+        id,
+        serde_json::to_value(result).unwrap_or_else(|_| json!({})),
+    )
+}
+
+/// Handle `run_load_test` tool — load test a collection request outside the lock.
+///
+/// Progress is published live (see [`publish_events`]); the tool
+/// result is the load test report as JSON, which is also saved as a run artifact.
+async fn handle_run_load_test(
+    id: Option<JsonRpcId>,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    service: &Arc<RwLock<McpServerService>>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> JsonRpcResponse {
+    let error_response = |id, text| {
+        let error_result = ToolCallResult {
+            content: vec![ToolResponseContent::Text { text }],
+            is_error: true,
+        };
+        JsonRpcResponse::success(
+            id,
+            serde_json::to_value(error_result).unwrap_or_else(|_| json!({})),
+        )
+    };
+    let args = arguments.unwrap_or_default();
+    let prepare_result = {
+        let svc = service.read().await;
+        svc.prepare_load_test(&args)
+    };
+    let prepared = match prepare_result {
+        Ok(prepared) => prepared,
+        Err(e) => return error_response(id, e),
+    };
+    let Some(request) = prepared
+        .collection
+        .requests
+        .iter()
+        .find(|r| r.id == prepared.request_id)
+    else {
+        return error_response(id, format!("Request not found: {}", prepared.request_id));
+    };
+    let load_test_id = args
+        .get("load_test_id")
+        .and_then(serde_json::Value::as_str)
+        .map_or_else(
+            || format!("load-{}", uuid::Uuid::now_v7()),
+            ToString::to_string,
+        );
+
+    let broadcaster = app_handle
+        .and_then(tauri::Manager::try_state::<SseBroadcasterHandle>)
+        .map(|state| state.inner().clone());
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let publisher = tokio::spawn(publish_events(
+        app_handle.cloned(),
+        broadcaster,
+        LOAD_TEST_EVENT,
+        rx,
+    ));
+    let report = run_load_test(
+        &prepared.collection,
+        request,
+        &prepared.dir,
+        &prepared.options,
+        &load_test_id,
+        &tx,
+    )
+    .await;
+    drop(tx);
+    let _ = publisher.await;
+    let report = match report {
+        Ok(report) => report,
+        Err(e) => return error_response(id, e),
+    };
+    if let Err(e) = save_load_test_report(&report) {
+        tracing::warn!("Failed to save load test report: {e}");
+    }
+
+    let result = ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: serde_json::to_string(&report).unwrap_or_else(|_| "{}".to_string()),
+        }],
+        is_error: false,
+    };
+    JsonRpcResponse::success(
+        id,
+        serde_json::to_value(result).unwrap_or_else(|_| json!({})),
+    )
+}
+
+/// Handle `graphql_introspect` tool — introspect and cache a schema outside the lock.
+///
+/// The tool result summarizes the cached schema; `graphql_list_operations`
+/// lists its operations.
+async fn handle_graphql_introspect(
+    id: Option<JsonRpcId>,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    service: &Arc<RwLock<McpServerService>>,
+) -> JsonRpcResponse {
+    let args = arguments.unwrap_or_default();
+    let prepare_result = {
+        let svc = service.read().await;
+        svc.prepare_graphql_introspect(&args)
+    };
+    let result = match prepare_result {
+        Ok(prepared) => {
+            introspect_schema(&prepared.collection, &prepared.request, &prepared.dir).await
+        }
+        Err(e) => Err(e),
+    };
+    let tool_result = match result {
+        Ok(cache) => {
+            let operations = cache.schema.operations();
+            let count = |kind: &str| operations.iter().filter(|op| op.kind == kind).count();
+            ToolCallResult {
+                content: vec![ToolResponseContent::Text {
+                    text: json!({
+                        "endpoint": cache.endpoint,
+                        "fetched_at": cache.fetched_at,
+                        "types": cache.schema.types.len(),
+                        "queries": count("query"),
+                        "mutations": count("mutation"),
+                        "subscriptions": count("subscription"),
+                    })
+                    .to_string(),
+                }],
+                is_error: false,
+            }
+        }
+        Err(e) => ToolCallResult {
+            content: vec![ToolResponseContent::Text { text: e }],
+            is_error: true,
+        },
+    };
+    JsonRpcResponse::success(
+        id,
+        serde_json::to_value(tool_result).unwrap_or_else(|_| json!({})),
+    )
+}
+
+/// Handle WebSocket session tools.
+async fn handle_websocket_tool(
+    id: Option<JsonRpcId>,
+    tool_name: &str,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    service: &Arc<RwLock<McpServerService>>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> JsonRpcResponse {
+    let args = arguments.unwrap_or_default();
+    let result = match tool_name {
+        "websocket_connect" => handle_websocket_connect(&args, service, app_handle).await,
+        "websocket_send" => handle_websocket_send(&args),
+        "websocket_messages" => handle_websocket_messages(&args).await,
+        "websocket_close" => handle_websocket_close(&args),
+        _ => Err(format!("Unknown WebSocket tool: {tool_name}")),
+    };
+
+    match result {
+        Ok(tool_result) => JsonRpcResponse::success(
+            id,
+            serde_json::to_value(tool_result).unwrap_or_else(|_| json!({})),
+        ),
+        Err(e) => {
+            let error_result = ToolCallResult {
+                content: vec![ToolResponseContent::Text { text: e }],
+                is_error: true,
+            };
+            JsonRpcResponse::success(
+                id,
+                serde_json::to_value(error_result).unwrap_or_else(|_| json!({})),
+            )
+        }
+    }
+}
+
+/// Required `session_id` argument of the `websocket_*` tools.
+fn websocket_session_arg(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<&str, String> {
+    args.get("session_id")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| "Missing required parameter: session_id".to_string())
+}
+
+/// Handle `websocket_connect` — open a session for a websocket collection request.
+///
+/// Frames are published live (see [`publish_events`]). When the
+/// session ends, its transcript is saved to history if the app is running.
+async fn handle_websocket_connect(
+    args: &serde_json::Map<String, serde_json::Value>,
+    service: &Arc<RwLock<McpServerService>>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> Result<ToolCallResult, String> {
+    let prepared = {
+        let svc = service.read().await;
+        svc.prepare_execute_request(args)?
+    };
+    if prepared.kind != RequestKind::WebSocket {
+        return Err(format!(
+            "Request {} is not a websocket request; send it with execute_request",
+            prepared.request_id
+        ));
+    }
+    let subprotocols = match args.get("subprotocols") {
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| format!("Invalid subprotocols: {e}"))?,
+        None => prepared.subprotocols,
+    };
+    let session_id = args
+        .get("session_id")
+        .and_then(serde_json::Value::as_str)
+        .map_or_else(
+            || format!("ws-{}", uuid::Uuid::now_v7()),
+            ToString::to_string,
+        );
+
+    let broadcaster = app_handle
+        .and_then(tauri::Manager::try_state::<SseBroadcasterHandle>)
+        .map(|state| state.inner().clone());
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(publish_events(
+        app_handle.cloned(),
+        broadcaster,
+        WEBSOCKET_EVENT,
+        rx,
+    ));
+    let opened = connect_websocket(prepared.params.clone(), subprotocols, session_id, tx)
+        .await
+        .map_err(|e| format!("WebSocket connection failed: {e}"))?;
+    if let Some(app) = app_handle.cloned() {
+        let params = prepared.params;
+        tokio::spawn(async move {
+            if let Ok(response) = opened.closed.await
+                && let Err(e) = save_request_history(app, params, response).await
+            {
+                tracing::warn!("Failed to save WebSocket transcript: {e}");
+            }
+        });
+    }
+
+    Ok(ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: serde_json::to_string(&opened.info).unwrap_or_else(|_| "{}".to_string()),
+        }],
+        is_error: false,
+    })
+}
+
+/// Handle `websocket_send` — queue a message on an open session.
+fn handle_websocket_send(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<ToolCallResult, String> {
+    let session_id = websocket_session_arg(args)?;
+    let message: WebSocketSend = serde_json::from_value(serde_json::Value::Object(args.clone()))
+        .map_err(|e| format!("Invalid message: {e}"))?;
+    send_websocket_message(session_id, &message)?;
+    Ok(ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: json!({ "session_id": session_id, "message": "Message sent" }).to_string(),
+        }],
+        is_error: false,
+    })
+}
+
+/// Handle `websocket_messages` — read the transcript, optionally waiting for news.
+async fn handle_websocket_messages(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<ToolCallResult, String> {
+    const MAX_WAIT_MS: u64 = 30_000;
+    let session_id = websocket_session_arg(args)?;
+    let after_seq = args.get("after_seq").and_then(serde_json::Value::as_u64);
+    let wait_ms = args
+        .get("wait_ms")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0)
+        .min(MAX_WAIT_MS);
+    let is_new = |seq: u64| after_seq.is_none_or(|after| seq > after);
+
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(wait_ms);
+    let (transcript, open) = loop {
+        let transcript = websocket_transcript(session_id)
+            .ok_or_else(|| format!("WebSocket session not found: {session_id}"))?;
+        let open = is_websocket_open(session_id);
+        let has_news = transcript.messages.iter().any(|m| is_new(m.seq));
+        if has_news || !open || tokio::time::Instant::now() >= deadline {
+            break (transcript, open);
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    };
+
+    let messages: Vec<_> = transcript
+        .messages
+        .iter()
+        .filter(|m| is_new(m.seq))
+        .collect();
+    Ok(ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: json!({
+                "session_id": session_id,
+                "open": open,
+                "protocol": transcript.protocol,
+                "messages": messages,
+                "close_code": transcript.close_code,
+                "close_reason": transcript.close_reason,
+            })
+            .to_string(),
+        }],
+        is_error: false,
+    })
+}
+
+/// Handle `websocket_close` — start the close handshake of an open session.
+fn handle_websocket_close(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<ToolCallResult, String> {
+    let session_id = websocket_session_arg(args)?;
+    let code = args
+        .get("code")
+        .and_then(serde_json::Value::as_u64)
+        .map(|code| u16::try_from(code).map_err(|_| format!("Invalid close code: {code}")))
+        .transpose()?;
+    let reason = args
+        .get("reason")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default();
+    close_websocket(session_id, code, reason)?;
+    Ok(ToolCallResult {
+        content: vec![ToolResponseContent::Text {
+            text: json!({ "session_id": session_id, "message": "Close frame sent" }).to_string(),
+        }],
+        is_error: false,
+    })
+}
+
 /// Handle canvas tools (observation and mutation).
 async fn handle_canvas_tool(
     id: Option<JsonRpcId>,
@@ -1665,9 +2277,10 @@ mod tests {
         let result = parsed.result.unwrap();
         let tools = result["tools"].as_array().unwrap();
         // 8 collection + 3 save/move/copy + 3 import/refresh/hurl + 6 canvas + 1 streaming
-        // + 2 project context + 1 execute_request + 3 suggestion + 3 environment
-        // + 3 drift review + 3 pinned versions = 36 total
-        assert_eq!(tools.len(), 36);
+        // + 2 project context + 2 execute/cancel request + 3 suggestion + 3 environment
+        // + 3 cookie jar + 3 drift review + 3 pinned versions + 1 collection run
+        // + 1 load test + 4 WebSocket + 2 GraphQL = 48 total
+        assert_eq!(tools.len(), 48);
     }
 
     #[tokio::test]
@@ -1986,7 +2599,12 @@ mod tests {
                     "collection_id": collection_id,
                     "name": "Test Request",
                     "method": "GET",
-                    "url": format!("{}/test", base_url)
+                    "url": format!("{}/test", base_url),
+                    "extract": [{ "variable": "ok", "from": "json_path", "path": "$.ok" }],
+                    "assertions": [
+                        { "check": "status_equals", "status": 200 },
+                        { "check": "json_path_equals", "path": "$.ok", "value": false }
+                    ]
                 }
             }
         })
@@ -2045,6 +2663,126 @@ mod tests {
             serde_json::from_str(exec_result["content"][0]["text"].as_str().unwrap()).unwrap();
         assert_eq!(result_text["status"], 200);
         assert!(result_text["body"].as_str().unwrap().contains("ok"));
+        assert_eq!(
+            result_text["extracted"],
+            json!([{ "variable": "ok", "scope": "runtime", "value": "true" }])
+        );
+        assert_eq!(
+            result_text["assertions"],
+            json!([
+                { "check": "status_equals", "status": 200, "passed": true, "actual": "200" },
+                {
+                    "check": "json_path_equals",
+                    "path": "$.ok",
+                    "value": false,
+                    "passed": false,
+                    "actual": "true",
+                    "message": "Expected $.ok to equal false, got true"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_execute_result_json_includes_timing_connection_and_certificates() {
+        use crate::domain::certificates::{CertificateChain, PeerCertificate};
+        use crate::domain::http::{ConnectionInfo, RequestTiming};
+
+        let response = HttpResponse {
+            status: 200,
+            status_text: "OK".to_string(),
+            timing: RequestTiming {
+                total_ms: 42,
+                transfer_ms: Some(3),
+                download_ms: Some(5),
+                ..RequestTiming::default()
+            },
+            connection: Some(ConnectionInfo {
+                http_version: "HTTP/2".to_string(),
+                remote_ip: Some("127.0.0.1".to_string()),
+                ..ConnectionInfo::default()
+            }),
+            certificates: Some(CertificateChain {
+                certificates: vec![PeerCertificate {
+                    subject: "CN = api.example.com".to_string(),
+                    ..PeerCertificate::default()
+                }],
+                issues: Vec::new(),
+            }),
+            ..HttpResponse::default()
+        };
+
+        let result = execute_result_json(&response, &[], &[]);
+
+        assert_eq!(result["timing"]["total_ms"], 42);
+        assert_eq!(result["timing"]["transfer_ms"], 3);
+        assert_eq!(result["timing"]["download_ms"], 5);
+        assert_eq!(result["connection"]["http_version"], "HTTP/2");
+        assert_eq!(result["connection"]["remote_ip"], "127.0.0.1");
+        assert_eq!(
+            result["certificates"]["certificates"][0]["subject"],
+            "CN = api.example.com"
+        );
+        let plain = execute_result_json(&HttpResponse::default(), &[], &[]);
+        assert!(plain.get("connection").is_none());
+        assert!(plain.get("certificates").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_cancel_request_not_running() {
+        let (service, canvas_state, drift_store, _dir) = make_service();
+
+        let cancel_req = json!({
+            "jsonrpc": "2.0", "id": 1, "method": "tools/call",
+            "params": {
+                "name": "cancel_request",
+                "arguments": { "collection_id": "col_1", "request_id": "req_1" }
+            }
+        })
+        .to_string();
+        let resp = dispatch(
+            &cancel_req,
+            &service,
+            &canvas_state,
+            None,
+            None,
+            &drift_store,
+            None,
+        )
+        .await
+        .unwrap();
+        let parsed: JsonRpcResponse = serde_json::from_str(&resp).unwrap();
+        let result = parsed.result.unwrap();
+        assert!(!result["isError"].as_bool().unwrap_or(false));
+        let content: serde_json::Value =
+            serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+        assert_eq!(content["correlation_id"], "mcp-col_1-req_1");
+        assert_eq!(content["cancelled"], false);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_cancel_request_missing_target() {
+        let (service, canvas_state, drift_store, _dir) = make_service();
+
+        let cancel_req = json!({
+            "jsonrpc": "2.0", "id": 1, "method": "tools/call",
+            "params": { "name": "cancel_request", "arguments": { "collection_id": "col_1" } }
+        })
+        .to_string();
+        let resp = dispatch(
+            &cancel_req,
+            &service,
+            &canvas_state,
+            None,
+            None,
+            &drift_store,
+            None,
+        )
+        .await
+        .unwrap();
+        let parsed: JsonRpcResponse = serde_json::from_str(&resp).unwrap();
+        let result = parsed.result.unwrap();
+        assert!(result["isError"].as_bool().unwrap_or(false));
     }
 
     #[tokio::test]
@@ -2085,6 +2823,67 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_dispatch_websocket_request_kind() {
+        let (service, canvas_state, drift_store, _dir) = make_service();
+        let call = |name: &str, arguments: serde_json::Value| {
+            let req = json!({
+                "jsonrpc": "2.0", "id": 1, "method": "tools/call",
+                "params": { "name": name, "arguments": arguments }
+            })
+            .to_string();
+            let (service, canvas_state, drift_store) = (&service, &canvas_state, &drift_store);
+            async move {
+                let resp = dispatch(&req, service, canvas_state, None, None, drift_store, None)
+                    .await
+                    .unwrap();
+                let parsed: JsonRpcResponse = serde_json::from_str(&resp).unwrap();
+                let result = parsed.result.unwrap();
+                let text = result["content"][0]["text"].as_str().unwrap().to_string();
+                (result["isError"].as_bool().unwrap_or(false), text)
+            }
+        };
+
+        let (_, text) = call("create_collection", json!({ "name": "Sockets" })).await;
+        let collection_id = serde_json::from_str::<serde_json::Value>(&text).unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let (_, text) = call(
+            "add_request",
+            json!({
+                "collection_id": collection_id,
+                "name": "Chat",
+                "method": "GET",
+                "url": "ws://127.0.0.1:1/chat",
+                "kind": "websocket",
+                "subprotocols": ["chat"]
+            }),
+        )
+        .await;
+        let request_id = serde_json::from_str::<serde_json::Value>(&text).unwrap()["request_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let target = json!({ "collection_id": collection_id, "request_id": request_id });
+
+        let (is_error, text) = call("execute_request", target.clone()).await;
+        assert!(is_error);
+        assert!(text.contains("websocket_connect"), "{text}");
+
+        let (is_error, text) = call("websocket_connect", target).await;
+        assert!(is_error);
+        assert!(text.contains("HTTP_REQUEST_FAILED"), "{text}");
+
+        let (is_error, text) = call(
+            "websocket_send",
+            json!({ "session_id": "ws-missing", "data": "hi" }),
+        )
+        .await;
+        assert!(is_error);
+        assert!(text.contains("not found"), "{text}");
+    }
+
     #[tokio::test]
     async fn test_canvas_subscribe_stream_returns_url() {
         let (service, canvas_state, drift_store, _dir) = make_service();
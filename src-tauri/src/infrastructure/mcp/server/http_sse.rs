@@ -55,6 +55,7 @@ impl EventBroadcaster {
     ///
     /// Returns the number of receivers that got the message.
     /// Returns 0 if no SSE clients are connected (this is fine).
+    #[allow(clippy::must_use_candidate)] // Fire-and-forget; the count is informational
     pub fn send(&self, envelope: EventEnvelope) -> usize {
         self.tx.send(envelope).unwrap_or(0)
     }
@@ -68,6 +69,12 @@ impl EventBroadcaster {
     }
 }
 
+impl Default for EventBroadcaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Shared state for the Axum MCP server.
 #[derive(Clone)]
 pub struct McpServerState {
@@ -2,16 +2,31 @@
 // SPDX-License-Identifier: MIT
 
 // Infrastructure layer - External interfaces (Tauri commands, networking)
+//
+// Modules that need the Tauri runtime are only built with the `desktop` feature.
 
+/// Headless command-line interface for CI runs.
+pub mod cli;
+/// Tauri command handlers.
+#[cfg(feature = "desktop")]
 pub mod commands;
 /// Git metadata infrastructure adapters.
 pub mod git;
+/// HTTP execution over curl with detailed timing.
 pub mod http;
 /// Hurl CLI adapter for test execution.
 pub mod hurl;
+#[cfg(feature = "desktop")]
 pub mod logging;
 /// MCP infrastructure adapters and transports.
+#[cfg(feature = "desktop")]
 pub mod mcp;
+/// RAM usage sampling and alerts.
+#[cfg(feature = "desktop")]
 pub mod memory_monitor;
 pub mod spec;
+/// Collection, history and settings persistence.
 pub mod storage;
+/// WebSocket client sessions over raw curl connections.
+#[cfg(feature = "desktop")]
+pub mod websocket;
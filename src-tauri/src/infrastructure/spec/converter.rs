@@ -4,8 +4,8 @@ use super::hasher::compute_spec_hash;
 use super::openapi_types::{OpenApiParameterLocation, OpenApiParsedParameter, OpenApiParsedSpec};
 use crate::domain::collection::{
     BodyType, Collection, CollectionMetadata, CollectionRequest, CollectionSource,
-    IntelligenceMetadata, RequestBody, RequestParam, SCHEMA_URL, SCHEMA_VERSION, SourceType,
-    SpecBinding,
+    IntelligenceMetadata, RequestBody, RequestKind, RequestParam, SCHEMA_URL, SCHEMA_VERSION,
+    SourceType, SpecBinding,
 };
 use std::collections::BTreeMap;
 
@@ -19,6 +19,7 @@ use std::collections::BTreeMap;
 /// - Uses integer version (1), not semver
 /// - Uses `BTreeMap` for headers (deterministic)
 /// - Adds `seq` field for ordering
+#[must_use]
 pub fn convert_to_collection(
     parsed: &OpenApiParsedSpec,
     raw_content: &str,
@@ -60,13 +61,24 @@ pub fn convert_to_collection(
                         body_type: BodyType::Json,
                         content: rb.example.clone(),
                         file: None,
+                        parts: Vec::new(),
+                        graphql: None,
                     })
                 }),
                 auth: None,
+                http_version: None,
+                retry: None,
+                follow_redirects: None,
+                unix_socket: None,
+                extract: Vec::new(),
+                assertions: Vec::new(),
                 docs: op.description.clone(),
                 is_streaming: op.is_streaming,
+                kind: RequestKind::Http,
+                subprotocols: Vec::new(),
                 binding: SpecBinding::from_operation(&op.operation_id, &op.path, &op.method),
                 intelligence: IntelligenceMetadata::default(),
+                folder: None,
                 tags: op.tags.clone(),
                 extensions: BTreeMap::new(),
             }
@@ -99,6 +111,8 @@ pub fn convert_to_collection(
         variables: BTreeMap::new(),
         environments: vec![],
         active_environment: None,
+        http_version: None,
+        retry: None,
         extensions: BTreeMap::new(),
         requests,
         pinned_versions: vec![],
@@ -3,6 +3,10 @@
 use std::time::Duration;
 
 use crate::domain::collection::spec_port::FetchResult;
+use crate::domain::errors::AppError;
+use crate::domain::http::RequestParams;
+use crate::domain::http_headers::HttpHeaders;
+use crate::infrastructure::http::execute_http_request;
 
 const HTTPBIN_SPEC_URL: &str = "https://httpbin.org/spec.json";
 const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
@@ -47,24 +51,25 @@ pub async fn fetch_openapi_spec(url: &str) -> Result<FetchResult, String> {
     }
 }
 
+/// Fetch the spec through the curl request pipeline, so spec imports honor the
+/// same proxy and TLS handling as request execution.
 async fn fetch_from_network(url: &str) -> Result<String, String> {
-    let client = reqwest::Client::builder()
-        .timeout(FETCH_TIMEOUT)
-        .build()
-        .map_err(|e| e.to_string())?;
+    let params = RequestParams {
+        url: url.to_string(),
+        headers: HttpHeaders::from([("Accept", "application/json")]),
+        timeout_ms: u64::try_from(FETCH_TIMEOUT.as_millis()).unwrap_or(u64::MAX),
+        ..Default::default()
+    };
 
-    let response = client
-        .get(url)
-        .header("Accept", "application/json")
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
+    let response = execute_http_request(params, None).await.map_err(|e| {
+        serde_json::from_str::<AppError>(&e).map_or(e, |app_error| app_error.message)
+    })?;
 
-    if !response.status().is_success() {
-        return Err(format!("HTTP {}", response.status()));
+    if !(200..300).contains(&response.status) {
+        return Err(format!("HTTP {} {}", response.status, response.status_text));
     }
 
-    response.text().await.map_err(|e| e.to_string())
+    Ok(String::from_utf8_lossy(&response.body_bytes()).into_owned())
 }
 
 #[cfg(test)]
@@ -12,6 +12,7 @@ use sha2::{Digest, Sha256};
 /// 4. Hash the result
 ///
 /// This ensures same spec = same hash, regardless of formatting.
+#[must_use]
 pub fn compute_spec_hash(content: &str) -> String {
     let normalized = normalize_json(content);
     let mut hasher = Sha256::new();
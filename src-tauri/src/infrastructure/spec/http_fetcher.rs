@@ -1,7 +1,7 @@
 //! HTTP/file/inline content fetcher adapter for the `ContentFetcher` port.
 //!
 //! Handles all three `SpecSource` variants:
-//! - `Url` → HTTP GET via `reqwest` (with httpbin fallback)
+//! - `Url` → HTTP GET via the curl request pipeline (with httpbin fallback)
 //! - `File` → `tokio::fs::read_to_string`
 //! - `Inline` → passthrough (no I/O)
 
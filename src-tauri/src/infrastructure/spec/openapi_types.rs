@@ -13,32 +13,49 @@ use serde::{Deserialize, Serialize};
 /// maps this into the domain `ParsedSpec` IR.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OpenApiParsedSpec {
+    /// `info.title`.
     pub title: String,
+    /// `info.version`.
     pub version: String,
+    /// `info.description`.
     pub description: Option<String>,
+    /// Declared servers, in spec order.
     pub servers: Vec<OpenApiServer>,
+    /// Every operation of every path.
     pub operations: Vec<OpenApiParsedOperation>,
 }
 
 /// A server entry from an `OpenAPI` spec.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OpenApiServer {
+    /// Server URL, possibly with `{variables}`.
     pub url: String,
+    /// Server description.
     pub description: Option<String>,
 }
 
 /// A parsed operation from an `OpenAPI` spec.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OpenApiParsedOperation {
+    /// `operationId`, or one derived from the method and path.
     pub operation_id: String,
+    /// Path template (e.g., "/users/{id}").
     pub path: String,
+    /// Uppercase HTTP method.
     pub method: String,
+    /// Short summary.
     pub summary: Option<String>,
+    /// Long description.
     pub description: Option<String>,
+    /// Operation tags.
     pub tags: Vec<String>,
+    /// Path, query and header parameters.
     pub parameters: Vec<OpenApiParsedParameter>,
+    /// Request body, if the operation takes one.
     pub request_body: Option<OpenApiParsedRequestBody>,
+    /// Whether the operation is marked deprecated.
     pub deprecated: bool,
+    /// Whether the operation responds with a stream (SSE or NDJSON).
     pub is_streaming: bool,
 }
 
@@ -56,11 +73,17 @@ pub struct OpenApiParsedRequestBody {
 /// A parsed parameter from an `OpenAPI` spec.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OpenApiParsedParameter {
+    /// Parameter name.
     pub name: String,
+    /// Where the parameter is sent.
     pub location: OpenApiParameterLocation,
+    /// Whether the parameter is required.
     pub required: bool,
+    /// Schema type (e.g., "integer").
     pub schema_type: Option<String>,
+    /// Schema default, serialized.
     pub default_value: Option<String>,
+    /// Parameter description.
     pub description: Option<String>,
 }
 
@@ -68,8 +91,11 @@ pub struct OpenApiParsedParameter {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum OpenApiParameterLocation {
+    /// Templated into the path.
     Path,
+    /// Sent in the query string.
     Query,
+    /// Sent as a request header.
     Header,
 }
 
@@ -239,7 +239,8 @@ pub fn delete_collection(collection_id: &str) -> Result<(), String> {
     delete_collection_in_dir(collection_id, &dir)
 }
 
-/// Delete a collection file from the specified directory.
+/// Delete a collection file, its cookie jar and its cached GraphQL schema from
+/// the specified directory.
 pub fn delete_collection_in_dir(collection_id: &str, dir: &Path) -> Result<(), String> {
     let path = dir.join(format!("{collection_id}.yaml"));
 
@@ -247,16 +248,23 @@ pub fn delete_collection_in_dir(collection_id: &str, dir: &Path) -> Result<(), S
         return Err(format!("Collection not found: {collection_id}"));
     }
 
-    fs::remove_file(&path).map_err(|e| format!("Failed to delete collection: {e}"))
+    fs::remove_file(&path).map_err(|e| format!("Failed to delete collection: {e}"))?;
+    super::cookie_store::delete_cookie_file_in_dir(dir, collection_id)?;
+    super::graphql_schema_store::delete_graphql_schema_in_dir(dir, collection_id)
 }
 
 /// Lightweight collection info for listing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollectionSummary {
+    /// Collection ID.
     pub id: String,
+    /// Display name.
     pub name: String,
+    /// Number of saved requests.
     pub request_count: usize,
+    /// Where the collection came from (e.g., "openapi", "manual").
     pub source_type: String,
+    /// Last modification time (RFC 3339).
     pub modified_at: String,
     /// Spec version from the collection's source (e.g., "1.2.3").
     pub spec_version: Option<String>,
@@ -0,0 +1,273 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Cookie jar persistence.
+//!
+//! Each collection's cookies live next to its YAML file in
+//! `<collection_id>.cookies.json`, with one jar per environment. Writes go
+//! through a process-wide lock so concurrent requests do not interleave
+//! read-modify-write cycles.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::cookies::{Cookie, CookieJarScope};
+
+use super::collection_store::get_collections_dir;
+
+/// Serializes cookie file updates within this process.
+static COOKIE_FILE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// On-disk layout of a collection's cookie file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CookieFile {
+    #[serde(default)]
+    jars: Vec<CookieJar>,
+}
+
+/// Cookies of one environment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CookieJar {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(default)]
+    cookies: Vec<Cookie>,
+}
+
+/// Validate a collection ID to prevent path traversal.
+///
+/// Accepts the characters [`Collection::generate_id`] produces: letters,
+/// digits, underscores and hyphens.
+///
+/// [`Collection::generate_id`]: crate::domain::collection::Collection::generate_id
+fn validate_collection_id(id: &str) -> Result<(), String> {
+    if id.is_empty() {
+        return Err("Collection ID cannot be empty".to_string());
+    }
+    if !id
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!(
+            "Invalid collection ID: '{id}' — must contain only letters, digits, '_', or '-'"
+        ));
+    }
+    Ok(())
+}
+
+/// Path of a collection's cookie file in `dir`.
+fn cookie_file_path(dir: &Path, collection_id: &str) -> Result<PathBuf, String> {
+    validate_collection_id(collection_id)?;
+    Ok(dir.join(format!("{collection_id}.cookies.json")))
+}
+
+fn read_cookie_file(path: &Path) -> Result<CookieFile, String> {
+    if !path.exists() {
+        return Ok(CookieFile::default());
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read cookie jar: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse cookie jar: {e}"))
+}
+
+fn write_cookie_file(path: &Path, file: &CookieFile) -> Result<(), String> {
+    if file.jars.is_empty() {
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| format!("Failed to remove cookie jar: {e}"))?;
+        }
+        return Ok(());
+    }
+    let json = serde_json::to_string_pretty(file)
+        .map_err(|e| format!("Failed to serialize cookie jar: {e}"))?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write cookie jar: {e}"))?;
+    fs::rename(&temp_path, path).map_err(|e| format!("Failed to write cookie jar: {e}"))
+}
+
+/// Load the cookies of a jar. A missing file or jar yields no cookies.
+///
+/// # Errors
+///
+/// Returns an error if the collection ID is invalid or the cookie file exists
+/// but cannot be read or parsed.
+pub fn load_cookies_in_dir(dir: &Path, scope: &CookieJarScope) -> Result<Vec<Cookie>, String> {
+    let file = read_cookie_file(&cookie_file_path(dir, &scope.collection_id)?)?;
+    Ok(file
+        .jars
+        .into_iter()
+        .find(|jar| jar.environment == scope.environment)
+        .map(|jar| jar.cookies)
+        .unwrap_or_default())
+}
+
+/// Load the cookies of a jar from the collections directory.
+///
+/// # Errors
+///
+/// Returns an error if the collections directory cannot be resolved or the
+/// cookie file cannot be read or parsed.
+pub fn load_cookies(scope: &CookieJarScope) -> Result<Vec<Cookie>, String> {
+    load_cookies_in_dir(&get_collections_dir()?, scope)
+}
+
+/// Apply `update` to a jar's cookies and persist the result.
+///
+/// Expired cookies are dropped; an emptied jar is removed from the file, and
+/// the file is deleted once no jars remain. Returns the updated cookies.
+///
+/// # Errors
+///
+/// Returns an error if the collection ID is invalid or the cookie file cannot
+/// be read, parsed, or written.
+pub fn update_cookies_in_dir(
+    dir: &Path,
+    scope: &CookieJarScope,
+    update: impl FnOnce(&mut Vec<Cookie>),
+) -> Result<Vec<Cookie>, String> {
+    let _guard = COOKIE_FILE_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let path = cookie_file_path(dir, &scope.collection_id)?;
+    let mut file = read_cookie_file(&path)?;
+
+    let index = file
+        .jars
+        .iter()
+        .position(|jar| jar.environment == scope.environment)
+        .unwrap_or_else(|| {
+            file.jars.push(CookieJar {
+                environment: scope.environment.clone(),
+                cookies: Vec::new(),
+            });
+            file.jars.len() - 1
+        });
+    let cookies = &mut file.jars[index].cookies;
+    update(cookies);
+    let now = chrono::Utc::now().timestamp();
+    cookies.retain(|cookie| !cookie.is_expired(now));
+    let updated = cookies.clone();
+    if updated.is_empty() {
+        file.jars.remove(index);
+    }
+
+    write_cookie_file(&path, &file)?;
+    Ok(updated)
+}
+
+/// Apply `update` to a jar's cookies in the collections directory.
+///
+/// # Errors
+///
+/// Returns an error if the collections directory cannot be resolved or the
+/// cookie file cannot be read, parsed, or written.
+pub fn update_cookies(
+    scope: &CookieJarScope,
+    update: impl FnOnce(&mut Vec<Cookie>),
+) -> Result<Vec<Cookie>, String> {
+    update_cookies_in_dir(&get_collections_dir()?, scope, update)
+}
+
+/// Delete a collection's cookie file, if any.
+///
+/// # Errors
+///
+/// Returns an error if the collection ID is invalid or the file exists but
+/// cannot be removed.
+pub fn delete_cookie_file_in_dir(dir: &Path, collection_id: &str) -> Result<(), String> {
+    let path = cookie_file_path(dir, collection_id)?;
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove cookie jar: {e}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::cookies::upsert_cookie;
+    use tempfile::TempDir;
+
+    fn scope(environment: Option<&str>) -> CookieJarScope {
+        CookieJarScope {
+            collection_id: "col_api_1234".to_string(),
+            environment: environment.map(str::to_string),
+        }
+    }
+
+    fn cookie(name: &str, value: &str) -> Cookie {
+        Cookie {
+            name: name.to_string(),
+            value: value.to_string(),
+            domain: "api.example.com".to_string(),
+            path: "/".to_string(),
+            include_subdomains: false,
+            secure: false,
+            http_only: false,
+            expires: None,
+        }
+    }
+
+    #[test]
+    fn test_jars_are_scoped_per_environment() {
+        let dir = TempDir::new().unwrap();
+        update_cookies_in_dir(dir.path(), &scope(Some("staging")), |jar| {
+            upsert_cookie(jar, cookie("session", "staging-token"));
+        })
+        .unwrap();
+        update_cookies_in_dir(dir.path(), &scope(None), |jar| {
+            upsert_cookie(jar, cookie("session", "default-token"));
+        })
+        .unwrap();
+
+        let staging = load_cookies_in_dir(dir.path(), &scope(Some("staging"))).unwrap();
+        assert_eq!(staging, vec![cookie("session", "staging-token")]);
+        let default = load_cookies_in_dir(dir.path(), &scope(None)).unwrap();
+        assert_eq!(default, vec![cookie("session", "default-token")]);
+        assert!(
+            load_cookies_in_dir(dir.path(), &scope(Some("prod")))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(dir.path().join("col_api_1234.cookies.json").exists());
+    }
+
+    #[test]
+    fn test_expired_cookies_are_dropped_and_empty_file_removed() {
+        let dir = TempDir::new().unwrap();
+        let jar = update_cookies_in_dir(dir.path(), &scope(None), |jar| {
+            upsert_cookie(jar, cookie("live", "1"));
+            upsert_cookie(
+                jar,
+                Cookie {
+                    expires: Some(1),
+                    ..cookie("stale", "0")
+                },
+            );
+        })
+        .unwrap();
+        assert_eq!(jar, vec![cookie("live", "1")]);
+
+        update_cookies_in_dir(dir.path(), &scope(None), Vec::clear).unwrap();
+        assert!(!dir.path().join("col_api_1234.cookies.json").exists());
+    }
+
+    #[test]
+    fn test_rejects_collection_ids_outside_the_directory() {
+        let dir = TempDir::new().unwrap();
+        let escaping = CookieJarScope {
+            collection_id: "../outside".to_string(),
+            environment: None,
+        };
+
+        let err = update_cookies_in_dir(dir.path(), &escaping, |jar| {
+            upsert_cookie(jar, cookie("session", "token"));
+        })
+        .unwrap_err();
+        assert!(err.contains("Invalid collection ID"));
+        assert!(load_cookies_in_dir(dir.path(), &escaping).is_err());
+        assert!(delete_cookie_file_in_dir(dir.path(), "").is_err());
+    }
+}
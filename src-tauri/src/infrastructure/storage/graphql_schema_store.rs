@@ -0,0 +1,126 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! GraphQL schema cache persistence.
+//!
+//! The schema introspected for a collection lives next to its YAML file in
+//! `<collection_id>.graphql.json`, so validation and operation listing work
+//! without contacting the endpoint again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::domain::graphql::GraphqlSchemaCache;
+
+use super::collection_store::get_collections_dir;
+
+/// Path of a collection's schema cache in `dir`.
+fn schema_file_path(dir: &Path, collection_id: &str) -> PathBuf {
+    dir.join(format!("{collection_id}.graphql.json"))
+}
+
+/// Save the schema cached for a collection, replacing any earlier one.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save_graphql_schema_in_dir(
+    dir: &Path,
+    collection_id: &str,
+    cache: &GraphqlSchemaCache,
+) -> Result<(), String> {
+    let path = schema_file_path(dir, collection_id);
+    let json = serde_json::to_string_pretty(cache)
+        .map_err(|e| format!("Failed to serialize GraphQL schema: {e}"))?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write GraphQL schema: {e}"))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to write GraphQL schema: {e}"))
+}
+
+/// Save the schema cached for a collection in the collections directory.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be determined or the file cannot
+/// be written.
+pub fn save_graphql_schema(collection_id: &str, cache: &GraphqlSchemaCache) -> Result<(), String> {
+    save_graphql_schema_in_dir(&get_collections_dir()?, collection_id, cache)
+}
+
+/// Load the schema cached for a collection. A missing cache yields `None`.
+///
+/// # Errors
+///
+/// Returns an error if the cache exists but cannot be read or parsed.
+pub fn load_graphql_schema_in_dir(
+    dir: &Path,
+    collection_id: &str,
+) -> Result<Option<GraphqlSchemaCache>, String> {
+    let path = schema_file_path(dir, collection_id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read GraphQL schema: {e}"))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse GraphQL schema: {e}"))
+}
+
+/// Load the schema cached for a collection from the collections directory.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be determined or the cache exists
+/// but cannot be read or parsed.
+pub fn load_graphql_schema(collection_id: &str) -> Result<Option<GraphqlSchemaCache>, String> {
+    load_graphql_schema_in_dir(&get_collections_dir()?, collection_id)
+}
+
+/// Remove the schema cached for a collection, if any.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be removed.
+pub fn delete_graphql_schema_in_dir(dir: &Path, collection_id: &str) -> Result<(), String> {
+    let path = schema_file_path(dir, collection_id);
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove GraphQL schema: {e}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::graphql::GraphqlSchema;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_load_and_delete_schema_cache() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(
+            load_graphql_schema_in_dir(dir.path(), "col_api_1234").unwrap(),
+            None
+        );
+
+        let cache = GraphqlSchemaCache {
+            endpoint: "https://api.test/graphql".to_string(),
+            fetched_at: "2026-01-31T10:30:00Z".to_string(),
+            schema: GraphqlSchema {
+                query_type: Some("Query".to_string()),
+                ..GraphqlSchema::default()
+            },
+        };
+        save_graphql_schema_in_dir(dir.path(), "col_api_1234", &cache).unwrap();
+        assert!(dir.path().join("col_api_1234.graphql.json").exists());
+        assert_eq!(
+            load_graphql_schema_in_dir(dir.path(), "col_api_1234").unwrap(),
+            Some(cache)
+        );
+
+        delete_graphql_schema_in_dir(dir.path(), "col_api_1234").unwrap();
+        assert!(!dir.path().join("col_api_1234.graphql.json").exists());
+        delete_graphql_schema_in_dir(dir.path(), "col_api_1234").unwrap();
+    }
+}
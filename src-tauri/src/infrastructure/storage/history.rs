@@ -289,24 +289,26 @@ pub async fn clear_history() -> Result<(), String> {
 mod tests {
     use super::*;
     use crate::domain::http::RequestTiming;
-    use std::collections::HashMap;
+    use crate::domain::http_headers::HttpHeaders;
     use std::env;
 
     fn create_test_entry() -> HistoryEntry {
         let request = RequestParams {
             url: "https://api.example.com/users".to_string(),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 30000,
+            ..Default::default()
         };
 
         let response = HttpResponse {
             status: 200,
             status_text: "OK".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: r#"{"users": []}"#.to_string(),
             timing: RequestTiming::default(),
+            ..Default::default()
         };
 
         HistoryEntry::new(request, response)
@@ -373,4 +375,46 @@ mod tests {
         assert_eq!(entry.request.url, parsed.request.url);
         assert_eq!(entry.response.status, parsed.response.status);
     }
+
+    #[test]
+    fn test_legacy_history_entry_with_header_maps_loads() {
+        // History files written before repeated headers were kept store headers as maps
+        let yaml = r#"
+id: hist_legacy
+timestamp: 2026-01-15T10:30:00Z
+request:
+  url: https://api.example.com/users
+  method: POST
+  headers:
+    Content-Type: application/json
+  body: '{"name": "ada"}'
+  timeout_ms: 30000
+response:
+  status: 201
+  status_text: Created
+  headers:
+    Location: /users/1
+    Set-Cookie: session=abc
+  body: '{"id": 1}'
+  timing:
+    total_ms: 42
+    dns_ms: null
+    connect_ms: null
+    tls_ms: null
+    first_byte_ms: null
+"#;
+        let entry: HistoryEntry = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(
+            entry.request.headers.get("content-type"),
+            Some("application/json")
+        );
+        assert_eq!(entry.response.headers.len(), 2);
+        assert_eq!(entry.response.headers.get("location"), Some("/users/1"));
+
+        // Re-saving writes the ordered list format without losing anything
+        let resaved: HistoryEntry =
+            serde_yaml_ng::from_str(&serde_yaml_ng::to_string(&entry).unwrap()).unwrap();
+        assert_eq!(resaved.request.headers, entry.request.headers);
+        assert_eq!(resaved.response.headers, entry.response.headers);
+    }
 }
@@ -0,0 +1,192 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Load test report persistence.
+//!
+//! Every finished load test is kept as `<load_test_id>.json` in the
+//! `load-tests` folder of the runi data directory, so results can be compared
+//! across runs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::domain::load_test::LoadTestReport;
+
+use super::get_data_dir;
+
+/// Get the load test reports directory.
+///
+/// Returns `~/.runi/load-tests/` (or platform equivalent).
+///
+/// # Errors
+///
+/// Returns an error if the data directory cannot be determined.
+pub fn get_load_tests_dir() -> Result<PathBuf, String> {
+    get_data_dir().map(|path| path.join("load-tests"))
+}
+
+/// Path of a report file, rejecting IDs that could escape `dir`.
+fn report_path(dir: &Path, load_test_id: &str) -> Result<PathBuf, String> {
+    if load_test_id.is_empty()
+        || !load_test_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("Invalid load test ID: {load_test_id}"));
+    }
+    Ok(dir.join(format!("{load_test_id}.json")))
+}
+
+/// Save a report in `dir`, replacing an earlier report with the same ID.
+///
+/// # Errors
+///
+/// Returns an error if the ID is invalid or the file cannot be written.
+pub fn save_load_test_report_in_dir(
+    report: &LoadTestReport,
+    dir: &Path,
+) -> Result<PathBuf, String> {
+    let path = report_path(dir, &report.load_test_id)?;
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create load test directory: {e}"))?;
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| format!("Failed to serialize load test report: {e}"))?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write load test report: {e}"))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to write load test report: {e}"))?;
+    Ok(path)
+}
+
+/// Save a report in the load test reports directory.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be resolved or the file cannot be written.
+pub fn save_load_test_report(report: &LoadTestReport) -> Result<PathBuf, String> {
+    save_load_test_report_in_dir(report, &get_load_tests_dir()?)
+}
+
+/// Load the report of a load test from `dir`.
+///
+/// # Errors
+///
+/// Returns an error if the ID is invalid or the report cannot be read or parsed.
+pub fn load_load_test_report_in_dir(
+    load_test_id: &str,
+    dir: &Path,
+) -> Result<LoadTestReport, String> {
+    let path = report_path(dir, load_test_id)?;
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read load test report {load_test_id}: {e}"))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse load test report {load_test_id}: {e}"))
+}
+
+/// Load the report of a load test from the load test reports directory.
+///
+/// # Errors
+///
+/// Returns an error if the report cannot be found, read or parsed.
+pub fn load_load_test_report(load_test_id: &str) -> Result<LoadTestReport, String> {
+    load_load_test_report_in_dir(load_test_id, &get_load_tests_dir()?)
+}
+
+/// List the reports in `dir`, newest first. Unreadable files are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the directory exists but cannot be read.
+pub fn list_load_test_reports_in_dir(dir: &Path) -> Result<Vec<LoadTestReport>, String> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read load test directory: {e}"))?;
+    let mut reports: Vec<LoadTestReport> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect();
+    reports.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(reports)
+}
+
+/// List the reports in the load test reports directory, newest first.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be resolved or read.
+pub fn list_load_test_reports() -> Result<Vec<LoadTestReport>, String> {
+    list_load_test_reports_in_dir(&get_load_tests_dir()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::load_test::{LoadTestOptions, LoadTestStats};
+    use tempfile::TempDir;
+
+    fn report(id: &str, started_at: &str) -> LoadTestReport {
+        LoadTestReport {
+            load_test_id: id.to_string(),
+            collection_id: "col_1".to_string(),
+            request_id: "req_1".to_string(),
+            request_name: "List users".to_string(),
+            method: "GET".to_string(),
+            url: "https://api.example.com/users".to_string(),
+            started_at: started_at.to_string(),
+            options: LoadTestOptions {
+                iterations: Some(100),
+                concurrency: 10,
+                ..Default::default()
+            },
+            cancelled: false,
+            stats: LoadTestStats {
+                requests: 100,
+                succeeded: 100,
+                throughput_rps: 48.5,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let saved = report("lt-1", "2026-10-17T12:00:00Z");
+        let path = save_load_test_report_in_dir(&saved, dir.path()).unwrap();
+        assert!(path.ends_with("lt-1.json"));
+        assert_eq!(
+            load_load_test_report_in_dir("lt-1", dir.path()).unwrap(),
+            saved
+        );
+    }
+
+    #[test]
+    fn test_list_is_newest_first_and_skips_other_files() {
+        let dir = TempDir::new().unwrap();
+        save_load_test_report_in_dir(&report("older", "2026-10-17T12:00:00Z"), dir.path()).unwrap();
+        save_load_test_report_in_dir(&report("newer", "2026-10-17T13:00:00Z"), dir.path()).unwrap();
+        fs::write(dir.path().join("notes.json"), "not a report").unwrap();
+
+        let ids: Vec<_> = list_load_test_reports_in_dir(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|r| r.load_test_id)
+            .collect();
+        assert_eq!(ids, vec!["newer", "older"]);
+        assert!(
+            list_load_test_reports_in_dir(&dir.path().join("missing"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_ids_cannot_escape_the_directory() {
+        let dir = TempDir::new().unwrap();
+        assert!(load_load_test_report_in_dir("../secrets", dir.path()).is_err());
+        assert!(save_load_test_report_in_dir(&report("a/b", "now"), dir.path()).is_err());
+    }
+}
@@ -152,23 +152,25 @@ impl HistoryStorage for MemoryHistoryStorage {
 mod tests {
     use super::*;
     use crate::domain::http::{HttpResponse, RequestParams, RequestTiming};
-    use std::collections::HashMap;
+    use crate::domain::http_headers::HttpHeaders;
 
     fn create_test_entry(url: &str) -> HistoryEntry {
         let request = RequestParams {
             url: url.to_string(),
             method: "GET".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: None,
             timeout_ms: 30000,
+            ..Default::default()
         };
 
         let response = HttpResponse {
             status: 200,
             status_text: "OK".to_string(),
-            headers: HashMap::new(),
+            headers: HttpHeaders::new(),
             body: r#"{"test": true}"#.to_string(),
             timing: RequestTiming::default(),
+            ..Default::default()
         };
 
         HistoryEntry::new(request, response)
@@ -3,11 +3,20 @@
 
 // Storage infrastructure for pluggable storage backends
 
+/// Collection YAML files on disk.
 pub mod collection_store;
+pub mod cookie_store;
 pub mod encryption;
 pub mod file_storage;
+pub mod graphql_schema_store;
+/// History entry storage and management.
 pub mod history;
+pub mod load_test_store;
+/// Session-only in-memory history storage.
 pub mod memory_storage;
+pub mod network_settings;
+pub mod runtime_variable_store;
+/// Storage trait abstraction for pluggable backends.
 pub mod traits;
 
 use std::path::{Path, PathBuf};
@@ -0,0 +1,93 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Global network settings (proxy) persisted as TOML in the data directory.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::http::ProxyConfig;
+
+const NETWORK_SETTINGS_FILE_NAME: &str = "network.toml";
+
+/// App-wide network settings, applied to every request unless an environment
+/// or the request itself overrides them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkSettings {
+    /// Global proxy settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+}
+
+/// Path of the network settings file (`<data dir>/network.toml`).
+///
+/// # Errors
+///
+/// Returns an error if the data directory cannot be determined.
+pub fn network_settings_path() -> Result<PathBuf, String> {
+    super::get_data_dir().map(|dir| dir.join(NETWORK_SETTINGS_FILE_NAME))
+}
+
+/// Load network settings, returning defaults if the file does not exist.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load_network_settings(path: &Path) -> Result<NetworkSettings, String> {
+    if !path.exists() {
+        return Ok(NetworkSettings::default());
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+}
+
+/// Save network settings, creating the parent directory if needed.
+///
+/// # Errors
+///
+/// Returns an error if the settings cannot be serialized or written.
+pub fn save_network_settings(path: &Path, settings: &NetworkSettings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {e}", parent.display()))?;
+    }
+    let content = toml::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize network settings: {e}"))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_file_returns_defaults() {
+        let dir = TempDir::new().unwrap();
+        let settings = load_network_settings(&dir.path().join("network.toml")).unwrap();
+        assert_eq!(settings, NetworkSettings::default());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("network.toml");
+        let settings = NetworkSettings {
+            proxy: Some(ProxyConfig {
+                url: Some("http://proxy.corp:3128".to_string()),
+                username: Some("alice".to_string()),
+                password: Some("s3cret".to_string()),
+                no_proxy: vec!["localhost".to_string(), ".corp".to_string()],
+                use_system: false,
+            }),
+        };
+
+        save_network_settings(&path, &settings).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("[proxy]"));
+
+        assert_eq!(load_network_settings(&path).unwrap(), settings);
+    }
+}
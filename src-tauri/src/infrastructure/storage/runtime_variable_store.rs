@@ -0,0 +1,61 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Runtime variables — values captured by extraction rules, per collection.
+//!
+//! Runtime variables live in memory for the session only, like history: an
+//! access token captured from a login response is never written to disk
+//! unless its rule targets the environment instead.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+/// Runtime variables, keyed by collection ID.
+static RUNTIME_VARIABLES: LazyLock<Mutex<HashMap<String, BTreeMap<String, String>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Runtime variables of a collection.
+#[must_use]
+pub fn runtime_variables(collection_id: &str) -> BTreeMap<String, String> {
+    RUNTIME_VARIABLES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(collection_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Set a runtime variable of a collection.
+pub fn set_runtime_variable(collection_id: &str, name: &str, value: &str) {
+    RUNTIME_VARIABLES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(collection_id.to_string())
+        .or_default()
+        .insert(name.to_string(), value.to_string());
+}
+
+/// Forget the runtime variables of a collection.
+pub fn clear_runtime_variables(collection_id: &str) {
+    RUNTIME_VARIABLES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(collection_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variables_are_kept_per_collection() {
+        set_runtime_variable("col_store_a", "token", "one");
+        set_runtime_variable("col_store_a", "token", "two");
+        set_runtime_variable("col_store_b", "token", "other");
+
+        assert_eq!(runtime_variables("col_store_a")["token"], "two");
+        clear_runtime_variables("col_store_a");
+        assert!(runtime_variables("col_store_a").is_empty());
+        assert_eq!(runtime_variables("col_store_b")["token"], "other");
+    }
+}
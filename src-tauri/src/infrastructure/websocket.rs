@@ -0,0 +1,1006 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+// WebSocket client sessions: upgrade handshake and frame exchange over a raw curl connection
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use curl::easy::Easy2;
+use rand::RngExt;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+use tracing::{debug, warn};
+
+use crate::domain::errors::AppError;
+use crate::domain::http::{HttpResponse, RequestParams, RequestTiming};
+use crate::domain::http_headers::HttpHeaders;
+use crate::domain::websocket::{
+    Frame, FrameDecoder, NORMAL_CLOSURE, Opcode, WebSocketDirection, WebSocketEvent,
+    WebSocketMessage, WebSocketSend, WebSocketSessionInfo, WebSocketTranscript, WebSocketUpdate,
+    accept_key, close_payload, encode_frame,
+};
+use crate::infrastructure::http::{RawConnection, open_connection};
+
+/// How long the session thread waits for a command before polling the socket again.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// How long to wait for the server to answer a close frame.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a single frame may take to write.
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest handshake response head accepted.
+const MAX_HANDSHAKE_SIZE: usize = 64 * 1024;
+
+/// Ended sessions kept around so their transcripts can still be read.
+const MAX_ENDED_SESSIONS: usize = 16;
+
+/// WebSocket sessions, open and recently ended, keyed by session ID.
+static SESSIONS: LazyLock<Mutex<HashMap<String, Session>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn sessions() -> std::sync::MutexGuard<'static, HashMap<String, Session>> {
+    SESSIONS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Registry entry of a session.
+struct Session {
+    info: WebSocketSessionInfo,
+    /// Commands for the session thread; `None` until the handshake succeeds
+    /// and once the session ended.
+    commands: Option<Sender<Command>>,
+    transcript: Arc<Mutex<WebSocketTranscript>>,
+    /// Whether the ID is reserved for a handshake still in progress.
+    connecting: bool,
+}
+
+impl Session {
+    /// Placeholder that reserves `session_id` while the handshake runs.
+    fn connecting(session_id: &str, url: &str) -> Self {
+        Self {
+            info: WebSocketSessionInfo {
+                session_id: session_id.to_string(),
+                url: url.to_string(),
+                protocol: None,
+                headers: HttpHeaders::default(),
+                opened_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            },
+            commands: None,
+            transcript: Arc::default(),
+            connecting: true,
+        }
+    }
+
+    /// Whether the ID is in use by an open or opening session.
+    const fn is_taken(&self) -> bool {
+        self.commands.is_some() || self.connecting
+    }
+}
+
+/// Request from the API to a session thread.
+enum Command {
+    Send(Frame),
+    Close(u16, String),
+}
+
+/// A session whose handshake succeeded.
+pub struct OpenedWebSocket {
+    /// The session.
+    pub info: WebSocketSessionInfo,
+    /// Resolves once the session ends, with a response carrying the handshake
+    /// headers and the full transcript, ready for history.
+    pub closed: oneshot::Receiver<HttpResponse>,
+}
+
+/// Open a WebSocket session to `params.url` (`ws://` or `wss://`).
+///
+/// The handshake sends `params.headers` and offers `subprotocols`; TLS and
+/// proxy settings apply as for HTTP requests. Once the server accepts, the
+/// session runs on its own thread: every frame sent or received goes out on
+/// `events` and into the transcript, pings are answered automatically, and
+/// the session ends when either side closes or the connection drops.
+///
+/// # Errors
+///
+/// Returns a JSON-serialized `AppError` string if the URL is invalid, the
+/// session ID is taken, the connection fails, or the server rejects the
+/// upgrade (`WEBSOCKET_HANDSHAKE_FAILED`).
+pub async fn connect_websocket(
+    params: RequestParams,
+    subprotocols: Vec<String>,
+    session_id: String,
+    events: UnboundedSender<WebSocketEvent>,
+) -> Result<OpenedWebSocket, String> {
+    let to_json = |e: AppError| serde_json::to_string(&e).unwrap_or(e.message);
+    // Reserve the ID for the handshake, so a concurrent connect cannot take it
+    let previous = {
+        let mut registry = sessions();
+        if registry.get(&session_id).is_some_and(Session::is_taken) {
+            return Err(to_json(AppError::new(
+                session_id.clone(),
+                "WEBSOCKET_SESSION_EXISTS",
+                format!("WebSocket session already open: {session_id}"),
+            )));
+        }
+        registry.insert(
+            session_id.clone(),
+            Session::connecting(&session_id, &params.url),
+        )
+    };
+
+    let corr_id = session_id.clone();
+    let (easy, handshake) = tokio::task::spawn_blocking(move || {
+        let target = HandshakeTarget::parse(&params.url, &corr_id)?;
+        let connection_params = RequestParams {
+            url: target.connect_url.clone(),
+            ..params.clone()
+        };
+        let mut easy = open_connection(&connection_params, &corr_id)?;
+        let handshake = perform_handshake(&mut easy, &params, &target, &subprotocols)
+            .map_err(|e| e.into_app_error(&corr_id))?;
+        Ok((easy, handshake))
+    })
+    .await
+    .unwrap_or_else(|e| {
+        Err(AppError::new(
+            session_id.clone(),
+            "TASK_EXECUTION_ERROR",
+            format!("Task execution failed: {e}"),
+        ))
+    })
+    .map_err(|e| {
+        // Release the reservation, bringing back the ended session it replaced
+        previous.map_or_else(
+            || sessions().remove(&session_id),
+            |previous| sessions().insert(session_id.clone(), previous),
+        );
+        to_json(e)
+    })?;
+
+    let info = WebSocketSessionInfo {
+        session_id: session_id.clone(),
+        url: handshake.url.clone(),
+        protocol: handshake.protocol.clone(),
+        headers: handshake.headers.clone(),
+        opened_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    };
+    let transcript = Arc::new(Mutex::new(WebSocketTranscript {
+        protocol: handshake.protocol.clone(),
+        ..WebSocketTranscript::default()
+    }));
+    let (command_tx, command_rx) = std::sync::mpsc::channel();
+    {
+        let mut registry = sessions();
+        prune_ended_sessions(&mut registry);
+        registry.insert(
+            session_id.clone(),
+            Session {
+                info: info.clone(),
+                commands: Some(command_tx),
+                transcript: Arc::clone(&transcript),
+                connecting: false,
+            },
+        );
+    }
+    let _ = events.send(WebSocketEvent {
+        session_id: session_id.clone(),
+        update: WebSocketUpdate::Opened {
+            session: info.clone(),
+        },
+    });
+
+    let (closed_tx, closed) = oneshot::channel();
+    let mut decoder = FrameDecoder::new();
+    let buffered = decoder.feed(&handshake.leftover);
+    let runner = SessionRunner {
+        easy,
+        decoder,
+        transcript,
+        events,
+        session_id,
+        started: Instant::now(),
+        closing_since: None,
+        close_sent: None,
+    };
+    std::thread::spawn(move || {
+        let response = runner.run(buffered, &command_rx, handshake.headers);
+        let _ = closed_tx.send(response);
+    });
+    Ok(OpenedWebSocket { info, closed })
+}
+
+/// Queue a message on an open session.
+///
+/// # Errors
+///
+/// Returns an error if the message is invalid or the session is not open.
+pub fn send_websocket_message(session_id: &str, message: &WebSocketSend) -> Result<(), String> {
+    let frame = message.to_frame()?;
+    send_command(session_id, Command::Send(frame))
+}
+
+/// Start the close handshake of an open session.
+///
+/// `code` defaults to 1000 (normal closure). The session ends once the server
+/// answers, or after a few seconds if it does not.
+///
+/// # Errors
+///
+/// Returns an error if the code may not be sent by a client, the reason is
+/// longer than 123 bytes, or the session is not open.
+pub fn close_websocket(session_id: &str, code: Option<u16>, reason: &str) -> Result<(), String> {
+    let code = code.unwrap_or(NORMAL_CLOSURE);
+    if !matches!(code, 1000..=1003 | 1007..=1011 | 3000..=4999) {
+        return Err(format!("Close code {code} cannot be sent by a client"));
+    }
+    if reason.len() > 123 {
+        return Err("Close reason is limited to 123 bytes".to_string());
+    }
+    send_command(session_id, Command::Close(code, reason.to_string()))
+}
+
+fn send_command(session_id: &str, command: Command) -> Result<(), String> {
+    let commands = sessions()
+        .get(session_id)
+        .ok_or_else(|| format!("WebSocket session not found: {session_id}"))?
+        .commands
+        .clone()
+        .ok_or_else(|| format!("WebSocket session is closed: {session_id}"))?;
+    commands
+        .send(command)
+        .map_err(|_| format!("WebSocket session is closed: {session_id}"))
+}
+
+/// Transcript of an open or recently ended session.
+#[must_use]
+pub fn websocket_transcript(session_id: &str) -> Option<WebSocketTranscript> {
+    sessions().get(session_id).map(|session| {
+        session
+            .transcript
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    })
+}
+
+/// Whether a session is still open.
+#[must_use]
+pub fn is_websocket_open(session_id: &str) -> bool {
+    sessions()
+        .get(session_id)
+        .is_some_and(|session| session.commands.is_some())
+}
+
+/// Open sessions, oldest first.
+#[must_use]
+pub fn list_websocket_sessions() -> Vec<WebSocketSessionInfo> {
+    let mut open: Vec<_> = sessions()
+        .values()
+        .filter(|session| session.commands.is_some())
+        .map(|session| session.info.clone())
+        .collect();
+    open.sort_by(|a, b| a.opened_at.cmp(&b.opened_at));
+    open
+}
+
+/// Drop the oldest ended sessions beyond [`MAX_ENDED_SESSIONS`].
+fn prune_ended_sessions(registry: &mut HashMap<String, Session>) {
+    let mut ended: Vec<(String, String)> = registry
+        .iter()
+        .filter(|(_, session)| !session.is_taken())
+        .map(|(id, session)| (session.info.opened_at.clone(), id.clone()))
+        .collect();
+    if ended.len() < MAX_ENDED_SESSIONS {
+        return;
+    }
+    ended.sort();
+    for (_, id) in &ended[..=ended.len() - MAX_ENDED_SESSIONS] {
+        registry.remove(id);
+    }
+}
+
+/// Where the handshake connects and what it asks for.
+struct HandshakeTarget {
+    /// `http(s)://` URL curl connects to.
+    connect_url: String,
+    /// The `ws(s)://` URL as given.
+    url: String,
+    /// `Host` header value.
+    host: String,
+    /// Path and query of the request line.
+    path: String,
+}
+
+impl HandshakeTarget {
+    fn parse(raw: &str, corr_id: &str) -> Result<Self, AppError> {
+        let invalid = |message: String| AppError::new(corr_id.to_string(), "INVALID_URL", message);
+        let url = url::Url::parse(raw).map_err(|e| invalid(format!("Invalid URL '{raw}': {e}")))?;
+        let scheme = match url.scheme() {
+            "ws" | "http" => "http",
+            "wss" | "https" => "https",
+            other => {
+                return Err(invalid(format!(
+                    "WebSocket URLs must use ws:// or wss://, not {other}://"
+                )));
+            }
+        };
+        let host = url
+            .host_str()
+            .ok_or_else(|| invalid(format!("Invalid URL '{raw}': missing host")))?;
+        let host = url
+            .port()
+            .map_or_else(|| host.to_string(), |port| format!("{host}:{port}"));
+        let path = url.query().map_or_else(
+            || url.path().to_string(),
+            |query| format!("{}?{query}", url.path()),
+        );
+        Ok(Self {
+            connect_url: format!("{scheme}://{host}/"),
+            url: raw.to_string(),
+            host,
+            path,
+        })
+    }
+}
+
+/// An accepted upgrade.
+struct Handshake {
+    url: String,
+    headers: HttpHeaders,
+    protocol: Option<String>,
+    /// Bytes read past the response head: the first frames, if the server
+    /// was quick.
+    leftover: Vec<u8>,
+}
+
+/// Why a handshake failed.
+enum HandshakeError {
+    /// The connection broke or timed out.
+    Transport(String),
+    /// The server answered, but not with a valid upgrade.
+    Rejected {
+        message: String,
+        status: Option<u16>,
+    },
+}
+
+impl HandshakeError {
+    fn rejected(message: impl Into<String>) -> Self {
+        Self::Rejected {
+            message: message.into(),
+            status: None,
+        }
+    }
+
+    fn into_app_error(self, corr_id: &str) -> AppError {
+        match self {
+            Self::Transport(message) => {
+                AppError::new(corr_id.to_string(), "HTTP_REQUEST_FAILED", message)
+            }
+            Self::Rejected { message, status } => AppError::with_details(
+                corr_id.to_string(),
+                "WEBSOCKET_HANDSHAKE_FAILED",
+                message,
+                serde_json::json!({ "status": status }),
+            ),
+        }
+    }
+}
+
+/// Send the upgrade request and check the server's answer.
+fn perform_handshake(
+    easy: &mut Easy2<RawConnection>,
+    params: &RequestParams,
+    target: &HandshakeTarget,
+    subprotocols: &[String],
+) -> Result<Handshake, HandshakeError> {
+    let deadline = Instant::now() + Duration::from_millis(params.timeout_ms);
+    let key = BASE64.encode(rand::rng().random::<[u8; 16]>());
+    let mut request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\n",
+        target.path, target.host
+    );
+    if !subprotocols.is_empty() {
+        let _ = write!(
+            request,
+            "Sec-WebSocket-Protocol: {}\r\n",
+            subprotocols.join(", ")
+        );
+    }
+    let reserved = [
+        "host",
+        "upgrade",
+        "connection",
+        "sec-websocket-key",
+        "sec-websocket-version",
+    ];
+    for header in &params.headers {
+        let name = header.name.to_ascii_lowercase();
+        if reserved.contains(&name.as_str())
+            || (name == "sec-websocket-protocol" && !subprotocols.is_empty())
+        {
+            continue;
+        }
+        let _ = write!(request, "{}: {}\r\n", header.name, header.value);
+    }
+    request.push_str("\r\n");
+    write_all(easy, request.as_bytes(), deadline).map_err(HandshakeError::Transport)?;
+
+    let (head, leftover) = read_response_head(easy, deadline)?;
+    let head = String::from_utf8_lossy(&head).into_owned();
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or_default();
+    let mut parts = status_line.splitn(3, ' ');
+    let _version = parts.next();
+    let status = parts.next().and_then(|code| code.parse::<u16>().ok());
+    let status_text = parts.next().unwrap_or_default().to_string();
+    let mut headers = HttpHeaders::new();
+    for line in lines.filter(|line| !line.is_empty()) {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.append(name.trim(), value.trim());
+        }
+    }
+
+    if status != Some(101) {
+        return Err(HandshakeError::Rejected {
+            message: format!(
+                "Server answered '{}' instead of switching protocols",
+                status_line.trim()
+            ),
+            status,
+        });
+    }
+    if !headers
+        .get("upgrade")
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+    {
+        return Err(HandshakeError::rejected(
+            "Server did not upgrade the connection to WebSocket",
+        ));
+    }
+    if headers.get("sec-websocket-accept") != Some(accept_key(&key).as_str()) {
+        return Err(HandshakeError::rejected(
+            "Server sent an invalid Sec-WebSocket-Accept key",
+        ));
+    }
+    let protocol = headers
+        .get("sec-websocket-protocol")
+        .map(ToString::to_string);
+    if let Some(protocol) = &protocol
+        && !subprotocols.contains(protocol)
+    {
+        return Err(HandshakeError::rejected(format!(
+            "Server selected subprotocol '{protocol}', which was not offered"
+        )));
+    }
+    debug!(url = %target.url, status_text = %status_text, protocol = ?protocol, "WebSocket handshake accepted");
+    Ok(Handshake {
+        url: target.url.clone(),
+        headers,
+        protocol,
+        leftover,
+    })
+}
+
+/// Read up to the blank line ending the response head. Returns the head and
+/// whatever was read past it.
+fn read_response_head(
+    easy: &mut Easy2<RawConnection>,
+    deadline: Instant,
+) -> Result<(Vec<u8>, Vec<u8>), HandshakeError> {
+    let mut received = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(end) = received.windows(4).position(|w| w == b"\r\n\r\n") {
+            let leftover = received.split_off(end + 4);
+            return Ok((received, leftover));
+        }
+        if received.len() > MAX_HANDSHAKE_SIZE {
+            return Err(HandshakeError::rejected("Handshake response is too large"));
+        }
+        match easy.recv(&mut buf) {
+            Ok(0) => {
+                return Err(HandshakeError::Transport(
+                    "Connection closed during the WebSocket handshake".to_string(),
+                ));
+            }
+            Ok(n) => received.extend_from_slice(&buf[..n]),
+            Err(e) if e.is_again() => {
+                if Instant::now() >= deadline {
+                    return Err(HandshakeError::Transport(
+                        "Timed out waiting for the WebSocket handshake".to_string(),
+                    ));
+                }
+                std::thread::sleep(POLL_INTERVAL);
+            }
+            Err(e) => return Err(HandshakeError::Transport(format!("Handshake failed: {e}"))),
+        }
+    }
+}
+
+/// Write all of `data`, waiting while the socket is busy.
+fn write_all(
+    easy: &mut Easy2<RawConnection>,
+    mut data: &[u8],
+    deadline: Instant,
+) -> Result<(), String> {
+    while !data.is_empty() {
+        match easy.send(data) {
+            Ok(n) => data = &data[n..],
+            Err(e) if e.is_again() => {
+                if Instant::now() >= deadline {
+                    return Err("Timed out writing to the connection".to_string());
+                }
+                std::thread::sleep(POLL_INTERVAL);
+            }
+            Err(e) => return Err(format!("Failed to write to the connection: {e}")),
+        }
+    }
+    Ok(())
+}
+
+/// How a session ended.
+enum SessionEnd {
+    /// Close handshake completed, or the connection dropped (`None` code).
+    Closed(Option<u16>, String),
+    /// Protocol or transport error.
+    Failed(String),
+}
+
+/// State of a running session, owned by its thread.
+struct SessionRunner {
+    easy: Easy2<RawConnection>,
+    decoder: FrameDecoder,
+    transcript: Arc<Mutex<WebSocketTranscript>>,
+    events: UnboundedSender<WebSocketEvent>,
+    session_id: String,
+    started: Instant,
+    /// When the client sent its close frame.
+    closing_since: Option<Instant>,
+    /// Code and reason of the close frame the client sent.
+    close_sent: Option<(u16, String)>,
+}
+
+impl SessionRunner {
+    /// Exchange frames until the session ends, then unregister it and build
+    /// its history response. `buffered` holds frames read with the handshake.
+    fn run(
+        mut self,
+        buffered: Result<Vec<Frame>, String>,
+        commands: &Receiver<Command>,
+        headers: HttpHeaders,
+    ) -> HttpResponse {
+        let end = buffered
+            .and_then(|frames| self.handle_frames(frames))
+            .unwrap_or_else(|e| Some(SessionEnd::Failed(e)))
+            .unwrap_or_else(|| self.exchange(commands));
+
+        if let Some(session) = sessions().get_mut(&self.session_id) {
+            session.commands = None;
+        }
+        let message_count = {
+            let mut transcript = self
+                .transcript
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if let SessionEnd::Closed(code, reason) = &end {
+                transcript.close_code = *code;
+                transcript.close_reason = Some(reason.clone());
+            }
+            transcript.messages.len() as u64
+        };
+        let update = match end {
+            SessionEnd::Closed(code, reason) => WebSocketUpdate::Closed {
+                code,
+                reason,
+                message_count,
+            },
+            SessionEnd::Failed(error) => {
+                warn!(session_id = %self.session_id, error = %error, "WebSocket session failed");
+                WebSocketUpdate::Failed { error }
+            }
+        };
+        self.publish(update);
+
+        let transcript = self
+            .transcript
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        HttpResponse {
+            status: 101,
+            status_text: "Switching Protocols".to_string(),
+            headers,
+            timing: RequestTiming {
+                total_ms: self.elapsed_ms(),
+                ..RequestTiming::default()
+            },
+            websocket: Some(transcript),
+            ..HttpResponse::default()
+        }
+    }
+
+    /// Alternate between draining the socket and waiting briefly for commands.
+    fn exchange(&mut self, commands: &Receiver<Command>) -> SessionEnd {
+        let mut buf = vec![0u8; 16 * 1024];
+        loop {
+            loop {
+                match self.easy.recv(&mut buf) {
+                    Ok(0) => {
+                        return match self.close_sent.take() {
+                            Some((code, reason)) => SessionEnd::Closed(Some(code), reason),
+                            None => SessionEnd::Closed(None, "Connection closed".to_string()),
+                        };
+                    }
+                    Ok(n) => {
+                        let frames = match self.decoder.feed(&buf[..n]) {
+                            Ok(frames) => frames,
+                            Err(e) => return SessionEnd::Failed(e),
+                        };
+                        match self.handle_frames(frames) {
+                            Ok(Some(end)) => return end,
+                            Ok(None) => {}
+                            Err(e) => return SessionEnd::Failed(e),
+                        }
+                    }
+                    Err(e) if e.is_again() => break,
+                    Err(e) => return SessionEnd::Failed(format!("Connection failed: {e}")),
+                }
+            }
+
+            if let Some(since) = self.closing_since
+                && since.elapsed() > CLOSE_TIMEOUT
+            {
+                let (code, _) = self.close_sent.take().unwrap_or_default();
+                return SessionEnd::Closed(
+                    Some(code),
+                    "Server did not answer the close frame".to_string(),
+                );
+            }
+
+            let command = match commands.recv_timeout(POLL_INTERVAL) {
+                Ok(command) => command,
+                Err(RecvTimeoutError::Timeout) => continue,
+                // Every sender is gone only if the registry entry was dropped
+                Err(RecvTimeoutError::Disconnected) => Command::Close(1001, String::new()),
+            };
+            if self.closing_since.is_some() {
+                continue;
+            }
+            let result = match command {
+                Command::Send(frame) => self.send(&frame),
+                Command::Close(code, reason) => {
+                    let frame = Frame {
+                        opcode: Opcode::Close,
+                        payload: close_payload(code, &reason),
+                    };
+                    self.closing_since = Some(Instant::now());
+                    self.close_sent = Some((code, reason));
+                    self.send(&frame)
+                }
+            };
+            if let Err(e) = result {
+                return SessionEnd::Failed(e);
+            }
+        }
+    }
+
+    /// Record received frames, answer pings and detect the end of the session.
+    fn handle_frames(&mut self, frames: Vec<Frame>) -> Result<Option<SessionEnd>, String> {
+        for frame in frames {
+            self.record(&frame, WebSocketDirection::Received);
+            match frame.opcode {
+                Opcode::Ping => {
+                    self.send(&Frame {
+                        opcode: Opcode::Pong,
+                        payload: frame.payload,
+                    })?;
+                }
+                Opcode::Close => {
+                    let (code, reason) =
+                        crate::domain::websocket::parse_close_payload(&frame.payload);
+                    if self.closing_since.is_none() {
+                        // Echo the server's close to complete the handshake
+                        let payload = code.map_or_else(Vec::new, |code| close_payload(code, ""));
+                        self.send(&Frame {
+                            opcode: Opcode::Close,
+                            payload,
+                        })?;
+                    }
+                    return Ok(Some(SessionEnd::Closed(code, reason)));
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// Write a frame, masked as clients must, and record it.
+    fn send(&mut self, frame: &Frame) -> Result<(), String> {
+        let mask = rand::rng().random::<[u8; 4]>();
+        let bytes = encode_frame(frame.opcode, &frame.payload, Some(mask));
+        write_all(&mut self.easy, &bytes, Instant::now() + SEND_TIMEOUT)?;
+        self.record(frame, WebSocketDirection::Sent);
+        Ok(())
+    }
+
+    fn record(&self, frame: &Frame, direction: WebSocketDirection) {
+        let message = {
+            let mut transcript = self
+                .transcript
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let message = WebSocketMessage::from_frame(
+                frame,
+                direction,
+                transcript.messages.len() as u64,
+                self.elapsed_ms(),
+            );
+            transcript.messages.push(message.clone());
+            message
+        };
+        self.publish(WebSocketUpdate::Message { message });
+    }
+
+    fn publish(&self, update: WebSocketUpdate) {
+        let _ = self.events.send(WebSocketEvent {
+            session_id: self.session_id.clone(),
+            update,
+        });
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::websocket::{WebSocketMessageKind, parse_close_payload};
+    use axum::Router;
+    use axum::body::Bytes;
+    use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+    use axum::http::{HeaderMap, StatusCode, Uri};
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+
+    /// Start an axum WebSocket echo server: text and binary messages come
+    /// back as they are, and a close is answered. Right after the handshake
+    /// the server pings the client. With `reject`, the upgrade is refused
+    /// with a 403. The URI and headers of the upgrade request are sent on the
+    /// returned channel.
+    async fn start_echo_server(
+        reject: bool,
+    ) -> Option<(
+        String,
+        tokio::sync::mpsc::UnboundedReceiver<(Uri, HeaderMap)>,
+    )> {
+        let listener = match tokio::net::TcpListener::bind("127.0.0.1:0").await {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                eprintln!("[TEST] Skipping WebSocket server bind: {err}");
+                return None;
+            }
+            Err(err) => panic!("bind test server: {err}"),
+        };
+        let addr = listener.local_addr().expect("resolve test server addr");
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let app = Router::new().route(
+            "/socket",
+            get(
+                move |ws: WebSocketUpgrade, uri: Uri, headers: HeaderMap| async move {
+                    let _ = tx.send((uri, headers));
+                    if reject {
+                        return StatusCode::FORBIDDEN.into_response();
+                    }
+                    ws.protocols(["chat"]).on_upgrade(echo).into_response()
+                },
+            ),
+        );
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        Some((format!("ws://{addr}/socket?room=1"), rx))
+    }
+
+    async fn echo(mut socket: WebSocket) {
+        if socket
+            .send(Message::Ping(Bytes::from_static(b"hb")))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        // Pings are answered and closes acknowledged by the server itself
+        while let Some(Ok(message)) = socket.recv().await {
+            if matches!(message, Message::Text(_) | Message::Binary(_))
+                && socket.send(message).await.is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    fn params(url: &str) -> RequestParams {
+        let mut headers = HttpHeaders::new();
+        headers.append("X-Token", "secret");
+        RequestParams {
+            url: url.to_string(),
+            method: "GET".to_string(),
+            headers,
+            timeout_ms: 5_000,
+            ..Default::default()
+        }
+    }
+
+    async fn next_message(
+        rx: &mut tokio::sync::mpsc::UnboundedReceiver<WebSocketEvent>,
+        direction: WebSocketDirection,
+    ) -> WebSocketMessage {
+        loop {
+            let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("timed out waiting for a message")
+                .expect("event channel closed");
+            if let WebSocketUpdate::Message { message } = event.update
+                && message.direction == direction
+                && message.kind != WebSocketMessageKind::Pong
+                && message.kind != WebSocketMessageKind::Ping
+            {
+                return message;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_echo_session_records_transcript() {
+        let Some((url, mut server)) = start_echo_server(false).await else {
+            return;
+        };
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let opened = connect_websocket(
+            params(&url),
+            vec!["chat".to_string(), "superchat".to_string()],
+            "ws_echo".to_string(),
+            tx,
+        )
+        .await
+        .unwrap();
+        assert_eq!(opened.info.protocol.as_deref(), Some("chat"));
+        assert!(is_websocket_open("ws_echo"));
+        assert!(
+            list_websocket_sessions()
+                .iter()
+                .any(|session| session.session_id == "ws_echo")
+        );
+
+        send_websocket_message(
+            "ws_echo",
+            &WebSocketSend {
+                kind: WebSocketMessageKind::Text,
+                data: "hello".to_string(),
+            },
+        )
+        .unwrap();
+        let echoed = next_message(&mut rx, WebSocketDirection::Received).await;
+        assert_eq!(
+            (echoed.kind, echoed.data.as_str()),
+            (WebSocketMessageKind::Text, "hello")
+        );
+
+        send_websocket_message(
+            "ws_echo",
+            &WebSocketSend {
+                kind: WebSocketMessageKind::Binary,
+                data: "AP8=".to_string(),
+            },
+        )
+        .unwrap();
+        let echoed = next_message(&mut rx, WebSocketDirection::Received).await;
+        assert_eq!(
+            (echoed.kind, echoed.data.as_str()),
+            (WebSocketMessageKind::Binary, "AP8=")
+        );
+
+        close_websocket("ws_echo", Some(4000), "done").unwrap();
+        let response = tokio::time::timeout(Duration::from_secs(10), opened.closed)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!is_websocket_open("ws_echo"));
+        assert!(
+            send_websocket_message(
+                "ws_echo",
+                &WebSocketSend {
+                    kind: WebSocketMessageKind::Text,
+                    data: "late".to_string(),
+                }
+            )
+            .is_err()
+        );
+
+        assert_eq!(response.status, 101);
+        let transcript = response.websocket.unwrap();
+        assert_eq!(transcript.close_code, Some(4000));
+        assert_eq!(transcript.protocol.as_deref(), Some("chat"));
+        let kinds: Vec<_> = transcript
+            .messages
+            .iter()
+            .map(|m| (m.direction, m.kind))
+            .collect();
+        // The server's ping was answered with a pong before anything else
+        assert_eq!(
+            kinds[..2],
+            [
+                (WebSocketDirection::Received, WebSocketMessageKind::Ping),
+                (WebSocketDirection::Sent, WebSocketMessageKind::Pong),
+            ]
+        );
+        assert!(kinds.contains(&(WebSocketDirection::Received, WebSocketMessageKind::Close)));
+        assert_eq!(websocket_transcript("ws_echo"), Some(transcript));
+
+        let (uri, headers) = server.recv().await.unwrap();
+        assert_eq!(uri, "/socket?room=1");
+        assert_eq!(headers["x-token"], "secret");
+        assert_eq!(headers["sec-websocket-protocol"], "chat, superchat");
+    }
+
+    #[tokio::test]
+    async fn test_rejected_upgrade_is_a_handshake_error() {
+        let Some((url, _server)) = start_echo_server(true).await else {
+            return;
+        };
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let Err(error) =
+            connect_websocket(params(&url), Vec::new(), "ws_rejected".to_string(), tx).await
+        else {
+            panic!("handshake should fail");
+        };
+
+        let error: AppError = serde_json::from_str(&error).unwrap();
+        assert_eq!(error.code, "WEBSOCKET_HANDSHAKE_FAILED");
+        assert!(error.message.contains("403"), "{}", error.message);
+        assert!(!is_websocket_open("ws_rejected"));
+        // The reservation made for the handshake is released
+        assert!(websocket_transcript("ws_rejected").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_scheme_and_close_codes() {
+        for _ in 0..2 {
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            let Err(error) = connect_websocket(
+                params("ftp://127.0.0.1/"),
+                Vec::new(),
+                "ws_scheme".to_string(),
+                tx,
+            )
+            .await
+            else {
+                panic!("ftp is not a WebSocket scheme");
+            };
+            // A failed attempt does not leave the session ID reserved
+            assert!(error.contains("INVALID_URL"), "{error}");
+        }
+
+        assert!(close_websocket("ws_missing", Some(1005), "").is_err());
+        assert!(
+            close_websocket("ws_missing", None, "")
+                .unwrap_err()
+                .contains("not found")
+        );
+        assert_eq!(
+            parse_close_payload(&close_payload(1000, "")),
+            (Some(1000), String::new())
+        );
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2026 BaseState LLC
+// SPDX-License-Identifier: MIT
+
+//! Runi - An intelligent API development partner.
+//!
+//! This crate provides the Tauri backend for the runi desktop application,
+//! handling HTTP request execution, command handlers, and application state management.
+//! The `runi` binary runs the desktop app; `runi-cli` runs collections headless
+//! and builds without the `desktop` feature, leaving out everything that needs Tauri.
+
+/// Application services orchestrating the domain.
+pub mod application;
+#[cfg(feature = "desktop")]
+mod desktop;
+/// Domain models and business logic.
+pub mod domain;
+/// External interfaces: Tauri commands, networking and storage.
+pub mod infrastructure;
+
+#[cfg(feature = "desktop")]
+pub use desktop::run;
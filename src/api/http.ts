@@ -18,6 +18,8 @@ import { generateCorrelationId, getCorrelationId, withCorrelationId } from '@/ut
 import { fromBackendError, isAppError, type AppError } from '@/types/errors';
 import { getConsoleService } from '@/services/console-service';
 import type { HttpResponse, RequestParams } from '@/types/http';
+import type { CollectionRequestExecution } from '@/types/generated/CollectionRequestExecution';
+import type { RequestDraft } from '@/types/generated/RequestDraft';
 
 /**
  * Extract error message from various error types.
@@ -46,6 +48,97 @@ function isTauri(): boolean {
   return typeof window !== 'undefined' && '__TAURI__' in window;
 }
 
+/**
+ * Convert an error from a Rust command into an Error carrying an AppError.
+ *
+ * Tauri serializes AppError, so it might be a string (JSON) or an object;
+ * plain strings become `HTTP_REQUEST_FAILED` errors. The error is logged to
+ * the console service once.
+ */
+function toBackendError(error: unknown, correlationId: string): Error {
+  let appError: AppError;
+
+  // Check if it's already an AppError
+  if (isAppError(error)) {
+    appError = error;
+  } else if (typeof error === 'string') {
+    // Try to parse JSON string
+    try {
+      const parsed = JSON.parse(error) as {
+        correlation_id?: string;
+        code?: string;
+        message?: string;
+        details?: unknown;
+      };
+      if (typeof parsed.correlation_id === 'string' && parsed.correlation_id.length > 0) {
+        appError = fromBackendError({
+          correlation_id: parsed.correlation_id,
+          code: typeof parsed.code === 'string' ? parsed.code : 'UNKNOWN_ERROR',
+          message: extractErrorMessage(parsed.message, error),
+          details: parsed.details,
+        });
+      } else {
+        throw new Error('Not a valid AppError JSON');
+      }
+    } catch (parseError: unknown) {
+      // Not JSON or not AppError - fallback
+      const errorMessage = extractErrorMessage(parseError, error);
+      appError = {
+        correlationId,
+        code: 'HTTP_REQUEST_FAILED',
+        message: errorMessage,
+        source: 'backend',
+        timestamp: Date.now(),
+      };
+    }
+  } else if (typeof error === 'object' && error !== null) {
+    // Try to parse as backend error object (snake_case)
+    const err = error as Record<string, unknown>;
+    if (typeof err.correlation_id === 'string') {
+      appError = fromBackendError({
+        correlation_id: err.correlation_id,
+        code: typeof err.code === 'string' ? err.code : 'UNKNOWN_ERROR',
+        message: extractErrorMessage(err.message, error),
+        details: err.details,
+      });
+    } else {
+      // Fallback: create frontend error with correlation ID
+      const errorMessage = extractErrorMessage(error);
+      appError = {
+        correlationId,
+        code: 'HTTP_REQUEST_FAILED',
+        message: errorMessage,
+        source: 'backend',
+        timestamp: Date.now(),
+      };
+    }
+  } else {
+    // Fallback: create frontend error with correlation ID
+    const errorMessage = extractErrorMessage(error);
+    appError = {
+      correlationId,
+      code: 'HTTP_REQUEST_FAILED',
+      message: errorMessage,
+      source: 'backend',
+      timestamp: Date.now(),
+    };
+  }
+
+  // Log error to console service once (do not call console.error here;
+  // the console service intercepts it and would add a duplicate log)
+  getConsoleService().addLog({
+    level: 'error',
+    message: `[${appError.code}] ${appError.message}`,
+    args: [error],
+    correlationId: appError.correlationId,
+  });
+
+  // Convert AppError to Error for throwing (ESLint requires Error objects)
+  const errorObj = new Error(appError.message);
+  (errorObj as Error & { appError: AppError }).appError = appError;
+  return errorObj;
+}
+
 /**
  * Execute an HTTP request through the Rust backend.
  *
@@ -57,12 +150,15 @@ function isTauri(): boolean {
  * Errors from Rust are converted to AppError with correlation IDs for tracing.
  *
  * @param params - The request parameters
+ * @param correlationId - Correlation ID for tracing and cancellation (default: the
+ *   one from context, or a new one)
  * @returns Promise resolving to the HTTP response
  * @throws AppError if the request fails (includes correlation ID for tracing)
  */
-export async function executeRequest(params: RequestParams): Promise<HttpResponse> {
-  // Generate correlation ID for this request (or use existing one from context)
-  const correlationId = getCorrelationId() ?? generateCorrelationId();
+export async function executeRequest(
+  params: RequestParams,
+  correlationId: string = getCorrelationId() ?? generateCorrelationId()
+): Promise<HttpResponse> {
   const isTauriEnv = isTauri();
   const consoleService = getConsoleService();
 
@@ -94,93 +190,149 @@ export async function executeRequest(params: RequestParams): Promise<HttpRespons
     try {
       const result = await invoke<HttpResponse>('execute_request', {
         params,
-        correlation_id: correlationId,
+        correlationId,
       });
       return result;
     } catch (error) {
-      // Convert Rust AppError to frontend AppError
-      // Tauri serializes AppError, so it might be a string (JSON) or an object
-      let appError: AppError;
-
-      // Check if it's already an AppError
-      if (isAppError(error)) {
-        appError = error;
-      } else if (typeof error === 'string') {
-        // Try to parse JSON string
-        try {
-          const parsed = JSON.parse(error) as {
-            correlation_id?: string;
-            code?: string;
-            message?: string;
-            details?: unknown;
-          };
-          if (typeof parsed.correlation_id === 'string' && parsed.correlation_id.length > 0) {
-            appError = fromBackendError({
-              correlation_id: parsed.correlation_id,
-              code: typeof parsed.code === 'string' ? parsed.code : 'UNKNOWN_ERROR',
-              message: extractErrorMessage(parsed.message, error),
-              details: parsed.details,
-            });
-          } else {
-            throw new Error('Not a valid AppError JSON');
-          }
-        } catch (parseError: unknown) {
-          // Not JSON or not AppError - fallback
-          const errorMessage = extractErrorMessage(parseError, error);
-          appError = {
-            correlationId,
-            code: 'HTTP_REQUEST_FAILED',
-            message: errorMessage,
-            source: 'backend',
-            timestamp: Date.now(),
-          };
-        }
-      } else if (typeof error === 'object' && error !== null) {
-        // Try to parse as backend error object (snake_case)
-        const err = error as Record<string, unknown>;
-        if (typeof err.correlation_id === 'string') {
-          appError = fromBackendError({
-            correlation_id: err.correlation_id,
-            code: typeof err.code === 'string' ? err.code : 'UNKNOWN_ERROR',
-            message: extractErrorMessage(err.message, error),
-            details: err.details,
-          });
-        } else {
-          // Fallback: create frontend error with correlation ID
-          const errorMessage = extractErrorMessage(error);
-          appError = {
-            correlationId,
-            code: 'HTTP_REQUEST_FAILED',
-            message: errorMessage,
-            source: 'backend',
-            timestamp: Date.now(),
-          };
-        }
-      } else {
-        // Fallback: create frontend error with correlation ID
-        const errorMessage = extractErrorMessage(error);
-        appError = {
-          correlationId,
-          code: 'HTTP_REQUEST_FAILED',
-          message: errorMessage,
-          source: 'backend',
-          timestamp: Date.now(),
-        };
-      }
+      throw toBackendError(error, correlationId);
+    }
+  });
+}
 
-      // Log error to console service once (do not call console.error here;
-      // the console service intercepts it and would add a duplicate log)
-      consoleService.addLog({
-        level: 'error',
-        message: `[${appError.code}] ${appError.message}`,
-        args: [error],
-        correlationId: appError.correlationId,
-      });
+/**
+ * Cancel an in-flight HTTP request by its correlation ID.
+ *
+ * The Rust backend aborts the curl transfer, and the pending
+ * `executeRequest` call rejects with a `REQUEST_CANCELLED` AppError.
+ *
+ * @param correlationId - Correlation ID the request was started with
+ * @returns Whether a running request was found and cancelled
+ */
+export async function cancelRequest(correlationId: string): Promise<boolean> {
+  if (!isTauri()) {
+    return false;
+  }
+  return invoke<boolean>('cancel_request', { correlationId });
+}
 
-      // Convert AppError to Error for throwing (ESLint requires Error objects)
-      const errorObj = new Error(appError.message);
-      (errorObj as Error & { appError: AppError }).appError = appError;
-      throw errorObj;
+/**
+ * Execute a request in streaming mode (SSE, NDJSON, token streams).
+ *
+ * Chunks are delivered live as `http:stream` events tagged with the given
+ * correlation ID; the promise resolves with the response and its decoded
+ * transcript once the stream closes. The body itself is not buffered, so the
+ * response's `body` is empty. The backend saves it to history.
+ *
+ * @param params - The request parameters
+ * @param correlationId - Correlation ID used to match `http:stream` events and to cancel
+ * @returns Promise resolving to the complete HTTP response
+ */
+export async function executeStreamingRequest(
+  params: RequestParams,
+  correlationId: string = generateCorrelationId()
+): Promise<HttpResponse> {
+  return withCorrelationId(correlationId, async () => {
+    try {
+      return await invoke<HttpResponse>('execute_streaming_request', { params, correlationId });
+    } catch (error) {
+      throw toBackendError(error, correlationId);
     }
   });
 }
+
+/**
+ * Resolve a saved collection request into the parameters it is sent with.
+ *
+ * Uses the same backend pipeline as MCP `execute_request` and the collection
+ * runner: variables, enabled query params, inherited auth and the active
+ * environment's settings are applied. Pass the result to `executeRequest` or
+ * `executeStreamingRequest`.
+ *
+ * @param collectionId - ID of the collection holding the request
+ * @param requestId - ID of the request to resolve
+ * @param draft - Unsaved edits from the request tab, used instead of the saved method, URL, headers and body
+ * @param timeoutMs - Request timeout (default: 30000)
+ * @returns Promise resolving to the request parameters
+ * @throws AppError if the request cannot be resolved
+ */
+export async function resolveCollectionRequest(
+  collectionId: string,
+  requestId: string,
+  draft?: RequestDraft,
+  timeoutMs?: number
+): Promise<RequestParams> {
+  const correlationId = getCorrelationId() ?? generateCorrelationId();
+  try {
+    return await invoke<RequestParams>('cmd_resolve_collection_request', {
+      collectionId,
+      requestId,
+      draft,
+      timeoutMs,
+    });
+  } catch (error) {
+    throw toBackendError(error, correlationId);
+  }
+}
+
+/**
+ * Send a saved collection request, apply its extraction rules and evaluate
+ * its assertions.
+ *
+ * The request goes through the same backend resolver as MCP and the
+ * collection runner: variables (including dynamic ones, defaults and nested
+ * references), query params, inherited auth and the active environment's
+ * settings are applied, and unresolved variables fail the send. Values
+ * captured from the response are stored in the collection's runtime
+ * variables or active environment, where later requests pick them up; each
+ * one is also announced with a `variable:changed` event.
+ *
+ * @param collectionId - ID of the collection holding the request
+ * @param requestId - ID of the request to send
+ * @param draft - Unsaved edits from the request tab, sent instead of the saved method, URL, headers and body
+ * @param timeoutMs - Request timeout (default: 30000)
+ * @param correlationId - Correlation ID for tracing and cancellation
+ * @returns Promise resolving to the request as sent, the response, the
+ *   extracted variables and the assertion results
+ * @throws AppError if the request cannot be resolved or the transfer fails
+ */
+export async function executeCollectionRequest(
+  collectionId: string,
+  requestId: string,
+  draft?: RequestDraft,
+  timeoutMs?: number,
+  correlationId: string = getCorrelationId() ?? generateCorrelationId()
+): Promise<CollectionRequestExecution> {
+  return withCorrelationId(correlationId, async () => {
+    try {
+      return await invoke<CollectionRequestExecution>('cmd_execute_collection_request', {
+        collectionId,
+        requestId,
+        draft,
+        timeoutMs,
+        correlationId,
+      });
+    } catch (error) {
+      throw toBackendError(error, correlationId);
+    }
+  });
+}
+
+/**
+ * Get the runtime variables of a collection: the values its requests'
+ * extraction rules captured this session.
+ *
+ * @param collectionId - ID of the collection
+ * @returns Promise resolving to the variables by name
+ */
+export async function getRuntimeVariables(collectionId: string): Promise<Record<string, string>> {
+  return invoke<Record<string, string>>('cmd_get_runtime_variables', { collectionId });
+}
+
+/**
+ * Forget the runtime variables of a collection.
+ *
+ * @param collectionId - ID of the collection
+ */
+export async function clearRuntimeVariables(collectionId: string): Promise<void> {
+  await invoke('cmd_clear_runtime_variables', { collectionId });
+}
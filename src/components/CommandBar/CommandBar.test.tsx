@@ -144,14 +144,14 @@ describe('CommandBar', () => {
         request: {
           method: 'GET' as const,
           url: 'https://api.example.com/posts',
-          headers: {},
+          headers: [],
           body: '',
           timeout_ms: 30000,
         },
         response: {
           status: 200,
           status_text: 'OK',
-          headers: {},
+          headers: [],
           body: '',
           timing: {
             total_ms: 100,
@@ -48,14 +48,14 @@ function createMockNetworkEntry(overrides: Partial<NetworkHistoryEntry> = {}): N
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[]',
       timing: {
         total_ms: 150,
@@ -92,14 +92,14 @@ const sampleNetworkEntries: NetworkHistoryEntry[] = [
     request: {
       method: 'GET',
       url: 'https://api.example.com/users',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '[]',
       timing: { total_ms: 120, dns_ms: 5, connect_ms: 10, tls_ms: 15, first_byte_ms: 80 },
     },
@@ -108,14 +108,14 @@ const sampleNetworkEntries: NetworkHistoryEntry[] = [
     request: {
       method: 'POST',
       url: 'https://api.example.com/users',
-      headers: {},
+      headers: [],
       body: '{"name":"test"}',
       timeout_ms: 30000,
     },
     response: {
       status: 201,
       status_text: 'Created',
-      headers: {},
+      headers: [],
       body: '{"id":1}',
       timing: { total_ms: 250, dns_ms: 8, connect_ms: 15, tls_ms: 20, first_byte_ms: 180 },
     },
@@ -124,14 +124,14 @@ const sampleNetworkEntries: NetworkHistoryEntry[] = [
     request: {
       method: 'GET',
       url: 'https://api.example.com/users/999',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 404,
       status_text: 'Not Found',
-      headers: {},
+      headers: [],
       body: '{"error":"Not found"}',
       timing: { total_ms: 45, dns_ms: 3, connect_ms: 8, tls_ms: 10, first_byte_ms: 35 },
     },
@@ -140,14 +140,14 @@ const sampleNetworkEntries: NetworkHistoryEntry[] = [
     request: {
       method: 'DELETE',
       url: 'https://api.example.com/users/1',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 500,
       status_text: 'Internal Server Error',
-      headers: {},
+      headers: [],
       body: '{"error":"Server error"}',
       timing: { total_ms: 5000, dns_ms: 5, connect_ms: 10, tls_ms: 15, first_byte_ms: 4900 },
     },
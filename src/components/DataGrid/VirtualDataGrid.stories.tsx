@@ -74,14 +74,14 @@ function createMockNetworkEntry(overrides: Partial<NetworkHistoryEntry> = {}): N
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[]',
       timing: {
         total_ms: 150,
@@ -123,7 +123,7 @@ function generateNetworkEntries(count: number): NetworkHistoryEntry[] {
       request: {
         url: `https://api.example.com/${i === 0 ? 'users' : `resource/${String(i)}`}`,
         method,
-        headers: { 'Content-Type': 'application/json' },
+        headers: [{ name: 'Content-Type', value: 'application/json' }],
         body: method === 'POST' || method === 'PUT' ? '{"data":"test"}' : null,
         timeout_ms: 30000,
       },
@@ -138,7 +138,7 @@ function generateNetworkEntries(count: number): NetworkHistoryEntry[] {
           }
           return 'Error';
         })(),
-        headers: { 'Content-Type': 'application/json' },
+        headers: [{ name: 'Content-Type', value: 'application/json' }],
         body: status === 204 ? '' : `{"id":${String(index)}}`,
         timing: {
           total_ms: 100 + i * 10,
@@ -31,14 +31,14 @@ function createMockEntry(overrides: DeepPartial<NetworkHistoryEntry> = {}): Netw
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[]',
       timing: {
         total_ms: 150,
@@ -160,7 +160,7 @@ describe('Features #38-40: Anchored Columns', () => {
           request: {
             url: 'https://api.example.com/very/long/url/path/that/could/affect/layout/if/not/fixed',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
@@ -170,7 +170,7 @@ describe('Features #38-40: Anchored Columns', () => {
           request: {
             url: 'https://short.url',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
@@ -207,7 +207,7 @@ describe('Features #38-40: Anchored Columns', () => {
           request: {
             url: 'https://api.example.com/even/longer/url/path/that/should/not/affect/fixed/columns',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
@@ -37,14 +37,14 @@ function createMockEntry(
     request: {
       url: overrides.url ?? 'https://api.example.com/users',
       method: (overrides.method ?? 'GET') as 'GET' | 'POST' | 'PUT' | 'DELETE' | 'PATCH',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: overrides.status ?? 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[]',
       timing: {
         total_ms: overrides.totalMs ?? 150,
@@ -32,14 +32,14 @@ function createMockEntry(overrides: DeepPartial<NetworkHistoryEntry> = {}): Netw
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[]',
       timing: {
         total_ms: 150,
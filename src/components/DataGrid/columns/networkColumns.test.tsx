@@ -38,14 +38,14 @@ function createMockEntry(overrides: Partial<NetworkHistoryEntry> = {}): NetworkH
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"users": []}',
       timing: {
         total_ms: 150,
@@ -503,14 +503,14 @@ describe('Network columns integration', () => {
       request: {
         url: 'https://api.example.com/users',
         method: 'POST',
-        headers: {},
+        headers: [],
         body: '{"name": "John"}',
         timeout_ms: 30000,
       },
       response: {
         status: 201,
         status_text: 'Created',
-        headers: {},
+        headers: [],
         body: '{"id": 1}',
         timing: {
           total_ms: 250,
@@ -25,14 +25,14 @@ function createMockEntry(overrides: Partial<NetworkHistoryEntry> = {}): NetworkH
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"users": []}',
       timing: {
         total_ms: 150,
@@ -21,17 +21,17 @@ describe('ExpandedActionButtons', () => {
     request: {
       url: 'https://api.example.com/users',
       method: 'POST',
-      headers: {
-        'Content-Type': 'application/json',
-        Authorization: 'Bearer token123',
-      },
+      headers: [
+        { name: 'Content-Type', value: 'application/json' },
+        { name: 'Authorization', value: 'Bearer token123' },
+      ],
       body: '{"name":"John"}',
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"id":1}',
       timing: {
         total_ms: 156,
@@ -57,10 +57,10 @@ const createMockEntry = (
     request: {
       url: 'https://api.example.com/users',
       method: status === 'get' ? 'GET' : 'POST',
-      headers: {
-        'Content-Type': 'application/json',
-        Authorization: 'Bearer token123',
-      },
+      headers: [
+        { name: 'Content-Type', value: 'application/json' },
+        { name: 'Authorization', value: 'Bearer token123' },
+      ],
       body:
         status === 'get'
           ? null
@@ -77,10 +77,10 @@ const createMockEntry = (
     response: {
       status: status === 'error' ? 500 : 200,
       status_text: status === 'error' ? 'Internal Server Error' : 'OK',
-      headers: {
-        'Content-Type': 'application/json',
-        'X-Rate-Limit': '100',
-      },
+      headers: [
+        { name: 'Content-Type', value: 'application/json' },
+        { name: 'X-Rate-Limit', value: '100' },
+      ],
       body:
         status === 'error'
           ? JSON.stringify({ error: 'Internal server error' }, null, 2)
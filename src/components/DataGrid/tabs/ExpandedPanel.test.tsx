@@ -116,14 +116,14 @@ describe('ExpandedPanel', () => {
     request: {
       method: 'GET',
       url: 'https://example.com/api',
-      headers: {},
+      headers: [],
       body: '',
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '',
       timing: {
         dns_ms: 10,
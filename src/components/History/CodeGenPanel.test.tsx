@@ -21,14 +21,14 @@ describe('CodeGenPanel', () => {
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { Authorization: 'Bearer token123' },
+      headers: [{ name: 'Authorization', value: 'Bearer token123' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_ms: 100,
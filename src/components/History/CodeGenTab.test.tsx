@@ -20,14 +20,14 @@ describe('CodeGenTab', () => {
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_ms: 100,
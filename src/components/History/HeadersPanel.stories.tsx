@@ -7,19 +7,20 @@ import type { Meta, StoryObj } from '@storybook/react-vite';
 import { expect, userEvent, within } from 'storybook/test';
 import { waitForFocus } from '@/utils/storybook-test-helpers';
 import { HeadersPanel } from './HeadersPanel';
+import type { HttpHeaders } from '@/types/http';
 
-const defaultRequestHeaders: Record<string, string> = {
-  'Content-Type': 'application/json',
-  Authorization: 'Bearer token123',
-  'User-Agent': 'runi/1.0.0',
-};
+const defaultRequestHeaders: HttpHeaders = [
+  { name: 'Content-Type', value: 'application/json' },
+  { name: 'Authorization', value: 'Bearer token123' },
+  { name: 'User-Agent', value: 'runi/1.0.0' },
+];
 
-const defaultResponseHeaders: Record<string, string> = {
-  'Content-Type': 'application/json',
-  'X-Rate-Limit': '100',
-  'X-Rate-Limit-Remaining': '99',
-  'X-Request-ID': 'abc-123-def-456',
-};
+const defaultResponseHeaders: HttpHeaders = [
+  { name: 'Content-Type', value: 'application/json' },
+  { name: 'X-Rate-Limit', value: '100' },
+  { name: 'X-Rate-Limit-Remaining', value: '99' },
+  { name: 'X-Request-ID', value: 'abc-123-def-456' },
+];
 
 const meta: Meta<typeof HeadersPanel> = {
   title: 'History/HeadersPanel',
@@ -94,7 +95,7 @@ export const Playground: Story = {
 export const WithEmptyStates: Story = {
   tags: ['test'],
   args: {
-    requestHeaders: {},
+    requestHeaders: [],
     responseHeaders: defaultResponseHeaders,
   },
   render: function WithEmptyStatesRender(args) {
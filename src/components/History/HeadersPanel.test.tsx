@@ -13,16 +13,16 @@ import { render, screen } from '@testing-library/react';
 import userEvent from '@testing-library/user-event';
 import { HeadersPanel } from './HeadersPanel';
 
-const mockRequestHeaders = {
-  'Content-Type': 'application/json',
-  Authorization: 'Bearer token123',
-};
+const mockRequestHeaders = [
+  { name: 'Content-Type', value: 'application/json' },
+  { name: 'Authorization', value: 'Bearer token123' },
+];
 
-const mockResponseHeaders = {
-  'Content-Type': 'application/json',
-  'X-Rate-Limit': '100',
-  'X-Rate-Limit-Remaining': '99',
-};
+const mockResponseHeaders = [
+  { name: 'Content-Type', value: 'application/json' },
+  { name: 'X-Rate-Limit', value: '100' },
+  { name: 'X-Rate-Limit-Remaining', value: '99' },
+];
 
 describe('HeadersPanel', () => {
   it('renders response headers by default', () => {
@@ -60,8 +60,24 @@ describe('HeadersPanel', () => {
     expect(headersText).toContain('application/json');
   });
 
+  it('lists repeated response headers on separate lines', () => {
+    render(
+      <HeadersPanel
+        requestHeaders={mockRequestHeaders}
+        responseHeaders={[
+          { name: 'Set-Cookie', value: 'a=1' },
+          { name: 'Set-Cookie', value: 'b=2' },
+        ]}
+      />
+    );
+
+    const codeBox = screen.getByTestId('code-box');
+    expect(codeBox.textContent).toContain('Set-Cookie: a=1');
+    expect(codeBox.textContent).toContain('Set-Cookie: b=2');
+  });
+
   it('displays empty state when no response headers', () => {
-    render(<HeadersPanel requestHeaders={mockRequestHeaders} responseHeaders={{}} />);
+    render(<HeadersPanel requestHeaders={mockRequestHeaders} responseHeaders={[]} />);
 
     expect(screen.getByText(/no response headers/i)).toBeInTheDocument();
     expect(screen.getByText(/this response has no headers/i)).toBeInTheDocument();
@@ -69,7 +85,7 @@ describe('HeadersPanel', () => {
 
   it('displays empty state when no request headers', async () => {
     const user = userEvent.setup();
-    render(<HeadersPanel requestHeaders={{}} responseHeaders={mockResponseHeaders} />);
+    render(<HeadersPanel requestHeaders={[]} responseHeaders={mockResponseHeaders} />);
 
     const requestTab = screen.getByRole('tab', { name: /request headers/i });
     await user.click(requestTab);
@@ -87,7 +103,7 @@ describe('HeadersPanel', () => {
   });
 
   it('hides copy button when no headers', () => {
-    render(<HeadersPanel requestHeaders={{}} responseHeaders={{}} />);
+    render(<HeadersPanel requestHeaders={[]} responseHeaders={[]} />);
 
     expect(screen.queryByRole('button', { name: /copy/i })).not.toBeInTheDocument();
   });
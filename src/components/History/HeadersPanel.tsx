@@ -14,12 +14,13 @@ import { cn } from '@/utils/cn';
 import { CodeEditor } from '@/components/CodeHighlighting/CodeEditor';
 import { EmptyState } from '@/components/ui/EmptyState';
 import { BaseTabsList } from '@/components/ui/BaseTabsList';
+import type { HttpHeaders } from '@/types/http';
 
 export interface HeadersPanelProps {
-  /** Request headers object */
-  requestHeaders: Record<string, string>;
-  /** Response headers object */
-  responseHeaders: Record<string, string>;
+  /** Request headers, in wire order */
+  requestHeaders: HttpHeaders;
+  /** Response headers, in wire order (repeated names kept) */
+  responseHeaders: HttpHeaders;
   /** Additional CSS classes */
   className?: string;
   /** Optional keyboard handler for hierarchical navigation */
@@ -39,8 +40,11 @@ export type HeadersPanelTabType = 'response' | 'request';
  * @example
  * ```tsx
  * <HeadersPanel
- *   requestHeaders={{ 'Content-Type': 'application/json' }}
- *   responseHeaders={{ 'Content-Type': 'application/json', 'X-Rate-Limit': '100' }}
+ *   requestHeaders={[{ name: 'Content-Type', value: 'application/json' }]}
+ *   responseHeaders={[
+ *     { name: 'Content-Type', value: 'application/json' },
+ *     { name: 'X-Rate-Limit', value: '100' },
+ *   ]}
  * />
  * ```
  */
@@ -54,17 +58,11 @@ export const HeadersPanel = ({
   const [activeTab, setActiveTab] = useState<HeadersPanelTabType>('response');
 
   const responseHeadersText = useMemo(
-    () =>
-      Object.entries(responseHeaders)
-        .map(([key, value]) => `${key}: ${value}`)
-        .join('\n'),
+    () => responseHeaders.map(({ name, value }) => `${name}: ${value}`).join('\n'),
     [responseHeaders]
   );
   const requestHeadersText = useMemo(
-    () =>
-      Object.entries(requestHeaders)
-        .map(([key, value]) => `${key}: ${value}`)
-        .join('\n'),
+    () => requestHeaders.map(({ name, value }) => `${name}: ${value}`).join('\n'),
     [requestHeaders]
   );
 
@@ -102,7 +100,7 @@ export const HeadersPanel = ({
         />
 
         <Tabs.Panel value="response" className="flex-1 flex flex-col" tabIndex={-1}>
-          {Object.keys(responseHeaders).length === 0 ? (
+          {responseHeaders.length === 0 ? (
             <EmptyState
               variant="muted"
               title="No response headers"
@@ -119,7 +117,7 @@ export const HeadersPanel = ({
           )}
         </Tabs.Panel>
         <Tabs.Panel value="request" className="flex-1 flex flex-col" tabIndex={-1}>
-          {Object.keys(requestHeaders).length === 0 ? (
+          {requestHeaders.length === 0 ? (
             <EmptyState
               variant="muted"
               title="No request headers"
@@ -19,20 +19,20 @@ const mockEntry: NetworkHistoryEntry = {
   request: {
     url: 'https://api.example.com/users',
     method: 'POST',
-    headers: {
-      'Content-Type': 'application/json',
-      Authorization: 'Bearer token123',
-    },
+    headers: [
+      { name: 'Content-Type', value: 'application/json' },
+      { name: 'Authorization', value: 'Bearer token123' },
+    ],
     body: '{"name":"John","email":"john@example.com"}',
     timeout_ms: 30000,
   },
   response: {
     status: 200,
     status_text: 'OK',
-    headers: {
-      'Content-Type': 'application/json',
-      'X-Rate-Limit': '100',
-    },
+    headers: [
+      { name: 'Content-Type', value: 'application/json' },
+      { name: 'X-Rate-Limit', value: '100' },
+    ],
     body: '{"id":1,"name":"John"}',
     timing: {
       total_ms: 156,
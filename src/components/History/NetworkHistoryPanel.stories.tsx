@@ -80,14 +80,14 @@ const mockEntries: NetworkHistoryEntry[] = [
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '[{"id":1,"name":"John"},{"id":2,"name":"Jane"}]',
       timing: { total_ms: 156, dns_ms: 12, connect_ms: 23, tls_ms: 34, first_byte_ms: 98 },
     },
@@ -105,14 +105,14 @@ const mockEntries: NetworkHistoryEntry[] = [
     request: {
       url: 'https://api.example.com/users',
       method: 'POST',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"name":"Alice","email":"alice@example.com"}',
       timeout_ms: 30000,
     },
     response: {
       status: 201,
       status_text: 'Created',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"id":3,"name":"Alice"}',
       timing: { total_ms: 234, dns_ms: null, connect_ms: null, tls_ms: null, first_byte_ms: null },
     },
@@ -130,14 +130,14 @@ const mockEntries: NetworkHistoryEntry[] = [
     request: {
       url: 'https://api.example.com/users/1',
       method: 'PUT',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"name":"John Updated"}',
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"id":1,"name":"John Updated"}',
       timing: { total_ms: 189, dns_ms: 8, connect_ms: 18, tls_ms: 28, first_byte_ms: 120 },
     },
@@ -155,14 +155,14 @@ const mockEntries: NetworkHistoryEntry[] = [
     request: {
       url: 'https://api.example.com/users/999',
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 404,
       status_text: 'Not Found',
-      headers: {},
+      headers: [],
       body: '{"error":"User not found"}',
       timing: { total_ms: 67, dns_ms: 5, connect_ms: 10, tls_ms: 15, first_byte_ms: 50 },
     },
@@ -180,14 +180,14 @@ const mockEntries: NetworkHistoryEntry[] = [
     request: {
       url: 'https://api.example.com/users/2',
       method: 'DELETE',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 204,
       status_text: 'No Content',
-      headers: {},
+      headers: [],
       body: '',
       timing: { total_ms: 145, dns_ms: 6, connect_ms: 12, tls_ms: 20, first_byte_ms: 100 },
     },
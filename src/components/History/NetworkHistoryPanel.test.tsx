@@ -54,14 +54,14 @@ describe('NetworkHistoryPanel', () => {
       request: {
         url: 'https://api.example.com/users',
         method: 'GET',
-        headers: {},
+        headers: [],
         body: null,
         timeout_ms: 30000,
       },
       response: {
         status: 200,
         status_text: 'OK',
-        headers: {},
+        headers: [],
         body: '[]',
         timing: { total_ms: 150, dns_ms: 10, connect_ms: 20, tls_ms: 30, first_byte_ms: 100 },
       },
@@ -79,14 +79,14 @@ describe('NetworkHistoryPanel', () => {
       request: {
         url: 'https://api.example.com/users',
         method: 'POST',
-        headers: {},
+        headers: [],
         body: '{"name": "Test"}',
         timeout_ms: 30000,
       },
       response: {
         status: 201,
         status_text: 'Created',
-        headers: {},
+        headers: [],
         body: '{"id": 1}',
         timing: {
           total_ms: 200,
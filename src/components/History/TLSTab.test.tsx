@@ -42,14 +42,14 @@ const mockEntry: NetworkHistoryEntry = {
   request: {
     url: 'https://api.example.com/users',
     method: 'POST',
-    headers: { 'Content-Type': 'application/json' },
+    headers: [{ name: 'Content-Type', value: 'application/json' }],
     body: '{"name":"John"}',
     timeout_ms: 30000,
   },
   response: {
     status: 200,
     status_text: 'OK',
-    headers: { 'Content-Type': 'application/json' },
+    headers: [{ name: 'Content-Type', value: 'application/json' }],
     body: '{"id":1}',
     timing: {
       total_ms: 156,
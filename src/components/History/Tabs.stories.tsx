@@ -39,11 +39,11 @@ function createMockEntry(overrides?: Partial<NetworkHistoryEntry>): NetworkHisto
     request: {
       url: 'https://api.example.com/users',
       method: 'POST',
-      headers: {
-        'Content-Type': 'application/json',
-        Authorization: 'Bearer token123',
-        'User-Agent': 'runi/1.0.0',
-      },
+      headers: [
+        { name: 'Content-Type', value: 'application/json' },
+        { name: 'Authorization', value: 'Bearer token123' },
+        { name: 'User-Agent', value: 'runi/1.0.0' },
+      ],
       body: JSON.stringify(
         {
           name: 'John Doe',
@@ -57,12 +57,12 @@ function createMockEntry(overrides?: Partial<NetworkHistoryEntry>): NetworkHisto
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {
-        'Content-Type': 'application/json',
-        'X-Rate-Limit': '100',
-        'X-Rate-Limit-Remaining': '99',
-        'X-Request-ID': 'abc-123-def-456',
-      },
+      headers: [
+        { name: 'Content-Type', value: 'application/json' },
+        { name: 'X-Rate-Limit', value: '100' },
+        { name: 'X-Rate-Limit-Remaining', value: '99' },
+        { name: 'X-Request-ID', value: 'abc-123-def-456' },
+      ],
       body: JSON.stringify(
         {
           id: 1,
@@ -167,14 +167,14 @@ export const ResponseTabGetRequest: Story = {
         request: {
           url: 'https://api.example.com/users',
           method: 'GET',
-          headers: { 'User-Agent': 'runi/1.0.0' },
+          headers: [{ name: 'User-Agent', value: 'runi/1.0.0' }],
           body: null,
           timeout_ms: 30000,
         },
         response: {
           status: 200,
           status_text: 'OK',
-          headers: { 'Content-Type': 'application/json' },
+          headers: [{ name: 'Content-Type', value: 'application/json' }],
           body: JSON.stringify(
             {
               users: [
@@ -215,7 +215,7 @@ export const ResponseTabLargeBody: Story = {
         response: {
           status: 200,
           status_text: 'OK',
-          headers: { 'Content-Type': 'application/json' },
+          headers: [{ name: 'Content-Type', value: 'application/json' }],
           body: JSON.stringify(
             {
               data: Array.from({ length: 100 }, (_, i) => ({
@@ -311,35 +311,35 @@ export const HeadersTabManyHeaders: Story = {
         request: {
           url: 'https://api.example.com/users',
           method: 'POST',
-          headers: {
-            'Content-Type': 'application/json',
-            Authorization: 'Bearer token123',
-            'User-Agent': 'runi/1.0.0',
-            'X-Request-ID': 'req-123',
-            'X-Correlation-ID': 'corr-456',
-            'X-Forwarded-For': '192.168.1.1',
-            'X-Forwarded-Proto': 'https',
-            Accept: 'application/json',
-            'Accept-Language': 'en-US,en;q=0.9',
-          },
+          headers: [
+            { name: 'Content-Type', value: 'application/json' },
+            { name: 'Authorization', value: 'Bearer token123' },
+            { name: 'User-Agent', value: 'runi/1.0.0' },
+            { name: 'X-Request-ID', value: 'req-123' },
+            { name: 'X-Correlation-ID', value: 'corr-456' },
+            { name: 'X-Forwarded-For', value: '192.168.1.1' },
+            { name: 'X-Forwarded-Proto', value: 'https' },
+            { name: 'Accept', value: 'application/json' },
+            { name: 'Accept-Language', value: 'en-US,en;q=0.9' },
+          ],
           body: '{"name":"John"}',
           timeout_ms: 30000,
         },
         response: {
           status: 200,
           status_text: 'OK',
-          headers: {
-            'Content-Type': 'application/json',
-            'X-Rate-Limit': '100',
-            'X-Rate-Limit-Remaining': '99',
-            'X-Rate-Limit-Reset': '1640995200',
-            'X-Request-ID': 'abc-123-def-456',
-            'X-Response-Time': '156ms',
-            'X-Powered-By': 'Express',
-            'Cache-Control': 'no-cache',
-            ETag: 'W/"abc123"',
-            'Last-Modified': 'Wed, 21 Oct 2015 07:28:00 GMT',
-          },
+          headers: [
+            { name: 'Content-Type', value: 'application/json' },
+            { name: 'X-Rate-Limit', value: '100' },
+            { name: 'X-Rate-Limit-Remaining', value: '99' },
+            { name: 'X-Rate-Limit-Reset', value: '1640995200' },
+            { name: 'X-Request-ID', value: 'abc-123-def-456' },
+            { name: 'X-Response-Time', value: '156ms' },
+            { name: 'X-Powered-By', value: 'Express' },
+            { name: 'Cache-Control', value: 'no-cache' },
+            { name: 'ETag', value: 'W/"abc123"' },
+            { name: 'Last-Modified', value: 'Wed, 21 Oct 2015 07:28:00 GMT' },
+          ],
           body: '{"id":1}',
           timing: {
             total_ms: 156,
@@ -505,10 +505,10 @@ export const CodeGenTabPostRequest: Story = {
         request: {
           url: 'https://api.example.com/users',
           method: 'POST',
-          headers: {
-            'Content-Type': 'application/json',
-            Authorization: 'Bearer token123',
-          },
+          headers: [
+            { name: 'Content-Type', value: 'application/json' },
+            { name: 'Authorization', value: 'Bearer token123' },
+          ],
           body: '{"name":"John Doe","email":"john@example.com"}',
           timeout_ms: 30000,
         },
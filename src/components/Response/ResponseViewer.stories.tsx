@@ -90,11 +90,11 @@ const createMockResponse = (
   return {
     status,
     status_text: statusText,
-    headers: {
-      'content-type': 'application/json',
-      'content-length': String(JSON.stringify(bodyData).length),
-      date: 'Wed, 16 Jan 2025 00:00:00 GMT',
-    },
+    headers: [
+      { name: 'content-type', value: 'application/json' },
+      { name: 'content-length', value: String(JSON.stringify(bodyData).length) },
+      { name: 'date', value: 'Wed, 16 Jan 2025 00:00:00 GMT' },
+    ],
     body: JSON.stringify(bodyData, null, 2),
     timing: {
       total_ms: 245,
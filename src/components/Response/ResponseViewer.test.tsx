@@ -15,10 +15,10 @@ describe('ResponseViewer', () => {
   const mockResponse: HttpResponse = {
     status: 200,
     status_text: 'OK',
-    headers: {
-      'content-type': 'application/json',
-      'content-length': '100',
-    },
+    headers: [
+      { name: 'content-type', value: 'application/json' },
+      { name: 'content-length', value: '100' },
+    ],
     body: '{"args":{},"headers":{"Accept":"*/*","Host":"httpbin.org"},"origin":"127.0.0.1","url":"https://httpbin.org/get"}',
     timing: {
       total_ms: 150,
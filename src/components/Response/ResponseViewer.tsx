@@ -4,7 +4,7 @@
  */
 
 import React, { useCallback, useEffect, useRef, useState } from 'react';
-import type { HttpResponse } from '@/types/http';
+import { getHeader, type HttpResponse } from '@/types/http';
 import { Tabs } from '@base-ui/react/tabs';
 import { detectSyntaxLanguage } from '@/components/CodeHighlighting/syntaxLanguage';
 import { motion, useReducedMotion } from 'motion/react';
@@ -61,16 +61,15 @@ function formatRawHttp(response: HttpResponse): string {
   lines.push(`HTTP/1.1 ${String(response.status)} ${response.status_text}`);
 
   // Headers
-  Object.entries(response.headers).forEach(([key, value]) => {
-    lines.push(`${key}: ${value}`);
+  response.headers.forEach(({ name, value }) => {
+    lines.push(`${name}: ${value}`);
   });
 
   // Blank line before body
   lines.push('');
 
   // Body (formatted if JSON with 2-space indent)
-  const contentTypeHeader =
-    response.headers['content-type'] ?? response.headers['Content-Type'] ?? undefined;
+  const contentTypeHeader = getHeader(response.headers, 'content-type');
   const language = detectSyntaxLanguage({ body: response.body, contentType: contentTypeHeader });
   if (language === 'json') {
     lines.push(formatJson(response.body));
@@ -185,10 +184,10 @@ export const ResponseViewer = ({
     return { opacity: 0.25, x: 0 };
   };
 
-  const headerCount = response !== null ? Object.keys(response.headers).length : 0;
+  const headerCount = response !== null ? response.headers.length : 0;
   const contentTypeHeader =
     response !== null
-      ? (response.headers['content-type'] ?? response.headers['Content-Type'] ?? undefined)
+      ? getHeader(response.headers, 'content-type')
       : undefined;
   const language =
     response !== null
@@ -323,9 +322,9 @@ export const ResponseViewer = ({
 
                 {/* Headers */}
                 <div className="space-y-1">
-                  {Object.entries(response.headers).map(([key, value]) => (
-                    <div key={key} className="font-mono text-sm flex">
-                      <span className="text-accent-blue">{key}</span>
+                  {response.headers.map(({ name, value }, index) => (
+                    <div key={`${name}-${String(index)}`} className="font-mono text-sm flex">
+                      <span className="text-accent-blue">{name}</span>
                       <span className="text-text-muted mx-1">:</span>
                       <span className="text-text-secondary break-all">{value}</span>
                     </div>
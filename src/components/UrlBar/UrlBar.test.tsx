@@ -114,6 +114,21 @@ describe('UrlBar', () => {
     expect(sendButton).toBeDisabled();
   });
 
+  it('turns the send button into a cancel button while loading', async () => {
+    const onCancel = vi.fn();
+    const onSend = vi.fn();
+    render(<UrlBar {...defaultProps} onSend={onSend} onCancel={onCancel} loading={true} />);
+
+    const sendButton = screen.getByTestId('send-button');
+    expect(sendButton).toHaveTextContent('Sending');
+    expect(sendButton).toHaveAccessibleName('Cancel Request');
+    expect(sendButton).not.toBeDisabled();
+
+    await userEvent.click(sendButton);
+    expect(onCancel).toHaveBeenCalledTimes(1);
+    expect(onSend).not.toHaveBeenCalled();
+  });
+
   it('disables send button when URL is empty', () => {
     render(<UrlBar {...defaultProps} url="" />);
 
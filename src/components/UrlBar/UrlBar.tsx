@@ -5,7 +5,7 @@
 
 import React from 'react';
 import { motion, useReducedMotion } from 'motion/react';
-import { SendHorizontal } from 'lucide-react';
+import { SendHorizontal, Square } from 'lucide-react';
 import { Input } from '@/components/ui/input';
 import { Button } from '@/components/ui/button';
 import * as Select from '@/components/ui/select';
@@ -23,6 +23,8 @@ interface UrlBarProps {
   onMethodChange?: (method: HttpMethod) => void;
   onUrlChange?: (url: string) => void;
   onSend?: () => void;
+  /** Cancel the request in flight; makes the send button a cancel button while loading */
+  onCancel?: () => void;
 }
 
 /**
@@ -45,10 +47,12 @@ export const UrlBar = ({
   onMethodChange,
   onUrlChange,
   onSend,
+  onCancel,
 }: UrlBarProps): React.JSX.Element => {
   const httpMethods: HttpMethod[] = ['GET', 'POST', 'PUT', 'PATCH', 'DELETE', 'HEAD', 'OPTIONS'];
 
   const isValidUrl = url.length > 0;
+  const canCancel = loading && onCancel !== undefined;
   const methodColor = getMethodColor(method);
 
   // Respect prefers-reduced-motion
@@ -166,17 +170,20 @@ export const UrlBar = ({
           variant="ghost"
           size="xs"
           noScale
-          onClick={onSend}
-          disabled={!isValidUrl || loading}
+          onClick={canCancel ? onCancel : onSend}
+          disabled={!canCancel && (!isValidUrl || loading)}
           data-test-id="send-button"
-          aria-label="Send Request"
+          aria-label={canCancel ? 'Cancel Request' : 'Send Request'}
           className={cn(
             'relative h-9 px-3 justify-center whitespace-nowrap text-text-muted hover:text-accent-blue motion-safe:transition-colors motion-reduce:transition-none',
             compositeFocusItemClasses
           )}
         >
           {loading ? (
-            renderLoadingState()
+            <span className="inline-flex items-center gap-1.5">
+              {renderLoadingState()}
+              {canCancel && <Square size={11} className="text-signal-error" aria-hidden="true" />}
+            </span>
           ) : (
             <SendHorizontal size={15} className={isValidUrl ? 'text-accent-blue' : undefined} />
           )}
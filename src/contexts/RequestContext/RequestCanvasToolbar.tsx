@@ -33,8 +33,15 @@ export const RequestCanvasToolbar: FC<CanvasToolbarProps> = ({
   const { entries } = useHistoryStore();
 
   // Request actions from useRequestActions hook
-  const { handleSend, handleMethodChange, handleUrlChange, localUrl, localMethod, isLoading } =
-    useRequestActions();
+  const {
+    handleSend,
+    handleCancel,
+    handleMethodChange,
+    handleUrlChange,
+    localUrl,
+    localMethod,
+    isLoading,
+  } = useRequestActions();
 
   // Secondary action handlers (code, docs, save, history)
 
@@ -72,6 +79,7 @@ export const RequestCanvasToolbar: FC<CanvasToolbarProps> = ({
         onMethodChange={handleMethodChange}
         onUrlChange={handleUrlChange}
         onSend={handleSend}
+        onCancel={handleCancel}
       />
       <ActionButtons
         onCode={handleCode}
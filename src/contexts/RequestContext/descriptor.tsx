@@ -11,6 +11,8 @@ import { ResponseViewer } from '@/components/Response/ResponseViewer';
 import { VigilanceMonitor } from '@/components/ui/VigilanceMonitor';
 import { RequestCanvasToolbar } from './RequestCanvasToolbar';
 import { useRequestStore, RequestContextIdContext } from '@/stores/useRequestStore';
+import { useHttpStream } from '@/hooks/useHttpStream';
+import { streamTranscriptText } from '@/types/http';
 
 import { useState } from 'react';
 import type { TabId } from '@/components/Response/ResponseViewer';
@@ -25,9 +27,18 @@ const RequestBuilderPanel: FC<CanvasPanelProps> = ({ contextId }): React.JSX.Ele
 };
 
 const ResponseViewerWrapper: FC = (): React.JSX.Element => {
-  const { response, isLoading } = useRequestStore();
+  const { response: finalResponse, isLoading, correlationId } = useRequestStore();
+  // Streaming requests show their messages as they arrive
+  const liveResponse = useHttpStream(isLoading ? correlationId : null);
   const [activeTab, setActiveTab] = useState<TabId>('body');
 
+  const shown = finalResponse ?? liveResponse;
+  // A streamed body is not buffered; show its transcript instead
+  const response =
+    shown?.stream !== undefined && shown.body === ''
+      ? { ...shown, body: streamTranscriptText(shown.stream) }
+      : shown;
+
   return (
     <ResponseViewer
       response={response}
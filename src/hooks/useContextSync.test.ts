@@ -154,7 +154,7 @@ describe('useContextSync', () => {
       const mockResponse = {
         status: 200,
         status_text: 'OK',
-        headers: {},
+        headers: [],
         body: '{"ok":true}',
         timing: { total_ms: 100 } as any,
       };
@@ -183,14 +183,14 @@ describe('useContextSync', () => {
           request: {
             url: 'https://api.example.com/history',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '',
             timing: { total_ms: 50 } as any,
           },
@@ -10,7 +10,7 @@ import { globalEventBus, logEventFlow, type CollectionRequestSelectedPayload } f
 import type { HistoryEntry } from '@/types/generated/HistoryEntry';
 import type { RequestTabState } from '@/types/canvas';
 import { requestContextDescriptor } from '@/contexts/RequestContext/descriptor';
-import type { HttpResponse } from '@/types/http';
+import { headersToRecord, type HttpResponse } from '@/types/http';
 
 /**
  * Bidirectional sync between `useCanvasStore` (multi-context canvas) and `useRequestStoreRaw` (keyed store).
@@ -191,7 +191,7 @@ export function useContextSync(): void {
           store.openRequestTab({
             method: entry.request.method,
             url: entry.request.url,
-            headers: entry.request.headers,
+            headers: headersToRecord(entry.request.headers),
             body: entry.request.body ?? '',
             source,
             label: deriveContextLabel(entry.request.url),
@@ -58,14 +58,14 @@ function createMockEntry(id: string): HistoryEntry {
     request: {
       url: `https://api.example.com/${id}`,
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_ms: 100,
@@ -0,0 +1,58 @@
+/**
+ * Copyright (c) 2026 BaseState LLC
+ * SPDX-License-Identifier: MIT
+ */
+
+/**
+ * @file useHttpStream hook
+ * @description Live response of a request executed in streaming mode
+ *
+ * Subscribes to the `http:stream` Tauri events of one correlation ID and
+ * assembles them into a provisional response while the stream is open.
+ */
+
+import { useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { applyStreamUpdate, type HttpResponse, type HttpStreamEvent } from '@/types/http';
+
+/**
+ * Follow the `http:stream` events of the request with `correlationId`.
+ *
+ * @param correlationId - Correlation ID of the streaming request, or null when none runs
+ * @returns The response received so far, or null before the stream starts
+ */
+export function useHttpStream(correlationId: string | null): HttpResponse | null {
+  const [liveResponse, setLiveResponse] = useState<HttpResponse | null>(null);
+
+  useEffect(() => {
+    setLiveResponse(null);
+    if (correlationId === null) {
+      return;
+    }
+
+    let disposed = false;
+    let unlisten: (() => void) | undefined;
+    listen<HttpStreamEvent>('http:stream', (event) => {
+      if (event.payload.correlation_id === correlationId) {
+        setLiveResponse((current) => applyStreamUpdate(current, event.payload));
+      }
+    })
+      .then((fn) => {
+        if (disposed) {
+          fn();
+        } else {
+          unlisten = fn;
+        }
+      })
+      .catch((error: unknown) => {
+        console.error('Failed to subscribe to stream events:', error);
+      });
+
+    return (): void => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, [correlationId]);
+
+  return liveResponse;
+}
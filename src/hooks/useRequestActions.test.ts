@@ -9,8 +9,11 @@ import {
   type RequestStore,
 } from '@/stores/useRequestStore';
 import { useHistoryStore } from '@/stores/useHistoryStore';
+import { useCanvasStore } from '@/stores/useCanvasStore';
+import { useCollectionStore } from '@/stores/useCollectionStore';
 import { globalEventBus } from '@/events/bus';
 import type { HttpResponse } from '@/types/http';
+import type { Collection } from '@/types/collection';
 import * as httpModule from '@/api/http';
 
 // Mock the http module
@@ -30,14 +33,14 @@ vi.mock('@tauri-apps/api/core', () => ({
           request: {
             url: 'https://httpbin.org/get',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{"success": true}',
             timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
           },
@@ -82,9 +85,19 @@ describe('useRequestActions', () => {
   });
 
   afterEach(() => {
+    useCanvasStore.getState().reset();
+    useCollectionStore.setState({ collections: [] });
     vi.restoreAllMocks();
   });
 
+  /** Make the active tab one opened from saved request `req_1` of `col_1`. */
+  const openSavedRequestTab = (): void => {
+    useCanvasStore.getState().updateContextState('request-saved', {
+      source: { type: 'collection', collectionId: 'col_1', requestId: 'req_1' },
+    });
+    useCanvasStore.setState({ activeContextId: 'request-saved' });
+  };
+
   it('initializes with empty URL and GET method', () => {
     const { result } = renderHook(() => useRequestActions());
 
@@ -131,7 +144,7 @@ describe('useRequestActions', () => {
     const mockResponse = {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"success": true}',
       timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
     };
@@ -155,7 +168,7 @@ describe('useRequestActions', () => {
     const mockResponse = {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"success": true}',
       timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
     };
@@ -173,6 +186,180 @@ describe('useRequestActions', () => {
     });
   });
 
+  it('handleSend sends saved requests through the backend resolver', async () => {
+    const mockResponse = {
+      status: 200,
+      status_text: 'OK',
+      headers: [],
+      body: '{"success": true}',
+      timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
+    };
+    const sent = {
+      url: 'https://api.example.com/users?page=1',
+      method: 'GET',
+      headers: [{ name: 'Authorization', value: 'Bearer t0k' }],
+      body: null,
+      timeout_ms: 30000,
+    };
+    vi.mocked(httpModule.executeCollectionRequest).mockResolvedValue({
+      request: sent,
+      response: mockResponse,
+      extracted: [],
+      assertions: [],
+    });
+    openSavedRequestTab();
+
+    const { result } = renderHook(() => useRequestActions());
+
+    act(() => {
+      result.current.handleUrlChange('{{baseUrl}}/users');
+    });
+
+    await act(async () => {
+      await result.current.handleSend();
+    });
+
+    expect(httpModule.executeRequest).not.toHaveBeenCalled();
+    expect(httpModule.executeCollectionRequest).toHaveBeenCalledWith(
+      'col_1',
+      'req_1',
+      {
+        method: 'GET',
+        url: '{{baseUrl}}/users',
+        headers: {},
+        body: '',
+      },
+      undefined,
+      expect.any(String)
+    );
+    expect(getContextState()?.response).toEqual(mockResponse);
+    await waitFor(() => {
+      expect(useHistoryStore.getState().entries.length).toBe(1);
+    });
+  });
+
+  it('handleSend streams saved streaming requests', async () => {
+    const sent = {
+      url: 'https://api.example.com/events',
+      method: 'GET',
+      headers: [],
+      body: null,
+      timeout_ms: 30000,
+    };
+    const streamed: HttpResponse = {
+      status: 200,
+      status_text: 'OK',
+      headers: [{ name: 'content-type', value: 'text/event-stream' }],
+      body: '',
+      timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
+      stream: { format: 'sse', messages: [{ seq: 0, elapsed_ms: 50, data: 'hello' }] },
+    };
+    useCollectionStore.setState({
+      collections: [
+        { id: 'col_1', requests: [{ id: 'req_1', is_streaming: true }] } as unknown as Collection,
+      ],
+    });
+    vi.mocked(httpModule.resolveCollectionRequest).mockResolvedValue(sent);
+    vi.mocked(httpModule.executeStreamingRequest).mockResolvedValue(streamed);
+    openSavedRequestTab();
+
+    const { result } = renderHook(() => useRequestActions());
+
+    await act(async () => {
+      await result.current.handleSend();
+    });
+
+    expect(httpModule.executeCollectionRequest).not.toHaveBeenCalled();
+    expect(httpModule.resolveCollectionRequest).toHaveBeenCalledWith('col_1', 'req_1', {
+      method: 'GET',
+      url: 'https://httpbin.org/get',
+      headers: {},
+      body: '',
+    });
+    expect(httpModule.executeStreamingRequest).toHaveBeenCalledWith(sent, expect.any(String));
+    expect(getContextState()?.response).toEqual(streamed);
+  });
+
+  it('handleSend reports the failed assertions of a saved request', async () => {
+    const mockResponse = {
+      status: 404,
+      status_text: 'Not Found',
+      headers: [],
+      body: '',
+      timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
+    };
+    vi.mocked(httpModule.executeCollectionRequest).mockResolvedValue({
+      request: {
+        url: 'https://api.example.com/users',
+        method: 'GET',
+        headers: [],
+        body: null,
+        timeout_ms: 30000,
+      },
+      response: mockResponse,
+      extracted: [],
+      assertions: [
+        {
+          check: 'status_equals',
+          status: 200,
+          passed: false,
+          actual: '404',
+          message: 'expected 200',
+        },
+        { check: 'header_exists', name: 'etag', passed: true },
+      ],
+    });
+    openSavedRequestTab();
+    const emitSpy = vi.spyOn(globalEventBus, 'emit');
+
+    const { result } = renderHook(() => useRequestActions());
+
+    await act(async () => {
+      await result.current.handleSend();
+    });
+
+    expect(emitSpy).toHaveBeenCalledWith('toast.show', {
+      type: 'warning',
+      message: '1 of 2 assertions failed',
+      details: 'status_equals: expected 200',
+    });
+    expect(getContextState()?.response).toEqual(mockResponse);
+  });
+
+  it('handleSend is blocked by an unresolved variable in a saved request', async () => {
+    const unresolved = new Error('Unresolved variables: baseUrl');
+    (unresolved as Error & { appError: unknown }).appError = {
+      code: 'HTTP_REQUEST_FAILED',
+      message: 'Unresolved variables: baseUrl',
+      correlationId: 'test-correlation-id',
+      source: 'backend' as const,
+      timestamp: 0,
+    };
+    vi.mocked(httpModule.executeCollectionRequest).mockRejectedValue(unresolved);
+    openSavedRequestTab();
+    const emitSpy = vi.spyOn(globalEventBus, 'emit');
+
+    const { result } = renderHook(() => useRequestActions());
+
+    act(() => {
+      result.current.handleUrlChange('{{baseUrl}}/users');
+    });
+
+    await act(async () => {
+      await result.current.handleSend();
+    });
+
+    // The backend refused to resolve it, so nothing went out with literal braces
+    expect(httpModule.executeRequest).not.toHaveBeenCalled();
+    expect(emitSpy).toHaveBeenCalledWith('toast.show', {
+      type: 'error',
+      message: '[HTTP_REQUEST_FAILED] Unresolved variables: baseUrl',
+      correlationId: 'test-correlation-id',
+    });
+    expect(getContextState()?.response).toBeNull();
+    expect(result.current.isLoading).toBe(false);
+  });
+
   it('handleSend handles errors and emits toast', async () => {
     const mockError = {
       code: 'NETWORK_ERROR',
@@ -221,7 +408,7 @@ describe('useRequestActions', () => {
         resolve({
           status: 200,
           status_text: 'OK',
-          headers: {},
+          headers: [],
           body: '{"success": true}',
           timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
         });
@@ -258,11 +445,56 @@ describe('useRequestActions', () => {
     });
   });
 
+  it('handleCancel cancels the request in flight by its correlation ID', async () => {
+    let rejectRequest: ((reason: unknown) => void) | undefined;
+    vi.mocked(httpModule.executeRequest).mockReturnValue(
+      new Promise<HttpResponse>((_, reject) => {
+        rejectRequest = reject;
+      })
+    );
+    vi.mocked(httpModule.cancelRequest).mockResolvedValue(true);
+    const emitSpy = vi.spyOn(globalEventBus, 'emit');
+
+    const { result } = renderHook(() => useRequestActions());
+
+    let request: Promise<void> | undefined;
+    act(() => {
+      request = result.current.handleSend();
+    });
+    await waitFor(() => {
+      expect(result.current.isLoading).toBe(true);
+    });
+
+    act(() => {
+      result.current.handleCancel();
+    });
+    const correlationId = vi.mocked(httpModule.executeRequest).mock.calls[0]?.[1];
+    expect(correlationId).toBeDefined();
+    expect(httpModule.cancelRequest).toHaveBeenCalledWith(correlationId);
+
+    rejectRequest!({
+      code: 'REQUEST_CANCELLED',
+      message: 'Request cancelled',
+      correlationId,
+      source: 'backend' as const,
+    });
+    await act(async () => {
+      await request;
+    });
+
+    expect(emitSpy).toHaveBeenCalledWith('toast.show', {
+      type: 'info',
+      message: 'Request cancelled',
+    });
+    expect(result.current.isLoading).toBe(false);
+    expect(getContextState()?.correlationId).toBeNull();
+  });
+
   it('handleSend clears response before executing', async () => {
     const mockResponse = {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"success": true}',
       timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
     };
@@ -288,7 +520,7 @@ describe('useRequestActions', () => {
     const mockResponse = {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"success": true}',
       timing: { total_ms: 100, dns_ms: 10, tls_ms: 20, connect_ms: 30, first_byte_ms: 40 },
     };
@@ -8,16 +8,28 @@
  */
 
 import { useState, useLayoutEffect, useContext } from 'react';
-import { executeRequest } from '@/api/http';
+import {
+  cancelRequest,
+  executeCollectionRequest,
+  executeRequest,
+  executeStreamingRequest,
+  resolveCollectionRequest,
+} from '@/api/http';
+import { generateCorrelationId } from '@/utils/correlation-id';
 import { isAppError, type AppError } from '@/types/errors';
 import { useHistoryStore } from '@/stores/useHistoryStore';
-import { createRequestParams, type HttpMethod } from '@/types/http';
+import {
+  createRequestParams,
+  type HttpMethod,
+  type HttpResponse,
+  type RequestParams,
+} from '@/types/http';
 import { useRequestStore, RequestContextIdContext } from '@/stores/useRequestStore';
 import { globalEventBus, type ToastEventPayload } from '@/events/bus';
 import { useCanvasStore } from '@/stores/useCanvasStore';
 import { useCollectionStore } from '@/stores/useCollectionStore';
-import { resolveVariables } from '@/utils/variables';
 import type { RequestTabState } from '@/types/canvas';
+import type { AssertionResult } from '@/types/generated/AssertionResult';
 
 export interface UseRequestActionsReturn {
   /** Local URL state (optimistic) */
@@ -30,21 +42,51 @@ export interface UseRequestActionsReturn {
   isValidUrl: boolean;
   /** Send the request */
   handleSend: () => Promise<void>;
+  /** Cancel the request in flight */
+  handleCancel: () => void;
   /** Update method (local and store) */
   handleMethodChange: (method: HttpMethod) => void;
   /** Update URL (local only) */
   handleUrlChange: (url: string) => void;
 }
 
+/**
+ * Surface the assertions a saved request failed as a warning toast.
+ */
+const reportFailedAssertions = (assertions: AssertionResult[]): void => {
+  const failed = assertions.filter((assertion) => !assertion.passed);
+  if (failed.length === 0) {
+    return;
+  }
+  globalEventBus.emit<ToastEventPayload>('toast.show', {
+    type: 'warning',
+    message: `${String(failed.length)} of ${String(assertions.length)} assertions failed`,
+    details: failed
+      .map((assertion) => `${assertion.check}: ${assertion.message ?? 'failed'}`)
+      .join('\n'),
+  });
+};
+
 /**
  * Hook for request actions (send, method change, URL change)
  */
 export const useRequestActions = (): UseRequestActionsReturn => {
   const contextId = useContext(RequestContextIdContext) ?? 'global';
-  const { method, url, headers, body, isLoading, setMethod, setUrl, setResponse, setLoading } =
-    useRequestStore();
+  const {
+    method,
+    url,
+    headers,
+    body,
+    isLoading,
+    correlationId,
+    setMethod,
+    setUrl,
+    setResponse,
+    setLoading,
+    setCorrelationId,
+  } = useRequestStore();
 
-  const { addEntry } = useHistoryStore();
+  const { addEntry, loadHistory } = useHistoryStore();
 
   const [localUrl, setLocalUrl] = useState(() => url);
   const [localMethod, setLocalMethod] = useState<HttpMethod>(() => method as HttpMethod);
@@ -63,62 +105,78 @@ export const useRequestActions = (): UseRequestActionsReturn => {
       return;
     }
 
-    // Resolve environment variables before sending
-    const canvasState = useCanvasStore.getState();
-    const { activeContextId, getContextState } = canvasState;
-    const tabState =
-      activeContextId !== null ? (getContextState(activeContextId) as RequestTabState) : undefined;
-    const collectionId = tabState?.source?.collectionId;
-    const collection =
-      collectionId !== undefined
-        ? useCollectionStore.getState().collections.find((c) => c.id === collectionId)
+    // Saved requests are resolved by the backend, like MCP and the collection runner
+    const { activeContextId, getContextState } = useCanvasStore.getState();
+    const source =
+      activeContextId !== null
+        ? (getContextState(activeContextId) as RequestTabState).source
         : undefined;
-    const activeEnv = collection?.environments.find(
-      (e) => e.name === collection.active_environment
-    );
-    const envVars: Record<string, string> = {
-      ...(collection?.variables ?? {}),
-      ...(activeEnv?.variables ?? {}),
-    };
-    const resolvedUrl = resolveVariables(localUrl, envVars);
-
-    const unresolvedPattern = /\{\{(\w+)\}\}/;
-    if (unresolvedPattern.test(resolvedUrl)) {
-      globalEventBus.emit<ToastEventPayload>('toast.show', {
-        type: 'error',
-        message: `URL contains unresolved variable: ${unresolvedPattern.exec(resolvedUrl)?.[0] ?? '{{…}}'}. Set the variable in the active environment.`,
-      });
-      return;
-    }
 
+    const requestCorrelationId = generateCorrelationId();
     setLoading(true);
+    setCorrelationId(requestCorrelationId);
     setResponse(null);
     setUrl(localUrl);
     setMethod(localMethod);
 
     try {
-      // Get current request state (headers and body from RequestBuilder)
-      const currentHeaders = headers;
-      const currentBody = body === '' ? null : body;
-
-      const params = createRequestParams(resolvedUrl, localMethod, {
-        headers: currentHeaders,
-        body: currentBody,
-      });
-      const result = await executeRequest(params);
+      let request: RequestParams;
+      let result: HttpResponse;
+      let savedByBackend = false;
+      const savedRequest =
+        source?.collectionId !== undefined
+          ? useCollectionStore
+              .getState()
+              .collections.find((c) => c.id === source.collectionId)
+              ?.requests.find((r) => r.id === source.requestId)
+          : undefined;
+      if (
+        source?.collectionId !== undefined &&
+        source.requestId !== undefined &&
+        savedRequest?.is_streaming === true
+      ) {
+        // Streamed into the response viewer as it arrives (see useHttpStream);
+        // the backend records the finished stream in history itself
+        request = await resolveCollectionRequest(source.collectionId, source.requestId, {
+          method: localMethod,
+          url: localUrl,
+          headers,
+          body,
+        });
+        result = await executeStreamingRequest(request, requestCorrelationId);
+        savedByBackend = true;
+      } else if (source?.collectionId !== undefined && source.requestId !== undefined) {
+        // Variables, query params, inherited auth and environment settings are
+        // applied on top of the tab's edits; unresolved variables fail the send
+        const execution = await executeCollectionRequest(
+          source.collectionId,
+          source.requestId,
+          { method: localMethod, url: localUrl, headers, body },
+          undefined,
+          requestCorrelationId
+        );
+        request = execution.request;
+        result = execution.response;
+        reportFailedAssertions(execution.assertions);
+        // Extraction rules may have stored values in the active environment
+        if (execution.extracted.some((v) => v.scope === 'environment' && v.value !== undefined)) {
+          void useCollectionStore.getState().loadCollection(source.collectionId);
+        }
+      } else {
+        request = createRequestParams(localUrl, localMethod, {
+          headers,
+          body: body === '' ? null : body,
+        });
+        result = await executeRequest(request, requestCorrelationId);
+      }
       setResponse(result);
 
-      // Auto-save to history after successful request (use resolved URL)
-      await addEntry(
-        {
-          url: resolvedUrl,
-          method: localMethod,
-          headers: currentHeaders,
-          body: currentBody,
-          timeout_ms: params.timeout_ms,
-        },
-        result
-      );
+      // Auto-save to history after successful request (as sent, variables resolved)
+      if (savedByBackend) {
+        await loadHistory();
+      } else {
+        await addEntry(request, result);
+      }
 
       // Check for history errors and surface via toast
       const historyError = useHistoryStore.getState().error;
@@ -144,7 +202,12 @@ export const useRequestActions = (): UseRequestActionsReturn => {
         appError = e;
       }
 
-      if (appError !== undefined) {
+      if (appError?.code === 'REQUEST_CANCELLED') {
+        globalEventBus.emit<ToastEventPayload>('toast.show', {
+          type: 'info',
+          message: 'Request cancelled',
+        });
+      } else if (appError !== undefined) {
         const errorMessage = `[${appError.code}] ${appError.message}`;
         // Show toast notification via event bus (loose coupling)
         globalEventBus.emit<ToastEventPayload>('toast.show', {
@@ -164,6 +227,13 @@ export const useRequestActions = (): UseRequestActionsReturn => {
       // Don't save to history on error
     } finally {
       setLoading(false);
+      setCorrelationId(null);
+    }
+  };
+
+  const handleCancel = (): void => {
+    if (correlationId !== null) {
+      void cancelRequest(correlationId);
     }
   };
 
@@ -182,6 +252,7 @@ export const useRequestActions = (): UseRequestActionsReturn => {
     isLoading,
     isValidUrl,
     handleSend,
+    handleCancel,
     handleMethodChange,
     handleUrlChange,
   };
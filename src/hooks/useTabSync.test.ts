@@ -197,7 +197,7 @@ describe('useTabSync', () => {
       const mockResponse = {
         status: 200,
         status_text: 'OK',
-        headers: {},
+        headers: [],
         body: '{"ok":true}',
         timing: {
           total_ms: 100,
@@ -233,14 +233,14 @@ describe('useTabSync', () => {
           request: {
             url: 'https://api.example.com/history',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '',
             timing: {
               total_ms: 50,
@@ -270,14 +270,14 @@ describe('useTabSync', () => {
         request: {
           url: 'https://api.example.com/repeat',
           method: 'POST',
-          headers: {},
+          headers: [],
           body: null,
           timeout_ms: 30000,
         },
         response: {
           status: 201,
           status_text: 'Created',
-          headers: {},
+          headers: [],
           body: '',
           timing: {
             total_ms: 50,
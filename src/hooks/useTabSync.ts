@@ -9,6 +9,7 @@ import { useTabStore } from '@/stores/useTabStore';
 import { deriveTabLabel, type TabSource } from '@/types/tab';
 import { globalEventBus, type CollectionRequestSelectedPayload } from '@/events/bus';
 import type { HistoryEntry } from '@/types/generated/HistoryEntry';
+import { headersToRecord } from '@/types/http';
 
 /**
  * Bidirectional sync between `useTabStore` (multi-tab) and `useRequestStoreRaw` (keyed store).
@@ -124,7 +125,7 @@ export function useTabSync(): void {
           store.openTab({
             method: entry.request.method,
             url: entry.request.url,
-            headers: entry.request.headers,
+            headers: headersToRecord(entry.request.headers),
             body: entry.request.body ?? '',
             label: deriveTabLabel(entry.request.url),
             source,
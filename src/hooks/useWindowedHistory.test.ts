@@ -27,14 +27,14 @@ interface MockHistoryEntry {
   request: {
     url: string;
     method: string;
-    headers: Record<string, string>;
+    headers: Array<{ name: string; value: string }>;
     body: string | null;
     timeout_ms: number;
   };
   response: {
     status: number;
     status_text: string;
-    headers: Record<string, string>;
+    headers: Array<{ name: string; value: string }>;
     body: string;
     timing: {
       total_time_ms: number;
@@ -55,14 +55,14 @@ function createMockEntry(id: string): MockHistoryEntry {
     request: {
       url: `https://api.example.com/${id}`,
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_time_ms: 100,
@@ -179,7 +179,7 @@ describe('HomePage - Auto-save to history', () => {
     const mockResponse = {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{"test": true}',
       timing: {
         total_ms: 150,
@@ -281,7 +281,7 @@ describe('HomePage - Auto-save to history', () => {
     const mockResponse = {
       status: 201,
       status_text: 'Created',
-      headers: {},
+      headers: [],
       body: '{"id": 1}',
       timing: {
         total_ms: 200,
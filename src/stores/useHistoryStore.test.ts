@@ -73,14 +73,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/users',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{"users": []}',
             timing: {
               total_ms: 100,
@@ -155,14 +155,14 @@ describe('useHistoryStore', () => {
         request: {
           url: 'https://api.example.com/test',
           method: 'POST',
-          headers: {},
+          headers: [],
           body: '{"test": true}',
           timeout_ms: 30000,
         },
         response: {
           status: 201,
           status_text: 'Created',
-          headers: {},
+          headers: [],
           body: '{"id": 1}',
           timing: {
             total_ms: 150,
@@ -204,14 +204,14 @@ describe('useHistoryStore', () => {
         request: {
           url: 'https://api.example.com/old',
           method: 'GET',
-          headers: {},
+          headers: [],
           body: null,
           timeout_ms: 30000,
         },
         response: {
           status: 200,
           status_text: 'OK',
-          headers: {},
+          headers: [],
           body: '{}',
           timing: {
             total_ms: 50,
@@ -231,14 +231,14 @@ describe('useHistoryStore', () => {
         request: {
           url: 'https://api.example.com/new',
           method: 'GET',
-          headers: {},
+          headers: [],
           body: null,
           timeout_ms: 30000,
         },
         response: {
           status: 200,
           status_text: 'OK',
-          headers: {},
+          headers: [],
           body: '{}',
           timing: {
             total_ms: 50,
@@ -281,14 +281,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/1',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{}',
             timing: {
               total_ms: 50,
@@ -305,14 +305,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/2',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{}',
             timing: {
               total_ms: 50,
@@ -366,14 +366,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/1',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{}',
             timing: {
               total_ms: 50,
@@ -815,14 +815,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/users',
             method: 'GET',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 200,
             status_text: 'OK',
-            headers: {},
+            headers: [],
             body: '{"users": []}',
             timing: {
               total_ms: 100,
@@ -839,14 +839,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/posts',
             method: 'POST',
-            headers: {},
+            headers: [],
             body: '{}',
             timeout_ms: 30000,
           },
           response: {
             status: 201,
             status_text: 'Created',
-            headers: {},
+            headers: [],
             body: '{}',
             timing: {
               total_ms: 150,
@@ -863,14 +863,14 @@ describe('useHistoryStore', () => {
           request: {
             url: 'https://api.example.com/items',
             method: 'DELETE',
-            headers: {},
+            headers: [],
             body: null,
             timeout_ms: 30000,
           },
           response: {
             status: 404,
             status_text: 'Not Found',
-            headers: {},
+            headers: [],
             body: '{}',
             timing: {
               total_ms: 50,
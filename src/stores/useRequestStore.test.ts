@@ -83,7 +83,7 @@ describe('useRequestStore', () => {
     const mockResponse: HttpResponse = {
       status: 200,
       status_text: 'OK',
-      headers: { 'Content-Type': 'application/json' },
+      headers: [{ name: 'Content-Type', value: 'application/json' }],
       body: '{"result": "success"}',
       timing: {
         total_ms: 150,
@@ -191,7 +191,7 @@ describe('useRequestStore', () => {
       const responseWithNullBody: HttpResponse = {
         status: 204,
         status_text: 'No Content',
-        headers: {},
+        headers: [],
         body: '',
         timing: {
           total_ms: 50,
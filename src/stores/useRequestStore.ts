@@ -22,6 +22,8 @@ export interface RequestContextState {
   body: string;
   response: HttpResponse | null;
   isLoading: boolean;
+  /** Correlation ID of the request in flight, used to cancel it */
+  correlationId: string | null;
 }
 
 export interface RequestStore {
@@ -35,6 +37,7 @@ export interface RequestStore {
   setBody: (contextId: string, body: string) => void;
   setResponse: (contextId: string, response: HttpResponse | null) => void;
   setLoading: (contextId: string, loading: boolean) => void;
+  setCorrelationId: (contextId: string, correlationId: string | null) => void;
   reset: (contextId: string) => void;
   initContext: (contextId: string, initialState?: Partial<RequestContextState>) => void;
 }
@@ -46,6 +49,7 @@ export const DEFAULT_REQUEST_STATE: RequestContextState = {
   body: '',
   response: null,
   isLoading: false,
+  correlationId: null,
 };
 
 /**
@@ -140,6 +144,18 @@ export const useRequestStoreRaw = create<RequestStore>()(
         }));
       },
 
+      setCorrelationId: (contextId, correlationId): void => {
+        set((state) => ({
+          contexts: {
+            ...state.contexts,
+            [contextId]: {
+              ...(state.contexts[contextId] ?? DEFAULT_REQUEST_STATE),
+              correlationId,
+            },
+          },
+        }));
+      },
+
       reset: (contextId): void => {
         set((state) => ({
           contexts: {
@@ -156,7 +172,7 @@ export const useRequestStoreRaw = create<RequestStore>()(
         contexts: Object.fromEntries(
           Object.entries(state.contexts).map(([id, ctx]) => [
             id,
-            { ...ctx, response: null, isLoading: false },
+            { ...ctx, response: null, isLoading: false, correlationId: null },
           ])
         ),
       }),
@@ -178,6 +194,7 @@ export const useRequestStore = (): RequestContextState & {
   setBody: (body: string) => void;
   setResponse: (response: HttpResponse | null) => void;
   setLoading: (loading: boolean) => void;
+  setCorrelationId: (correlationId: string | null) => void;
   reset: () => void;
 } => {
   const contextId = useContext(RequestContextIdContext) ?? 'global';
@@ -204,6 +221,9 @@ export const useRequestStore = (): RequestContextState & {
     setLoading: (isLoading: boolean): void => {
       actions.setLoading(contextId, isLoading);
     },
+    setCorrelationId: (correlationId: string | null): void => {
+      actions.setCorrelationId(contextId, correlationId);
+    },
     reset: (): void => {
       actions.reset(contextId);
     },
@@ -9,7 +9,18 @@
  * IMPORTANT: These types MUST match src-tauri/src/domain/collection/*.rs
  */
 
+import type { Assertion } from '@/types/generated/Assertion';
+import type { ConnectToOverride } from '@/types/generated/ConnectToOverride';
+import type { ExtractionRule } from '@/types/generated/ExtractionRule';
+import type { GraphqlQuery } from '@/types/generated/GraphqlQuery';
+import type { HttpVersion } from '@/types/generated/HttpVersion';
+import type { MultipartPart } from '@/types/generated/MultipartPart';
 import type { PinnedSpecVersion } from '@/types/generated/PinnedSpecVersion';
+import type { ProxyConfig } from '@/types/generated/ProxyConfig';
+import type { ResolveOverride } from '@/types/generated/ResolveOverride';
+import type { RetryPolicy } from '@/types/generated/RetryPolicy';
+import type { RunUpdate } from '@/types/generated/RunUpdate';
+import type { TlsConfig } from '@/types/generated/TlsConfig';
 
 /** Schema URL for validation */
 export const SCHEMA_URL = 'https://runi.dev/schema/collection/v1.json';
@@ -23,6 +34,14 @@ export type ExtensionFields = Record<`x-${string}`, unknown>;
 export interface CollectionEnvironment {
   name: string;
   variables?: Record<string, string>;
+  /** TLS settings (client certificate, CA bundle, verification) for this environment. */
+  tls?: TlsConfig;
+  /** Proxy settings for this environment; override the global proxy. */
+  proxy?: ProxyConfig;
+  /** Host names pinned to fixed addresses (curl `--resolve`). */
+  resolve?: ResolveOverride[];
+  /** Hosts and ports connected to in place of requested ones (curl `--connect-to`). */
+  connect_to?: ConnectToOverride[];
 }
 
 export interface Collection extends ExtensionFields {
@@ -35,6 +54,10 @@ export interface Collection extends ExtensionFields {
   variables: Record<string, string>;
   environments: CollectionEnvironment[];
   active_environment?: string;
+  /** HTTP version for requests that don't set their own. */
+  http_version?: HttpVersion;
+  /** Retry policy for requests that don't set their own. */
+  retry?: RetryPolicy;
   requests: CollectionRequest[];
   pinned_versions: PinnedSpecVersion[];
 }
@@ -77,10 +100,28 @@ export interface CollectionRequest extends ExtensionFields {
   params: RequestParam[];
   body?: RequestBody;
   auth?: AuthConfig;
+  /** HTTP version for this request (overrides the collection's). */
+  http_version?: HttpVersion;
+  /** Retry policy for this request (overrides the collection's). */
+  retry?: RetryPolicy;
+  /** Whether to follow `Location` redirects (default: true). */
+  follow_redirects?: boolean;
+  /** Unix domain socket to send the request through instead of TCP. */
+  unix_socket?: string;
+  /** Values captured from the response into variables after the request runs. */
+  extract?: ExtractionRule[];
+  /** Checks on the response, evaluated every time the request runs. */
+  assertions?: Assertion[];
   docs?: string;
   is_streaming: boolean;
+  /** How the request is sent (default `http`). */
+  kind?: 'http' | 'websocket';
+  /** WebSocket subprotocols offered during the handshake. */
+  subprotocols?: string[];
   binding: SpecBinding;
   intelligence: IntelligenceMetadata;
+  /** Folder the request is grouped under, as a `/`-separated path. */
+  folder?: string;
   tags: string[];
 }
 
@@ -94,16 +135,30 @@ export interface RequestBody {
   type: BodyType;
   content?: string;
   file?: string;
+  parts?: MultipartPart[];
+  /** Query, variables and operation name of a `graphql` body. */
+  graphql?: GraphqlQuery;
 }
 
-export type BodyType = 'none' | 'json' | 'form' | 'raw' | 'graphql' | 'xml';
+export type BodyType =
+  | 'none'
+  | 'json'
+  | 'form'
+  | 'multipart'
+  | 'binary'
+  | 'raw'
+  | 'graphql'
+  | 'xml';
 
 export interface AuthConfig {
   type: AuthType;
   token?: string;
   username?: string;
   password?: string;
+  /** Header name for API key auth (default `X-API-Key`). */
   header?: string;
+  /** Query parameter name for API key auth; sends the key in the query string instead. */
+  query_param?: string;
 }
 
 export type AuthType = 'none' | 'bearer' | 'basic' | 'api_key';
@@ -225,3 +280,11 @@ export function createDefaultBinding(): SpecBinding {
 export function createDefaultIntelligence(): IntelligenceMetadata {
   return { ai_generated: false };
 }
+
+/**
+ * Payload of the `collection:run` event emitted while a collection run goes on.
+ *
+ * Mirrors Rust's `CollectionRunEvent` (a `RunUpdate` flattened next to its
+ * run ID), which ts-rs cannot generate because of the flatten.
+ */
+export type CollectionRunEvent = { run_id: string } & RunUpdate;
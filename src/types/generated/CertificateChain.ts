@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CertificateIssue } from "./CertificateIssue";
+import type { PeerCertificate } from "./PeerCertificate";
+
+/**
+ * Certificates the server presented, leaf first, with anything worth flagging.
+ */
+export type CertificateChain = { 
+/**
+ * The chain as sent by the server; the first entry is the server's own certificate.
+ */
+certificates: Array<PeerCertificate>, 
+/**
+ * Problems with the chain. Empty when nothing was found.
+ */
+issues?: Array<CertificateIssue>, };
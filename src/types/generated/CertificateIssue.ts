@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A problem with the presented certificate chain.
+ */
+export type CertificateIssue = "expired" | "not_yet_valid" | "self_signed" | "hostname_mismatch";
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssertionResult } from "./AssertionResult";
+import type { ExtractedVariable } from "./ExtractedVariable";
+import type { HttpResponse } from "./HttpResponse";
+import type { RequestParams } from "./RequestParams";
+
+/**
+ * A collection request as sent and its response, together with the
+ * variables its extraction rules captured and the outcome of its assertions.
+ */
+export type CollectionRequestExecution = { 
+/**
+ * The request as sent, with variables, query params and auth applied.
+ */
+request: RequestParams, 
+/**
+ * The response.
+ */
+response: HttpResponse, 
+/**
+ * Outcome of each extraction rule, in rule order.
+ */
+extracted: Array<ExtractedVariable>, 
+/**
+ * Outcome of each assertion, in assertion order.
+ */
+assertions: Array<AssertionResult>, };
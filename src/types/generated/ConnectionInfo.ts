@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a request reached the server and how much it transferred.
+ */
+export type ConnectionInfo = { 
+/**
+ * HTTP version of the final response, e.g. `HTTP/1.1`, `HTTP/2` or `HTTP/3`.
+ */
+http_version: string, 
+/**
+ * IP address of the server (or proxy) the final request was sent to.
+ */
+remote_ip?: string, 
+/**
+ * Port of the server (or proxy) the final request was sent to.
+ */
+remote_port?: number, 
+/**
+ * Bytes of the issued requests as curl counts them, across redirects.
+ */
+request_bytes: number, 
+/**
+ * Bytes of request body uploaded.
+ */
+upload_bytes: number, 
+/**
+ * Bytes of response headers received, across redirects.
+ */
+response_header_bytes: number, 
+/**
+ * Bytes of response body downloaded.
+ */
+download_bytes: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Identifies the cookie jar a request reads from and writes to.
+ */
+export type CookieJarScope = { 
+/**
+ * Collection that owns the jar.
+ */
+collection_id: string, 
+/**
+ * Environment the jar belongs to; `None` when no environment is active.
+ */
+environment?: string, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where an extraction rule reads its value from.
+ */
+export type ExtractionSource = { "from": "json_path", 
+/**
+ * The `JSONPath` query.
+ */
+path: string, } | { "from": "header", 
+/**
+ * Header name.
+ */
+name: string, } | { "from": "regex", 
+/**
+ * The regular expression.
+ */
+pattern: string, 
+/**
+ * Capture group to take; defaults to the first group, or the whole
+ * match when the pattern has none.
+ */
+group?: number, } | { "from": "status" };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GraphqlInputValue } from "./GraphqlInputValue";
+
+/**
+ * A field of an object or interface.
+ */
+export type GraphqlField = { 
+/**
+ * Field name.
+ */
+name: string, 
+/**
+ * Type as written in SDL, e.g. `[User!]!`.
+ */
+type_ref: string, 
+/**
+ * Arguments the field accepts.
+ */
+args?: Array<GraphqlInputValue>, 
+/**
+ * Description from the schema.
+ */
+description?: string, 
+/**
+ * Whether the field is deprecated.
+ */
+deprecated?: boolean, };
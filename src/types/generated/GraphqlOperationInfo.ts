@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GraphqlInputValue } from "./GraphqlInputValue";
+
+/**
+ * A root field that can be called as a query, mutation or subscription.
+ */
+export type GraphqlOperationInfo = { 
+/**
+ * `query`, `mutation` or `subscription`.
+ */
+kind: string, 
+/**
+ * Name of the root field.
+ */
+name: string, 
+/**
+ * Arguments of the root field.
+ */
+args: Array<GraphqlInputValue>, 
+/**
+ * Return type as written in SDL.
+ */
+type_ref: string, 
+/**
+ * Description from the schema.
+ */
+description?: string, 
+/**
+ * Whether the field is deprecated.
+ */
+deprecated?: boolean, };
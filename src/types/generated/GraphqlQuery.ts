@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A GraphQL request: the document, its variables and the operation to run.
+ *
+ * Stored on [`crate::domain::collection::RequestBody::graphql`] and
+ * serialized to the wire by [`Self::to_json_body`] (POST) or
+ * [`Self::apply_to_url`] (GET).
+ */
+export type GraphqlQuery = { 
+/**
+ * The GraphQL document.
+ */
+query: string, 
+/**
+ * Variable values, as a JSON object.
+ */
+variables?: Record<string, unknown>, 
+/**
+ * Operation to run when the document defines several.
+ */
+operation_name?: string, };
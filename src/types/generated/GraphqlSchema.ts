@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GraphqlType } from "./GraphqlType";
+
+/**
+ * A GraphQL schema: root operation types and every named type.
+ */
+export type GraphqlSchema = { 
+/**
+ * Root type of queries.
+ */
+query_type?: string, 
+/**
+ * Root type of mutations, if the schema supports them.
+ */
+mutation_type?: string, 
+/**
+ * Root type of subscriptions, if the schema supports them.
+ */
+subscription_type?: string, 
+/**
+ * Named types by name, introspection types (`__*`) excluded.
+ */
+types: { [key in string]: GraphqlType }, };
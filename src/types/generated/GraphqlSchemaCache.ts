@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GraphqlSchema } from "./GraphqlSchema";
+
+/**
+ * A schema fetched by introspection, as cached for a collection.
+ */
+export type GraphqlSchemaCache = { 
+/**
+ * URL the schema was introspected from.
+ */
+endpoint: string, 
+/**
+ * When the schema was fetched (ISO 8601).
+ */
+fetched_at: string, 
+/**
+ * The schema.
+ */
+schema: GraphqlSchema, };
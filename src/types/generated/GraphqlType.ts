@@ -0,0 +1,37 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GraphqlField } from "./GraphqlField";
+import type { GraphqlInputValue } from "./GraphqlInputValue";
+import type { GraphqlTypeKind } from "./GraphqlTypeKind";
+
+/**
+ * A named type of the schema.
+ */
+export type GraphqlType = { 
+/**
+ * Kind of the type.
+ */
+kind: GraphqlTypeKind, 
+/**
+ * Description from the schema.
+ */
+description?: string, 
+/**
+ * Fields of an object or interface.
+ */
+fields?: Array<GraphqlField>, 
+/**
+ * Fields of an input object.
+ */
+input_fields?: Array<GraphqlInputValue>, 
+/**
+ * Objects implementing an interface or belonging to a union.
+ */
+possible_types?: Array<string>, 
+/**
+ * Interfaces an object or interface implements.
+ */
+interfaces?: Array<string>, 
+/**
+ * Values of an enum.
+ */
+enum_values?: Array<string>, };
@@ -1,8 +1,21 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CertificateChain } from "./CertificateChain";
+import type { ConnectionInfo } from "./ConnectionInfo";
+import type { HttpHeaders } from "./HttpHeaders";
+import type { RedirectHop } from "./RedirectHop";
 import type { RequestTiming } from "./RequestTiming";
+import type { ResponseBodyKind } from "./ResponseBodyKind";
+import type { RetryAttempt } from "./RetryAttempt";
+import type { StreamTranscript } from "./StreamTranscript";
+import type { WebSocketTranscript } from "./WebSocketTranscript";
+import type { WireTrace } from "./WireTrace";
 
 /**
  * Response from an HTTP request.
+ *
+ * Textual bodies are carried in `body`. Binary bodies leave `body` empty and
+ * carry the raw bytes base64-encoded in `body_base64`, so images, PDFs and
+ * compressed payloads survive the round trip unchanged.
  */
 export type HttpResponse = { 
 /**
@@ -14,14 +27,55 @@ status: number,
  */
 status_text: string, 
 /**
- * Response headers as key-value pairs.
+ * Response headers in wire order; repeated names (e.g. `Set-Cookie`) are kept.
  */
-headers: Record<string, string>, 
+headers: HttpHeaders, 
 /**
- * Response body as a string.
+ * Response body decoded as text (empty for binary bodies).
  */
 body: string, 
+/**
+ * Raw response body, base64-encoded. Only set for non-text bodies.
+ */
+body_base64?: string, 
+/**
+ * Detected kind of the response body.
+ */
+body_kind?: ResponseBodyKind, 
+/**
+ * Size of the raw response body in bytes, as received.
+ */
+body_size?: number, 
 /**
  * Timing information for the request.
  */
-timing: RequestTiming, };
+timing: RequestTiming, 
+/**
+ * Decoded messages, for requests executed in streaming mode.
+ */
+stream?: StreamTranscript, 
+/**
+ * Messages of a WebSocket session, for history entries of WebSocket requests.
+ */
+websocket?: WebSocketTranscript, 
+/**
+ * Redirects followed before the final response, in order.
+ */
+redirects?: Array<RedirectHop>, 
+/**
+ * Negotiated protocol, remote address and transfer sizes.
+ */
+connection?: ConnectionInfo, 
+/**
+ * Certificate chain the server presented, for HTTPS responses.
+ */
+certificates?: CertificateChain, 
+/**
+ * Every attempt made under the request's retry policy, in order. Empty
+ * when no policy applied.
+ */
+attempts?: Array<RetryAttempt>, 
+/**
+ * Wire-level trace, for requests sent with [`RequestParams::verbose`].
+ */
+trace?: WireTrace, };
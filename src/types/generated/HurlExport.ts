@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HurlExportFile } from "./HurlExportFile";
+
+/**
+ * What a Hurl export wrote.
+ */
+export type HurlExport = { 
+/**
+ * The `.hurl` files, one per collection or tag.
+ */
+files: Array<HurlExportFile>, 
+/**
+ * Path of the variables file, when there are variables to export.
+ */
+variables_file?: string, 
+/**
+ * What could not be carried over to Hurl.
+ */
+warnings?: Array<string>, };
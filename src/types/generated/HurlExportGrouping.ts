@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How exported requests are split into files.
+ */
+export type HurlExportGrouping = "collection" | "tag";
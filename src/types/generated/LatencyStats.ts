@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Latency distribution of one timing phase, in milliseconds.
+ *
+ * Percentiles use the nearest-rank method.
+ */
+export type LatencyStats = { 
+/**
+ * Number of samples.
+ */
+count: number, 
+/**
+ * Fastest sample.
+ */
+min_ms: number, 
+/**
+ * Arithmetic mean, rounded down.
+ */
+mean_ms: number, 
+/**
+ * Median.
+ */
+p50_ms: number, 
+/**
+ * 90th percentile.
+ */
+p90_ms: number, 
+/**
+ * 99th percentile.
+ */
+p99_ms: number, 
+/**
+ * Slowest sample.
+ */
+max_ms: number, };
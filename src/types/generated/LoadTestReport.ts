@@ -0,0 +1,48 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LoadTestOptions } from "./LoadTestOptions";
+import type { LoadTestStats } from "./LoadTestStats";
+
+/**
+ * Result of a load test, saved as a run artifact.
+ */
+export type LoadTestReport = { 
+/**
+ * Load test ID; also the correlation ID of its requests.
+ */
+load_test_id: string, 
+/**
+ * ID of the collection holding the request.
+ */
+collection_id: string, 
+/**
+ * ID of the request that was sent.
+ */
+request_id: string, 
+/**
+ * Name of the request.
+ */
+request_name: string, 
+/**
+ * HTTP method.
+ */
+method: string, 
+/**
+ * URL as sent, with variables resolved.
+ */
+url: string, 
+/**
+ * RFC 3339 UTC timestamp of the start of the test.
+ */
+started_at: string, 
+/**
+ * Options the test ran with.
+ */
+options: LoadTestOptions, 
+/**
+ * Whether the test was stopped with `cancel_request` before its end.
+ */
+cancelled: boolean, 
+/**
+ * Final statistics.
+ */
+stats: LoadTestStats, };
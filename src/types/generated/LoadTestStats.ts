@@ -0,0 +1,47 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PhaseLatencies } from "./PhaseLatencies";
+
+/**
+ * Aggregate results of a load test, so far or in total.
+ */
+export type LoadTestStats = { 
+/**
+ * Requests completed, with or without a response.
+ */
+requests: number, 
+/**
+ * Responses with a status below 400.
+ */
+succeeded: number, 
+/**
+ * Responses with a 4xx or 5xx status.
+ */
+failed: number, 
+/**
+ * Requests that got no response (connection errors, timeouts).
+ */
+errored: number, 
+/**
+ * Share of requests that failed or errored, from 0 to 1.
+ */
+error_rate: number, 
+/**
+ * Completed requests per second of elapsed time.
+ */
+throughput_rps: number, 
+/**
+ * Time since the test started, in milliseconds.
+ */
+elapsed_ms: number, 
+/**
+ * Number of responses per status code.
+ */
+status_codes: { [key in number]: number }, 
+/**
+ * Number of errored requests per error code.
+ */
+errors: { [key in string]: number }, 
+/**
+ * Latency per timing phase, over requests that got a response.
+ */
+latency: PhaseLatencies, };
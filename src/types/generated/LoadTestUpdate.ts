@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LoadTestOptions } from "./LoadTestOptions";
+import type { LoadTestStats } from "./LoadTestStats";
+
+/**
+ * Live update of a running load test.
+ */
+export type LoadTestUpdate = { "kind": "started", 
+/**
+ * URL being tested.
+ */
+url: string, 
+/**
+ * Options the test runs with.
+ */
+options: LoadTestOptions, } | { "kind": "progress", 
+/**
+ * Workers started and not yet done.
+ */
+active_workers: number, 
+/**
+ * Statistics over the requests completed so far.
+ */
+stats: LoadTestStats, } | { "kind": "finished", 
+/**
+ * Whether the test was cancelled.
+ */
+cancelled: boolean, 
+/**
+ * Final statistics.
+ */
+stats: LoadTestStats, };
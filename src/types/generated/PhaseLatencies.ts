@@ -0,0 +1,39 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LatencyStats } from "./LatencyStats";
+
+/**
+ * Latency distributions for each phase of [`RequestTiming`].
+ *
+ * A phase is absent when no response reported it (e.g. `tls` for plain HTTP).
+ *
+ * [`RequestTiming`]: super::http::RequestTiming
+ */
+export type PhaseLatencies = { 
+/**
+ * Total request duration.
+ */
+total?: LatencyStats, 
+/**
+ * DNS resolution.
+ */
+dns?: LatencyStats, 
+/**
+ * TCP connection.
+ */
+connect?: LatencyStats, 
+/**
+ * TLS handshake.
+ */
+tls?: LatencyStats, 
+/**
+ * Time to first byte.
+ */
+first_byte?: LatencyStats, 
+/**
+ * Connection ready to last byte received.
+ */
+transfer?: LatencyStats, 
+/**
+ * First to last response byte.
+ */
+download?: LatencyStats, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Proxy settings for outgoing requests.
+ *
+ * Configured globally, per environment on `CollectionEnvironment::proxy`, or per
+ * request on [`RequestParams::proxy`]; the most specific one wins as a whole.
+ * A config without `url` and without `use_system` means "connect directly".
+ */
+export type ProxyConfig = { 
+/**
+ * Proxy URL, e.g. `http://proxy:3128`, `https://proxy:443` or `socks5h://proxy:1080`.
+ */
+url?: string, 
+/**
+ * Proxy username.
+ */
+username?: string, 
+/**
+ * Proxy password.
+ */
+password?: string, 
+/**
+ * Hosts that bypass the proxy: exact hosts, domain suffixes (`.corp.example`),
+ * IP addresses, CIDR ranges, or `*` for everything.
+ */
+no_proxy?: Array<string>, 
+/**
+ * When `url` is unset, use the `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY` and
+ * `NO_PROXY` environment variables.
+ */
+use_system?: boolean, };
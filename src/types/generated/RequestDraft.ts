@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unsaved edits of a saved request, as shown in its request tab.
+ *
+ * Sent with [`cmd_execute_collection_request`] in place of the saved
+ * method, URL, headers and body; everything else comes from the saved
+ * request and the collection.
+ */
+export type RequestDraft = { 
+/**
+ * HTTP method.
+ */
+method: string, 
+/**
+ * URL, variables unresolved.
+ */
+url: string, 
+/**
+ * Headers, variables unresolved.
+ */
+headers: { [key in string]: string }, 
+/**
+ * Inline body content; empty for none.
+ */
+body: string, };
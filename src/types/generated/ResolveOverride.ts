@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Pin a host name to fixed IP addresses, like curl `--resolve`.
+ *
+ * TLS verification and the `Host` header still use the host name, so a
+ * staging host can be pointed at one backend without editing `/etc/hosts`.
+ */
+export type ResolveOverride = { 
+/**
+ * Host name to pin, e.g. `api.staging.example`.
+ */
+host: string, 
+/**
+ * Port the pin applies to. Defaults to the port of the request URL.
+ */
+port?: number, 
+/**
+ * IPv4 or IPv6 addresses to connect to, tried in order.
+ */
+addresses: Array<string>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Detected kind of a response body.
+ *
+ * Derived from the `Content-Type` header when present, otherwise sniffed from
+ * the payload bytes. Only textual kinds are decoded into `HttpResponse::body`.
+ */
+export type ResponseBodyKind = "empty" | "json" | "xml" | "html" | "text" | "image" | "binary";
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One try of a request executed under a [`RetryPolicy`].
+ */
+export type RetryAttempt = { 
+/**
+ * Attempt number, starting at 1.
+ */
+attempt: number, 
+/**
+ * Response status, when the attempt got a response.
+ */
+status?: number, 
+/**
+ * Error code and message, when the attempt failed without a response.
+ */
+error?: string, 
+/**
+ * How long the attempt took, in milliseconds.
+ */
+duration_ms: number, 
+/**
+ * Wait before the next attempt, in milliseconds. Absent on the last attempt.
+ */
+retry_delay_ms?: number, };
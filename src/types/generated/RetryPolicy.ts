@@ -0,0 +1,39 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * When and how often a failed request is tried again.
+ *
+ * Configured per request on `RequestParams::retry`, or on a collection and
+ * its requests, where the request's policy wins.
+ */
+export type RetryPolicy = { 
+/**
+ * Total number of attempts, including the first (default: 3).
+ */
+max_attempts?: number, 
+/**
+ * Wait before the first retry, doubled for every further retry (default: 500).
+ */
+initial_delay_ms?: number, 
+/**
+ * Upper bound for any single wait, including one asked for by
+ * `Retry-After` (default: 30000).
+ */
+max_delay_ms?: number, 
+/**
+ * Randomize each backoff wait between half and all of its value (default: true).
+ */
+jitter?: boolean, 
+/**
+ * Response statuses that are retried (default: 429, 502, 503, 504).
+ */
+retry_on_status?: Array<number>, 
+/**
+ * Retry when the connection fails or breaks mid-transfer (default: true).
+ */
+retry_on_connection_error?: boolean, 
+/**
+ * Also retry methods that are not idempotent, such as POST and PATCH
+ * (default: false).
+ */
+retry_non_idempotent?: boolean, };
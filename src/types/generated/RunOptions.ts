@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a collection is run.
+ */
+export type RunOptions = { 
+/**
+ * Stop at the first failed request; the rest are reported as skipped.
+ */
+stop_on_failure?: boolean, 
+/**
+ * Pause between consecutive requests, in milliseconds.
+ */
+delay_ms?: number, 
+/**
+ * Only run requests in one of these folders or their subfolders.
+ */
+folders?: Array<string>, 
+/**
+ * Only run requests carrying at least one of these tags.
+ */
+tags?: Array<string>, 
+/**
+ * Only run these requests, given by ID or name.
+ */
+requests?: Array<string>, 
+/**
+ * Timeout for each request, in milliseconds (default: 30000).
+ */
+timeout_ms?: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of one request in a run.
+ *
+ * A response is judged by the request's assertions when it has any, and by
+ * its status otherwise.
+ */
+export type RunOutcome = "passed" | "failed" | "errored" | "skipped";
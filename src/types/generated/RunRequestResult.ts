@@ -0,0 +1,53 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssertionResult } from "./AssertionResult";
+import type { ExtractedVariable } from "./ExtractedVariable";
+import type { RunOutcome } from "./RunOutcome";
+
+/**
+ * One request's entry in a [`RunReport`].
+ */
+export type RunRequestResult = { 
+/**
+ * Request ID within the collection.
+ */
+request_id: string, 
+/**
+ * Request name.
+ */
+name: string, 
+/**
+ * HTTP method.
+ */
+method: string, 
+/**
+ * URL as sent, with variables resolved.
+ */
+url: string, 
+/**
+ * Outcome of the request.
+ */
+outcome: RunOutcome, 
+/**
+ * Response status, when a response arrived.
+ */
+status?: number, 
+/**
+ * Response status text, when a response arrived.
+ */
+status_text?: string, 
+/**
+ * Error code and message, for errored requests.
+ */
+error?: string, 
+/**
+ * Time spent on the request, in milliseconds.
+ */
+duration_ms: number, 
+/**
+ * Outcome of the request's extraction rules, when a response arrived.
+ */
+extracted?: Array<ExtractedVariable>, 
+/**
+ * Outcome of the request's assertions, when a response arrived.
+ */
+assertions?: Array<AssertionResult>, };
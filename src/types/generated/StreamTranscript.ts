@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StreamFormat } from "./StreamFormat";
+import type { StreamMessage } from "./StreamMessage";
+
+/**
+ * Messages received while streaming a response, kept with the history entry.
+ */
+export type StreamTranscript = { 
+/**
+ * Format the body was decoded as.
+ */
+format: StreamFormat, 
+/**
+ * Decoded messages in arrival order.
+ */
+messages: Array<StreamMessage>, };
@@ -0,0 +1,41 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HttpHeaders } from "./HttpHeaders";
+import type { StreamFormat } from "./StreamFormat";
+import type { StreamMessage } from "./StreamMessage";
+
+/**
+ * Live update for a request executed in streaming mode.
+ */
+export type StreamUpdate = { "kind": "started", 
+/**
+ * HTTP status code.
+ */
+status: number, 
+/**
+ * HTTP status text.
+ */
+status_text: string, 
+/**
+ * Response headers, in wire order.
+ */
+headers: HttpHeaders, 
+/**
+ * Format the body is decoded as.
+ */
+format: StreamFormat, } | { "kind": "message", 
+/**
+ * The decoded message.
+ */
+message: StreamMessage, } | { "kind": "finished", 
+/**
+ * Total request duration in milliseconds.
+ */
+total_ms: number, 
+/**
+ * Number of messages received.
+ */
+message_count: number, } | { "kind": "failed", 
+/**
+ * Error description.
+ */
+error: string, };
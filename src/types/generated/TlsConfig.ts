@@ -0,0 +1,41 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CertFormat } from "./CertFormat";
+import type { TlsVersion } from "./TlsVersion";
+
+/**
+ * TLS settings for a request: client certificate (mTLS), trust store and verification.
+ *
+ * Paths are read by curl when the request runs. Configured per request on
+ * [`RequestParams::tls`] or per environment on `CollectionEnvironment::tls`;
+ * see [`TlsConfig::merged_with`] for how the two combine.
+ */
+export type TlsConfig = { 
+/**
+ * Path to the client certificate: PEM, or a PKCS#12 bundle.
+ */
+client_cert?: string, 
+/**
+ * Format of `client_cert`. Inferred from the extension when omitted
+ * (`.p12` / `.pfx` are PKCS#12, anything else PEM).
+ */
+client_cert_format?: CertFormat, 
+/**
+ * Path to the client private key (PEM). Not needed for PKCS#12 bundles.
+ */
+client_key?: string, 
+/**
+ * Passphrase of the private key or PKCS#12 bundle.
+ */
+client_key_password?: string, 
+/**
+ * Path to a PEM CA bundle to trust instead of the system store (private CAs).
+ */
+ca_bundle?: string, 
+/**
+ * Skip certificate chain and hostname verification. Insecure; local endpoints only.
+ */
+insecure_skip_verify?: boolean, 
+/**
+ * Minimum TLS protocol version to accept.
+ */
+min_tls_version?: TlsVersion, };
@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebSocketDirection } from "./WebSocketDirection";
+import type { WebSocketMessageKind } from "./WebSocketMessageKind";
+
+/**
+ * A message sent or received during a WebSocket session.
+ */
+export type WebSocketMessage = { 
+/**
+ * Zero-based position of the message in the session.
+ */
+seq: number, 
+/**
+ * Milliseconds since the connection was opened.
+ */
+elapsed_ms: number, 
+/**
+ * Which side sent the message.
+ */
+direction: WebSocketDirection, 
+/**
+ * Message kind.
+ */
+kind: WebSocketMessageKind, 
+/**
+ * Payload: base64 for binary messages, the reason for close frames, and
+ * text (lossily decoded for control frames) otherwise.
+ */
+data: string, 
+/**
+ * Status code of a close frame.
+ */
+close_code?: number, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebSocketMessage } from "./WebSocketMessage";
+
+/**
+ * Messages of a WebSocket session, kept with its history entry.
+ */
+export type WebSocketTranscript = { 
+/**
+ * Subprotocol the server selected, if any.
+ */
+protocol?: string, 
+/**
+ * Messages in the order they were sent or received.
+ */
+messages: Array<WebSocketMessage>, 
+/**
+ * Close code of the session, once closed. Absent when the connection
+ * dropped without a close frame.
+ */
+close_code?: number, 
+/**
+ * Close reason of the session, once closed.
+ */
+close_reason?: string, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebSocketMessage } from "./WebSocketMessage";
+import type { WebSocketSessionInfo } from "./WebSocketSessionInfo";
+
+/**
+ * Live update of a WebSocket session.
+ */
+export type WebSocketUpdate = { "kind": "opened", 
+/**
+ * The new session.
+ */
+session: WebSocketSessionInfo, } | { "kind": "message", 
+/**
+ * The message.
+ */
+message: WebSocketMessage, } | { "kind": "closed", 
+/**
+ * Close code, when a close frame was received.
+ */
+code?: number, 
+/**
+ * Close reason.
+ */
+reason: string, 
+/**
+ * Number of messages in the transcript.
+ */
+message_count: number, } | { "kind": "failed", 
+/**
+ * Error description.
+ */
+error: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WireTraceKind } from "./WireTraceKind";
+
+/**
+ * One message of a [`WireTrace`].
+ */
+export type WireTraceEntry = { 
+/**
+ * What the message records.
+ */
+kind: WireTraceKind, 
+/**
+ * The message text, with line endings normalized to `\n`.
+ */
+text: string, 
+/**
+ * Time since the transfer started, in milliseconds.
+ */
+elapsed_ms: number, };
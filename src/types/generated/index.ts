@@ -1,17 +1,85 @@
 // Re-export all generated types from ts-rs
 // These types are generated from Rust structs - do not edit manually
 
+export type { Assertion } from './Assertion';
+export type { AssertionResult } from './AssertionResult';
 export type { CanvasEventHint } from './CanvasEventHint';
 export type { CanvasStateSnapshot } from './CanvasStateSnapshot';
+export type { CertFormat } from './CertFormat';
+export type { CertificateChain } from './CertificateChain';
+export type { CertificateIssue } from './CertificateIssue';
+export type { CollectionRequestExecution } from './CollectionRequestExecution';
 export type { CollectionSource } from './CollectionSource';
+export type { ConnectToOverride } from './ConnectToOverride';
+export type { ConnectionInfo } from './ConnectionInfo';
+export type { Cookie } from './Cookie';
+export type { CookieJarScope } from './CookieJarScope';
 export type { DriftOperation } from './DriftOperation';
+export type { ExtractedVariable } from './ExtractedVariable';
+export type { ExtractionRule } from './ExtractionRule';
+export type { ExtractionSource } from './ExtractionSource';
+export type { GraphqlField } from './GraphqlField';
+export type { GraphqlInputValue } from './GraphqlInputValue';
+export type { GraphqlIssue } from './GraphqlIssue';
+export type { GraphqlOperationInfo } from './GraphqlOperationInfo';
+export type { GraphqlQuery } from './GraphqlQuery';
+export type { GraphqlSchema } from './GraphqlSchema';
+export type { GraphqlSchemaCache } from './GraphqlSchemaCache';
+export type { GraphqlType } from './GraphqlType';
+export type { GraphqlTypeKind } from './GraphqlTypeKind';
 export type { HistoryEntry } from './HistoryEntry';
+export type { HttpHeader } from './HttpHeader';
+export type { HttpHeaders } from './HttpHeaders';
 export type { HttpResponse } from './HttpResponse';
+export type { HttpVersion } from './HttpVersion';
+export type { HurlExport } from './HurlExport';
+export type { HurlExportFile } from './HurlExportFile';
+export type { HurlExportGrouping } from './HurlExportGrouping';
+export type { HurlExportOptions } from './HurlExportOptions';
+export type { JsonType } from './JsonType';
+export type { LatencyStats } from './LatencyStats';
+export type { LoadTestOptions } from './LoadTestOptions';
+export type { LoadTestReport } from './LoadTestReport';
+export type { LoadTestStats } from './LoadTestStats';
+export type { LoadTestUpdate } from './LoadTestUpdate';
+export type { MultipartPart } from './MultipartPart';
 export type { OperationChange } from './OperationChange';
+export type { PeerCertificate } from './PeerCertificate';
+export type { PhaseLatencies } from './PhaseLatencies';
+export type { ProxyConfig } from './ProxyConfig';
+export type { RedirectHop } from './RedirectHop';
+export type { RequestDraft } from './RequestDraft';
 export type { RequestParams } from './RequestParams';
 export type { RequestTiming } from './RequestTiming';
+export type { ResolveOverride } from './ResolveOverride';
+export type { ResponseBodyKind } from './ResponseBodyKind';
+export type { RetryAttempt } from './RetryAttempt';
+export type { RetryPolicy } from './RetryPolicy';
+export type { RunOptions } from './RunOptions';
+export type { RunOutcome } from './RunOutcome';
+export type { RunReport } from './RunReport';
+export type { RunRequestResult } from './RunRequestResult';
+export type { RunUpdate } from './RunUpdate';
 export type { SourceType } from './SourceType';
 export type { SpecRefreshResult } from './SpecRefreshResult';
+export type { StreamFormat } from './StreamFormat';
+export type { StreamMessage } from './StreamMessage';
+export type { StreamTranscript } from './StreamTranscript';
+export type { StreamUpdate } from './StreamUpdate';
 export type { TabSummary } from './TabSummary';
 export type { TabType } from './TabType';
 export type { TemplateSummary } from './TemplateSummary';
+export type { TlsConfig } from './TlsConfig';
+export type { TlsVersion } from './TlsVersion';
+export type { VariableChanged } from './VariableChanged';
+export type { VariableScope } from './VariableScope';
+export type { WebSocketDirection } from './WebSocketDirection';
+export type { WebSocketMessage } from './WebSocketMessage';
+export type { WebSocketMessageKind } from './WebSocketMessageKind';
+export type { WebSocketSend } from './WebSocketSend';
+export type { WebSocketSessionInfo } from './WebSocketSessionInfo';
+export type { WebSocketTranscript } from './WebSocketTranscript';
+export type { WebSocketUpdate } from './WebSocketUpdate';
+export type { WireTrace } from './WireTrace';
+export type { WireTraceEntry } from './WireTraceEntry';
+export type { WireTraceKind } from './WireTraceKind';
@@ -0,0 +1,63 @@
+/**
+ * Copyright (c) 2026 BaseState LLC
+ * SPDX-License-Identifier: MIT
+ */
+
+import { describe, expect, it } from 'vitest';
+import { applyStreamUpdate, streamTranscriptText, type HttpResponse } from './http';
+
+describe('applyStreamUpdate', () => {
+  const started = (): HttpResponse | null =>
+    applyStreamUpdate(null, {
+      kind: 'started',
+      status: 200,
+      status_text: 'OK',
+      headers: [{ name: 'content-type', value: 'text/event-stream' }],
+      format: 'sse',
+    });
+
+  it('opens a provisional response on start', () => {
+    const response = started();
+
+    expect(response?.status).toBe(200);
+    expect(response?.body).toBe('');
+    expect(response?.stream).toEqual({ format: 'sse', messages: [] });
+  });
+
+  it('appends each message to the body and the transcript', () => {
+    let response = started();
+    response = applyStreamUpdate(response, {
+      kind: 'message',
+      message: { seq: 0, elapsed_ms: 10, data: 'one' },
+    });
+    response = applyStreamUpdate(response, {
+      kind: 'message',
+      message: { seq: 1, elapsed_ms: 25, data: 'two' },
+    });
+
+    expect(response?.body).toBe('one\ntwo');
+    expect(response?.stream?.messages).toHaveLength(2);
+    expect(response?.timing.total_ms).toBe(25);
+  });
+
+  it('ignores messages before the stream starts', () => {
+    const response = applyStreamUpdate(null, {
+      kind: 'message',
+      message: { seq: 0, elapsed_ms: 10, data: 'early' },
+    });
+
+    expect(response).toBeNull();
+  });
+});
+
+describe('streamTranscriptText', () => {
+  it('joins messages one per line', () => {
+    const messages = [
+      { seq: 0, elapsed_ms: 1, data: '{"a":1}' },
+      { seq: 1, elapsed_ms: 2, data: '{"a":2}' },
+    ];
+
+    expect(streamTranscriptText({ format: 'ndjson', messages })).toBe('{"a":1}\n{"a":2}');
+    expect(streamTranscriptText({ format: 'raw', messages })).toBe('{"a":1}{"a":2}');
+  });
+});
@@ -13,9 +13,69 @@
  */
 
 // Re-export generated types from Rust
-export type { HttpResponse, RequestParams, RequestTiming } from './generated/index';
+export type {
+  ConnectionInfo,
+  HttpHeader,
+  HttpHeaders,
+  HttpResponse,
+  HttpVersion,
+  LatencyStats,
+  LoadTestOptions,
+  LoadTestReport,
+  LoadTestStats,
+  LoadTestUpdate,
+  MultipartPart,
+  PhaseLatencies,
+  RequestParams,
+  RequestTiming,
+  RetryAttempt,
+  RetryPolicy,
+  StreamTranscript,
+  WebSocketDirection,
+  WebSocketMessage,
+  WebSocketMessageKind,
+  WebSocketSend,
+  WebSocketSessionInfo,
+  WebSocketTranscript,
+  WebSocketUpdate,
+  WireTrace,
+  WireTraceEntry,
+  WireTraceKind,
+} from './generated/index';
 
-import { type RequestParams } from './generated/index';
+import {
+  type HttpHeaders,
+  type HttpResponse,
+  type LoadTestUpdate,
+  type RequestParams,
+  type StreamTranscript,
+  type StreamUpdate,
+  type WebSocketUpdate,
+} from './generated/index';
+
+/**
+ * Payload of the `http:stream` event emitted while a streaming request runs.
+ *
+ * Mirrors Rust's `HttpStreamEvent` (a `StreamUpdate` flattened next to its
+ * correlation ID), which ts-rs cannot generate because of the flatten.
+ */
+export type HttpStreamEvent = { correlation_id: string } & StreamUpdate;
+
+/**
+ * Payload of the `load_test` event emitted while a load test runs.
+ *
+ * Mirrors Rust's `LoadTestEvent` (a `LoadTestUpdate` flattened next to its
+ * load test ID), which ts-rs cannot generate because of the flatten.
+ */
+export type LoadTestEvent = { load_test_id: string } & LoadTestUpdate;
+
+/**
+ * Payload of the `websocket` event emitted while a WebSocket session is open.
+ *
+ * Mirrors Rust's `WebSocketEvent` (a `WebSocketUpdate` flattened next to its
+ * session ID), which ts-rs cannot generate because of the flatten.
+ */
+export type WebSocketEvent = { session_id: string } & WebSocketUpdate;
 
 /**
  * HTTP methods supported by the API client.
@@ -27,19 +87,122 @@ export type HttpMethod = 'GET' | 'POST' | 'PUT' | 'PATCH' | 'DELETE' | 'HEAD' |
  */
 export const DEFAULT_TIMEOUT_MS = 30000;
 
+/**
+ * Build an ordered header list from a name → value record.
+ */
+export function headersFromRecord(record: Record<string, string>): HttpHeaders {
+  return Object.entries(record).map(([name, value]) => ({ name, value }));
+}
+
+/**
+ * Collapse a header list into a name → value record for editing.
+ *
+ * Repeated names are joined with `, ` (the HTTP list form) so no value is lost.
+ */
+export function headersToRecord(headers: HttpHeaders): Record<string, string> {
+  const record: Record<string, string> = {};
+  for (const { name, value } of headers) {
+    const existing = record[name];
+    record[name] = existing !== undefined ? `${existing}, ${value}` : value;
+  }
+  return record;
+}
+
+/**
+ * Value of the first header named `name` (case-insensitive).
+ */
+export function getHeader(headers: HttpHeaders, name: string): string | undefined {
+  const lower = name.toLowerCase();
+  return headers.find((header) => header.name.toLowerCase() === lower)?.value;
+}
+
+/**
+ * Text of a streamed response: its messages one per line, or raw chunks
+ * joined as they arrived.
+ */
+export function streamTranscriptText(stream: StreamTranscript): string {
+  const separator = stream.format === 'raw' ? '' : '\n';
+  return stream.messages.map((message) => message.data).join(separator);
+}
+
+/**
+ * Apply an `http:stream` update to the provisional response of a request
+ * that is still streaming.
+ *
+ * `started` opens the response with its status and headers; each `message`
+ * is appended to `stream` and to `body`, so the response viewer shows the
+ * stream as it grows. `finished` and `failed` leave it as is; the final
+ * response comes from `executeStreamingRequest`.
+ */
+export function applyStreamUpdate(
+  response: HttpResponse | null,
+  update: StreamUpdate
+): HttpResponse | null {
+  switch (update.kind) {
+    case 'started':
+      return {
+        status: update.status,
+        status_text: update.status_text,
+        headers: update.headers,
+        body: '',
+        timing: { total_ms: 0, dns_ms: null, connect_ms: null, tls_ms: null, first_byte_ms: null },
+        stream: { format: update.format, messages: [] },
+      };
+    case 'message': {
+      if (response?.stream === undefined) {
+        return response;
+      }
+      const { format, messages } = response.stream;
+      const separator = format === 'raw' || response.body === '' ? '' : '\n';
+      return {
+        ...response,
+        body: `${response.body}${separator}${update.message.data}`,
+        timing: { ...response.timing, total_ms: update.message.elapsed_ms },
+        stream: { format, messages: [...messages, update.message] },
+      };
+    }
+    case 'finished':
+    case 'failed':
+      return response;
+  }
+}
+
 /**
  * Create default request params with sensible defaults.
  */
 export function createRequestParams(
   url: string,
   method: HttpMethod = 'GET',
-  options?: Partial<Pick<RequestParams, 'headers' | 'body' | 'timeout_ms'>>
+  options?: Partial<
+    Pick<
+      RequestParams,
+      | 'body'
+      | 'body_file'
+      | 'multipart'
+      | 'timeout_ms'
+      | 'tls'
+      | 'proxy'
+      | 'cookie_jar'
+      | 'http_version'
+      | 'retry'
+    > & {
+      headers: Record<string, string> | HttpHeaders;
+    }
+  >
 ): RequestParams {
+  const headers = options?.headers ?? [];
   return {
     url,
     method,
-    headers: options?.headers ?? {},
+    headers: Array.isArray(headers) ? headers : headersFromRecord(headers),
     body: options?.body ?? null,
+    ...(options?.body_file !== undefined && { body_file: options.body_file }),
+    ...(options?.multipart !== undefined && { multipart: options.multipart }),
     timeout_ms: options?.timeout_ms ?? DEFAULT_TIMEOUT_MS,
+    ...(options?.tls !== undefined && { tls: options.tls }),
+    ...(options?.proxy !== undefined && { proxy: options.proxy }),
+    ...(options?.cookie_jar !== undefined && { cookie_jar: options.cookie_jar }),
+    ...(options?.http_version !== undefined && { http_version: options.http_version }),
+    ...(options?.retry !== undefined && { retry: options.retry }),
   };
 }
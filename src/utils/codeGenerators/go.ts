@@ -4,6 +4,7 @@
  */
 
 import type { NetworkHistoryEntry } from '@/types/history';
+import { headersToRecord } from '@/types/http';
 
 /**
  * Escape a string for use in a Go string literal.
@@ -35,7 +36,8 @@ function formatHeaders(headers: Record<string, string>): string {
  * @returns Go code string that reproduces the request
  */
 export function generateGoCode(entry: NetworkHistoryEntry): string {
-  const { method, url, headers, body } = entry.request;
+  const { method, url, body } = entry.request;
+  const headers = headersToRecord(entry.request.headers);
 
   const lines: string[] = [
     'package main',
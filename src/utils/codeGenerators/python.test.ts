@@ -14,14 +14,14 @@ describe('generatePythonCode', () => {
     request: {
       url: 'https://api.example.com/users',
       method: 'GET',
-      headers: {},
+      headers: [],
       body: null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_ms: 100,
@@ -49,7 +49,7 @@ describe('generatePythonCode', () => {
       request: {
         url: 'https://api.example.com/users',
         method: 'POST',
-        headers: { 'Content-Type': 'application/json' },
+        headers: [{ name: 'Content-Type', value: 'application/json' }],
         body: '{"name":"John"}',
         timeout_ms: 30000,
       },
@@ -66,7 +66,7 @@ describe('generatePythonCode', () => {
       request: {
         url: 'https://api.example.com/users',
         method: 'GET',
-        headers: { Authorization: 'Bearer token123' },
+        headers: [{ name: 'Authorization', value: 'Bearer token123' }],
         body: null,
         timeout_ms: 30000,
       },
@@ -4,6 +4,7 @@
  */
 
 import type { NetworkHistoryEntry } from '@/types/history';
+import { headersToRecord } from '@/types/http';
 
 /**
  * Escape a string for use in a Python string literal.
@@ -66,7 +67,8 @@ function convertToPythonDict(obj: unknown): string {
  * @returns Python code string that reproduces the request
  */
 export function generatePythonCode(entry: NetworkHistoryEntry): string {
-  const { method, url, headers, body } = entry.request;
+  const { method, url, body } = entry.request;
+  const headers = headersToRecord(entry.request.headers);
 
   const lines: string[] = ['import requests', ''];
 
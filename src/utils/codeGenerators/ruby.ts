@@ -4,6 +4,7 @@
  */
 
 import type { NetworkHistoryEntry } from '@/types/history';
+import { headersToRecord } from '@/types/http';
 
 /**
  * Escape a string for use in a Ruby string literal.
@@ -19,7 +20,8 @@ function escapeRubyString(str: string): string {
  * @returns Ruby code string that reproduces the request
  */
 export function generateRubyCode(entry: NetworkHistoryEntry): string {
-  const { method, url, headers, body } = entry.request;
+  const { method, url, body } = entry.request;
+  const headers = headersToRecord(entry.request.headers);
 
   const lines: string[] = ['require "net/http"', 'require "json"', ''];
 
@@ -6,6 +6,7 @@
 import { describe, it, expect } from 'vitest';
 import { generateCurlCommand } from './curl';
 import type { NetworkHistoryEntry } from '@/types/history';
+import { headersFromRecord } from '@/types/http';
 
 /**
  * Create a minimal NetworkHistoryEntry for testing.
@@ -24,14 +25,14 @@ function createMockEntry(
     request: {
       method: overrides.method ?? 'GET',
       url: overrides.url ?? 'https://api.example.com/users',
-      headers: overrides.headers ?? {},
+      headers: headersFromRecord(overrides.headers ?? {}),
       body: overrides.body ?? null,
       timeout_ms: 30000,
     },
     response: {
       status: 200,
       status_text: 'OK',
-      headers: {},
+      headers: [],
       body: '{}',
       timing: {
         total_ms: 100,
@@ -119,6 +120,18 @@ describe('generateCurlCommand', () => {
     expect(curl).toContain("-H 'X-Request-ID: abc-123'");
   });
 
+  it('repeats headers that occur more than once', () => {
+    const entry = createMockEntry();
+    entry.request.headers = [
+      { name: 'Accept', value: 'application/json' },
+      { name: 'Accept', value: 'text/plain' },
+    ];
+
+    const curl = generateCurlCommand(entry);
+
+    expect(curl).toContain("-H 'Accept: application/json' \\\n  -H 'Accept: text/plain'");
+  });
+
   it('escapes single quotes in URL', () => {
     const entry = createMockEntry({
       url: "https://api.example.com/search?q=it's",
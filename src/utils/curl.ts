@@ -28,8 +28,8 @@ export function generateCurlCommand(entry: NetworkHistoryEntry): string {
   parts.push(`-X ${method.toUpperCase()}`);
 
   // Add headers
-  for (const [key, value] of Object.entries(headers)) {
-    parts.push(`-H '${escapeShellArg(key)}: ${escapeShellArg(value)}'`);
+  for (const { name, value } of headers) {
+    parts.push(`-H '${escapeShellArg(name)}: ${escapeShellArg(value)}'`);
   }
 
   // Add body for methods that support it